@@ -0,0 +1,21 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AirdropError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    Unauthorized = 3,
+    DropNotFound = 4,
+    InvalidAmount = 5,
+    InvalidExpiry = 6,
+    SnapshotNotFound = 7,
+    DropExpired = 8,
+    NotExpired = 9,
+    AlreadyClaimed = 10,
+    InvalidProof = 11,
+    WrongDropKind = 12,
+    InsufficientFunds = 13,
+    AlreadySwept = 14,
+}
@@ -0,0 +1,39 @@
+use soroban_sdk::{contractevent, Address, BytesN};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+}
+
+/// Emitted when a drop is funded and its root published.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DropCreatedEvent {
+    #[topic]
+    pub drop_id: u32,
+    pub token: Address,
+    pub root: BytesN<32>,
+    pub funded_amount: i128,
+    pub expires_at: u64,
+}
+
+/// Emitted for every successful claim.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimedEvent {
+    #[topic]
+    pub claimant: Address,
+    pub drop_id: u32,
+    pub amount: i128,
+}
+
+/// Emitted when the unclaimed remainder of an expired drop is swept.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DropSweptEvent {
+    #[topic]
+    pub drop_id: u32,
+    pub recipient: Address,
+    pub amount: i128,
+}
@@ -0,0 +1,323 @@
+#![no_std]
+
+mod errors;
+mod events;
+mod merkle;
+mod registry;
+mod storage;
+
+use errors::AirdropError;
+use registry::RegistryClient;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+use storage::{DataKey, Drop, DropKind, PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_THRESHOLD};
+
+#[contract]
+pub struct AirdropContract;
+
+#[contractimpl]
+impl AirdropContract {
+    /// Check that `admin` is the stored admin and has signed
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), AirdropError> {
+        let stored_admin = Self::get_admin(env.clone())?;
+        if *admin != stored_admin {
+            return Err(AirdropError::Unauthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
+
+    fn load_drop(env: &Env, drop_id: u32) -> Result<Drop, AirdropError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Drop(drop_id))
+            .ok_or(AirdropError::DropNotFound)
+    }
+
+    /// Write a drop back and keep it clear of archival
+    fn save_drop(env: &Env, drop: &Drop) {
+        let key = DataKey::Drop(drop.id);
+        env.storage().persistent().set(&key, drop);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// Escrow `funded_amount` from the admin and store a new drop
+    fn open_drop(
+        env: &Env,
+        admin: &Address,
+        token: Address,
+        root: BytesN<32>,
+        kind: DropKind,
+        funded_amount: i128,
+        expires_at: u64,
+    ) -> Result<u32, AirdropError> {
+        if funded_amount <= 0 {
+            return Err(AirdropError::InvalidAmount);
+        }
+        if expires_at <= env.ledger().timestamp() {
+            return Err(AirdropError::InvalidExpiry);
+        }
+
+        TokenClient::new(env, &token).transfer(
+            admin,
+            env.current_contract_address(),
+            &funded_amount,
+        );
+
+        let drop_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextDropId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextDropId, &(drop_id + 1));
+        let drop = Drop {
+            id: drop_id,
+            token: token.clone(),
+            root: root.clone(),
+            kind,
+            funded_amount,
+            claimed_amount: 0,
+            expires_at,
+            swept: false,
+        };
+        Self::save_drop(env, &drop);
+
+        events::DropCreatedEvent {
+            drop_id,
+            token,
+            root,
+            funded_amount,
+            expires_at,
+        }
+        .publish(env);
+        Ok(drop_id)
+    }
+
+    /// Check `proof` proves `leaf` and pay `amount` out of the drop, once
+    /// per claimant
+    fn settle(
+        env: &Env,
+        claimant: Address,
+        mut drop: Drop,
+        leaf: BytesN<32>,
+        proof: &Vec<BytesN<32>>,
+        amount: i128,
+    ) -> Result<i128, AirdropError> {
+        if drop.swept || env.ledger().timestamp() >= drop.expires_at {
+            return Err(AirdropError::DropExpired);
+        }
+        let claimed_key = DataKey::Claimed(drop.id, claimant.clone());
+        if env.storage().persistent().has(&claimed_key) {
+            return Err(AirdropError::AlreadyClaimed);
+        }
+        if !merkle::verify(env, &drop.root, leaf, proof) {
+            return Err(AirdropError::InvalidProof);
+        }
+        if amount <= 0 {
+            return Err(AirdropError::InvalidAmount);
+        }
+        if amount > drop.funded_amount - drop.claimed_amount {
+            return Err(AirdropError::InsufficientFunds);
+        }
+
+        drop.claimed_amount += amount;
+        Self::save_drop(env, &drop);
+        env.storage().persistent().set(&claimed_key, &true);
+        env.storage().persistent().extend_ttl(
+            &claimed_key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+        TokenClient::new(env, &drop.token).transfer(
+            &env.current_contract_address(),
+            &claimant,
+            &amount,
+        );
+
+        events::ClaimedEvent {
+            claimant,
+            drop_id: drop.id,
+            amount,
+        }
+        .publish(env);
+        Ok(amount)
+    }
+
+    /// Initialize the contract with an admin address
+    pub fn initialize(env: Env, admin: Address) -> Result<(), AirdropError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(AirdropError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::NextDropId, &0u32);
+
+        events::InitializedEvent { admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Publish `root`, a Merkle root of `(address, amount)` allocations, and
+    /// escrow `funded_amount` of `token` from the admin to pay them. Claims
+    /// close at `expires_at`. Returns the drop ID.
+    pub fn create_drop(
+        env: Env,
+        admin: Address,
+        token: Address,
+        root: BytesN<32>,
+        funded_amount: i128,
+        expires_at: u64,
+    ) -> Result<u32, AirdropError> {
+        Self::require_admin(&env, &admin)?;
+        Self::open_drop(
+            &env,
+            &admin,
+            token,
+            root,
+            DropKind::Allocations,
+            funded_amount,
+            expires_at,
+        )
+    }
+
+    /// Open a drop over contributor registry snapshot `epoch`, paying
+    /// `amount_per_point` for each point of score. The root is read from
+    /// the registry itself, so nobody has to be trusted to compute it.
+    /// Claims are first come, first served once `funded_amount` runs out.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_snapshot_drop(
+        env: Env,
+        admin: Address,
+        token: Address,
+        registry: Address,
+        epoch: u32,
+        amount_per_point: i128,
+        funded_amount: i128,
+        expires_at: u64,
+    ) -> Result<u32, AirdropError> {
+        Self::require_admin(&env, &admin)?;
+        if amount_per_point <= 0 {
+            return Err(AirdropError::InvalidAmount);
+        }
+        let root = match RegistryClient::new(&env, &registry).try_get_snapshot_root(&epoch) {
+            Ok(Ok(root)) => root,
+            _ => return Err(AirdropError::SnapshotNotFound),
+        };
+        Self::open_drop(
+            &env,
+            &admin,
+            token,
+            root,
+            DropKind::Snapshot(registry, epoch, amount_per_point),
+            funded_amount,
+            expires_at,
+        )
+    }
+
+    /// Claim `amount` from an allocations drop, proving `(claimant, amount)`
+    /// is a leaf of its root. Returns the amount paid.
+    pub fn claim(
+        env: Env,
+        claimant: Address,
+        drop_id: u32,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<i128, AirdropError> {
+        claimant.require_auth();
+        let drop = Self::load_drop(&env, drop_id)?;
+        if drop.kind != DropKind::Allocations {
+            return Err(AirdropError::WrongDropKind);
+        }
+        let leaf = merkle::leaf(&env, &claimant, amount);
+        Self::settle(&env, claimant, drop, leaf, &proof, amount)
+    }
+
+    /// Claim from a snapshot drop, proving `claimant` held `score` in the
+    /// snapshot. Returns the amount paid.
+    pub fn claim_snapshot(
+        env: Env,
+        claimant: Address,
+        drop_id: u32,
+        score: u64,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<i128, AirdropError> {
+        claimant.require_auth();
+        let drop = Self::load_drop(&env, drop_id)?;
+        let DropKind::Snapshot(_, _, amount_per_point) = drop.kind else {
+            return Err(AirdropError::WrongDropKind);
+        };
+        let amount = (score as i128)
+            .checked_mul(amount_per_point)
+            .ok_or(AirdropError::InvalidAmount)?;
+        let leaf = merkle::leaf(&env, &claimant, score);
+        Self::settle(&env, claimant, drop, leaf, &proof, amount)
+    }
+
+    /// Return whatever an expired drop still holds to the admin. Returns
+    /// the amount swept.
+    pub fn sweep(env: Env, admin: Address, drop_id: u32) -> Result<i128, AirdropError> {
+        Self::require_admin(&env, &admin)?;
+        let mut drop = Self::load_drop(&env, drop_id)?;
+        if drop.swept {
+            return Err(AirdropError::AlreadySwept);
+        }
+        if env.ledger().timestamp() < drop.expires_at {
+            return Err(AirdropError::NotExpired);
+        }
+
+        let amount = drop.funded_amount - drop.claimed_amount;
+        drop.swept = true;
+        Self::save_drop(&env, &drop);
+        if amount > 0 {
+            TokenClient::new(&env, &drop.token).transfer(
+                &env.current_contract_address(),
+                &admin,
+                &amount,
+            );
+        }
+
+        events::DropSweptEvent {
+            drop_id,
+            recipient: admin,
+            amount,
+        }
+        .publish(&env);
+        Ok(amount)
+    }
+
+    /// Get a drop by ID
+    pub fn get_drop(env: Env, drop_id: u32) -> Result<Drop, AirdropError> {
+        Self::load_drop(&env, drop_id)
+    }
+
+    /// Whether `claimant` has already claimed from a drop
+    pub fn has_claimed(env: Env, drop_id: u32, claimant: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Claimed(drop_id, claimant))
+    }
+
+    /// Number of drops ever created; IDs run from 0 to this count
+    pub fn get_drop_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextDropId)
+            .unwrap_or(0)
+    }
+
+    /// Get admin address
+    pub fn get_admin(env: Env) -> Result<Address, AirdropError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(AirdropError::NotInitialized)
+    }
+}
+
+#[cfg(test)]
+mod test;
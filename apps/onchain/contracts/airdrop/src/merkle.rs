@@ -0,0 +1,38 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, IntoVal, Val, Vec};
+
+// Same tree as the contributor registry's snapshots, so a snapshot root can
+// be used as a drop root unchanged. Leaves are `sha256(xdr((address, value)))`
+// and interior nodes hash their two children in ascending byte order, so
+// proofs need no left/right flags.
+
+pub(crate) fn leaf<T: IntoVal<Env, Val>>(env: &Env, address: &Address, value: T) -> BytesN<32> {
+    let value: Val = value.into_val(env);
+    env.crypto()
+        .sha256(&(address.clone(), value).to_xdr(env))
+        .into()
+}
+
+pub(crate) fn parent(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (lo, hi) = if a.to_array() <= b.to_array() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let mut buf = Bytes::from_array(env, &lo.to_array());
+    buf.extend_from_array(&hi.to_array());
+    env.crypto().sha256(&buf).into()
+}
+
+pub(crate) fn verify(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    let mut node = leaf;
+    for sibling in proof.iter() {
+        node = parent(env, &node, &sibling);
+    }
+    node == *root
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::{contractclient, BytesN, Env};
+
+/// The slice of the contributor registry's interface this contract reads.
+// Only the generated client is called; the trait itself is never implemented.
+#[allow(dead_code)]
+#[contractclient(name = "RegistryClient")]
+pub trait Registry {
+    fn get_snapshot_root(env: Env, epoch: u32) -> BytesN<32>;
+}
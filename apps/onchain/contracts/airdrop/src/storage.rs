@@ -0,0 +1,45 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Drops and claim receipts touched by any write are kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,                 // -> Address
+    Drop(u32),             // drop id -> Drop
+    Claimed(u32, Address), // (drop id, claimant) -> bool
+    NextDropId,            // -> u32
+}
+
+/// What a drop's Merkle leaves commit to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DropKind {
+    /// Leaves are `(address, amount)` with `amount` an `i128` of the token.
+    Allocations,
+    /// The root of a contributor registry snapshot; leaves are
+    /// `(address, score)` and each point pays `amount_per_point`.
+    Snapshot(Address, u32, i128),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Drop {
+    pub id: u32,
+    pub token: Address,
+    pub root: BytesN<32>,
+    pub kind: DropKind,
+    /// Tokens escrowed for claims when the drop was created.
+    pub funded_amount: i128,
+    pub claimed_amount: i128,
+    /// Claims close at this timestamp; what is left may then be swept.
+    pub expires_at: u64,
+    pub swept: bool,
+}
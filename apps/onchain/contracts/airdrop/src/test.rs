@@ -0,0 +1,245 @@
+use crate::errors::AirdropError;
+use crate::merkle;
+use crate::storage::DropKind;
+use crate::{AirdropContract, AirdropContractClient};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, BytesN, Env, Vec,
+};
+
+/// Stands in for the contributor registry's `get_snapshot_root`.
+#[contract]
+struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_root(env: Env, epoch: u32, root: BytesN<32>) {
+        env.storage().instance().set(&epoch, &root);
+    }
+
+    pub fn get_snapshot_root(env: Env, epoch: u32) -> BytesN<32> {
+        env.storage().instance().get(&epoch).unwrap()
+    }
+}
+
+const EXPIRY: u64 = 30 * 86_400;
+
+struct Setup<'a> {
+    env: Env,
+    client: AirdropContractClient<'a>,
+    admin: Address,
+    alice: Address,
+    bob: Address,
+    carol: Address,
+    token: TokenClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    StellarAssetClient::new(&env, &asset.address()).mint(&admin, &10_000);
+    let token = TokenClient::new(&env, &asset.address());
+
+    let contract_id = env.register(AirdropContract, ());
+    let client = AirdropContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    Setup {
+        alice: Address::generate(&env),
+        bob: Address::generate(&env),
+        carol: Address::generate(&env),
+        env,
+        client,
+        admin,
+        token,
+    }
+}
+
+/// Three leaves give root = H(H(a, b), c), with c carried up one level.
+/// Returns the root and each leaf's proof.
+fn tree(
+    s: &Setup,
+    a: BytesN<32>,
+    b: BytesN<32>,
+    c: BytesN<32>,
+) -> (BytesN<32>, [Vec<BytesN<32>>; 3]) {
+    let ab = merkle::parent(&s.env, &a, &b);
+    let root = merkle::parent(&s.env, &ab, &c);
+    (
+        root,
+        [
+            vec![&s.env, b.clone(), c.clone()],
+            vec![&s.env, a, c],
+            vec![&s.env, ab],
+        ],
+    )
+}
+
+/// An allocations drop paying alice 100, bob 200 and carol 300
+fn allocations(s: &Setup) -> (u32, [Vec<BytesN<32>>; 3]) {
+    let (root, proofs) = tree(
+        s,
+        merkle::leaf(&s.env, &s.alice, 100i128),
+        merkle::leaf(&s.env, &s.bob, 200i128),
+        merkle::leaf(&s.env, &s.carol, 300i128),
+    );
+    let expires_at = s.env.ledger().timestamp() + EXPIRY;
+    let drop_id = s
+        .client
+        .create_drop(&s.admin, &s.token.address, &root, &600, &expires_at);
+    (drop_id, proofs)
+}
+
+#[test]
+fn test_claim_with_proof() {
+    let s = setup();
+    let (drop_id, [alice_proof, bob_proof, carol_proof]) = allocations(&s);
+    assert_eq!(s.token.balance(&s.client.address), 600);
+
+    assert_eq!(s.client.claim(&s.alice, &drop_id, &100, &alice_proof), 100);
+    assert_eq!(s.client.claim(&s.bob, &drop_id, &200, &bob_proof), 200);
+    assert_eq!(s.client.claim(&s.carol, &drop_id, &300, &carol_proof), 300);
+    assert_eq!(s.token.balance(&s.alice), 100);
+    assert_eq!(s.token.balance(&s.carol), 300);
+    assert!(s.client.has_claimed(&drop_id, &s.bob));
+    assert_eq!(s.client.get_drop(&drop_id).claimed_amount, 600);
+}
+
+#[test]
+fn test_claim_rejects_bad_proofs_and_repeats() {
+    let s = setup();
+    let (drop_id, [alice_proof, bob_proof, _]) = allocations(&s);
+
+    assert_eq!(
+        s.client.try_claim(&s.alice, &drop_id, &101, &alice_proof),
+        Err(Ok(AirdropError::InvalidProof))
+    );
+    assert_eq!(
+        s.client.try_claim(&s.alice, &drop_id, &200, &bob_proof),
+        Err(Ok(AirdropError::InvalidProof))
+    );
+    s.client.claim(&s.alice, &drop_id, &100, &alice_proof);
+    assert_eq!(
+        s.client.try_claim(&s.alice, &drop_id, &100, &alice_proof),
+        Err(Ok(AirdropError::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_expiry_and_sweep() {
+    let s = setup();
+    let (drop_id, [alice_proof, bob_proof, _]) = allocations(&s);
+    s.client.claim(&s.alice, &drop_id, &100, &alice_proof);
+
+    assert_eq!(
+        s.client.try_sweep(&s.admin, &drop_id),
+        Err(Ok(AirdropError::NotExpired))
+    );
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + EXPIRY);
+    assert_eq!(
+        s.client.try_claim(&s.bob, &drop_id, &200, &bob_proof),
+        Err(Ok(AirdropError::DropExpired))
+    );
+    assert_eq!(s.client.sweep(&s.admin, &drop_id), 500);
+    assert_eq!(s.token.balance(&s.admin), 9_900);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert_eq!(
+        s.client.try_sweep(&s.admin, &drop_id),
+        Err(Ok(AirdropError::AlreadySwept))
+    );
+}
+
+#[test]
+fn test_snapshot_drop_pays_per_point() {
+    let s = setup();
+    let registry = MockRegistryClient::new(&s.env, &s.env.register(MockRegistry, ()));
+    // Registry leaves commit to `u64` scores
+    let (root, [alice_proof, bob_proof, carol_proof]) = tree(
+        &s,
+        merkle::leaf(&s.env, &s.alice, 10u64),
+        merkle::leaf(&s.env, &s.bob, 20u64),
+        merkle::leaf(&s.env, &s.carol, 30u64),
+    );
+    registry.set_root(&4, &root);
+    let expires_at = s.env.ledger().timestamp() + EXPIRY;
+
+    assert_eq!(
+        s.client.try_create_snapshot_drop(
+            &s.admin,
+            &s.token.address,
+            &registry.address,
+            &5,
+            &5,
+            &500,
+            &expires_at
+        ),
+        Err(Ok(AirdropError::SnapshotNotFound))
+    );
+    let drop_id = s.client.create_snapshot_drop(
+        &s.admin,
+        &s.token.address,
+        &registry.address,
+        &4,
+        &5,
+        &250,
+        &expires_at,
+    );
+    let drop = s.client.get_drop(&drop_id);
+    assert_eq!(drop.root, root);
+    assert_eq!(
+        drop.kind,
+        DropKind::Snapshot(registry.address.clone(), 4, 5)
+    );
+
+    assert_eq!(
+        s.client
+            .claim_snapshot(&s.alice, &drop_id, &10, &alice_proof),
+        50
+    );
+    assert_eq!(
+        s.client.claim_snapshot(&s.bob, &drop_id, &20, &bob_proof),
+        100
+    );
+    // Only 100 of the 250 funded remain for carol's 150
+    assert_eq!(
+        s.client
+            .try_claim_snapshot(&s.carol, &drop_id, &30, &carol_proof),
+        Err(Ok(AirdropError::InsufficientFunds))
+    );
+    assert_eq!(
+        s.client.try_claim(&s.carol, &drop_id, &30, &carol_proof),
+        Err(Ok(AirdropError::WrongDropKind))
+    );
+}
+
+#[test]
+fn test_create_validates_input() {
+    let s = setup();
+    let root = BytesN::from_array(&s.env, &[7u8; 32]);
+    let now = s.env.ledger().timestamp();
+    let stranger = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client
+            .try_create_drop(&s.admin, &s.token.address, &root, &0, &(now + 10)),
+        Err(Ok(AirdropError::InvalidAmount))
+    );
+    assert_eq!(
+        s.client
+            .try_create_drop(&s.admin, &s.token.address, &root, &100, &now),
+        Err(Ok(AirdropError::InvalidExpiry))
+    );
+    assert_eq!(
+        s.client
+            .try_create_drop(&stranger, &s.token.address, &root, &100, &(now + 10)),
+        Err(Ok(AirdropError::Unauthorized))
+    );
+    assert_eq!(s.client.get_drop_count(), 0);
+}
@@ -0,0 +1,16 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BadgeError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    Unauthorized = 3,
+    BadgeTypeNotFound = 4,
+    BadgeTypeExists = 5,
+    TooManyBadgeTypes = 6,
+    InvalidMetadata = 7,
+    AlreadyHeld = 8,
+    BadgeNotFound = 9,
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::{contractevent, Address, Symbol};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+    pub registry: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModeratorUpdatedEvent {
+    #[topic]
+    pub moderator: Address,
+    pub enabled: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BadgeTypeDefinedEvent {
+    #[topic]
+    pub badge_type: Symbol,
+    pub min_score: Option<u64>,
+}
+
+/// Emitted when a badge is minted to a contributor.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BadgeIssuedEvent {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub badge_type: Symbol,
+    pub badge_id: u64,
+}
@@ -0,0 +1,295 @@
+#![no_std]
+
+mod errors;
+mod events;
+mod storage;
+
+use errors::BadgeError;
+use notification_interface::ReputationHookTrait;
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+use storage::{
+    Badge, BadgeType, DataKey, MAX_SCORE_BADGES, MAX_URI_LEN, PERSISTENT_BUMP_AMOUNT,
+    PERSISTENT_BUMP_THRESHOLD,
+};
+
+#[contract]
+pub struct BadgesContract;
+
+#[contractimpl]
+impl BadgesContract {
+    /// Check that `admin` is the stored admin and has signed
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), BadgeError> {
+        let stored_admin = Self::get_admin(env.clone())?;
+        if *admin != stored_admin {
+            return Err(BadgeError::Unauthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
+
+    /// `issuer` must be the admin or a moderator, and have signed
+    fn require_issuer(env: &Env, issuer: &Address) -> Result<(), BadgeError> {
+        let admin = Self::get_admin(env.clone())?;
+        if *issuer != admin && !Self::is_moderator(env.clone(), issuer.clone()) {
+            return Err(BadgeError::Unauthorized);
+        }
+        issuer.require_auth();
+        Ok(())
+    }
+
+    /// Write a persistent entry and keep it clear of archival
+    fn save<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        env.storage().persistent().extend_ttl(
+            key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// Mint a badge of `badge_type` to `owner`, who may hold one of each type
+    fn mint(
+        env: &Env,
+        owner: &Address,
+        badge_type: &Symbol,
+        definition: &BadgeType,
+    ) -> Result<u64, BadgeError> {
+        let held_key = DataKey::Held(owner.clone(), badge_type.clone());
+        if env.storage().persistent().has(&held_key) {
+            return Err(BadgeError::AlreadyHeld);
+        }
+
+        let badge_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextBadgeId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextBadgeId, &(badge_id + 1));
+        let badge = Badge {
+            id: badge_id,
+            badge_type: badge_type.clone(),
+            owner: owner.clone(),
+            metadata_uri: definition.metadata_uri.clone(),
+            issued_at: env.ledger().timestamp(),
+        };
+        Self::save(env, &DataKey::Badge(badge_id), &badge);
+        Self::save(env, &held_key, &badge_id);
+        let count = Self::badge_count(env.clone(), owner.clone());
+        Self::save(env, &DataKey::OwnerBadge(owner.clone(), count), &badge_id);
+        Self::save(env, &DataKey::OwnerCount(owner.clone()), &(count + 1));
+
+        events::BadgeIssuedEvent {
+            owner: owner.clone(),
+            badge_type: badge_type.clone(),
+            badge_id,
+        }
+        .publish(env);
+        Ok(badge_id)
+    }
+
+    /// Initialize the contract with an admin and the contributor registry
+    /// whose reputation hook awards score badges
+    pub fn initialize(env: Env, admin: Address, registry: Address) -> Result<(), BadgeError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(BadgeError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Registry, &registry);
+        env.storage().instance().set(&DataKey::NextBadgeId, &0u64);
+
+        events::InitializedEvent { admin, registry }.publish(&env);
+        Ok(())
+    }
+
+    /// Let `moderator` issue badges by hand, or stop them
+    pub fn set_moderator(
+        env: Env,
+        admin: Address,
+        moderator: Address,
+        enabled: bool,
+    ) -> Result<(), BadgeError> {
+        Self::require_admin(&env, &admin)?;
+        let key = DataKey::Moderator(moderator.clone());
+        if enabled {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        events::ModeratorUpdatedEvent { moderator, enabled }.publish(&env);
+        Ok(())
+    }
+
+    /// Whether `address` is a moderator
+    pub fn is_moderator(env: Env, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Moderator(address))
+            .unwrap_or(false)
+    }
+
+    /// Define a badge type. With `min_score` set, the registry hook awards
+    /// it to anyone whose score reaches that value; otherwise moderators
+    /// issue it by hand. Types cannot be redefined once created.
+    pub fn define_badge(
+        env: Env,
+        admin: Address,
+        badge_type: Symbol,
+        metadata_uri: String,
+        min_score: Option<u64>,
+    ) -> Result<(), BadgeError> {
+        Self::require_admin(&env, &admin)?;
+        let key = DataKey::BadgeType(badge_type.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(BadgeError::BadgeTypeExists);
+        }
+        if metadata_uri.is_empty() || metadata_uri.len() > MAX_URI_LEN {
+            return Err(BadgeError::InvalidMetadata);
+        }
+        if min_score.is_some() {
+            let mut score_badges = Self::get_score_badges(env.clone());
+            if score_badges.len() >= MAX_SCORE_BADGES {
+                return Err(BadgeError::TooManyBadgeTypes);
+            }
+            score_badges.push_back(badge_type.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::ScoreBadges, &score_badges);
+        }
+        Self::save(
+            &env,
+            &key,
+            &BadgeType {
+                metadata_uri,
+                min_score,
+            },
+        );
+
+        events::BadgeTypeDefinedEvent {
+            badge_type,
+            min_score,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Award a badge to `owner`. `issuer` must be the admin or a moderator.
+    /// Returns the badge ID.
+    pub fn issue(
+        env: Env,
+        issuer: Address,
+        owner: Address,
+        badge_type: Symbol,
+    ) -> Result<u64, BadgeError> {
+        Self::require_issuer(&env, &issuer)?;
+        let definition = Self::get_badge_type(env.clone(), badge_type.clone())?;
+        Self::mint(&env, &owner, &badge_type, &definition)
+    }
+
+    pub fn get_badge(env: Env, badge_id: u64) -> Result<Badge, BadgeError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Badge(badge_id))
+            .ok_or(BadgeError::BadgeNotFound)
+    }
+
+    pub fn get_badge_type(env: Env, badge_type: Symbol) -> Result<BadgeType, BadgeError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BadgeType(badge_type))
+            .ok_or(BadgeError::BadgeTypeNotFound)
+    }
+
+    /// Badge types the registry hook awards by score
+    pub fn get_score_badges(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ScoreBadges)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Whether `owner` holds a badge of `badge_type`
+    pub fn has_badge(env: Env, owner: Address, badge_type: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Held(owner, badge_type))
+    }
+
+    /// Number of badges `owner` holds
+    pub fn badge_count(env: Env, owner: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OwnerCount(owner))
+            .unwrap_or(0)
+    }
+
+    /// Every badge `owner` holds, oldest first
+    pub fn badges_of(env: Env, owner: Address) -> Vec<Badge> {
+        let mut badges = Vec::new(&env);
+        for idx in 0..Self::badge_count(env.clone(), owner.clone()) {
+            let badge_id: u64 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::OwnerBadge(owner.clone(), idx))
+                .unwrap();
+            if let Ok(badge) = Self::get_badge(env.clone(), badge_id) {
+                badges.push_back(badge);
+            }
+        }
+        badges
+    }
+
+    /// Number of badges ever issued; IDs run from 0 to this count
+    pub fn get_badge_total(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextBadgeId)
+            .unwrap_or(0)
+    }
+
+    pub fn get_registry(env: Env) -> Result<Address, BadgeError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Registry)
+            .ok_or(BadgeError::NotInitialized)
+    }
+
+    /// Get admin address
+    pub fn get_admin(env: Env) -> Result<Address, BadgeError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(BadgeError::NotInitialized)
+    }
+}
+
+#[contractimpl]
+impl ReputationHookTrait for BadgesContract {
+    /// Called by the registry after every score change; awards each score
+    /// badge `address` has newly qualified for. Only the registry may call.
+    fn on_reputation_changed(env: Env, address: Address, _old_score: u64, new_score: u64) {
+        let registry = match Self::get_registry(env.clone()) {
+            Ok(registry) => registry,
+            Err(err) => panic_with_error!(&env, err),
+        };
+        registry.require_auth();
+
+        for badge_type in Self::get_score_badges(env.clone()).iter() {
+            let Ok(definition) = Self::get_badge_type(env.clone(), badge_type.clone()) else {
+                continue;
+            };
+            let qualifies = definition.min_score.is_some_and(|min| new_score >= min);
+            if qualifies && !Self::has_badge(env.clone(), address.clone(), badge_type.clone()) {
+                let _ = Self::mint(&env, &address, &badge_type, &definition);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
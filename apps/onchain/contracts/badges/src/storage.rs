@@ -0,0 +1,53 @@
+use soroban_sdk::{contracttype, Address, String, Symbol};
+
+/// Upper bound on badge types awarded automatically by score; every
+/// reputation change checks each of them.
+pub const MAX_SCORE_BADGES: u32 = 16;
+
+/// Longest metadata URI accepted, in bytes.
+pub const MAX_URI_LEN: u32 = 256;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Badges touched by any write are kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,                    // -> Address
+    Registry,                 // -> Address of the contributor registry calling the hook
+    Moderator(Address),       // -> bool, may issue badges by hand
+    BadgeType(Symbol),        // badge type -> BadgeType
+    ScoreBadges,              // -> Vec<Symbol> types with a min_score
+    Badge(u64),               // badge id -> Badge
+    NextBadgeId,              // -> u64
+    Held(Address, Symbol),    // (owner, badge type) -> u64 badge id
+    OwnerCount(Address),      // owner -> u32 badges held
+    OwnerBadge(Address, u32), // (owner, index) -> u64 badge id
+}
+
+/// An achievement that can be awarded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BadgeType {
+    pub metadata_uri: String,
+    /// Awarded automatically once a contributor's registry score reaches
+    /// this, if set.
+    pub min_score: Option<u64>,
+}
+
+/// A non-transferable badge held by one contributor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Badge {
+    pub id: u64,
+    pub badge_type: Symbol,
+    pub owner: Address,
+    pub metadata_uri: String,
+    pub issued_at: u64,
+}
@@ -0,0 +1,161 @@
+use crate::errors::BadgeError;
+use crate::{BadgesContract, BadgesContractClient};
+use notification_interface::ReputationHookClient;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env, String, Symbol,
+};
+
+/// Stands in for the contributor registry calling its reputation hook.
+#[contract]
+struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn change_score(env: Env, hook: Address, address: Address, old: u64, new: u64) {
+        ReputationHookClient::new(&env, &hook).on_reputation_changed(&address, &old, &new);
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    client: BadgesContractClient<'a>,
+    admin: Address,
+    registry: MockRegistryClient<'a>,
+    contributor: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let registry = MockRegistryClient::new(&env, &env.register(MockRegistry, ()));
+    let contract_id = env.register(BadgesContract, ());
+    let client = BadgesContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &registry.address);
+
+    Setup {
+        contributor: Address::generate(&env),
+        env,
+        client,
+        admin,
+        registry,
+    }
+}
+
+fn uri(env: &Env, path: &str) -> String {
+    String::from_str(env, path)
+}
+
+#[test]
+fn test_moderator_issues_badge() {
+    let s = setup();
+    let moderator = Address::generate(&s.env);
+    let mentor = symbol_short!("mentor");
+    s.client
+        .define_badge(&s.admin, &mentor, &uri(&s.env, "ipfs://mentor"), &None);
+
+    assert_eq!(
+        s.client.try_issue(&moderator, &s.contributor, &mentor),
+        Err(Ok(BadgeError::Unauthorized))
+    );
+    s.client.set_moderator(&s.admin, &moderator, &true);
+    let badge_id = s.client.issue(&moderator, &s.contributor, &mentor);
+
+    let badge = s.client.get_badge(&badge_id);
+    assert_eq!(badge.owner, s.contributor);
+    assert_eq!(badge.badge_type, mentor);
+    assert_eq!(badge.metadata_uri, uri(&s.env, "ipfs://mentor"));
+    assert_eq!(badge.issued_at, 1_000);
+    assert!(s.client.has_badge(&s.contributor, &mentor));
+    assert_eq!(
+        s.client.try_issue(&moderator, &s.contributor, &mentor),
+        Err(Ok(BadgeError::AlreadyHeld))
+    );
+}
+
+#[test]
+fn test_badges_of_enumerates() {
+    let s = setup();
+    let first = symbol_short!("first_pr");
+    let reviewer = symbol_short!("reviewer");
+    s.client
+        .define_badge(&s.admin, &first, &uri(&s.env, "ipfs://first"), &None);
+    s.client
+        .define_badge(&s.admin, &reviewer, &uri(&s.env, "ipfs://rev"), &None);
+    s.client.issue(&s.admin, &s.contributor, &first);
+    s.client.issue(&s.admin, &s.contributor, &reviewer);
+
+    let badges = s.client.badges_of(&s.contributor);
+    assert_eq!(badges.len(), 2);
+    assert_eq!(badges.get(0).unwrap().badge_type, first);
+    assert_eq!(badges.get(1).unwrap().badge_type, reviewer);
+    assert_eq!(s.client.badge_count(&s.contributor), 2);
+    assert_eq!(s.client.badges_of(&s.admin).len(), 0);
+}
+
+#[test]
+fn test_registry_hook_awards_score_badges() {
+    let s = setup();
+    let bronze = symbol_short!("bronze");
+    let silver = symbol_short!("silver");
+    s.client
+        .define_badge(&s.admin, &bronze, &uri(&s.env, "ipfs://bronze"), &Some(100));
+    s.client
+        .define_badge(&s.admin, &silver, &uri(&s.env, "ipfs://silver"), &Some(500));
+    assert_eq!(s.client.get_score_badges().len(), 2);
+
+    s.registry
+        .change_score(&s.client.address, &s.contributor, &0, &150);
+    assert!(s.client.has_badge(&s.contributor, &bronze));
+    assert!(!s.client.has_badge(&s.contributor, &silver));
+
+    // Dropping and regaining the score does not mint a second badge
+    s.registry
+        .change_score(&s.client.address, &s.contributor, &150, &50);
+    s.registry
+        .change_score(&s.client.address, &s.contributor, &50, &600);
+    assert_eq!(s.client.badge_count(&s.contributor), 2);
+    assert!(s.client.has_badge(&s.contributor, &silver));
+}
+
+#[test]
+fn test_only_registry_calls_hook() {
+    let s = setup();
+    let bronze = symbol_short!("bronze");
+    s.client
+        .define_badge(&s.admin, &bronze, &uri(&s.env, "ipfs://bronze"), &Some(100));
+
+    s.env.set_auths(&[]);
+    let hook = ReputationHookClient::new(&s.env, &s.client.address);
+    assert!(hook
+        .try_on_reputation_changed(&s.contributor, &0, &1_000)
+        .is_err());
+    assert!(!s.client.has_badge(&s.contributor, &bronze));
+}
+
+#[test]
+fn test_define_badge_validation() {
+    let s = setup();
+    let mentor = symbol_short!("mentor");
+    assert_eq!(
+        s.client
+            .try_define_badge(&s.admin, &mentor, &uri(&s.env, ""), &None),
+        Err(Ok(BadgeError::InvalidMetadata))
+    );
+    s.client
+        .define_badge(&s.admin, &mentor, &uri(&s.env, "ipfs://mentor"), &None);
+    assert_eq!(
+        s.client
+            .try_define_badge(&s.admin, &mentor, &uri(&s.env, "ipfs://other"), &None),
+        Err(Ok(BadgeError::BadgeTypeExists))
+    );
+    assert_eq!(
+        s.client
+            .try_issue(&s.admin, &s.contributor, &Symbol::new(&s.env, "unknown")),
+        Err(Ok(BadgeError::BadgeTypeNotFound))
+    );
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BountyError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    Unauthorized = 3,
+    BountyNotFound = 4,
+    InvalidAmount = 5,
+    InvalidDeadline = 6,
+    InvalidStatus = 7,
+    BountyExpired = 8,
+    NotExpired = 9,
+    InvalidSubmission = 10,
+}
@@ -0,0 +1,106 @@
+use soroban_sdk::{contractevent, Address, String};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+}
+
+/// Emitted when the admin adds or removes a moderator.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModeratorUpdatedEvent {
+    #[topic]
+    pub moderator: Address,
+    pub enabled: bool,
+}
+
+/// Emitted when a maintainer posts a bounty and locks its reward.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyPostedEvent {
+    #[topic]
+    pub poster: Address,
+    pub bounty_id: u64,
+    pub token: Address,
+    pub reward: i128,
+    pub deadline: u64,
+}
+
+/// Emitted when a contributor takes on a bounty.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyClaimedEvent {
+    #[topic]
+    pub claimant: Address,
+    pub bounty_id: u64,
+}
+
+/// Emitted when the claimant gives a bounty back.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyAbandonedEvent {
+    #[topic]
+    pub claimant: Address,
+    pub bounty_id: u64,
+}
+
+/// Emitted when the poster or a moderator takes a bounty off its claimant.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyUnassignedEvent {
+    #[topic]
+    pub claimant: Address,
+    pub bounty_id: u64,
+    pub unassigned_by: Address,
+}
+
+/// Emitted when the claimant submits work for review.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorkSubmittedEvent {
+    #[topic]
+    pub claimant: Address,
+    pub bounty_id: u64,
+    pub submission: String,
+}
+
+/// Emitted when a submission is sent back to the claimant.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubmissionRejectedEvent {
+    #[topic]
+    pub reviewer: Address,
+    pub bounty_id: u64,
+}
+
+/// Emitted when a submission is approved and the reward paid out.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyReleasedEvent {
+    #[topic]
+    pub claimant: Address,
+    pub bounty_id: u64,
+    pub reward: i128,
+    pub approved_by: Address,
+}
+
+/// Emitted when the poster withdraws an unclaimed bounty.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyCancelledEvent {
+    #[topic]
+    pub poster: Address,
+    pub bounty_id: u64,
+    pub refunded: i128,
+}
+
+/// Emitted when an expired bounty's reward goes back to the poster.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyRefundedEvent {
+    #[topic]
+    pub poster: Address,
+    pub bounty_id: u64,
+    pub refunded: i128,
+}
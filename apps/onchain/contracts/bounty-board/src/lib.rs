@@ -0,0 +1,417 @@
+#![no_std]
+
+mod errors;
+mod events;
+mod storage;
+
+use errors::BountyError;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use storage::{
+    Bounty, BountyStatus, DataKey, MAX_TEXT_LEN, PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_THRESHOLD,
+    RESUBMISSION_WINDOW, REVIEW_PERIOD,
+};
+
+#[contract]
+pub struct BountyBoardContract;
+
+#[contractimpl]
+impl BountyBoardContract {
+    /// Check that `admin` is the stored admin and has signed
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), BountyError> {
+        let stored_admin = Self::get_admin(env.clone())?;
+        if *admin != stored_admin {
+            return Err(BountyError::Unauthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
+
+    fn load_bounty(env: &Env, bounty_id: u64) -> Result<Bounty, BountyError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Bounty(bounty_id))
+            .ok_or(BountyError::BountyNotFound)
+    }
+
+    /// Write a bounty back and keep it clear of archival
+    fn save_bounty(env: &Env, bounty: &Bounty) {
+        let key = DataKey::Bounty(bounty.id);
+        env.storage().persistent().set(&key, bounty);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// `reviewer` must be the bounty's poster or a moderator, and have signed
+    fn require_reviewer(env: &Env, bounty: &Bounty, reviewer: &Address) -> Result<(), BountyError> {
+        if *reviewer != bounty.poster && !Self::is_moderator(env.clone(), reviewer.clone()) {
+            return Err(BountyError::Unauthorized);
+        }
+        reviewer.require_auth();
+        Ok(())
+    }
+
+    /// `claimant` must hold the bounty and have signed
+    fn require_claimant(bounty: &Bounty, claimant: &Address) -> Result<(), BountyError> {
+        if bounty.claimant.as_ref() != Some(claimant) {
+            return Err(BountyError::Unauthorized);
+        }
+        claimant.require_auth();
+        Ok(())
+    }
+
+    fn validate_text(text: &String) -> Result<(), BountyError> {
+        if text.is_empty() || text.len() > MAX_TEXT_LEN {
+            return Err(BountyError::InvalidSubmission);
+        }
+        Ok(())
+    }
+
+    /// Pay a bounty's locked reward out of the contract
+    fn pay(env: &Env, bounty: &Bounty, to: &Address) {
+        TokenClient::new(env, &bounty.token).transfer(
+            &env.current_contract_address(),
+            to,
+            &bounty.reward,
+        );
+    }
+
+    /// Initialize the contract with an admin address
+    pub fn initialize(env: Env, admin: Address) -> Result<(), BountyError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(BountyError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::NextBountyId, &0u64);
+
+        events::InitializedEvent { admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Let `moderator` approve or reject submissions on any bounty, or
+    /// remove them
+    pub fn set_moderator(
+        env: Env,
+        admin: Address,
+        moderator: Address,
+        enabled: bool,
+    ) -> Result<(), BountyError> {
+        Self::require_admin(&env, &admin)?;
+        let key = DataKey::Moderator(moderator.clone());
+        if enabled {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        events::ModeratorUpdatedEvent { moderator, enabled }.publish(&env);
+        Ok(())
+    }
+
+    /// Whether `address` is a moderator
+    pub fn is_moderator(env: Env, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Moderator(address))
+            .unwrap_or(false)
+    }
+
+    /// Post a bounty, locking `reward` of `token` from the poster until the
+    /// work is approved or the bounty is cancelled or expires. Work must be
+    /// submitted before `deadline`. Returns the bounty ID.
+    pub fn post_bounty(
+        env: Env,
+        poster: Address,
+        title: String,
+        token: Address,
+        reward: i128,
+        deadline: u64,
+    ) -> Result<u64, BountyError> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(BountyError::NotInitialized);
+        }
+        poster.require_auth();
+        if reward <= 0 {
+            return Err(BountyError::InvalidAmount);
+        }
+        let now = env.ledger().timestamp();
+        if deadline <= now {
+            return Err(BountyError::InvalidDeadline);
+        }
+        Self::validate_text(&title)?;
+
+        TokenClient::new(&env, &token).transfer(&poster, env.current_contract_address(), &reward);
+
+        let bounty_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextBountyId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextBountyId, &(bounty_id + 1));
+        let bounty = Bounty {
+            id: bounty_id,
+            poster: poster.clone(),
+            title,
+            token: token.clone(),
+            reward,
+            deadline,
+            status: BountyStatus::Open,
+            claimant: None,
+            submission: None,
+            created_at: now,
+        };
+        Self::save_bounty(&env, &bounty);
+
+        events::BountyPostedEvent {
+            poster,
+            bounty_id,
+            token,
+            reward,
+            deadline,
+        }
+        .publish(&env);
+        Ok(bounty_id)
+    }
+
+    /// Withdraw an open bounty nobody has claimed, refunding the poster
+    pub fn cancel_bounty(env: Env, poster: Address, bounty_id: u64) -> Result<(), BountyError> {
+        let mut bounty = Self::load_bounty(&env, bounty_id)?;
+        if poster != bounty.poster {
+            return Err(BountyError::Unauthorized);
+        }
+        poster.require_auth();
+        if bounty.status != BountyStatus::Open {
+            return Err(BountyError::InvalidStatus);
+        }
+
+        bounty.status = BountyStatus::Cancelled;
+        Self::save_bounty(&env, &bounty);
+        Self::pay(&env, &bounty, &poster);
+
+        events::BountyCancelledEvent {
+            poster,
+            bounty_id,
+            refunded: bounty.reward,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Take on an open bounty. Only one contributor works a bounty at a time.
+    pub fn claim_bounty(env: Env, claimant: Address, bounty_id: u64) -> Result<(), BountyError> {
+        claimant.require_auth();
+        let mut bounty = Self::load_bounty(&env, bounty_id)?;
+        if bounty.status != BountyStatus::Open {
+            return Err(BountyError::InvalidStatus);
+        }
+        if env.ledger().timestamp() >= bounty.deadline {
+            return Err(BountyError::BountyExpired);
+        }
+        if claimant == bounty.poster {
+            return Err(BountyError::Unauthorized);
+        }
+
+        bounty.status = BountyStatus::Claimed;
+        bounty.claimant = Some(claimant.clone());
+        Self::save_bounty(&env, &bounty);
+
+        events::BountyClaimedEvent {
+            claimant,
+            bounty_id,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Give a claimed bounty back so someone else can take it
+    pub fn abandon_bounty(env: Env, claimant: Address, bounty_id: u64) -> Result<(), BountyError> {
+        let mut bounty = Self::load_bounty(&env, bounty_id)?;
+        Self::require_claimant(&bounty, &claimant)?;
+        if bounty.status != BountyStatus::Claimed {
+            return Err(BountyError::InvalidStatus);
+        }
+
+        bounty.status = BountyStatus::Open;
+        bounty.claimant = None;
+        Self::save_bounty(&env, &bounty);
+
+        events::BountyAbandonedEvent {
+            claimant,
+            bounty_id,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Take a claimed bounty off a claimant who is sitting on it, reopening it
+    /// for others. `reviewer` must be the poster or a moderator; work already
+    /// submitted has to be reviewed instead.
+    pub fn unassign_bounty(env: Env, reviewer: Address, bounty_id: u64) -> Result<(), BountyError> {
+        let mut bounty = Self::load_bounty(&env, bounty_id)?;
+        Self::require_reviewer(&env, &bounty, &reviewer)?;
+        if bounty.status != BountyStatus::Claimed {
+            return Err(BountyError::InvalidStatus);
+        }
+        let claimant = bounty.claimant.clone().ok_or(BountyError::InvalidStatus)?;
+
+        bounty.status = BountyStatus::Open;
+        bounty.claimant = None;
+        Self::save_bounty(&env, &bounty);
+
+        events::BountyUnassignedEvent {
+            claimant,
+            bounty_id,
+            unassigned_by: reviewer,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Hand in work on a claimed bounty before its deadline
+    pub fn submit_work(
+        env: Env,
+        claimant: Address,
+        bounty_id: u64,
+        submission: String,
+    ) -> Result<(), BountyError> {
+        let mut bounty = Self::load_bounty(&env, bounty_id)?;
+        Self::require_claimant(&bounty, &claimant)?;
+        if bounty.status != BountyStatus::Claimed {
+            return Err(BountyError::InvalidStatus);
+        }
+        if env.ledger().timestamp() >= bounty.deadline {
+            return Err(BountyError::BountyExpired);
+        }
+        Self::validate_text(&submission)?;
+
+        bounty.status = BountyStatus::Submitted;
+        bounty.submission = Some(submission.clone());
+        Self::save_bounty(&env, &bounty);
+
+        events::WorkSubmittedEvent {
+            claimant,
+            bounty_id,
+            submission,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Send a submission back to the claimant, who may resubmit before the
+    /// deadline. A rejection close to or after the deadline pushes it back to
+    /// `RESUBMISSION_WINDOW` from now, so the claimant always gets another
+    /// try and the reward stays locked through a fresh review period.
+    /// `reviewer` must be the poster or a moderator.
+    pub fn reject_submission(
+        env: Env,
+        reviewer: Address,
+        bounty_id: u64,
+    ) -> Result<(), BountyError> {
+        let mut bounty = Self::load_bounty(&env, bounty_id)?;
+        Self::require_reviewer(&env, &bounty, &reviewer)?;
+        if bounty.status != BountyStatus::Submitted {
+            return Err(BountyError::InvalidStatus);
+        }
+
+        bounty.status = BountyStatus::Claimed;
+        bounty.submission = None;
+        bounty.deadline = bounty
+            .deadline
+            .max(env.ledger().timestamp().saturating_add(RESUBMISSION_WINDOW));
+        Self::save_bounty(&env, &bounty);
+
+        events::SubmissionRejectedEvent {
+            reviewer,
+            bounty_id,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Accept a submission and release the reward to the claimant.
+    /// `approver` must be the poster or a moderator.
+    pub fn approve_submission(
+        env: Env,
+        approver: Address,
+        bounty_id: u64,
+    ) -> Result<(), BountyError> {
+        let mut bounty = Self::load_bounty(&env, bounty_id)?;
+        Self::require_reviewer(&env, &bounty, &approver)?;
+        if bounty.status != BountyStatus::Submitted {
+            return Err(BountyError::InvalidStatus);
+        }
+        let claimant = bounty.claimant.clone().ok_or(BountyError::InvalidStatus)?;
+
+        bounty.status = BountyStatus::Completed;
+        Self::save_bounty(&env, &bounty);
+        Self::pay(&env, &bounty, &claimant);
+
+        events::BountyReleasedEvent {
+            claimant,
+            bounty_id,
+            reward: bounty.reward,
+            approved_by: approver,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Return an expired bounty's reward to its poster. Anyone may trigger
+    /// it. Open and claimed bounties expire at the deadline; work submitted
+    /// in time keeps the reward locked for `REVIEW_PERIOD` longer, so the
+    /// poster cannot simply sit out the review, and a rejection cannot cut
+    /// that short (see `reject_submission`).
+    pub fn refund_expired(env: Env, bounty_id: u64) -> Result<i128, BountyError> {
+        let mut bounty = Self::load_bounty(&env, bounty_id)?;
+        let expires_at = match bounty.status {
+            BountyStatus::Open | BountyStatus::Claimed => bounty.deadline,
+            BountyStatus::Submitted => bounty.deadline.saturating_add(REVIEW_PERIOD),
+            _ => return Err(BountyError::InvalidStatus),
+        };
+        if env.ledger().timestamp() < expires_at {
+            return Err(BountyError::NotExpired);
+        }
+
+        bounty.status = BountyStatus::Refunded;
+        Self::save_bounty(&env, &bounty);
+        Self::pay(&env, &bounty, &bounty.poster);
+
+        events::BountyRefundedEvent {
+            poster: bounty.poster,
+            bounty_id,
+            refunded: bounty.reward,
+        }
+        .publish(&env);
+        Ok(bounty.reward)
+    }
+
+    /// Get a bounty by ID
+    pub fn get_bounty(env: Env, bounty_id: u64) -> Result<Bounty, BountyError> {
+        Self::load_bounty(&env, bounty_id)
+    }
+
+    /// Number of bounties ever posted; IDs run from 0 to this count
+    pub fn get_bounty_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextBountyId)
+            .unwrap_or(0)
+    }
+
+    /// Get admin address
+    pub fn get_admin(env: Env) -> Result<Address, BountyError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(BountyError::NotInitialized)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,67 @@
+use soroban_sdk::{contracttype, Address, String};
+
+/// Longest bounty title or submission link accepted, in bytes.
+pub const MAX_TEXT_LEN: u32 = 256;
+
+/// Time the poster keeps to review work submitted before the deadline:
+/// 7 days past the deadline.
+pub const REVIEW_PERIOD: u64 = 7 * 86_400;
+
+/// Time a claimant keeps to resubmit after a rejection, pushing the deadline
+/// back if it would otherwise fall sooner: 3 days.
+pub const RESUBMISSION_WINDOW: u64 = 3 * 86_400;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Bounties touched by any write are kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,              // -> Address
+    Moderator(Address), // -> bool, may approve or reject any submission
+    Bounty(u64),        // bounty id -> Bounty
+    NextBountyId,       // -> u64
+}
+
+/// Where a bounty is in its lifecycle.
+///
+/// `Open -> Claimed -> Submitted -> Completed`, with `Submitted -> Claimed`
+/// on rejection and `Claimed -> Open` when the claimant walks away or the
+/// poster or a moderator unassigns them. Open
+/// bounties can be `Cancelled` by the poster; unfinished ones are
+/// `Refunded` once they expire.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BountyStatus {
+    Open,
+    Claimed,
+    Submitted,
+    Completed,
+    Cancelled,
+    Refunded,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bounty {
+    pub id: u64,
+    pub poster: Address,
+    pub title: String,
+    pub token: Address,
+    /// Locked in the contract from posting until release or refund.
+    pub reward: i128,
+    /// Work must be submitted before this timestamp. A rejection may push it
+    /// back by up to `RESUBMISSION_WINDOW`.
+    pub deadline: u64,
+    pub status: BountyStatus,
+    pub claimant: Option<Address>,
+    /// Link to the submitted work, e.g. a pull request URL.
+    pub submission: Option<String>,
+    pub created_at: u64,
+}
@@ -0,0 +1,311 @@
+use crate::errors::BountyError;
+use crate::storage::{BountyStatus, RESUBMISSION_WINDOW, REVIEW_PERIOD};
+use crate::{BountyBoardContract, BountyBoardContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: BountyBoardContractClient<'a>,
+    admin: Address,
+    poster: Address,
+    contributor: Address,
+    token: TokenClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let poster = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    StellarAssetClient::new(&env, &asset.address()).mint(&poster, &10_000);
+    let token = TokenClient::new(&env, &asset.address());
+
+    let contract_id = env.register(BountyBoardContract, ());
+    let client = BountyBoardContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    Setup {
+        env,
+        client,
+        admin,
+        poster,
+        contributor,
+        token,
+    }
+}
+
+fn post(s: &Setup, reward: i128) -> u64 {
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.client.post_bounty(
+        &s.poster,
+        &String::from_str(&s.env, "Fix flaky CI"),
+        &s.token.address,
+        &reward,
+        &deadline,
+    )
+}
+
+fn pr(env: &Env) -> String {
+    String::from_str(env, "https://github.com/org/repo/pull/1")
+}
+
+#[test]
+fn test_post_locks_reward() {
+    let s = setup();
+    let bounty_id = post(&s, 1_000);
+
+    assert_eq!(bounty_id, 0);
+    assert_eq!(s.client.get_bounty_count(), 1);
+    assert_eq!(s.token.balance(&s.poster), 9_000);
+    assert_eq!(s.token.balance(&s.client.address), 1_000);
+    let bounty = s.client.get_bounty(&bounty_id);
+    assert_eq!(bounty.status, BountyStatus::Open);
+    assert_eq!(bounty.claimant, None);
+}
+
+#[test]
+fn test_post_rejects_bad_input() {
+    let s = setup();
+    let now = s.env.ledger().timestamp();
+    let title = String::from_str(&s.env, "Docs");
+
+    assert_eq!(
+        s.client
+            .try_post_bounty(&s.poster, &title, &s.token.address, &0, &(now + 10)),
+        Err(Ok(BountyError::InvalidAmount))
+    );
+    assert_eq!(
+        s.client
+            .try_post_bounty(&s.poster, &title, &s.token.address, &100, &now),
+        Err(Ok(BountyError::InvalidDeadline))
+    );
+    assert_eq!(
+        s.client.try_post_bounty(
+            &s.poster,
+            &String::from_str(&s.env, ""),
+            &s.token.address,
+            &100,
+            &(now + 10)
+        ),
+        Err(Ok(BountyError::InvalidSubmission))
+    );
+}
+
+#[test]
+fn test_full_lifecycle_pays_claimant() {
+    let s = setup();
+    let bounty_id = post(&s, 1_000);
+
+    s.client.claim_bounty(&s.contributor, &bounty_id);
+    s.client
+        .submit_work(&s.contributor, &bounty_id, &pr(&s.env));
+    let bounty = s.client.get_bounty(&bounty_id);
+    assert_eq!(bounty.status, BountyStatus::Submitted);
+    assert_eq!(bounty.submission, Some(pr(&s.env)));
+
+    s.client.approve_submission(&s.poster, &bounty_id);
+    assert_eq!(
+        s.client.get_bounty(&bounty_id).status,
+        BountyStatus::Completed
+    );
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_only_one_claimant_at_a_time() {
+    let s = setup();
+    let bounty_id = post(&s, 1_000);
+    let rival = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client.try_claim_bounty(&s.poster, &bounty_id),
+        Err(Ok(BountyError::Unauthorized))
+    );
+    s.client.claim_bounty(&s.contributor, &bounty_id);
+    assert_eq!(
+        s.client.try_claim_bounty(&rival, &bounty_id),
+        Err(Ok(BountyError::InvalidStatus))
+    );
+    assert_eq!(
+        s.client.try_submit_work(&rival, &bounty_id, &pr(&s.env)),
+        Err(Ok(BountyError::Unauthorized))
+    );
+
+    s.client.abandon_bounty(&s.contributor, &bounty_id);
+    s.client.claim_bounty(&rival, &bounty_id);
+    assert_eq!(s.client.get_bounty(&bounty_id).claimant, Some(rival));
+}
+
+#[test]
+fn test_moderator_can_review() {
+    let s = setup();
+    let bounty_id = post(&s, 1_000);
+    let moderator = Address::generate(&s.env);
+    let stranger = Address::generate(&s.env);
+
+    s.client.claim_bounty(&s.contributor, &bounty_id);
+    s.client
+        .submit_work(&s.contributor, &bounty_id, &pr(&s.env));
+    assert_eq!(
+        s.client.try_approve_submission(&stranger, &bounty_id),
+        Err(Ok(BountyError::Unauthorized))
+    );
+
+    s.client.set_moderator(&s.admin, &moderator, &true);
+    assert!(s.client.is_moderator(&moderator));
+    s.client.reject_submission(&moderator, &bounty_id);
+    let bounty = s.client.get_bounty(&bounty_id);
+    assert_eq!(bounty.status, BountyStatus::Claimed);
+    assert_eq!(bounty.submission, None);
+
+    s.client
+        .submit_work(&s.contributor, &bounty_id, &pr(&s.env));
+    s.client.approve_submission(&moderator, &bounty_id);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_cancel_only_while_open() {
+    let s = setup();
+    let first = post(&s, 1_000);
+    let second = post(&s, 2_000);
+
+    s.client.cancel_bounty(&s.poster, &first);
+    assert_eq!(s.client.get_bounty(&first).status, BountyStatus::Cancelled);
+    assert_eq!(s.token.balance(&s.poster), 8_000);
+
+    s.client.claim_bounty(&s.contributor, &second);
+    assert_eq!(
+        s.client.try_cancel_bounty(&s.poster, &second),
+        Err(Ok(BountyError::InvalidStatus))
+    );
+    assert_eq!(
+        s.client.try_cancel_bounty(&s.contributor, &second),
+        Err(Ok(BountyError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_expiry_refunds_poster() {
+    let s = setup();
+    let bounty_id = post(&s, 1_000);
+    let deadline = s.client.get_bounty(&bounty_id).deadline;
+
+    s.client.claim_bounty(&s.contributor, &bounty_id);
+    assert_eq!(
+        s.client.try_refund_expired(&bounty_id),
+        Err(Ok(BountyError::NotExpired))
+    );
+
+    s.env.ledger().set_timestamp(deadline);
+    assert_eq!(
+        s.client
+            .try_submit_work(&s.contributor, &bounty_id, &pr(&s.env)),
+        Err(Ok(BountyError::BountyExpired))
+    );
+    assert_eq!(s.client.refund_expired(&bounty_id), 1_000);
+    assert_eq!(
+        s.client.get_bounty(&bounty_id).status,
+        BountyStatus::Refunded
+    );
+    assert_eq!(s.token.balance(&s.poster), 10_000);
+    assert_eq!(
+        s.client.try_refund_expired(&bounty_id),
+        Err(Ok(BountyError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_submitted_work_gets_review_period() {
+    let s = setup();
+    let bounty_id = post(&s, 1_000);
+    let deadline = s.client.get_bounty(&bounty_id).deadline;
+
+    s.client.claim_bounty(&s.contributor, &bounty_id);
+    s.client
+        .submit_work(&s.contributor, &bounty_id, &pr(&s.env));
+
+    s.env.ledger().set_timestamp(deadline + REVIEW_PERIOD - 1);
+    assert_eq!(
+        s.client.try_refund_expired(&bounty_id),
+        Err(Ok(BountyError::NotExpired))
+    );
+    // The poster can still approve late
+    s.client.approve_submission(&s.poster, &bounty_id);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_initialize_twice_fails() {
+    let s = setup();
+    assert_eq!(
+        s.client.try_initialize(&s.admin),
+        Err(Ok(BountyError::AlreadyInitialized))
+    );
+    assert_eq!(s.client.get_admin(), s.admin);
+}
+
+#[test]
+fn test_late_rejection_reopens_resubmission() {
+    let s = setup();
+    let bounty_id = post(&s, 1_000);
+    let deadline = s.client.get_bounty(&bounty_id).deadline;
+    s.client.claim_bounty(&s.contributor, &bounty_id);
+    s.client
+        .submit_work(&s.contributor, &bounty_id, &pr(&s.env));
+
+    // Rejected during the review period, after the original deadline
+    let rejected_at = deadline + 10;
+    s.env.ledger().set_timestamp(rejected_at);
+    s.client.reject_submission(&s.poster, &bounty_id);
+    let new_deadline = rejected_at + RESUBMISSION_WINDOW;
+    assert_eq!(s.client.get_bounty(&bounty_id).deadline, new_deadline);
+    assert_eq!(
+        s.client.try_refund_expired(&bounty_id),
+        Err(Ok(BountyError::NotExpired))
+    );
+
+    s.client
+        .submit_work(&s.contributor, &bounty_id, &pr(&s.env));
+    s.env
+        .ledger()
+        .set_timestamp(new_deadline + REVIEW_PERIOD - 1);
+    assert_eq!(
+        s.client.try_refund_expired(&bounty_id),
+        Err(Ok(BountyError::NotExpired))
+    );
+    s.env.ledger().set_timestamp(new_deadline + REVIEW_PERIOD);
+    assert_eq!(s.client.refund_expired(&bounty_id), 1_000);
+}
+
+#[test]
+fn test_reviewer_can_unassign_squatter() {
+    let s = setup();
+    let bounty_id = post(&s, 1_000);
+    let rival = Address::generate(&s.env);
+    s.client.claim_bounty(&s.contributor, &bounty_id);
+
+    assert_eq!(
+        s.client.try_unassign_bounty(&rival, &bounty_id),
+        Err(Ok(BountyError::Unauthorized))
+    );
+    s.client.unassign_bounty(&s.poster, &bounty_id);
+    let bounty = s.client.get_bounty(&bounty_id);
+    assert_eq!(bounty.status, BountyStatus::Open);
+    assert_eq!(bounty.claimant, None);
+
+    s.client.claim_bounty(&rival, &bounty_id);
+    s.client.submit_work(&rival, &bounty_id, &pr(&s.env));
+    assert_eq!(
+        s.client.try_unassign_bounty(&s.poster, &bounty_id),
+        Err(Ok(BountyError::InvalidStatus))
+    );
+}
@@ -0,0 +1,104 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, String};
+
+use crate::errors::ContributorError;
+use crate::storage::{ConfigKey, ProfileKey};
+
+/// Next unused signing nonce for `address`. Every signed payload about an
+/// address embeds its current nonce, so each signature is good only once.
+pub(crate) fn nonce(env: &Env, address: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&ProfileKey::Nonce(address.clone()))
+        .unwrap_or(0)
+}
+
+/// Return the current nonce for `address` and advance it.
+pub(crate) fn use_nonce(env: &Env, address: &Address) -> u64 {
+    let current = nonce(env, address);
+    env.storage()
+        .persistent()
+        .set(&ProfileKey::Nonce(address.clone()), &(current + 1));
+    current
+}
+
+/// Message the attester signs off-chain to vouch that `address` controls
+/// the GitHub account `handle`: the XDR encoding of
+/// `("verify", registry, address, handle, nonce)`.
+pub(crate) fn handle_payload(env: &Env, address: &Address, handle: &String, nonce: u64) -> Bytes {
+    (
+        symbol_short!("verify"),
+        env.current_contract_address(),
+        address.clone(),
+        handle.clone(),
+        nonce,
+    )
+        .to_xdr(env)
+}
+
+pub(crate) fn get_attester(env: &Env) -> Result<BytesN<32>, ContributorError> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::Attester)
+        .ok_or(ContributorError::NotConfigured)
+}
+
+/// Verify `signature` over `payload` against the configured attester key.
+/// An invalid signature traps the invocation.
+pub(crate) fn verify(
+    env: &Env,
+    payload: &Bytes,
+    signature: &BytesN<64>,
+) -> Result<(), ContributorError> {
+    let attester = get_attester(env)?;
+    env.crypto().ed25519_verify(&attester, payload, signature);
+    Ok(())
+}
+
+/// Message a contributor signs off-chain so a sponsor can register them:
+/// the XDR encoding of `("register", registry, contributor, handle, nonce)`.
+pub(crate) fn registration_payload(
+    env: &Env,
+    contributor: &Address,
+    handle: &String,
+    nonce: u64,
+) -> Bytes {
+    (
+        symbol_short!("register"),
+        env.current_contract_address(),
+        contributor.clone(),
+        handle.clone(),
+        nonce,
+    )
+        .to_xdr(env)
+}
+
+/// Recover the ed25519 key behind a classic `G...` account address. Contract
+/// addresses have no such key and are rejected.
+pub(crate) fn account_public_key(
+    env: &Env,
+    address: &Address,
+) -> Result<BytesN<32>, ContributorError> {
+    // ScVal::Address(ScAddress::Account(PublicKey::Ed25519(key))) is three
+    // 4-byte discriminants followed by the 32-byte key.
+    let xdr = address.to_xdr(env);
+    let header = [0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0];
+    if xdr.len() != 44 || xdr.slice(0..12) != Bytes::from_array(env, &header) {
+        return Err(ContributorError::UnsupportedSigner);
+    }
+    let mut key = [0u8; 32];
+    xdr.slice(12..44).copy_into_slice(&mut key);
+    Ok(BytesN::from_array(env, &key))
+}
+
+/// Verify that `contributor` signed `payload`. An invalid signature traps.
+pub(crate) fn verify_account(
+    env: &Env,
+    contributor: &Address,
+    payload: &Bytes,
+    signature: &BytesN<64>,
+) -> Result<(), ContributorError> {
+    let key = account_public_key(env, contributor)?;
+    env.crypto().ed25519_verify(&key, payload, signature);
+    Ok(())
+}
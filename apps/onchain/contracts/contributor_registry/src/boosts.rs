@@ -0,0 +1,45 @@
+use soroban_sdk::{contracttype, Env, Vec};
+
+use crate::storage::{ConfigKey, BPS_DENOMINATOR};
+
+// ── Types ────────────────────────────────────────────────────
+
+/// Reputation additions made in `[start, end)` (ledger timestamps) are
+/// scaled by `multiplier_bps` basis points.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoostWindow {
+    pub start: u64,
+    pub end: u64,
+    pub multiplier_bps: u32,
+}
+
+// ── Internal helpers ─────────────────────────────────────────
+
+pub(crate) fn windows(env: &Env) -> Vec<BoostWindow> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::Boosts)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Multiplier in force right now. Overlapping windows do not stack; the
+/// largest one wins.
+pub(crate) fn current_multiplier(env: &Env) -> u32 {
+    let now = env.ledger().timestamp();
+    windows(env)
+        .iter()
+        .filter(|w| w.start <= now && now < w.end)
+        .map(|w| w.multiplier_bps)
+        .max()
+        .unwrap_or(BPS_DENOMINATOR)
+}
+
+/// Scale a positive delta by the active boost. Deductions pass through.
+pub(crate) fn apply(env: &Env, delta: i64) -> i64 {
+    if delta <= 0 {
+        return delta;
+    }
+    let scaled = delta as i128 * current_multiplier(env) as i128 / BPS_DENOMINATOR as i128;
+    i64::try_from(scaled).unwrap_or(i64::MAX)
+}
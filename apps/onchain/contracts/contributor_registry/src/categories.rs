@@ -0,0 +1,149 @@
+use soroban_sdk::{symbol_short, Address, Env, Map, Symbol};
+
+use crate::errors::ContributorError;
+use crate::storage::{ConfigKey, ProfileKey, MAX_CATEGORIES, WEIGHT_BASE};
+
+// ── Internal helpers ─────────────────────────────────────────
+//
+// Categorised points are tracked per contributor next to the scalar
+// `reputation_score`, which stays the raw total of every point granted.
+// Weights are percentages of `WEIGHT_BASE` and only affect the weighted view.
+// The categorised points never add up to more than the raw score: decay
+// scales them down with it, and any other cut or clamp of the raw score trims
+// them proportionally once it would fall below their sum.
+
+fn default_weights(env: &Env) -> Map<Symbol, u32> {
+    let mut weights = Map::new(env);
+    for category in [
+        symbol_short!("code"),
+        symbol_short!("review"),
+        symbol_short!("docs"),
+        symbol_short!("community"),
+    ] {
+        weights.set(category, WEIGHT_BASE);
+    }
+    weights
+}
+
+pub(crate) fn weights(env: &Env) -> Map<Symbol, u32> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::CategoryWeights)
+        .unwrap_or_else(|| default_weights(env))
+}
+
+pub(crate) fn set_weights(env: &Env, weights: &Map<Symbol, u32>) -> Result<(), ContributorError> {
+    if weights.is_empty() {
+        return Err(ContributorError::InvalidConfig);
+    }
+    if weights.len() > MAX_CATEGORIES {
+        return Err(ContributorError::LimitExceeded);
+    }
+    env.storage()
+        .instance()
+        .set(&ConfigKey::CategoryWeights, weights);
+    Ok(())
+}
+
+pub(crate) fn scores(env: &Env, address: &Address) -> Map<Symbol, u64> {
+    env.storage()
+        .persistent()
+        .get(&ProfileKey::CategoryScores(address.clone()))
+        .unwrap_or(Map::new(env))
+}
+
+/// Apply `delta` to one category, flooring at zero. Returns the old and new
+/// category score.
+pub(crate) fn apply(
+    env: &Env,
+    address: &Address,
+    category: &Symbol,
+    delta: i64,
+) -> Result<(u64, u64), ContributorError> {
+    if !weights(env).contains_key(category.clone()) {
+        return Err(ContributorError::InvalidCategory);
+    }
+    let mut all = scores(env, address);
+    let old = all.get(category.clone()).unwrap_or(0);
+    let new = if delta > 0 {
+        old.checked_add(delta as u64)
+            .ok_or(ContributorError::ReputationOverflow)?
+    } else {
+        old.saturating_sub(delta.unsigned_abs())
+    };
+    all.set(category.clone(), new);
+    env.storage()
+        .persistent()
+        .set(&ProfileKey::CategoryScores(address.clone()), &all);
+    Ok((old, new))
+}
+
+/// Multiply every category score of `address` by `to / from`, rounding down.
+pub(crate) fn rescale(env: &Env, address: &Address, from: u64, to: u64) {
+    if from == 0 || to >= from {
+        return;
+    }
+    let mut all = scores(env, address);
+    if all.is_empty() {
+        return;
+    }
+    for (category, score) in all.clone().iter() {
+        let scaled = score as u128 * to as u128 / from as u128;
+        all.set(category, scaled as u64);
+    }
+    env.storage()
+        .persistent()
+        .set(&ProfileKey::CategoryScores(address.clone()), &all);
+}
+
+/// Scale the category split down if it adds up to more than `raw_total`.
+pub(crate) fn cap(env: &Env, address: &Address, raw_total: u64) {
+    let categorised = scores(env, address)
+        .values()
+        .iter()
+        .fold(0u64, |total, score| total.saturating_add(score));
+    rescale(env, address, categorised, raw_total);
+}
+
+/// Weighted total: each category at its configured weight, plus any points
+/// granted without a category at the base weight.
+pub(crate) fn weighted_total(env: &Env, address: &Address, raw_total: u64) -> u64 {
+    let weights = weights(env);
+    let mut categorised: u64 = 0;
+    let mut weighted: u128 = 0;
+    for (category, score) in scores(env, address).iter() {
+        categorised = categorised.saturating_add(score);
+        let weight = weights.get(category).unwrap_or(WEIGHT_BASE);
+        weighted += score as u128 * weight as u128 / WEIGHT_BASE as u128;
+    }
+    let uncategorised = raw_total.saturating_sub(categorised) as u128;
+    (weighted + uncategorised).min(u64::MAX as u128) as u64
+}
+
+/// Add every category score of `from` onto `to` and drop `from`'s split.
+pub(crate) fn merge_into(env: &Env, from: &Address, to: &Address) -> Result<(), ContributorError> {
+    let source = scores(env, from);
+    if source.is_empty() {
+        return Ok(());
+    }
+    let mut target = scores(env, to);
+    for (category, score) in source.iter() {
+        let total = target
+            .get(category.clone())
+            .unwrap_or(0)
+            .checked_add(score)
+            .ok_or(ContributorError::ReputationOverflow)?;
+        target.set(category, total);
+    }
+    env.storage()
+        .persistent()
+        .set(&ProfileKey::CategoryScores(to.clone()), &target);
+    clear(env, from);
+    Ok(())
+}
+
+pub(crate) fn clear(env: &Env, address: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&ProfileKey::CategoryScores(address.clone()));
+}
@@ -0,0 +1,154 @@
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::errors::ContributorError;
+use crate::events::ContributionRecordedEvent;
+use crate::storage::{DataKey, MAX_PAGE_SIZE};
+
+// ── Types ────────────────────────────────────────────────────
+
+/// A graded piece of work, linked back to the pull request it came from.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionData {
+    pub id: u64,
+    pub contributor: Address,
+    pub repo: String,
+    pub pr_number: u32,
+    pub points: u32,
+    pub recorded_by: Address,
+    pub timestamp: u64,
+}
+
+// ── Internal helpers ─────────────────────────────────────────
+
+fn next_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextContributionId)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextContributionId, &(id + 1));
+    id
+}
+
+pub(crate) fn contribution_count(env: &Env, contributor: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ContributionCount(contributor.clone()))
+        .unwrap_or(0)
+}
+
+/// Store a new contribution record and index it under its contributor.
+/// Each `(repo, pr_number)` pair can only be recorded once.
+pub(crate) fn record(
+    env: &Env,
+    recorded_by: &Address,
+    contributor: &Address,
+    repo: String,
+    pr_number: u32,
+    points: u32,
+) -> Result<u64, ContributorError> {
+    if repo.is_empty() {
+        return Err(ContributorError::InvalidContribution);
+    }
+    let pr_key = DataKey::PullRequestIndex(repo.clone(), pr_number);
+    if env.storage().persistent().has(&pr_key) {
+        return Err(ContributorError::ContributionAlreadyRecorded);
+    }
+
+    let id = next_id(env);
+    let record = ContributionData {
+        id,
+        contributor: contributor.clone(),
+        repo: repo.clone(),
+        pr_number,
+        points,
+        recorded_by: recorded_by.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+
+    let count = contribution_count(env, contributor);
+    let storage = env.storage().persistent();
+    storage.set(&DataKey::Contribution(id), &record);
+    storage.set(&pr_key, &id);
+    storage.set(&DataKey::ContributionOf(contributor.clone(), count), &id);
+    storage.set(
+        &DataKey::ContributionCount(contributor.clone()),
+        &(count + 1),
+    );
+
+    ContributionRecordedEvent {
+        contributor: contributor.clone(),
+        contribution_id: id,
+        repo,
+        pr_number,
+        points,
+    }
+    .publish(env);
+
+    Ok(id)
+}
+
+/// Re-home up to `limit` contribution records of `from` onto `to`, newest
+/// first, appending them after `to`'s own records. Returns how many moved.
+pub(crate) fn reassign(env: &Env, from: &Address, to: &Address, limit: u32) -> u32 {
+    let mut remaining = contribution_count(env, from);
+    let storage = env.storage().persistent();
+    let mut count = contribution_count(env, to);
+    let mut moved = 0;
+    while remaining > 0 && moved < limit {
+        remaining -= 1;
+        moved += 1;
+        let key = DataKey::ContributionOf(from.clone(), remaining);
+        let Some(id) = storage.get::<_, u64>(&key) else {
+            continue;
+        };
+        storage.remove(&key);
+        if let Ok(mut record) = get(env, id) {
+            record.contributor = to.clone();
+            storage.set(&DataKey::Contribution(id), &record);
+        }
+        storage.set(&DataKey::ContributionOf(to.clone(), count), &id);
+        count += 1;
+    }
+    if moved == 0 {
+        return 0;
+    }
+    storage.set(&DataKey::ContributionCount(to.clone()), &count);
+    if remaining == 0 {
+        storage.remove(&DataKey::ContributionCount(from.clone()));
+    } else {
+        storage.set(&DataKey::ContributionCount(from.clone()), &remaining);
+    }
+    moved
+}
+
+pub(crate) fn get(env: &Env, id: u64) -> Result<ContributionData, ContributorError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Contribution(id))
+        .ok_or(ContributorError::ContributionNotFound)
+}
+
+pub(crate) fn list_for(
+    env: &Env,
+    contributor: &Address,
+    start: u32,
+    limit: u32,
+) -> Vec<ContributionData> {
+    let count = contribution_count(env, contributor);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+    let mut page = Vec::new(env);
+    for i in start..end {
+        let id: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ContributionOf(contributor.clone(), i));
+        if let Some(record) = id.and_then(|id| get(env, id).ok()) {
+            page.push_back(record);
+        }
+    }
+    page
+}
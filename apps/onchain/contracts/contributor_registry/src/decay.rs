@@ -0,0 +1,55 @@
+use soroban_sdk::{Address, Env};
+
+use crate::errors::ContributorError;
+use crate::storage::{
+    ConfigKey, ContributorData, DecayConfig, ProfileKey, BPS_DENOMINATOR, MAX_DECAY_EPOCHS,
+};
+
+// ── Internal helpers ─────────────────────────────────────────
+
+pub(crate) fn config(env: &Env) -> Result<DecayConfig, ContributorError> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::DecayConfig)
+        .ok_or(ContributorError::NotConfigured)
+}
+
+/// Timestamp decay is measured from: the last reputation gain, or the last
+/// decayed epoch boundary, falling back to registration time.
+pub(crate) fn last_activity(env: &Env, contributor: &ContributorData) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&ProfileKey::LastActivity(contributor.address.clone()))
+        .unwrap_or(contributor.registered_timestamp)
+}
+
+pub(crate) fn set_last_activity(env: &Env, address: &Address, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&ProfileKey::LastActivity(address.clone()), &timestamp);
+}
+
+/// Work out how many whole epochs have elapsed for `contributor` and what
+/// their score falls to once those epochs are applied. Returns
+/// `(epochs_applied, new_score)`; at most `MAX_DECAY_EPOCHS` are applied.
+pub(crate) fn pending(
+    env: &Env,
+    config: &DecayConfig,
+    contributor: &ContributorData,
+) -> (u64, u64) {
+    let elapsed = env
+        .ledger()
+        .timestamp()
+        .saturating_sub(last_activity(env, contributor));
+    let epochs = (elapsed / config.epoch_length).min(MAX_DECAY_EPOCHS);
+
+    let keep = (BPS_DENOMINATOR - config.rate_bps) as u128;
+    let mut score = contributor.reputation_score as u128;
+    for _ in 0..epochs {
+        if score == 0 {
+            break;
+        }
+        score = score * keep / BPS_DENOMINATOR as u128;
+    }
+    (epochs, score as u64)
+}
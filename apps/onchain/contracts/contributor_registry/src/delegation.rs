@@ -0,0 +1,99 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::errors::ContributorError;
+use crate::events::DelegationChangedEvent;
+use crate::storage::{ProfileKey, MAX_DELEGATORS};
+
+// ── Internal helpers ─────────────────────────────────────────
+//
+// Delegation is a single hop: a delegator's score counts towards exactly one
+// delegatee, and a delegatee's power is never passed on. Refusing chains is
+// what rules out cycles.
+
+pub(crate) fn delegate_of(env: &Env, address: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&ProfileKey::Delegate(address.clone()))
+}
+
+pub(crate) fn delegators_of(env: &Env, address: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&ProfileKey::Delegators(address.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_delegators(env: &Env, address: &Address, delegators: &Vec<Address>) {
+    let key = ProfileKey::Delegators(address.clone());
+    if delegators.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, delegators);
+    }
+}
+
+fn detach(env: &Env, delegator: &Address) -> Option<Address> {
+    let previous = delegate_of(env, delegator)?;
+    let mut delegators = delegators_of(env, &previous);
+    if let Some(i) = delegators.first_index_of(delegator) {
+        delegators.remove(i);
+    }
+    set_delegators(env, &previous, &delegators);
+    env.storage()
+        .persistent()
+        .remove(&ProfileKey::Delegate(delegator.clone()));
+    Some(previous)
+}
+
+pub(crate) fn delegate(
+    env: &Env,
+    delegator: &Address,
+    delegatee: &Address,
+) -> Result<(), ContributorError> {
+    if delegator == delegatee
+        || delegate_of(env, delegatee).is_some()
+        || !delegators_of(env, delegator).is_empty()
+    {
+        return Err(ContributorError::InvalidDelegation);
+    }
+
+    // Read the delegatee's list only after detaching, so re-delegating to the
+    // same address replaces the old entry instead of adding a second one
+    detach(env, delegator);
+    let mut delegators = delegators_of(env, delegatee);
+    if delegators.len() >= MAX_DELEGATORS {
+        return Err(ContributorError::LimitExceeded);
+    }
+    delegators.push_back(delegator.clone());
+    set_delegators(env, delegatee, &delegators);
+    env.storage()
+        .persistent()
+        .set(&ProfileKey::Delegate(delegator.clone()), delegatee);
+
+    DelegationChangedEvent {
+        delegator: delegator.clone(),
+        delegatee: Some(delegatee.clone()),
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+pub(crate) fn undelegate(env: &Env, delegator: &Address) {
+    if detach(env, delegator).is_some() {
+        DelegationChangedEvent {
+            delegator: delegator.clone(),
+            delegatee: None,
+        }
+        .publish(env);
+    }
+}
+
+/// Sever every delegation edge touching `address`, used when a profile is
+/// deleted.
+pub(crate) fn clear(env: &Env, address: &Address) {
+    undelegate(env, address);
+    for delegator in delegators_of(env, address).iter() {
+        undelegate(env, &delegator);
+    }
+}
@@ -0,0 +1,176 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::errors::ContributorError;
+use crate::events::EndorsedEvent;
+use crate::storage::DataKey;
+
+// ── Internal helpers ─────────────────────────────────────────
+
+pub(crate) fn count(env: &Env, endorsee: &Address, skill: &Symbol) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EndorsementCount(endorsee.clone(), skill.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn skills(env: &Env, endorsee: &Address) -> Vec<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EndorsedSkills(endorsee.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn has_endorsed(
+    env: &Env,
+    endorser: &Address,
+    endorsee: &Address,
+    skill: &Symbol,
+) -> bool {
+    env.storage().persistent().has(&DataKey::Endorsement(
+        endorser.clone(),
+        endorsee.clone(),
+        skill.clone(),
+    ))
+}
+
+/// Bump the per-skill count and endorser index for `endorsee`, returning
+/// the new count. The caller has already written the dedupe marker.
+fn append(env: &Env, endorser: &Address, endorsee: &Address, skill: &Symbol) -> u32 {
+    let storage = env.storage().persistent();
+    let index = count(env, endorsee, skill);
+    storage.set(
+        &DataKey::Endorser(endorsee.clone(), skill.clone(), index),
+        endorser,
+    );
+    storage.set(
+        &DataKey::EndorsementCount(endorsee.clone(), skill.clone()),
+        &(index + 1),
+    );
+
+    let mut endorsed_skills = skills(env, endorsee);
+    if !endorsed_skills.contains(skill) {
+        endorsed_skills.push_back(skill.clone());
+        storage.set(&DataKey::EndorsedSkills(endorsee.clone()), &endorsed_skills);
+    }
+    index + 1
+}
+
+/// Move up to `limit` endorsements of `from` onto `to`, newest first.
+/// Endorsers who already vouched for `to` on the same skill, and `to`
+/// itself, are dropped rather than counted twice. Returns how many were
+/// processed.
+pub(crate) fn reassign(env: &Env, from: &Address, to: &Address, limit: u32) -> u32 {
+    let storage = env.storage().persistent();
+    let mut remaining = Vec::new(env);
+    let mut processed = 0;
+    for skill in skills(env, from).iter() {
+        let mut index = count(env, from, &skill);
+        while index > 0 && processed < limit {
+            index -= 1;
+            processed += 1;
+            let index_key = DataKey::Endorser(from.clone(), skill.clone(), index);
+            let Some(endorser) = storage.get::<_, Address>(&index_key) else {
+                continue;
+            };
+            storage.remove(&index_key);
+            storage.remove(&DataKey::Endorsement(
+                endorser.clone(),
+                from.clone(),
+                skill.clone(),
+            ));
+            if endorser == *to || has_endorsed(env, &endorser, to, &skill) {
+                continue;
+            }
+            storage.set(
+                &DataKey::Endorsement(endorser.clone(), to.clone(), skill.clone()),
+                &true,
+            );
+            append(env, &endorser, to, &skill);
+        }
+        let count_key = DataKey::EndorsementCount(from.clone(), skill.clone());
+        if index == 0 {
+            storage.remove(&count_key);
+        } else {
+            storage.set(&count_key, &index);
+            remaining.push_back(skill);
+        }
+    }
+    if remaining.is_empty() {
+        storage.remove(&DataKey::EndorsedSkills(from.clone()));
+    } else {
+        storage.set(&DataKey::EndorsedSkills(from.clone()), &remaining);
+    }
+    processed
+}
+
+/// Remove up to `limit` endorsements `address` has received, newest first,
+/// dropping each skill once its last endorser is gone. Returns whether any
+/// are left.
+pub(crate) fn clear(env: &Env, address: &Address, limit: u32) -> bool {
+    let storage = env.storage().persistent();
+    let mut remaining = Vec::new(env);
+    let mut cleared = 0;
+    for skill in skills(env, address).iter() {
+        let mut index = count(env, address, &skill);
+        while index > 0 && cleared < limit {
+            index -= 1;
+            let index_key = DataKey::Endorser(address.clone(), skill.clone(), index);
+            if let Some(endorser) = storage.get::<_, Address>(&index_key) {
+                storage.remove(&DataKey::Endorsement(
+                    endorser,
+                    address.clone(),
+                    skill.clone(),
+                ));
+            }
+            storage.remove(&index_key);
+            cleared += 1;
+        }
+        let count_key = DataKey::EndorsementCount(address.clone(), skill.clone());
+        if index == 0 {
+            storage.remove(&count_key);
+        } else {
+            storage.set(&count_key, &index);
+            remaining.push_back(skill);
+        }
+    }
+    if remaining.is_empty() {
+        storage.remove(&DataKey::EndorsedSkills(address.clone()));
+        return false;
+    }
+    storage.set(&DataKey::EndorsedSkills(address.clone()), &remaining);
+    true
+}
+
+/// Record a single endorsement. Each `(endorser, endorsee, skill)` triple
+/// counts at most once.
+pub(crate) fn endorse(
+    env: &Env,
+    endorser: &Address,
+    endorsee: &Address,
+    skill: &Symbol,
+) -> Result<u32, ContributorError> {
+    if endorser == endorsee {
+        return Err(ContributorError::SelfEndorsement);
+    }
+    if has_endorsed(env, endorser, endorsee, skill) {
+        return Err(ContributorError::AlreadyExists);
+    }
+
+    let storage = env.storage().persistent();
+    storage.set(
+        &DataKey::Endorsement(endorser.clone(), endorsee.clone(), skill.clone()),
+        &true,
+    );
+
+    let total = append(env, endorser, endorsee, skill);
+
+    EndorsedEvent {
+        endorsee: endorsee.clone(),
+        endorser: endorser.clone(),
+        skill: skill.clone(),
+        total,
+    }
+    .publish(env);
+
+    Ok(total)
+}
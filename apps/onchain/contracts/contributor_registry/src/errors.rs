@@ -19,4 +19,49 @@ pub enum ContributorError {
     ProposalExpired = 13,
     AlreadySigned = 14,
     BelowThreshold = 15,
+    NoPendingAdmin = 16,
+    ContractPaused = 17,
+    ContractNotPaused = 18,
+    /// An admin setting (TTL, tiers, decay, fee, handle length, ...) was
+    /// rejected as out of range.
+    InvalidConfig = 19,
+    ContributionNotFound = 20,
+    ContributionAlreadyRecorded = 21,
+    InvalidContribution = 22,
+    /// A batch, list, or set would grow past its fixed cap.
+    LimitExceeded = 23,
+    ContributorBanned = 24,
+    /// The feature needs an admin setting (attester, decay, treasury, ...)
+    /// that has not been made yet.
+    NotConfigured = 25,
+    SelfEndorsement = 26,
+    /// The endorsement, skill tag, or admin being added is already present.
+    AlreadyExists = 27,
+    /// The skill tag, linked handle, or admin being removed is not present.
+    NotFound = 28,
+    InvalidProfileField = 29,
+    UnsupportedSigner = 30,
+    InvalidPlatform = 31,
+    InvalidHandle = 32,
+    PlatformHandleTaken = 33,
+    SnapshotNotFound = 34,
+    InvalidDelegation = 35,
+    InvalidReferrer = 36,
+    HandleTooLong = 37,
+    HandleInvalidChars = 38,
+    LastAdmin = 39,
+    InvalidRange = 40,
+    InvalidCategory = 41,
+    ContributorMerged = 42,
+    ReputationFrozen = 43,
+    InsufficientReputation = 44,
+    RegistrationNotApproved = 45,
+    ImportFinalized = 46,
+    UnknownReason = 47,
+    /// A moderator touched this score again before the cooldown elapsed.
+    RateLimited = 48,
+    /// Scores and the contributor list are frozen while a snapshot is built.
+    SnapshotInProgress = 49,
+    /// A deleted profile's endorsements must be purged before it re-registers.
+    EndorsementsPending = 50,
 }
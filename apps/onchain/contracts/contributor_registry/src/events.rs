@@ -1,4 +1,4 @@
-use soroban_sdk::{contractevent, Address, BytesN};
+use soroban_sdk::{contractevent, Address, BytesN, String, Symbol};
 
 use crate::multisig::{ProposalAction, ProposalStatus};
 
@@ -16,6 +16,55 @@ pub struct AdminChangedEvent {
     pub new_admin: Address,
 }
 
+#[contractevent]
+pub struct AdminTransferProposedEvent {
+    #[topic]
+    pub current_admin: Address,
+    pub pending_admin: Address,
+}
+
+#[contractevent]
+pub struct AdminAddedEvent {
+    #[topic]
+    pub admin: Address,
+    pub new_admin: Address,
+}
+
+#[contractevent]
+pub struct AdminRemovedEvent {
+    #[topic]
+    pub admin: Address,
+    pub removed: Address,
+}
+
+#[contractevent]
+pub struct ModeratorAddedEvent {
+    #[topic]
+    pub admin: Address,
+    pub moderator: Address,
+}
+
+#[contractevent]
+pub struct ModeratorRemovedEvent {
+    #[topic]
+    pub admin: Address,
+    pub moderator: Address,
+}
+
+#[contractevent]
+pub struct ContractPausedEvent {
+    #[topic]
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+#[contractevent]
+pub struct ContractUnpausedEvent {
+    #[topic]
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
 #[contractevent]
 pub struct ProposalCreatedEvent {
     #[topic]
@@ -58,3 +107,202 @@ pub struct MultisigConfiguredEvent {
     pub threshold: u32,
     pub signer_count: u32,
 }
+
+#[contractevent(topics = ["contributor", "registered"])]
+pub struct ContributorRegisteredEvent {
+    #[topic]
+    pub address: Address,
+    pub github_handle: String,
+    pub registered_timestamp: u64,
+}
+
+#[contractevent(topics = ["contributor", "removed"])]
+pub struct ContributorRemovedEvent {
+    #[topic]
+    pub address: Address,
+    pub github_handle: String,
+    pub removed_by: Address,
+}
+
+#[contractevent(topics = ["contributor", "handle_updated"])]
+pub struct HandleUpdatedEvent {
+    #[topic]
+    pub address: Address,
+    pub old_handle: String,
+    pub new_handle: String,
+}
+
+#[contractevent(topics = ["contributor", "verified"])]
+pub struct HandleVerifiedEvent {
+    #[topic]
+    pub address: Address,
+    pub github_handle: String,
+}
+
+#[contractevent(topics = ["contributor", "banned"])]
+pub struct ContributorBannedEvent {
+    #[topic]
+    pub address: Address,
+    pub banned_by: Address,
+}
+
+#[contractevent(topics = ["contributor", "unbanned"])]
+pub struct ContributorUnbannedEvent {
+    #[topic]
+    pub address: Address,
+    pub unbanned_by: Address,
+}
+
+#[contractevent(topics = ["reputation", "updated"])]
+pub struct ReputationUpdatedEvent {
+    #[topic]
+    pub address: Address,
+    pub github_handle: String,
+    pub old_score: u64,
+    pub new_score: u64,
+}
+
+#[contractevent(topics = ["contribution", "recorded"])]
+pub struct ContributionRecordedEvent {
+    #[topic]
+    pub contributor: Address,
+    pub contribution_id: u64,
+    pub repo: String,
+    pub pr_number: u32,
+    pub points: u32,
+}
+
+#[contractevent(topics = ["contributor", "endorsed"])]
+pub struct EndorsedEvent {
+    #[topic]
+    pub endorsee: Address,
+    pub endorser: Address,
+    pub skill: Symbol,
+    pub total: u32,
+}
+
+#[contractevent(topics = ["contributor", "profile_uri"])]
+pub struct ProfileUriUpdatedEvent {
+    #[topic]
+    pub address: Address,
+    pub uri: Option<String>,
+}
+
+#[contractevent(topics = ["contributor", "sponsored"])]
+pub struct RegistrationSponsoredEvent {
+    #[topic]
+    pub address: Address,
+    pub sponsor: Address,
+}
+
+#[contractevent(topics = ["contributor", "handle_linked"])]
+pub struct HandleLinkedEvent {
+    #[topic]
+    pub address: Address,
+    pub platform: Symbol,
+    pub handle: String,
+}
+
+#[contractevent(topics = ["contributor", "handle_unlinked"])]
+pub struct HandleUnlinkedEvent {
+    #[topic]
+    pub address: Address,
+    pub platform: Symbol,
+    pub handle: String,
+}
+
+#[contractevent(topics = ["reputation", "snapshot"])]
+pub struct SnapshotTakenEvent {
+    #[topic]
+    pub epoch: u32,
+    pub root: BytesN<32>,
+    pub contributor_count: u32,
+}
+
+#[contractevent(topics = ["reputation", "delegated"])]
+pub struct DelegationChangedEvent {
+    #[topic]
+    pub delegator: Address,
+    pub delegatee: Option<Address>,
+}
+
+#[contractevent(topics = ["contributor", "referred"])]
+pub struct ReferralRecordedEvent {
+    #[topic]
+    pub referrer: Address,
+    pub referee: Address,
+}
+
+#[contractevent(topics = ["contributor", "deactivated"])]
+pub struct ContributorDeactivatedEvent {
+    #[topic]
+    pub address: Address,
+}
+
+#[contractevent(topics = ["contributor", "reactivated"])]
+pub struct ContributorReactivatedEvent {
+    #[topic]
+    pub address: Address,
+}
+
+#[contractevent(topics = ["reputation", "category"])]
+pub struct CategoryReputationUpdatedEvent {
+    #[topic]
+    pub address: Address,
+    pub category: Symbol,
+    pub old_score: u64,
+    pub new_score: u64,
+}
+
+#[contractevent(topics = ["contributor", "merged"])]
+pub struct ContributorsMergedEvent {
+    #[topic]
+    pub primary: Address,
+    pub duplicate: Address,
+    pub reputation_moved: u64,
+}
+
+#[contractevent(topics = ["reputation", "frozen"])]
+pub struct ReputationFrozenEvent {
+    #[topic]
+    pub address: Address,
+    pub frozen_by: Address,
+}
+
+#[contractevent(topics = ["reputation", "unfrozen"])]
+pub struct ReputationUnfrozenEvent {
+    #[topic]
+    pub address: Address,
+    pub unfrozen_by: Address,
+}
+
+#[contractevent(topics = ["contributor", "profile"])]
+pub struct ProfileUpdatedEvent {
+    #[topic]
+    pub address: Address,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+}
+
+#[contractevent(topics = ["reputation", "milestone"])]
+pub struct MilestoneReachedEvent {
+    #[topic]
+    pub address: Address,
+    pub threshold: u64,
+    pub score: u64,
+}
+
+#[contractevent(topics = ["contributor", "preferences"])]
+pub struct PreferencesUpdatedEvent {
+    #[topic]
+    pub address: Address,
+    pub preferences: u32,
+}
+
+#[contractevent(topics = ["contributor", "handle_released"])]
+pub struct HandleReleasedEvent {
+    #[topic]
+    pub address: Address,
+    pub handle: String,
+    pub admin: Address,
+}
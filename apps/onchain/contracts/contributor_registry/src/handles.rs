@@ -0,0 +1,133 @@
+use soroban_sdk::{symbol_short, Address, Env, Map, String, Symbol};
+
+use crate::errors::ContributorError;
+use crate::storage::{
+    ConfigKey, DataKey, ProfileKey, DEFAULT_MAX_HANDLE_LEN, HANDLE_LEN_LIMIT, MAX_LINKED_HANDLES,
+};
+
+// ── Internal helpers ─────────────────────────────────────────
+
+/// Handles linked on platforms other than GitHub, which stays on the
+/// profile itself as `github_handle`.
+pub(crate) fn linked(env: &Env, address: &Address) -> Map<Symbol, String> {
+    env.storage()
+        .persistent()
+        .get(&ProfileKey::LinkedHandles(address.clone()))
+        .unwrap_or(Map::new(env))
+}
+
+fn save(env: &Env, address: &Address, handles: &Map<Symbol, String>) {
+    let key = ProfileKey::LinkedHandles(address.clone());
+    if handles.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, handles);
+    }
+}
+
+pub(crate) fn max_github_len(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MaxHandleLength)
+        .unwrap_or(DEFAULT_MAX_HANDLE_LEN)
+}
+
+/// GitHub handles are non-empty, at most the configured length, and made of
+/// ASCII alphanumerics and hyphens.
+pub(crate) fn validate_github(env: &Env, handle: &String) -> Result<(), ContributorError> {
+    if handle.is_empty() {
+        return Err(ContributorError::InvalidGitHubHandle);
+    }
+    let len = handle.len();
+    if len > max_github_len(env) {
+        return Err(ContributorError::HandleTooLong);
+    }
+    let mut buf = [0u8; HANDLE_LEN_LIMIT as usize];
+    let bytes = &mut buf[..len as usize];
+    handle.copy_into_slice(bytes);
+    if !bytes
+        .iter()
+        .all(|b| b.is_ascii_alphanumeric() || *b == b'-')
+    {
+        return Err(ContributorError::HandleInvalidChars);
+    }
+    Ok(())
+}
+
+pub(crate) fn github() -> Symbol {
+    symbol_short!("github")
+}
+
+pub(crate) fn owner(env: &Env, platform: &Symbol, handle: &String) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlatformIndex(platform.clone(), handle.clone()))
+}
+
+/// Attach `handle` on `platform` to `address`, replacing any handle already
+/// linked there. Each `(platform, handle)` pair belongs to one profile.
+pub(crate) fn link(
+    env: &Env,
+    address: &Address,
+    platform: &Symbol,
+    handle: &String,
+) -> Result<Option<String>, ContributorError> {
+    if *platform == github() {
+        return Err(ContributorError::InvalidPlatform);
+    }
+    if handle.is_empty() {
+        return Err(ContributorError::InvalidHandle);
+    }
+    if let Some(existing) = owner(env, platform, handle) {
+        if existing != *address {
+            return Err(ContributorError::PlatformHandleTaken);
+        }
+    }
+
+    let mut handles = linked(env, address);
+    let previous = handles.get(platform.clone());
+    if previous.is_none() && handles.len() >= MAX_LINKED_HANDLES {
+        return Err(ContributorError::LimitExceeded);
+    }
+    if let Some(old) = &previous {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PlatformIndex(platform.clone(), old.clone()));
+    }
+    handles.set(platform.clone(), handle.clone());
+    save(env, address, &handles);
+    env.storage().persistent().set(
+        &DataKey::PlatformIndex(platform.clone(), handle.clone()),
+        address,
+    );
+    Ok(previous)
+}
+
+pub(crate) fn unlink(
+    env: &Env,
+    address: &Address,
+    platform: &Symbol,
+) -> Result<String, ContributorError> {
+    let mut handles = linked(env, address);
+    let handle = handles
+        .get(platform.clone())
+        .ok_or(ContributorError::NotFound)?;
+    handles.remove(platform.clone());
+    save(env, address, &handles);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PlatformIndex(platform.clone(), handle.clone()));
+    Ok(handle)
+}
+
+/// Drop every linked handle, used when a profile is deleted.
+pub(crate) fn clear(env: &Env, address: &Address) {
+    for (platform, handle) in linked(env, address).iter() {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PlatformIndex(platform, handle));
+    }
+    env.storage()
+        .persistent()
+        .remove(&ProfileKey::LinkedHandles(address.clone()));
+}
@@ -0,0 +1,64 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::storage::{DataKey, LEADERBOARD_SIZE};
+
+// ── Types ────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    pub address: Address,
+    pub score: u64,
+}
+
+// ── Internal helpers ─────────────────────────────────────────
+
+pub(crate) fn get(env: &Env) -> Vec<LeaderboardEntry> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TopContributors)
+        .unwrap_or(Vec::new(env))
+}
+
+fn without(env: &Env, address: &Address) -> Vec<LeaderboardEntry> {
+    let mut board = get(env);
+    if let Some(i) = board.iter().position(|e| e.address == *address) {
+        board.remove(i as u32);
+    }
+    board
+}
+
+/// Re-rank `address` at `score`. Ties keep the earlier holder in front, and
+/// zero scores are never listed. Contributors pushed off the bottom only
+/// return after their next reputation change.
+pub(crate) fn update(env: &Env, address: &Address, score: u64) {
+    let mut board = without(env, address);
+    if score > 0 {
+        let pos = board
+            .iter()
+            .position(|e| e.score < score)
+            .unwrap_or(board.len() as usize) as u32;
+        if pos < LEADERBOARD_SIZE {
+            board.insert(
+                pos,
+                LeaderboardEntry {
+                    address: address.clone(),
+                    score,
+                },
+            );
+            while board.len() > LEADERBOARD_SIZE {
+                board.pop_back();
+            }
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::TopContributors, &board);
+}
+
+pub(crate) fn remove(env: &Env, address: &Address) {
+    let board = without(env, address);
+    env.storage()
+        .instance()
+        .set(&DataKey::TopContributors, &board);
+}
@@ -1,20 +1,58 @@
 #![no_std]
 
+mod attestation;
+mod boosts;
+mod categories;
+mod contributions;
+mod decay;
+mod delegation;
+mod endorsements;
 mod errors;
 mod events;
+mod handles;
+mod leaderboard;
+mod milestones;
 mod multisig;
+mod skills;
+mod snapshots;
 mod storage;
+mod streaks;
 
+use boosts::BoostWindow;
+use contributions::ContributionData;
 use errors::ContributorError;
-use events::{AdminChangedEvent, MultisigConfiguredEvent, UpgradedEvent};
+use events::{
+    AdminAddedEvent, AdminChangedEvent, AdminRemovedEvent, AdminTransferProposedEvent,
+    CategoryReputationUpdatedEvent, ContractPausedEvent, ContractUnpausedEvent,
+    ContributorBannedEvent, ContributorDeactivatedEvent, ContributorReactivatedEvent,
+    ContributorRegisteredEvent, ContributorRemovedEvent, ContributorUnbannedEvent,
+    ContributorsMergedEvent, HandleLinkedEvent, HandleReleasedEvent, HandleUnlinkedEvent,
+    HandleUpdatedEvent, HandleVerifiedEvent, ModeratorAddedEvent, ModeratorRemovedEvent,
+    MultisigConfiguredEvent, PreferencesUpdatedEvent, ProfileUpdatedEvent, ProfileUriUpdatedEvent,
+    ReferralRecordedEvent, RegistrationSponsoredEvent, ReputationFrozenEvent,
+    ReputationUnfrozenEvent, ReputationUpdatedEvent, UpgradedEvent,
+};
+use leaderboard::LeaderboardEntry;
 use multisig::{
     cancel, consume_approval, expire, get_config, get_proposal, propose, sign, validate_config,
     MultisigConfig, ProposalAction, ProposalStatus, Signer,
 };
-use notification_interface::{Notification, NotificationReceiverTrait};
+use notification_interface::{Notification, NotificationReceiverTrait, ReputationHookClient};
+use snapshots::{Snapshot, SnapshotBuild};
 use soroban_sdk::xdr::FromXdr;
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Symbol, Vec};
-use storage::{ContributorData, DataKey};
+use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env, Map, String, Symbol, Vec};
+use storage::{
+    append_reputation_history, bump_contributor_ttl, bump_instance_ttl, extend_instance_ttl,
+    instance_live_until, read_contributor, ConfigKey, ContributorData, DataKey, DecayConfig,
+    ProfileKey, RangeCursor, RegistrationFee, ReputationEvent, StorageHealth, BPS_DENOMINATOR,
+    HANDLE_LEN_LIMIT, MAX_ADMINS, MAX_BATCH_SIZE, MAX_BIO_LEN, MAX_BOOSTS, MAX_BOOST_BPS,
+    MAX_DISPLAY_NAME_LEN, MAX_ENDORSEMENT_CLEAR, MAX_MERGE_BATCH, MAX_MIGRATION_BATCH,
+    MAX_MILESTONES, MAX_PAGE_SIZE, MAX_RANGE_BUCKETS, MAX_RANGE_PAGE, MAX_REASONS,
+    MAX_REASON_LABEL_LEN, MAX_TIERS, MAX_URI_LEN, PERSISTENT_BUMP_AMOUNT,
+    PERSISTENT_BUMP_THRESHOLD, PREF_ALL, REASON_CONTRIBUTION, REASON_DECAY, REASON_DEPOSIT,
+    REASON_MERGE, REASON_UNSPECIFIED, REGISTRATION_BUCKET,
+};
+use streaks::StreakData;
 
 #[contract]
 pub struct ContributorRegistryContract;
@@ -40,6 +78,450 @@ impl ContributorRegistryContract {
         Ok(())
     }
 
+    /// Swap-remove `address` from the enumeration list so it stays dense.
+    fn remove_from_list(env: &Env, address: &Address) {
+        let index_key = DataKey::ContributorIndex(address.clone());
+        let Some(index) = env.storage().persistent().get::<_, u32>(&index_key) else {
+            return;
+        };
+        let last = Self::contributor_count(env.clone()) - 1;
+        if index != last {
+            let moved: Address = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ContributorAt(last))
+                .unwrap();
+            env.storage()
+                .persistent()
+                .set(&DataKey::ContributorAt(index), &moved);
+            env.storage()
+                .persistent()
+                .set(&DataKey::ContributorIndex(moved), &index);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ContributorAt(last));
+        env.storage().persistent().remove(&index_key);
+        env.storage()
+            .instance()
+            .set(&DataKey::ContributorCount, &last);
+    }
+
+    /// Load a profile, extending its TTL so active contributors never archive.
+    fn load_contributor(env: &Env, address: &Address) -> Result<ContributorData, ContributorError> {
+        let contributor =
+            read_contributor(env, address).ok_or(ContributorError::ContributorNotFound)?;
+        bump_contributor_ttl(
+            env,
+            &contributor,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+        Ok(contributor)
+    }
+
+    fn save_contributor(env: &Env, contributor: &ContributorData) {
+        env.storage().persistent().set(
+            &DataKey::Contributor(contributor.address.clone()),
+            contributor,
+        );
+        bump_contributor_ttl(
+            env,
+            contributor,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+        bump_instance_ttl(env);
+    }
+
+    /// Built-in reason codes are always accepted; anything above them must be
+    /// registered in the codebook with `set_reason`.
+    fn ensure_known_reason(env: &Env, code: u32) -> Result<(), ContributorError> {
+        if code <= REASON_MERGE || Self::get_reasons(env.clone()).contains_key(code) {
+            return Ok(());
+        }
+        Err(ContributorError::UnknownReason)
+    }
+
+    fn ensure_not_banned(env: &Env, address: &Address) -> Result<(), ContributorError> {
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Banned(address.clone()))
+        {
+            return Err(ContributorError::ContributorBanned);
+        }
+        Ok(())
+    }
+
+    fn ensure_not_paused(env: &Env) -> Result<(), ContributorError> {
+        if env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false)
+        {
+            return Err(ContributorError::ContractPaused);
+        }
+        Ok(())
+    }
+
+    /// Paged snapshots read scores as they go, so nothing that moves a score
+    /// or reshuffles the enumeration list may run until the build completes.
+    fn ensure_no_snapshot_build(env: &Env) -> Result<(), ContributorError> {
+        if snapshots::in_progress(env).is_some() {
+            return Err(ContributorError::SnapshotInProgress);
+        }
+        Ok(())
+    }
+
+    /// The admin set. Deployments upgraded from the single-admin layout still
+    /// hold their admin under `DataKey::Admin` until the set is first written.
+    fn load_admins(env: &Env) -> Result<Vec<Address>, ContributorError> {
+        let storage = env.storage().instance();
+        if let Some(admins) = storage.get(&DataKey::Admins) {
+            return Ok(admins);
+        }
+        storage
+            .get::<_, Address>(&DataKey::Admin)
+            .map(|admin| Vec::from_array(env, [admin]))
+            .ok_or(ContributorError::NotInitialized)
+    }
+
+    /// Store the admin set, retiring the legacy single-admin entry
+    fn save_admins(env: &Env, admins: &Vec<Address>) {
+        env.storage().instance().set(&DataKey::Admins, admins);
+        env.storage().instance().remove(&DataKey::Admin);
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), ContributorError> {
+        if !Self::load_admins(env)?.contains(caller) {
+            return Err(ContributorError::Unauthorized);
+        }
+        caller.require_auth();
+        Ok(())
+    }
+
+    fn require_admin_or_moderator(env: &Env, caller: &Address) -> Result<(), ContributorError> {
+        let is_admin = Self::load_admins(env)?.contains(caller);
+        let is_moderator = env
+            .storage()
+            .persistent()
+            .has(&DataKey::Moderator(caller.clone()));
+        if !is_admin && !is_moderator {
+            return Err(ContributorError::Unauthorized);
+        }
+        caller.require_auth();
+        Ok(())
+    }
+
+    /// Enforce the per-contributor update cooldown for moderator-driven
+    /// changes and stamp the current ledger. Admins bypass the check.
+    fn enforce_cooldown(
+        env: &Env,
+        caller: &Address,
+        address: &Address,
+    ) -> Result<(), ContributorError> {
+        let cooldown = Self::get_update_cooldown(env.clone());
+        if cooldown == 0 {
+            return Ok(());
+        }
+        let key = ProfileKey::LastUpdateLedger(address.clone());
+        let now = env.ledger().sequence();
+        let last: Option<u32> = env.storage().persistent().get(&key);
+        if last.is_some_and(|last| now < last.saturating_add(cooldown))
+            && !Self::load_admins(env)?.contains(caller)
+        {
+            return Err(ContributorError::RateLimited);
+        }
+        env.storage().persistent().set(&key, &now);
+        Ok(())
+    }
+
+    /// Apply a signed delta to a contributor's score, saturating at zero and
+    /// clamping at the configured maximum. Returns `(old_score, new_score)`.
+    fn apply_reputation_delta(
+        env: &Env,
+        address: &Address,
+        delta: i64,
+        reason_code: u32,
+        by: &Address,
+    ) -> Result<(u64, u64), ContributorError> {
+        Self::change_reputation(env, address, delta, reason_code, by, true)
+    }
+
+    /// `apply_reputation_delta`, where a gain only counts as activity for
+    /// decay and streaks if `record_activity` is set. Merges move score that
+    /// was earned elsewhere, so they leave both alone.
+    fn change_reputation(
+        env: &Env,
+        address: &Address,
+        delta: i64,
+        reason_code: u32,
+        by: &Address,
+        record_activity: bool,
+    ) -> Result<(u64, u64), ContributorError> {
+        Self::ensure_not_paused(env)?;
+        Self::ensure_no_snapshot_build(env)?;
+        Self::ensure_known_reason(env, reason_code)?;
+        Self::ensure_not_banned(env, address)?;
+        let mut contributor = Self::load_contributor(env, address)?;
+        if contributor.frozen {
+            return Err(ContributorError::ReputationFrozen);
+        }
+
+        let max_score: u64 = env
+            .storage()
+            .instance()
+            .get(&ConfigKey::MaxReputation)
+            .unwrap_or(u64::MAX);
+
+        let old_score = contributor.reputation_score;
+        let new_score = if delta > 0 {
+            old_score
+                .checked_add(delta as u64)
+                .ok_or(ContributorError::ReputationOverflow)?
+                .min(max_score)
+        } else {
+            old_score.saturating_sub(delta.unsigned_abs())
+        };
+        contributor.reputation_score = new_score;
+        Self::save_contributor(env, &contributor);
+        categories::cap(env, address, new_score);
+        if delta > 0 && record_activity {
+            decay::set_last_activity(env, address, env.ledger().timestamp());
+            streaks::record(env, address);
+        }
+        if contributor.active {
+            leaderboard::update(env, address, new_score);
+        }
+        append_reputation_history(
+            env,
+            address,
+            &ReputationEvent {
+                timestamp: env.ledger().timestamp(),
+                delta,
+                reason_code,
+                by: by.clone(),
+            },
+        );
+
+        ReputationUpdatedEvent {
+            address: address.clone(),
+            github_handle: contributor.github_handle,
+            old_score,
+            new_score,
+        }
+        .publish(env);
+        milestones::announce(env, address, old_score, new_score);
+        Self::call_reputation_hook(env, address, old_score, new_score);
+
+        Ok((old_score, new_score))
+    }
+
+    /// Tell the configured hook contract about a score change. A failing hook
+    /// is ignored so it can never block reputation updates.
+    fn call_reputation_hook(env: &Env, address: &Address, old_score: u64, new_score: u64) {
+        if old_score == new_score {
+            return;
+        }
+        let Some(hook) = Self::get_reputation_hook(env.clone()) else {
+            return;
+        };
+        let _ = ReputationHookClient::new(env, &hook)
+            .try_on_reputation_changed(address, &old_score, &new_score);
+    }
+
+    /// Drop a profile together with every index and side record keyed by its
+    /// address. Reputation history and contribution records are kept. A
+    /// referral is taken back off the referrer's count. Endorsements are left
+    /// to the caller.
+    fn delete_profile(env: &Env, contributor: &ContributorData) {
+        let address = &contributor.address;
+        let referrer: Option<Address> = env
+            .storage()
+            .persistent()
+            .get(&ProfileKey::Referrer(address.clone()));
+        if let Some(referrer) = referrer {
+            let count_key = ProfileKey::ReferralCount(referrer);
+            let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+            if count > 1 {
+                env.storage().persistent().set(&count_key, &(count - 1));
+            } else {
+                env.storage().persistent().remove(&count_key);
+            }
+            env.storage()
+                .persistent()
+                .remove(&ProfileKey::Referrer(address.clone()));
+        }
+        env.storage()
+            .persistent()
+            .remove(&ProfileKey::ReferralCount(address.clone()));
+        env.storage()
+            .persistent()
+            .remove(&ProfileKey::Streak(address.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Contributor(address.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::GitHubIndex(contributor.github_handle.clone()));
+        env.storage()
+            .persistent()
+            .remove(&ProfileKey::LastActivity(address.clone()));
+        env.storage()
+            .persistent()
+            .remove(&ProfileKey::Preferences(address.clone()));
+        env.storage()
+            .persistent()
+            .remove(&ProfileKey::LastUpdateLedger(address.clone()));
+        env.storage()
+            .persistent()
+            .remove(&ProfileKey::LiveUntil(address.clone()));
+        Self::remove_from_list(env, address);
+        leaderboard::remove(env, address);
+        skills::clear(env, address);
+        handles::clear(env, address);
+        delegation::clear(env, address);
+        categories::clear(env, address);
+    }
+
+    fn set_frozen(env: &Env, address: &Address, frozen: bool) -> Result<(), ContributorError> {
+        let mut contributor = Self::load_contributor(env, address)?;
+        contributor.frozen = frozen;
+        Self::save_contributor(env, &contributor);
+        Ok(())
+    }
+
+    /// Move the configured registration fee, if any, from `payer` to the
+    /// treasury.
+    fn charge_registration_fee(env: &Env, payer: &Address) -> Result<(), ContributorError> {
+        let Some(fee) = env
+            .storage()
+            .instance()
+            .get::<_, RegistrationFee>(&ConfigKey::RegistrationFee)
+        else {
+            return Ok(());
+        };
+        let treasury: Address = env
+            .storage()
+            .instance()
+            .get(&ConfigKey::Treasury)
+            .ok_or(ContributorError::NotConfigured)?;
+        token::Client::new(env, &fee.token).transfer(payer, &treasury, &fee.amount);
+        Ok(())
+    }
+
+    /// In invite-only mode, use up `address`'s one-time registration
+    /// approval. Outside it, registration is open.
+    fn consume_registration_approval(env: &Env, address: &Address) -> Result<(), ContributorError> {
+        if !Self::is_invite_only(env.clone()) {
+            return Ok(());
+        }
+        let key = ProfileKey::RegistrationApproved(address.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ContributorError::RegistrationNotApproved);
+        }
+        env.storage().persistent().remove(&key);
+        Ok(())
+    }
+
+    /// Write a new profile and its indexes. Callers have already checked
+    /// authorisation for `address`.
+    fn create_profile(
+        env: &Env,
+        payer: &Address,
+        address: Address,
+        github_handle: String,
+    ) -> Result<(), ContributorError> {
+        Self::ensure_not_banned(env, &address)?;
+        handles::validate_github(env, &github_handle)?;
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Contributor(address.clone()))
+        {
+            return Err(ContributorError::ContributorAlreadyExists);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&ProfileKey::MergedInto(address.clone()))
+        {
+            return Err(ContributorError::ContributorMerged);
+        }
+        if !endorsements::skills(env, &address).is_empty() {
+            return Err(ContributorError::EndorsementsPending);
+        }
+        Self::ensure_github_handle_available(env, &github_handle, &address)?;
+        Self::consume_registration_approval(env, &address)?;
+        Self::charge_registration_fee(env, payer)?;
+        Self::insert_profile(
+            env,
+            &ContributorData {
+                address,
+                github_handle,
+                reputation_score: 0,
+                registered_timestamp: env.ledger().timestamp(),
+                verified: false,
+                profile_uri: None,
+                display_name: None,
+                bio: None,
+                active: true,
+                frozen: false,
+            },
+        );
+        Ok(())
+    }
+
+    /// Write a new profile and enter it into the handle index, the
+    /// enumeration list, and its registration-day bucket.
+    fn insert_profile(env: &Env, contributor: &ContributorData) {
+        let address = contributor.address.clone();
+        let github_handle = contributor.github_handle.clone();
+        let timestamp = contributor.registered_timestamp;
+        env.storage()
+            .persistent()
+            .set(&DataKey::GitHubIndex(github_handle.clone()), &address);
+        Self::save_contributor(env, contributor);
+        Self::index_profile(env, &address, timestamp);
+
+        ContributorRegisteredEvent {
+            address,
+            github_handle,
+            registered_timestamp: timestamp,
+        }
+        .publish(env);
+    }
+
+    /// Append `address` to the enumeration list and its registration-day
+    /// bucket.
+    fn index_profile(env: &Env, address: &Address, timestamp: u64) {
+        let count = Self::contributor_count(env.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::ContributorAt(count), &address);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ContributorIndex(address.clone()), &count);
+        env.storage()
+            .instance()
+            .set(&DataKey::ContributorCount, &(count + 1));
+
+        let bucket = timestamp / REGISTRATION_BUCKET;
+        let in_bucket: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RegisteredOnCount(bucket))
+            .unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RegisteredOn(bucket, in_bucket), &address);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RegisteredOnCount(bucket), &(in_bucket + 1));
+    }
+
     // ── Initialisation ───────────────────────────────────────
 
     pub fn initialize(
@@ -68,6 +550,7 @@ impl ContributorRegistryContract {
         env.storage()
             .instance()
             .set(&DataKey::NextProposalId, &0u64);
+        Self::save_admins(&env, &Vec::from_array(&env, [bootstrapper.address.clone()]));
 
         MultisigConfiguredEvent {
             configured_by: bootstrapper.address.clone(),
@@ -140,39 +623,105 @@ impl ContributorRegistryContract {
 
     // ── Contributor operations ───────────────────────────────
 
+    /// Register the caller's profile. `referrer`, when given, must be another
+    /// registered contributor and is credited with the onboarding.
     pub fn register_contributor(
         env: Env,
         address: Address,
         github_handle: String,
+        referrer: Option<Address>,
     ) -> Result<(), ContributorError> {
         if !env.storage().instance().has(&DataKey::MultisigConfig) {
             return Err(ContributorError::NotInitialized);
         }
+        Self::ensure_not_paused(&env)?;
         address.require_auth();
-        if github_handle.is_empty() {
-            return Err(ContributorError::InvalidGitHubHandle);
+        if let Some(referrer) = &referrer {
+            if *referrer == address
+                || !env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::Contributor(referrer.clone()))
+            {
+                return Err(ContributorError::InvalidReferrer);
+            }
         }
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::Contributor(address.clone()))
-        {
-            return Err(ContributorError::ContributorAlreadyExists);
+        Self::create_profile(&env, &address, address.clone(), github_handle)?;
+
+        if let Some(referrer) = referrer {
+            let count_key = ProfileKey::ReferralCount(referrer.clone());
+            let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+            env.storage().persistent().set(&count_key, &(count + 1));
+            env.storage()
+                .persistent()
+                .set(&ProfileKey::Referrer(address.clone()), &referrer);
+
+            ReferralRecordedEvent {
+                referrer,
+                referee: address,
+            }
+            .publish(&env);
         }
-        Self::ensure_github_handle_available(&env, &github_handle, &address)?;
-        let timestamp = env.ledger().timestamp();
-        let contributor = ContributorData {
-            address: address.clone(),
-            github_handle: github_handle.clone(),
-            reputation_score: 0,
-            registered_timestamp: timestamp,
-        };
-        env.storage()
-            .persistent()
-            .set(&DataKey::Contributor(address.clone()), &contributor);
-        env.storage()
-            .persistent()
-            .set(&DataKey::GitHubIndex(github_handle), &address);
+
+        Ok(())
+    }
+
+    /// Register `contributor` on their behalf. The contributor signs the
+    /// registration payload off-chain with their account key and `sponsor`
+    /// submits it, so new contributors need no XLM of their own.
+    pub fn register_for(
+        env: Env,
+        sponsor: Address,
+        contributor: Address,
+        github_handle: String,
+        signature: BytesN<64>,
+    ) -> Result<(), ContributorError> {
+        if !env.storage().instance().has(&DataKey::MultisigConfig) {
+            return Err(ContributorError::NotInitialized);
+        }
+        Self::ensure_not_paused(&env)?;
+        sponsor.require_auth();
+        let nonce = attestation::use_nonce(&env, &contributor);
+        let payload = attestation::registration_payload(&env, &contributor, &github_handle, nonce);
+        attestation::verify_account(&env, &contributor, &payload, &signature)?;
+        Self::create_profile(&env, &sponsor, contributor.clone(), github_handle)?;
+
+        RegistrationSponsoredEvent {
+            address: contributor,
+            sponsor,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Delete a contributor profile and every index pointing at it.
+    /// Callable by the contributor themselves or by the admin. Endorsements
+    /// beyond `MAX_ENDORSEMENT_CLEAR` wait for `purge_endorsements`.
+    pub fn remove_contributor(
+        env: Env,
+        caller: Address,
+        address: Address,
+    ) -> Result<(), ContributorError> {
+        if caller == address {
+            caller.require_auth();
+        } else {
+            Self::require_admin(&env, &caller)?;
+        }
+        Self::ensure_no_snapshot_build(&env)?;
+        let contributor =
+            read_contributor(&env, &address).ok_or(ContributorError::ContributorNotFound)?;
+
+        Self::delete_profile(&env, &contributor);
+        endorsements::clear(&env, &address, MAX_ENDORSEMENT_CLEAR);
+
+        ContributorRemovedEvent {
+            address,
+            github_handle: contributor.github_handle,
+            removed_by: caller,
+        }
+        .publish(&env);
+
         Ok(())
     }
 
@@ -180,474 +729,5196 @@ impl ContributorRegistryContract {
         env: Env,
         address: Address,
         github_handle: String,
+    ) -> Result<(), ContributorError> {
+        Self::update_handle(env, address, github_handle)
+    }
+
+    /// Point a profile at a renamed GitHub account, moving the reverse index
+    /// entry with it.
+    pub fn update_handle(
+        env: Env,
+        address: Address,
+        new_handle: String,
     ) -> Result<(), ContributorError> {
         if !env.storage().instance().has(&DataKey::MultisigConfig) {
             return Err(ContributorError::NotInitialized);
         }
         address.require_auth();
-        if github_handle.is_empty() {
-            return Err(ContributorError::InvalidGitHubHandle);
-        }
-        let mut contributor: ContributorData = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Contributor(address.clone()))
-            .ok_or(ContributorError::ContributorNotFound)?;
+        Self::ensure_not_banned(&env, &address)?;
+        handles::validate_github(&env, &new_handle)?;
+        let mut contributor = Self::load_contributor(&env, &address)?;
 
-        Self::ensure_github_handle_available(&env, &github_handle, &address)?;
-        if contributor.github_handle != github_handle {
+        Self::ensure_github_handle_available(&env, &new_handle, &address)?;
+        let old_handle = contributor.github_handle.clone();
+        if old_handle != new_handle {
             env.storage()
                 .persistent()
-                .remove(&DataKey::GitHubIndex(contributor.github_handle.clone()));
+                .remove(&DataKey::GitHubIndex(old_handle.clone()));
+            // An attestation only vouches for the handle it was signed over.
+            contributor.verified = false;
         }
-        contributor.github_handle = github_handle.clone();
-        env.storage()
-            .persistent()
-            .set(&DataKey::Contributor(address.clone()), &contributor);
+        contributor.github_handle = new_handle.clone();
         env.storage()
             .persistent()
-            .set(&DataKey::GitHubIndex(github_handle), &address);
+            .set(&DataKey::GitHubIndex(new_handle.clone()), &address);
+        Self::save_contributor(&env, &contributor);
+
+        HandleUpdatedEvent {
+            address,
+            old_handle,
+            new_handle,
+        }
+        .publish(&env);
+
         Ok(())
     }
 
-    // ── Sensitive functions — multisig-gated ─────────────────
-
-    pub fn update_reputation(
+    /// Free a squatted GitHub handle. The holder keeps their profile but with
+    /// an empty, unverified handle until they pick another with
+    /// `update_handle`.
+    pub fn release_handle(
         env: Env,
-        executor: Address,
-        proposal_id: u64,
-        contributor_address: Address,
-        delta: i64,
+        admin: Address,
+        github_handle: String,
     ) -> Result<(), ContributorError> {
-        consume_approval(
-            &env,
-            &executor,
-            proposal_id,
-            &ProposalAction::UpdateReputation,
-        )?;
-
-        let mut contributor: ContributorData = env
+        Self::require_admin(&env, &admin)?;
+        let index_key = DataKey::GitHubIndex(github_handle.clone());
+        let address: Address = env
             .storage()
             .persistent()
-            .get(&DataKey::Contributor(contributor_address.clone()))
-            .ok_or(ContributorError::ContributorNotFound)?;
+            .get(&index_key)
+            .ok_or(ContributorError::NotFound)?;
+        env.storage().persistent().remove(&index_key);
+        if let Ok(mut contributor) = Self::load_contributor(&env, &address) {
+            contributor.github_handle = String::from_str(&env, "");
+            contributor.verified = false;
+            Self::save_contributor(&env, &contributor);
+        }
+
+        HandleReleasedEvent {
+            address,
+            handle: github_handle,
+            admin,
+        }
+        .publish(&env);
 
-        let new_score = if delta > 0 {
-            contributor
-                .reputation_score
-                .checked_add(delta as u64)
-                .ok_or(ContributorError::ReputationOverflow)?
-        } else {
-            let abs = delta.checked_abs().unwrap_or(0) as u64;
-            contributor.reputation_score.saturating_sub(abs)
-        };
-        contributor.reputation_score = new_score;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Contributor(contributor_address), &contributor);
         Ok(())
     }
 
-    pub fn upgrade(
+    /// Mark a profile's GitHub handle as verified using an ed25519 signature
+    /// from the configured attester over `(contributor, github_handle, nonce)`.
+    pub fn verify_handle(
         env: Env,
-        executor: Address,
-        proposal_id: u64,
-        new_wasm_hash: BytesN<32>,
+        contributor: Address,
+        attestation: BytesN<64>,
     ) -> Result<(), ContributorError> {
-        consume_approval(&env, &executor, proposal_id, &ProposalAction::Upgrade)?;
+        Self::ensure_not_banned(&env, &contributor)?;
+        let mut profile = Self::load_contributor(&env, &contributor)?;
+        let nonce = attestation::use_nonce(&env, &contributor);
+        let payload =
+            attestation::handle_payload(&env, &contributor, &profile.github_handle, nonce);
+        attestation::verify(&env, &payload, &attestation)?;
 
-        env.deployer()
-            .update_current_contract_wasm(new_wasm_hash.clone());
+        profile.verified = true;
+        Self::save_contributor(&env, &profile);
 
-        UpgradedEvent {
-            admin: executor,
-            new_wasm_hash,
+        HandleVerifiedEvent {
+            address: contributor,
+            github_handle: profile.github_handle,
         }
         .publish(&env);
 
         Ok(())
     }
 
-    pub fn set_admin(
+    /// Set the directory listing's display name and bio. Empty strings clear
+    /// the field.
+    pub fn update_profile(
         env: Env,
-        executor: Address,
-        proposal_id: u64,
-        new_admin: Address,
+        address: Address,
+        display_name: String,
+        bio: String,
     ) -> Result<(), ContributorError> {
-        consume_approval(&env, &executor, proposal_id, &ProposalAction::SetAdmin)?;
+        Self::ensure_not_paused(&env)?;
+        address.require_auth();
+        Self::ensure_not_banned(&env, &address)?;
+        if display_name.len() > MAX_DISPLAY_NAME_LEN || bio.len() > MAX_BIO_LEN {
+            return Err(ContributorError::InvalidProfileField);
+        }
+        let mut contributor = Self::load_contributor(&env, &address)?;
+        contributor.display_name = (!display_name.is_empty()).then_some(display_name);
+        contributor.bio = (!bio.is_empty()).then_some(bio);
+        Self::save_contributor(&env, &contributor);
+
+        ProfileUpdatedEvent {
+            address,
+            display_name: contributor.display_name,
+            bio: contributor.bio,
+        }
+        .publish(&env);
 
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        Ok(())
+    }
 
-        AdminChangedEvent {
-            old_admin: executor,
-            new_admin,
+    /// Attach an identity on another platform (GitLab, Radicle, Discord, ...)
+    /// to the profile. GitHub stays on [`Self::update_handle`].
+    pub fn link_handle(
+        env: Env,
+        address: Address,
+        platform: Symbol,
+        handle: String,
+    ) -> Result<(), ContributorError> {
+        Self::ensure_not_paused(&env)?;
+        address.require_auth();
+        Self::ensure_not_banned(&env, &address)?;
+        Self::load_contributor(&env, &address)?;
+        handles::link(&env, &address, &platform, &handle)?;
+
+        HandleLinkedEvent {
+            address,
+            platform,
+            handle,
         }
         .publish(&env);
 
         Ok(())
     }
 
-    // ── Queries ──────────────────────────────────────────────
+    pub fn unlink_handle(
+        env: Env,
+        address: Address,
+        platform: Symbol,
+    ) -> Result<(), ContributorError> {
+        address.require_auth();
+        let handle = handles::unlink(&env, &address, &platform)?;
 
-    pub fn get_reputation(env: Env, contributor: Address) -> Result<u64, ContributorError> {
-        Ok(Self::get_contributor(env, contributor)?.reputation_score)
+        HandleUnlinkedEvent {
+            address,
+            platform,
+            handle,
+        }
+        .publish(&env);
+
+        Ok(())
     }
 
-    pub fn get_contributor(
+    /// Put the caller's profile on hiatus. History and score are kept, but the
+    /// profile leaves the leaderboard until [`Self::reactivate`]. No-op if
+    /// already inactive.
+    pub fn deactivate(env: Env, address: Address) -> Result<(), ContributorError> {
+        address.require_auth();
+        let mut contributor = Self::load_contributor(&env, &address)?;
+        if !contributor.active {
+            return Ok(());
+        }
+        contributor.active = false;
+        Self::save_contributor(&env, &contributor);
+        leaderboard::remove(&env, &address);
+        ContributorDeactivatedEvent { address }.publish(&env);
+        Ok(())
+    }
+
+    pub fn reactivate(env: Env, address: Address) -> Result<(), ContributorError> {
+        Self::ensure_not_paused(&env)?;
+        address.require_auth();
+        let mut contributor = Self::load_contributor(&env, &address)?;
+        if contributor.active {
+            return Ok(());
+        }
+        contributor.active = true;
+        Self::save_contributor(&env, &contributor);
+        if Self::ensure_not_banned(&env, &address).is_ok() {
+            leaderboard::update(&env, &address, contributor.reputation_score);
+        }
+        ContributorReactivatedEvent { address }.publish(&env);
+        Ok(())
+    }
+
+    /// Point the profile at an extended off-chain document. An empty `uri`
+    /// clears the pointer.
+    pub fn set_profile_uri(
         env: Env,
         address: Address,
-    ) -> Result<ContributorData, ContributorError> {
+        uri: String,
+    ) -> Result<(), ContributorError> {
+        Self::ensure_not_paused(&env)?;
+        address.require_auth();
+        Self::ensure_not_banned(&env, &address)?;
+        if uri.len() > MAX_URI_LEN {
+            return Err(ContributorError::InvalidProfileField);
+        }
+        let mut contributor = Self::load_contributor(&env, &address)?;
+        contributor.profile_uri = if uri.is_empty() { None } else { Some(uri) };
+        Self::save_contributor(&env, &contributor);
+
+        ProfileUriUpdatedEvent {
+            address,
+            uri: contributor.profile_uri,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Record the caller's consent bits (`PREF_*`). Everything defaults to
+    /// off; the web app reads these and must honour them.
+    pub fn set_preferences(
+        env: Env,
+        address: Address,
+        preferences: u32,
+    ) -> Result<(), ContributorError> {
+        Self::ensure_not_paused(&env)?;
+        address.require_auth();
+        Self::ensure_not_banned(&env, &address)?;
+        if preferences & !PREF_ALL != 0 {
+            return Err(ContributorError::InvalidProfileField);
+        }
+        Self::load_contributor(&env, &address)?;
         env.storage()
             .persistent()
-            .get(&DataKey::Contributor(address))
-            .ok_or(ContributorError::ContributorNotFound)
+            .set(&ProfileKey::Preferences(address.clone()), &preferences);
+
+        PreferencesUpdatedEvent {
+            address,
+            preferences,
+        }
+        .publish(&env);
+
+        Ok(())
     }
 
-    pub fn get_contributor_by_github(
+    /// Tag the caller's own profile with `skill` so maintainers can find them
+    /// through [`Self::find_by_skill`].
+    pub fn add_skill(env: Env, address: Address, skill: Symbol) -> Result<(), ContributorError> {
+        Self::ensure_not_paused(&env)?;
+        address.require_auth();
+        Self::ensure_not_banned(&env, &address)?;
+        Self::load_contributor(&env, &address)?;
+        skills::add(&env, &address, &skill)
+    }
+
+    pub fn remove_skill(env: Env, address: Address, skill: Symbol) -> Result<(), ContributorError> {
+        address.require_auth();
+        Self::load_contributor(&env, &address)?;
+        skills::remove(&env, &address, &skill)
+    }
+
+    /// Lend the delegator's voting weight to `delegatee` without moving any
+    /// score. Delegation is one hop only: neither side may be part of
+    /// another delegation chain. Re-delegating replaces the previous choice.
+    pub fn delegate_reputation(
         env: Env,
-        github_handle: String,
-    ) -> Result<ContributorData, ContributorError> {
-        let address: Address = env
+        delegator: Address,
+        delegatee: Address,
+    ) -> Result<(), ContributorError> {
+        Self::ensure_not_paused(&env)?;
+        delegator.require_auth();
+        Self::ensure_not_banned(&env, &delegator)?;
+        Self::ensure_not_banned(&env, &delegatee)?;
+        Self::load_contributor(&env, &delegator)?;
+        Self::load_contributor(&env, &delegatee)?;
+        delegation::delegate(&env, &delegator, &delegatee)
+    }
+
+    pub fn undelegate_reputation(env: Env, delegator: Address) -> Result<(), ContributorError> {
+        delegator.require_auth();
+        delegation::undelegate(&env, &delegator);
+        Ok(())
+    }
+
+    /// Vouch for `endorsee`'s `skill`. Both parties must be registered and
+    /// in good standing, and each endorser counts once per skill.
+    pub fn endorse(
+        env: Env,
+        endorser: Address,
+        endorsee: Address,
+        skill: Symbol,
+    ) -> Result<u32, ContributorError> {
+        Self::ensure_not_paused(&env)?;
+        endorser.require_auth();
+        Self::ensure_not_banned(&env, &endorser)?;
+        Self::ensure_not_banned(&env, &endorsee)?;
+        Self::load_contributor(&env, &endorser)?;
+        Self::load_contributor(&env, &endorsee)?;
+        endorsements::endorse(&env, &endorser, &endorsee, &skill)
+    }
+
+    /// Clear the next `MAX_ENDORSEMENT_CLEAR` endorsements left behind by a
+    /// deleted profile, so the address can register again. Anyone may call
+    /// it. Returns whether any are left.
+    pub fn purge_endorsements(env: Env, address: Address) -> Result<bool, ContributorError> {
+        if env
             .storage()
             .persistent()
-            .get(&DataKey::GitHubIndex(github_handle))
-            .ok_or(ContributorError::ContributorNotFound)?;
-        Self::get_contributor(env, address)
+            .has(&DataKey::Contributor(address.clone()))
+        {
+            return Err(ContributorError::ContributorAlreadyExists);
+        }
+        Ok(endorsements::clear(&env, &address, MAX_ENDORSEMENT_CLEAR))
     }
 
-    pub fn get_multisig_config(env: Env) -> Result<MultisigConfig, ContributorError> {
-        get_config(&env)
+    // ── Admin operations ─────────────────────────────────────
+
+    /// Set the ed25519 public key whose signatures count as handle attestations.
+    pub fn set_attester(
+        env: Env,
+        admin: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&ConfigKey::Attester, &public_key);
+        Ok(())
     }
 
-    pub fn get_proposal(
+    /// Grade `address` in a single category (code, review, docs, ...). The
+    /// change is mirrored onto the raw `reputation_score`; the category split
+    /// feeds [`Self::get_weighted_reputation`]. Returns the new category score.
+    pub fn add_category_reputation(
         env: Env,
-        proposal_id: u64,
-    ) -> Result<multisig::Proposal, ContributorError> {
-        get_proposal(&env, proposal_id)
+        caller: Address,
+        address: Address,
+        category: Symbol,
+        delta: i64,
+        reason_code: u32,
+    ) -> Result<u64, ContributorError> {
+        Self::require_admin_or_moderator(&env, &caller)?;
+        Self::ensure_not_paused(&env)?;
+        Self::ensure_not_banned(&env, &address)?;
+        Self::load_contributor(&env, &address)?;
+        Self::enforce_cooldown(&env, &caller, &address)?;
+        let delta = boosts::apply(&env, delta);
+        let (old_score, new_score) = categories::apply(&env, &address, &category, delta)?;
+        let applied = new_score as i128 - old_score as i128;
+        Self::apply_reputation_delta(&env, &address, applied as i64, reason_code, &caller)?;
+
+        CategoryReputationUpdatedEvent {
+            address,
+            category,
+            old_score,
+            new_score,
+        }
+        .publish(&env);
+
+        Ok(new_score)
+    }
+
+    /// Adjust a contributor's score by `delta`. Negative deltas saturate at
+    /// zero and positive deltas are clamped to the configured maximum, so
+    /// concurrent grading jobs can never clobber each other's updates.
+    /// Callable by the admin or any moderator.
+    pub fn add_reputation(
+        env: Env,
+        caller: Address,
+        address: Address,
+        delta: i64,
+        reason_code: u32,
+    ) -> Result<u64, ContributorError> {
+        Self::require_admin_or_moderator(&env, &caller)?;
+        Self::enforce_cooldown(&env, &caller, &address)?;
+        let delta = boosts::apply(&env, delta);
+        let (_, new_score) =
+            Self::apply_reputation_delta(&env, &address, delta, reason_code, &caller)?;
+        Ok(new_score)
+    }
+
+    /// Credit points to many contributors in one invocation, e.g. for a
+    /// weekly grading job. The whole batch reverts if any entry fails.
+    pub fn update_reputation_batch(
+        env: Env,
+        admin: Address,
+        updates: Vec<(Address, u64)>,
+        reason_code: u32,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin_or_moderator(&env, &admin)?;
+        if updates.len() > MAX_BATCH_SIZE {
+            return Err(ContributorError::LimitExceeded);
+        }
+        for (address, points) in updates.iter() {
+            let delta = i64::try_from(points).map_err(|_| ContributorError::ReputationOverflow)?;
+            Self::enforce_cooldown(&env, &admin, &address)?;
+            let delta = boosts::apply(&env, delta);
+            Self::apply_reputation_delta(&env, &address, delta, reason_code, &admin)?;
+        }
+        Ok(())
+    }
+
+    /// Record a graded pull request for `contributor` and credit its points
+    /// to their reputation. Callable by the admin or any moderator.
+    pub fn record_contribution(
+        env: Env,
+        moderator: Address,
+        contributor: Address,
+        repo: String,
+        pr_number: u32,
+        points: u32,
+    ) -> Result<u64, ContributorError> {
+        Self::require_admin_or_moderator(&env, &moderator)?;
+        Self::enforce_cooldown(&env, &moderator, &contributor)?;
+        Self::apply_reputation_delta(
+            &env,
+            &contributor,
+            boosts::apply(&env, points as i64),
+            REASON_CONTRIBUTION,
+            &moderator,
+        )?;
+        contributions::record(&env, &moderator, &contributor, repo, pr_number, points)
+    }
+
+    /// Flag `address` as banned: its profile can no longer be updated or
+    /// graded, and it cannot register again. Callable by admin or moderators.
+    pub fn ban_contributor(
+        env: Env,
+        caller: Address,
+        address: Address,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin_or_moderator(&env, &caller)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Banned(address.clone()), &true);
+        leaderboard::remove(&env, &address);
+        ContributorBannedEvent {
+            address,
+            banned_by: caller,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn unban_contributor(
+        env: Env,
+        caller: Address,
+        address: Address,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin_or_moderator(&env, &caller)?;
+        let key = DataKey::Banned(address.clone());
+        if env.storage().persistent().has(&key) {
+            env.storage().persistent().remove(&key);
+            if let Ok(contributor) = Self::load_contributor(&env, &address) {
+                if contributor.active {
+                    leaderboard::update(&env, &address, contributor.reputation_score);
+                }
+            }
+            ContributorUnbannedEvent {
+                address,
+                unbanned_by: caller,
+            }
+            .publish(&env);
+        }
+        Ok(())
+    }
+
+    /// Add another admin. Any existing admin may extend the set.
+    pub fn add_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        let mut admins = Self::load_admins(&env)?;
+        if admins.contains(&new_admin) {
+            return Err(ContributorError::AlreadyExists);
+        }
+        if admins.len() >= MAX_ADMINS {
+            return Err(ContributorError::LimitExceeded);
+        }
+        admins.push_back(new_admin.clone());
+        Self::save_admins(&env, &admins);
+        AdminAddedEvent { admin, new_admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Remove `target` from the admin set. The last admin cannot be removed.
+    pub fn remove_admin(env: Env, admin: Address, target: Address) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        let mut admins = Self::load_admins(&env)?;
+        let index = admins
+            .first_index_of(&target)
+            .ok_or(ContributorError::NotFound)?;
+        if admins.len() == 1 {
+            return Err(ContributorError::LastAdmin);
+        }
+        admins.remove(index);
+        Self::save_admins(&env, &admins);
+        // A nomination made by the removed admin goes with them
+        let pending: Option<(Address, Address)> =
+            env.storage().instance().get(&DataKey::PendingAdmin);
+        if matches!(pending, Some((proposer, _)) if proposer == target) {
+            env.storage().instance().remove(&DataKey::PendingAdmin);
+        }
+        AdminRemovedEvent {
+            admin,
+            removed: target,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Lock `address`'s score while a dispute is resolved. Grading, decay and
+    /// merges all leave a frozen profile untouched.
+    pub fn freeze_reputation(
+        env: Env,
+        moderator: Address,
+        address: Address,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin_or_moderator(&env, &moderator)?;
+        Self::set_frozen(&env, &address, true)?;
+        ReputationFrozenEvent {
+            address,
+            frozen_by: moderator,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn unfreeze_reputation(
+        env: Env,
+        moderator: Address,
+        address: Address,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin_or_moderator(&env, &moderator)?;
+        Self::set_frozen(&env, &address, false)?;
+        ReputationUnfrozenEvent {
+            address,
+            unfrozen_by: moderator,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Grant `moderator` permission to grade contributors.
+    pub fn add_moderator(
+        env: Env,
+        admin: Address,
+        moderator: Address,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Moderator(moderator.clone()), &true);
+        ModeratorAddedEvent { admin, moderator }.publish(&env);
+        Ok(())
+    }
+
+    /// Revoke a moderator's grading permission. No-op if not a moderator.
+    pub fn remove_moderator(
+        env: Env,
+        admin: Address,
+        moderator: Address,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        let key = DataKey::Moderator(moderator.clone());
+        if env.storage().persistent().has(&key) {
+            env.storage().persistent().remove(&key);
+            ModeratorRemovedEvent { admin, moderator }.publish(&env);
+        }
+        Ok(())
+    }
+
+    /// Emergency brake: block registrations and reputation changes.
+    pub fn pause(env: Env, admin: Address) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        Self::ensure_not_paused(&env)?;
+        env.storage().instance().set(&DataKey::Paused, &true);
+        ContractPausedEvent {
+            admin,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn unpause(env: Env, admin: Address) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if !Self::is_paused(env.clone()) {
+            return Err(ContributorError::ContractNotPaused);
+        }
+        env.storage().instance().set(&DataKey::Paused, &false);
+        ContractUnpausedEvent {
+            admin,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Configure tier boundaries as strictly ascending score thresholds.
+    /// A contributor's tier is the number of thresholds their score meets,
+    /// so `[100, 500, 1000]` maps to tiers 0 (below 100) through 3.
+    pub fn set_tiers(
+        env: Env,
+        admin: Address,
+        thresholds: Vec<u64>,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if thresholds.len() > MAX_TIERS {
+            return Err(ContributorError::InvalidConfig);
+        }
+        let mut previous: Option<u64> = None;
+        for threshold in thresholds.iter() {
+            if previous.is_some_and(|p| threshold <= p) {
+                return Err(ContributorError::InvalidConfig);
+            }
+            previous = Some(threshold);
+        }
+        env.storage().instance().set(&ConfigKey::Tiers, &thresholds);
+        Ok(())
+    }
+
+    /// Schedule a boost: reputation additions made between `start`
+    /// (inclusive) and `end` (exclusive) are scaled by `multiplier_bps`.
+    /// Windows that have already ended are dropped.
+    pub fn set_boost(
+        env: Env,
+        admin: Address,
+        start: u64,
+        end: u64,
+        multiplier_bps: u32,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if start >= end {
+            return Err(ContributorError::InvalidRange);
+        }
+        if !(BPS_DENOMINATOR..=MAX_BOOST_BPS).contains(&multiplier_bps) {
+            return Err(ContributorError::InvalidConfig);
+        }
+        let now = env.ledger().timestamp();
+        let mut windows = Vec::new(&env);
+        for window in boosts::windows(&env).iter() {
+            if window.end > now {
+                windows.push_back(window);
+            }
+        }
+        if windows.len() >= MAX_BOOSTS {
+            return Err(ContributorError::LimitExceeded);
+        }
+        windows.push_back(BoostWindow {
+            start,
+            end,
+            multiplier_bps,
+        });
+        env.storage().instance().set(&ConfigKey::Boosts, &windows);
+        Ok(())
+    }
+
+    pub fn clear_boosts(env: Env, admin: Address) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().remove(&ConfigKey::Boosts);
+        Ok(())
+    }
+
+    /// Let moderators change any one contributor's score at most once per
+    /// `ledgers` ledgers. Zero turns the limit off; admins are never limited.
+    pub fn set_update_cooldown(
+        env: Env,
+        admin: Address,
+        ledgers: u32,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&ConfigKey::UpdateCooldown, &ledgers);
+        Ok(())
+    }
+
+    /// Call `on_reputation_changed(address, old, new)` on `contract` after
+    /// every score change.
+    pub fn set_reputation_hook(
+        env: Env,
+        admin: Address,
+        contract: Address,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&ConfigKey::ReputationHook, &contract);
+        Ok(())
+    }
+
+    pub fn clear_reputation_hook(env: Env, admin: Address) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().remove(&ConfigKey::ReputationHook);
+        Ok(())
+    }
+
+    /// Register or relabel a reason code for reputation changes. Codes up to
+    /// `REASON_MERGE` are reserved for the registry's own bookkeeping.
+    pub fn set_reason(
+        env: Env,
+        admin: Address,
+        code: u32,
+        label: String,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if code <= REASON_MERGE || label.is_empty() || label.len() > MAX_REASON_LABEL_LEN {
+            return Err(ContributorError::InvalidConfig);
+        }
+        let mut reasons = Self::get_reasons(env.clone());
+        if !reasons.contains_key(code) && reasons.len() >= MAX_REASONS {
+            return Err(ContributorError::LimitExceeded);
+        }
+        reasons.set(code, label);
+        env.storage().instance().set(&ConfigKey::Reasons, &reasons);
+        Ok(())
+    }
+
+    /// Seed the registry with legacy profiles without the contributors
+    /// signing anything. Scores above the configured maximum are clamped to
+    /// it; banned and merged-away addresses are refused. Only available until
+    /// `finalize_import`.
+    pub fn import_contributors(
+        env: Env,
+        admin: Address,
+        entries: Vec<ContributorData>,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if Self::is_import_finalized(env.clone()) {
+            return Err(ContributorError::ImportFinalized);
+        }
+        if entries.len() > MAX_BATCH_SIZE {
+            return Err(ContributorError::LimitExceeded);
+        }
+        let max_score: u64 = env
+            .storage()
+            .instance()
+            .get(&ConfigKey::MaxReputation)
+            .unwrap_or(u64::MAX);
+        for mut entry in entries.iter() {
+            Self::ensure_not_banned(&env, &entry.address)?;
+            handles::validate_github(&env, &entry.github_handle)?;
+            if entry
+                .profile_uri
+                .as_ref()
+                .is_some_and(|u| u.len() > MAX_URI_LEN)
+                || entry
+                    .display_name
+                    .as_ref()
+                    .is_some_and(|n| n.len() > MAX_DISPLAY_NAME_LEN)
+                || entry.bio.as_ref().is_some_and(|b| b.len() > MAX_BIO_LEN)
+            {
+                return Err(ContributorError::InvalidProfileField);
+            }
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::Contributor(entry.address.clone()))
+            {
+                return Err(ContributorError::ContributorAlreadyExists);
+            }
+            if env
+                .storage()
+                .persistent()
+                .has(&ProfileKey::MergedInto(entry.address.clone()))
+            {
+                return Err(ContributorError::ContributorMerged);
+            }
+            Self::ensure_github_handle_available(&env, &entry.github_handle, &entry.address)?;
+
+            entry.reputation_score = entry.reputation_score.min(max_score);
+            Self::insert_profile(&env, &entry);
+            if entry.active && entry.reputation_score > 0 {
+                leaderboard::update(&env, &entry.address, entry.reputation_score);
+            }
+        }
+        Ok(())
+    }
+
+    /// Rewrite profiles stored in the original four-field layout in the
+    /// current one, and enter them into the enumeration list, registration
+    /// buckets and leaderboard, which did not exist when they were written.
+    /// Reads already handle the old layout; this makes the profiles visible
+    /// to listings and snapshots. Addresses that are already current are
+    /// skipped. At most `MAX_MIGRATION_BATCH` addresses per call.
+    pub fn migrate_contributors(
+        env: Env,
+        admin: Address,
+        addresses: Vec<Address>,
+    ) -> Result<u32, ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if addresses.len() > MAX_MIGRATION_BATCH {
+            return Err(ContributorError::LimitExceeded);
+        }
+        let mut migrated = 0;
+        for address in addresses.iter() {
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::ContributorIndex(address.clone()))
+            {
+                continue;
+            }
+            let contributor =
+                read_contributor(&env, &address).ok_or(ContributorError::ContributorNotFound)?;
+            Self::save_contributor(&env, &contributor);
+            Self::index_profile(&env, &address, contributor.registered_timestamp);
+            if contributor.reputation_score > 0 {
+                leaderboard::update(&env, &address, contributor.reputation_score);
+            }
+            migrated += 1;
+        }
+        Ok(migrated)
+    }
+
+    /// Close the import window for good.
+    pub fn finalize_import(env: Env, admin: Address) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if Self::is_import_finalized(env.clone()) {
+            return Err(ContributorError::ImportFinalized);
+        }
+        env.storage()
+            .instance()
+            .set(&ConfigKey::ImportFinalized, &true);
+        Ok(())
+    }
+
+    /// Toggle invite-only mode, in which only addresses pre-approved with
+    /// `approve_registration` can register.
+    pub fn set_invite_only(
+        env: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&ConfigKey::InviteOnly, &enabled);
+        Ok(())
+    }
+
+    /// Let `address` register once while invite-only mode is on.
+    pub fn approve_registration(
+        env: Env,
+        admin: Address,
+        address: Address,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .persistent()
+            .set(&ProfileKey::RegistrationApproved(address), &true);
+        Ok(())
+    }
+
+    pub fn revoke_registration(
+        env: Env,
+        admin: Address,
+        address: Address,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        let key = ProfileKey::RegistrationApproved(address);
+        if !env.storage().persistent().has(&key) {
+            return Err(ContributorError::NotFound);
+        }
+        env.storage().persistent().remove(&key);
+        Ok(())
+    }
+
+    /// Replace the reputation milestones announced with a
+    /// `("reputation", "milestone")` event. Thresholds must be strictly
+    /// ascending.
+    pub fn set_milestones(
+        env: Env,
+        admin: Address,
+        thresholds: Vec<u64>,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if thresholds.len() > MAX_MILESTONES {
+            return Err(ContributorError::InvalidConfig);
+        }
+        let mut previous: Option<u64> = None;
+        for threshold in thresholds.iter() {
+            if previous.is_some_and(|p| threshold <= p) {
+                return Err(ContributorError::InvalidConfig);
+            }
+            previous = Some(threshold);
+        }
+        env.storage()
+            .instance()
+            .set(&ConfigKey::Milestones, &thresholds);
+        Ok(())
+    }
+
+    /// Charge `amount` of `token` on every new registration, paid by the
+    /// contributor (or their sponsor) into the treasury. An `amount` of zero
+    /// turns the fee off.
+    pub fn set_registration_fee(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if amount < 0 {
+            return Err(ContributorError::InvalidConfig);
+        }
+        if amount == 0 {
+            env.storage().instance().remove(&ConfigKey::RegistrationFee);
+            return Ok(());
+        }
+        if !env.storage().instance().has(&ConfigKey::Treasury) {
+            return Err(ContributorError::NotConfigured);
+        }
+        env.storage().instance().set(
+            &ConfigKey::RegistrationFee,
+            &RegistrationFee { token, amount },
+        );
+        Ok(())
+    }
+
+    /// Set the address that receives registration fees.
+    pub fn set_treasury(
+        env: Env,
+        admin: Address,
+        treasury: Address,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&ConfigKey::Treasury, &treasury);
+        Ok(())
+    }
+
+    /// Set the longest GitHub handle accepted on registration or rename,
+    /// between 1 and `HANDLE_LEN_LIMIT`. Existing profiles are unaffected.
+    pub fn set_max_handle_length(
+        env: Env,
+        admin: Address,
+        max_len: u32,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if max_len == 0 || max_len > HANDLE_LEN_LIMIT {
+            return Err(ContributorError::InvalidConfig);
+        }
+        env.storage()
+            .instance()
+            .set(&ConfigKey::MaxHandleLength, &max_len);
+        Ok(())
+    }
+
+    pub fn get_max_handle_length(env: Env) -> u32 {
+        handles::max_github_len(&env)
+    }
+
+    /// Replace the category weights, in percent of `WEIGHT_BASE`. Only the
+    /// categories listed here can be graded afterwards.
+    pub fn set_category_weights(
+        env: Env,
+        admin: Address,
+        weights: Map<Symbol, u32>,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        categories::set_weights(&env, &weights)
+    }
+
+    /// Set the maximum reputation score any contributor can reach.
+    pub fn set_max_reputation(
+        env: Env,
+        admin: Address,
+        max_score: u64,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&ConfigKey::MaxReputation, &max_score);
+        Ok(())
+    }
+
+    /// Configure inactivity decay: each full `epoch_length` seconds without a
+    /// reputation gain costs `rate_bps` basis points of the current score.
+    pub fn set_decay_config(
+        env: Env,
+        admin: Address,
+        epoch_length: u64,
+        rate_bps: u32,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if epoch_length == 0 || rate_bps == 0 || rate_bps > BPS_DENOMINATOR {
+            return Err(ContributorError::InvalidConfig);
+        }
+        env.storage().instance().set(
+            &ConfigKey::DecayConfig,
+            &DecayConfig {
+                epoch_length,
+                rate_bps,
+            },
+        );
+        Ok(())
+    }
+
+    /// Keeper entry point: apply any elapsed decay epochs to `contributors`.
+    /// Unknown or banned addresses are skipped so one bad entry cannot block
+    /// the rest. Returns how many scores actually fell.
+    pub fn decay_reputation(
+        env: Env,
+        caller: Address,
+        contributors: Vec<Address>,
+    ) -> Result<u32, ContributorError> {
+        Self::require_admin_or_moderator(&env, &caller)?;
+        Self::ensure_not_paused(&env)?;
+        if contributors.len() > MAX_BATCH_SIZE {
+            return Err(ContributorError::LimitExceeded);
+        }
+        let config = decay::config(&env)?;
+
+        let mut decayed = 0;
+        for address in contributors.iter() {
+            if Self::ensure_not_banned(&env, &address).is_err() {
+                continue;
+            }
+            let Ok(contributor) = Self::load_contributor(&env, &address) else {
+                continue;
+            };
+            if contributor.frozen {
+                continue;
+            }
+            let (epochs, new_score) = decay::pending(&env, &config, &contributor);
+            if epochs == 0 {
+                continue;
+            }
+            let anchor = decay::last_activity(&env, &contributor);
+            decay::set_last_activity(&env, &address, anchor + epochs * config.epoch_length);
+
+            let loss = contributor.reputation_score - new_score;
+            if loss > 0 {
+                categories::rescale(&env, &address, contributor.reputation_score, new_score);
+                let delta = -i64::try_from(loss).unwrap_or(i64::MAX);
+                Self::apply_reputation_delta(&env, &address, delta, REASON_DECAY, &caller)?;
+                decayed += 1;
+            }
+        }
+        Ok(decayed)
+    }
+
+    /// Set the length, in seconds, of the windows used for streak tracking.
+    pub fn set_streak_window(
+        env: Env,
+        admin: Address,
+        window: u64,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if window == 0 {
+            return Err(ContributorError::InvalidConfig);
+        }
+        env.storage()
+            .instance()
+            .set(&ConfigKey::StreakWindow, &window);
+        Ok(())
+    }
+
+    /// Admin-defined reason codes and their labels. Built-in codes are not
+    /// listed.
+    pub fn get_reasons(env: Env) -> Map<u32, String> {
+        env.storage()
+            .instance()
+            .get(&ConfigKey::Reasons)
+            .unwrap_or(Map::new(&env))
+    }
+
+    pub fn is_import_finalized(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&ConfigKey::ImportFinalized)
+            .unwrap_or(false)
+    }
+
+    pub fn is_invite_only(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&ConfigKey::InviteOnly)
+            .unwrap_or(false)
+    }
+
+    pub fn is_registration_approved(env: Env, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&ProfileKey::RegistrationApproved(address))
+    }
+
+    pub fn get_boosts(env: Env) -> Vec<BoostWindow> {
+        boosts::windows(&env)
+    }
+
+    /// Multiplier applied to reputation additions right now, in basis points.
+    pub fn get_boost_multiplier(env: Env) -> u32 {
+        boosts::current_multiplier(&env)
+    }
+
+    pub fn get_update_cooldown(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&ConfigKey::UpdateCooldown)
+            .unwrap_or(0)
+    }
+
+    pub fn get_reputation_hook(env: Env) -> Option<Address> {
+        env.storage().instance().get(&ConfigKey::ReputationHook)
+    }
+
+    pub fn get_preferences(env: Env, address: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ProfileKey::Preferences(address))
+            .unwrap_or(0)
+    }
+
+    pub fn get_milestones(env: Env) -> Vec<u64> {
+        milestones::thresholds(&env)
+    }
+
+    pub fn get_streak_window(env: Env) -> u64 {
+        streaks::window(&env)
+    }
+
+    pub fn get_streak(env: Env, address: Address) -> StreakData {
+        streaks::get(&env, &address)
+    }
+
+    pub fn get_decay_config(env: Env) -> Result<DecayConfig, ContributorError> {
+        decay::config(&env)
+    }
+
+    /// Freeze current scores under a new epoch ID and store the Merkle root
+    /// of the `(address, score)` leaves for downstream airdrop and voting
+    /// contracts. Each call folds in up to `limit` more contributors, capped
+    /// at `MAX_SNAPSHOT_PAGE`; call again until it returns the new epoch.
+    pub fn snapshot_epoch(
+        env: Env,
+        admin: Address,
+        limit: u32,
+    ) -> Result<Option<u32>, ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        let count = Self::contributor_count(env.clone());
+        Ok(snapshots::advance(&env, count, limit).map(|snapshot| snapshot.epoch))
+    }
+
+    /// Discard a half-built snapshot, lifting the freeze it holds on scores.
+    pub fn cancel_snapshot(env: Env, admin: Address) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if snapshots::in_progress(&env).is_none() {
+            return Err(ContributorError::InvalidConfig);
+        }
+        env.storage().instance().remove(&DataKey::SnapshotBuild);
+        Ok(())
+    }
+
+    /// Fold a duplicate registration into `primary`: reputation (including
+    /// category splits) moves over without counting as new activity, and
+    /// `duplicate` is deleted and tombstoned so it cannot register again. Its
+    /// contribution records and endorsements follow in pages through
+    /// `move_merged_records`.
+    pub fn merge_contributors(
+        env: Env,
+        admin: Address,
+        primary: Address,
+        duplicate: Address,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if primary == duplicate {
+            return Err(ContributorError::InvalidConfig);
+        }
+        Self::ensure_no_snapshot_build(&env)?;
+        Self::load_contributor(&env, &primary)?;
+        let dup = Self::load_contributor(&env, &duplicate)?;
+        if dup.frozen {
+            return Err(ContributorError::ReputationFrozen);
+        }
+
+        let moved = dup.reputation_score;
+        if moved > 0 {
+            let delta = i64::try_from(moved).map_err(|_| ContributorError::ReputationOverflow)?;
+            Self::change_reputation(&env, &primary, delta, REASON_MERGE, &admin, false)?;
+        }
+        categories::merge_into(&env, &duplicate, &primary)?;
+        let merged = Self::load_contributor(&env, &primary)?.reputation_score;
+        categories::cap(&env, &primary, merged);
+
+        Self::delete_profile(&env, &dup);
+        env.storage()
+            .persistent()
+            .set(&ProfileKey::MergedInto(duplicate.clone()), &primary);
+
+        ContributorsMergedEvent {
+            primary,
+            duplicate,
+            reputation_moved: moved,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Move the next `MAX_MERGE_BATCH` contribution records and endorsements
+    /// of a merged duplicate onto the profile it was merged into. Anyone may
+    /// call it. Returns whether any are left.
+    pub fn move_merged_records(env: Env, duplicate: Address) -> Result<bool, ContributorError> {
+        let primary = Self::get_merged_into(env.clone(), duplicate.clone())
+            .ok_or(ContributorError::ContributorNotFound)?;
+        let moved = contributions::reassign(&env, &duplicate, &primary, MAX_MERGE_BATCH);
+        endorsements::reassign(&env, &duplicate, &primary, MAX_MERGE_BATCH - moved);
+        Ok(contributions::contribution_count(&env, &duplicate) > 0
+            || !endorsements::skills(&env, &duplicate).is_empty())
+    }
+
+    /// Nominate `new_admin` as the next admin. The role only moves once the
+    /// nominee calls [`Self::accept_admin`]; a later nomination replaces this one.
+    pub fn propose_admin(
+        env: Env,
+        current_admin: Address,
+        new_admin: Address,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &current_admin)?;
+        env.storage().instance().set(
+            &DataKey::PendingAdmin,
+            &(current_admin.clone(), new_admin.clone()),
+        );
+
+        AdminTransferProposedEvent {
+            current_admin,
+            pending_admin: new_admin,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Claim the admin role previously offered via [`Self::propose_admin`].
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), ContributorError> {
+        let (old_admin, pending): (Address, Address) = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(ContributorError::NoPendingAdmin)?;
+        if pending != new_admin {
+            return Err(ContributorError::Unauthorized);
+        }
+        new_admin.require_auth();
+
+        // The nominee takes the proposer's seat in the admin set, so the
+        // proposer must still hold one.
+        let mut admins = Self::load_admins(&env)?;
+        let seat = admins
+            .first_index_of(&old_admin)
+            .ok_or(ContributorError::Unauthorized)?;
+        admins.remove(seat);
+        if !admins.contains(&new_admin) {
+            admins.push_back(new_admin.clone());
+        }
+        Self::save_admins(&env, &admins);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        AdminChangedEvent {
+            old_admin,
+            new_admin,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    // ── Sensitive functions — multisig-gated ─────────────────
+
+    pub fn update_reputation(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        contributor_address: Address,
+        delta: i64,
+    ) -> Result<(), ContributorError> {
+        consume_approval(
+            &env,
+            &executor,
+            proposal_id,
+            &ProposalAction::UpdateReputation,
+        )?;
+
+        Self::apply_reputation_delta(
+            &env,
+            &contributor_address,
+            boosts::apply(&env, delta),
+            REASON_UNSPECIFIED,
+            &executor,
+        )?;
+        Ok(())
+    }
+
+    /// Replace the contract WASM in place, keeping all contributor storage
+    /// under the same contract address.
+    ///
+    /// Upgrades are deliberately not single-admin: `executor` must hold an
+    /// approved [`ProposalAction::Upgrade`] proposal. Emits [`UpgradedEvent`].
+    pub fn upgrade(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), ContributorError> {
+        consume_approval(&env, &executor, proposal_id, &ProposalAction::Upgrade)?;
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        UpgradedEvent {
+            admin: executor,
+            new_wasm_hash,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    pub fn set_admin(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        new_admin: Address,
+    ) -> Result<(), ContributorError> {
+        consume_approval(&env, &executor, proposal_id, &ProposalAction::SetAdmin)?;
+
+        // Multisig recovery path: the whole admin set collapses to `new_admin`.
+        Self::save_admins(&env, &Vec::from_array(&env, [new_admin.clone()]));
+
+        AdminChangedEvent {
+            old_admin: executor,
+            new_admin,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    // ── Queries ──────────────────────────────────────────────
+
+    pub fn get_reputation(env: Env, contributor: Address) -> Result<u64, ContributorError> {
+        Ok(Self::get_contributor(env, contributor)?.reputation_score)
+    }
+
+    /// Gate for other contracts: succeeds only if `address` is a registered,
+    /// unbanned contributor with at least `min` reputation.
+    pub fn require_min_reputation(
+        env: Env,
+        address: Address,
+        min: u64,
+    ) -> Result<(), ContributorError> {
+        Self::ensure_not_banned(&env, &address)?;
+        if Self::load_contributor(&env, &address)?.reputation_score < min {
+            return Err(ContributorError::InsufficientReputation);
+        }
+        Ok(())
+    }
+
+    pub fn has_min_reputation(env: Env, address: Address, min: u64) -> bool {
+        Self::require_min_reputation(env, address, min).is_ok()
+    }
+
+    /// The longest-serving member of the admin set.
+    pub fn get_admin(env: Env) -> Result<Address, ContributorError> {
+        Ok(Self::load_admins(&env)?.get_unchecked(0))
+    }
+
+    pub fn get_admins(env: Env) -> Result<Vec<Address>, ContributorError> {
+        Self::load_admins(&env)
+    }
+
+    pub fn is_admin(env: Env, address: Address) -> bool {
+        Self::load_admins(&env)
+            .map(|admins| admins.contains(&address))
+            .unwrap_or(false)
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false)
+    }
+
+    pub fn is_banned(env: Env, address: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Banned(address))
+    }
+
+    pub fn is_moderator(env: Env, address: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Moderator(address))
+    }
+
+    pub fn get_attester(env: Env) -> Result<BytesN<32>, ContributorError> {
+        attestation::get_attester(&env)
+    }
+
+    /// Nonce the next signed payload about `address` must carry.
+    pub fn get_nonce(env: Env, address: Address) -> u64 {
+        attestation::nonce(&env, &address)
+    }
+
+    pub fn get_tiers(env: Env) -> Vec<u64> {
+        env.storage()
+            .instance()
+            .get(&ConfigKey::Tiers)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_tier(env: Env, address: Address) -> Result<u32, ContributorError> {
+        let score = Self::load_contributor(&env, &address)?.reputation_score;
+        let mut tier = 0;
+        for threshold in Self::get_tiers(env).iter() {
+            if score < threshold {
+                break;
+            }
+            tier += 1;
+        }
+        Ok(tier)
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get::<_, (Address, Address)>(&DataKey::PendingAdmin)
+            .map(|(_, nominee)| nominee)
+    }
+
+    pub fn get_max_reputation(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&ConfigKey::MaxReputation)
+            .unwrap_or(u64::MAX)
+    }
+
+    pub fn get_contributor(
+        env: Env,
+        address: Address,
+    ) -> Result<ContributorData, ContributorError> {
+        Self::load_contributor(&env, &address)
+    }
+
+    /// Look up several profiles at once, in the order given, with `None` for
+    /// unregistered addresses. Only the first `MAX_PAGE_SIZE` addresses are
+    /// looked up.
+    pub fn get_contributors(env: Env, addresses: Vec<Address>) -> Vec<Option<ContributorData>> {
+        let mut profiles = Vec::new(&env);
+        for address in addresses.iter().take(MAX_PAGE_SIZE as usize) {
+            profiles.push_back(Self::load_contributor(&env, &address).ok());
+        }
+        profiles
+    }
+
+    pub fn get_contributor_by_github(
+        env: Env,
+        github_handle: String,
+    ) -> Result<ContributorData, ContributorError> {
+        let address: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::GitHubIndex(github_handle))
+            .ok_or(ContributorError::ContributorNotFound)?;
+        Self::get_contributor(env, address)
+    }
+
+    /// Resolve a GitHub handle to its on-chain profile via the reverse index.
+    pub fn get_contributor_by_handle(
+        env: Env,
+        handle: String,
+    ) -> Result<ContributorData, ContributorError> {
+        Self::get_contributor_by_github(env, handle)
+    }
+
+    /// Extend the TTL of a contributor profile and its handle index entry to
+    /// `ledgers`. Anyone may pay the rent to keep a profile live.
+    pub fn extend_contributor_ttl(
+        env: Env,
+        address: Address,
+        ledgers: u32,
+    ) -> Result<(), ContributorError> {
+        if ledgers == 0 || ledgers > env.storage().max_ttl() {
+            return Err(ContributorError::InvalidConfig);
+        }
+        let contributor =
+            read_contributor(&env, &address).ok_or(ContributorError::ContributorNotFound)?;
+        bump_contributor_ttl(&env, &contributor, ledgers, ledgers);
+        let floor = env.ledger().sequence().saturating_add(ledgers);
+        env.storage()
+            .persistent()
+            .set(&ProfileKey::LiveUntil(contributor.address), &floor);
+        Ok(())
+    }
+
+    /// Extend the registry instance (config, counters, admin set) to live
+    /// at least `ledgers` more ledgers.
+    pub fn extend_instance_ttl(
+        env: Env,
+        admin: Address,
+        ledgers: u32,
+    ) -> Result<(), ContributorError> {
+        Self::require_admin(&env, &admin)?;
+        if ledgers == 0 || ledgers > env.storage().max_ttl() {
+            return Err(ContributorError::InvalidConfig);
+        }
+        extend_instance_ttl(&env, ledgers);
+        Ok(())
+    }
+
+    /// Known-good archival horizon of the instance and of one sampled
+    /// profile (the first in enumeration order), for monitoring.
+    pub fn storage_health(env: Env) -> StorageHealth {
+        let sample: Option<Address> = env.storage().persistent().get(&DataKey::ContributorAt(0));
+        let sample_live_until = sample.as_ref().and_then(|address| {
+            env.storage()
+                .persistent()
+                .get(&ProfileKey::LiveUntil(address.clone()))
+        });
+        StorageHealth {
+            ledger: env.ledger().sequence(),
+            max_ttl: env.storage().max_ttl(),
+            instance_live_until: instance_live_until(&env),
+            sample,
+            sample_live_until,
+        }
+    }
+
+    pub fn get_contribution(env: Env, id: u64) -> Result<ContributionData, ContributorError> {
+        contributions::get(&env, id)
+    }
+
+    pub fn get_contribution_count(env: Env, contributor: Address) -> u32 {
+        contributions::contribution_count(&env, &contributor)
+    }
+
+    /// Return up to `limit` contribution records for `contributor`, oldest
+    /// first, starting at entry `start`. `limit` is capped at `MAX_PAGE_SIZE`.
+    pub fn get_contributions(
+        env: Env,
+        contributor: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<ContributionData> {
+        contributions::list_for(&env, &contributor, start, limit)
+    }
+
+    /// Every handle linked to `address`, keyed by platform, including the
+    /// profile's GitHub handle under `github`.
+    pub fn get_handles(
+        env: Env,
+        address: Address,
+    ) -> Result<Map<Symbol, String>, ContributorError> {
+        let contributor = Self::load_contributor(&env, &address)?;
+        let mut all = handles::linked(&env, &address);
+        all.set(handles::github(), contributor.github_handle);
+        Ok(all)
+    }
+
+    pub fn get_contributor_by_platform(
+        env: Env,
+        platform: Symbol,
+        handle: String,
+    ) -> Result<ContributorData, ContributorError> {
+        if platform == handles::github() {
+            return Self::get_contributor_by_github(env, handle);
+        }
+        let address = handles::owner(&env, &platform, &handle)
+            .ok_or(ContributorError::ContributorNotFound)?;
+        Self::load_contributor(&env, &address)
+    }
+
+    pub fn get_skills(env: Env, address: Address) -> Vec<Symbol> {
+        skills::skills_of(&env, &address)
+    }
+
+    /// Return up to `limit` contributors tagged with `skill`, starting at
+    /// entry `start`. `limit` is capped at `MAX_PAGE_SIZE`; order is not stable
+    /// across removals.
+    pub fn find_by_skill(env: Env, skill: Symbol, start: u32, limit: u32) -> Vec<Address> {
+        skills::find(&env, &skill, start, limit)
+    }
+
+    /// Whether `address` is registered and not on hiatus. Grant matching
+    /// should skip contributors for whom this is false.
+    pub fn is_active(env: Env, address: Address) -> bool {
+        Self::load_contributor(&env, &address)
+            .map(|c| c.active)
+            .unwrap_or(false)
+    }
+
+    pub fn get_category_weights(env: Env) -> Map<Symbol, u32> {
+        categories::weights(&env)
+    }
+
+    pub fn get_category_scores(env: Env, address: Address) -> Map<Symbol, u64> {
+        categories::scores(&env, &address)
+    }
+
+    /// Reputation with each category scaled by its configured weight.
+    pub fn get_weighted_reputation(env: Env, address: Address) -> Result<u64, ContributorError> {
+        let raw = Self::get_reputation(env.clone(), address.clone())?;
+        Ok(categories::weighted_total(&env, &address, raw))
+    }
+
+    /// The profile a tombstoned duplicate was merged into, if any.
+    pub fn get_merged_into(env: Env, address: Address) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&ProfileKey::MergedInto(address))
+    }
+
+    pub fn get_referrer(env: Env, address: Address) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&ProfileKey::Referrer(address))
+    }
+
+    pub fn get_referral_count(env: Env, address: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ProfileKey::ReferralCount(address))
+            .unwrap_or(0)
+    }
+
+    pub fn get_registration_fee(env: Env) -> Option<RegistrationFee> {
+        env.storage().instance().get(&ConfigKey::RegistrationFee)
+    }
+
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        env.storage().instance().get(&ConfigKey::Treasury)
+    }
+
+    pub fn get_delegate(env: Env, address: Address) -> Option<Address> {
+        delegation::delegate_of(&env, &address)
+    }
+
+    pub fn get_delegators(env: Env, address: Address) -> Vec<Address> {
+        delegation::delegators_of(&env, &address)
+    }
+
+    /// Voting weight of `address`: its own score plus every score delegated
+    /// to it, or zero while it has delegated its own weight elsewhere.
+    pub fn get_effective_reputation(env: Env, address: Address) -> u64 {
+        if delegation::delegate_of(&env, &address).is_some() {
+            return 0;
+        }
+        let own = Self::get_reputation(env.clone(), address.clone()).unwrap_or(0);
+        delegation::delegators_of(&env, &address)
+            .iter()
+            .fold(own, |total, delegator| {
+                let score = Self::get_reputation(env.clone(), delegator).unwrap_or(0);
+                total.saturating_add(score)
+            })
+    }
+
+    /// Epoch of the most recent completed snapshot, if any
+    pub fn get_latest_snapshot_epoch(env: Env) -> Option<u32> {
+        snapshots::latest_epoch(&env)
+    }
+
+    /// Progress of the snapshot `snapshot_epoch` is building, if any
+    pub fn get_snapshot_build(env: Env) -> Option<SnapshotBuild> {
+        snapshots::in_progress(&env)
+    }
+
+    pub fn get_snapshot(env: Env, epoch: u32) -> Result<Snapshot, ContributorError> {
+        snapshots::get(&env, epoch)
+    }
+
+    pub fn get_snapshot_root(env: Env, epoch: u32) -> Result<BytesN<32>, ContributorError> {
+        Ok(snapshots::get(&env, epoch)?.root)
+    }
+
+    /// Check a Merkle proof that `address` held `score` in `epoch`.
+    pub fn verify_snapshot_proof(
+        env: Env,
+        epoch: u32,
+        address: Address,
+        score: u64,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<bool, ContributorError> {
+        let snapshot = snapshots::get(&env, epoch)?;
+        let leaf = snapshots::leaf(&env, &address, score);
+        Ok(snapshots::verify(&env, &snapshot.root, leaf, &proof))
+    }
+
+    /// Top contributors by reputation, highest first, at most
+    /// `LEADERBOARD_SIZE` entries.
+    pub fn get_leaderboard(env: Env) -> Vec<LeaderboardEntry> {
+        leaderboard::get(&env)
+    }
+
+    pub fn get_endorsement_count(env: Env, address: Address, skill: Symbol) -> u32 {
+        endorsements::count(&env, &address, &skill)
+    }
+
+    /// Every skill `address` has been endorsed for, with its endorsement count.
+    pub fn get_endorsements(env: Env, address: Address) -> Vec<(Symbol, u32)> {
+        let mut out = Vec::new(&env);
+        for skill in endorsements::skills(&env, &address).iter() {
+            let total = endorsements::count(&env, &address, &skill);
+            out.push_back((skill, total));
+        }
+        out
+    }
+
+    pub fn has_endorsed(env: Env, endorser: Address, endorsee: Address, skill: Symbol) -> bool {
+        endorsements::has_endorsed(&env, &endorser, &endorsee, &skill)
+    }
+
+    /// Number of recorded reputation changes for `address`.
+    pub fn get_reputation_history_len(env: Env, address: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReputationHistoryLen(address))
+            .unwrap_or(0)
+    }
+
+    /// Return up to `limit` reputation changes for `address`, oldest first,
+    /// starting at entry `start`. `limit` is capped at `MAX_PAGE_SIZE`.
+    pub fn get_reputation_history(
+        env: Env,
+        address: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<ReputationEvent> {
+        let len = Self::get_reputation_history_len(env.clone(), address.clone());
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(len);
+        let mut page = Vec::new(&env);
+        for i in start..end {
+            if let Some(entry) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ReputationHistory(address.clone(), i))
+            {
+                page.push_back(entry);
+            }
+        }
+        page
+    }
+
+    /// Number of registered contributors.
+    pub fn contributor_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ContributorCount)
+            .unwrap_or(0)
+    }
+
+    /// Return up to `limit` contributor addresses starting at index `start`.
+    /// Entries are in registration order except that removing a contributor
+    /// moves the last entry into the freed slot. `limit` is capped at
+    /// `MAX_PAGE_SIZE`.
+    pub fn list_contributors(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        let count = Self::contributor_count(env.clone());
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+        let mut page = Vec::new(&env);
+        for i in start..end {
+            if let Some(address) = env
+                .storage()
+                .persistent()
+                .get::<_, Address>(&DataKey::ContributorAt(i))
+            {
+                page.push_back(address);
+            }
+        }
+        page
+    }
+
+    /// Return up to `limit` contributors registered between `from_ts` and
+    /// `to_ts` inclusive, resuming at `cursor` (the cursor returned with the
+    /// previous page, or `None` to begin; `None` comes back once the range
+    /// is exhausted). The range may span at most
+    /// `MAX_RANGE_BUCKETS` days and one call walks at most `MAX_RANGE_PAGE`
+    /// entries, which bounds its reads; removed profiles are left out, so a
+    /// page can come back short with more to follow.
+    pub fn list_registered_between(
+        env: Env,
+        from_ts: u64,
+        to_ts: u64,
+        cursor: Option<RangeCursor>,
+        limit: u32,
+    ) -> Result<(Vec<Address>, Option<RangeCursor>), ContributorError> {
+        let first = from_ts / REGISTRATION_BUCKET;
+        let last = to_ts / REGISTRATION_BUCKET;
+        if from_ts > to_ts || last - first >= MAX_RANGE_BUCKETS {
+            return Err(ContributorError::InvalidRange);
+        }
+        let (mut bucket, mut index) = match cursor {
+            Some(cursor) if cursor.bucket >= first => (cursor.bucket, cursor.index),
+            _ => (first, 0),
+        };
+        let limit = limit.min(MAX_RANGE_PAGE);
+        let mut contributors = Vec::new(&env);
+        let mut walked = 0;
+        while bucket <= last {
+            let count: u32 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::RegisteredOnCount(bucket))
+                .unwrap_or(0);
+            while index < count {
+                if contributors.len() == limit || walked == MAX_RANGE_PAGE {
+                    return Ok((contributors, Some(RangeCursor { bucket, index })));
+                }
+                walked += 1;
+                let slot = index;
+                index += 1;
+                let Some(address) = env
+                    .storage()
+                    .persistent()
+                    .get::<_, Address>(&DataKey::RegisteredOn(bucket, slot))
+                else {
+                    continue;
+                };
+                let Some(profile) = read_contributor(&env, &address) else {
+                    continue;
+                };
+                // A removed-then-re-registered address also sits in its old
+                // bucket; only the bucket of the live registration counts.
+                let ts = profile.registered_timestamp;
+                if ts / REGISTRATION_BUCKET != bucket || ts < from_ts || ts > to_ts {
+                    continue;
+                }
+                contributors.push_back(profile.address);
+            }
+            bucket += 1;
+            index = 0;
+        }
+        Ok((contributors, None))
+    }
+
+    pub fn get_multisig_config(env: Env) -> Result<MultisigConfig, ContributorError> {
+        get_config(&env)
+    }
+
+    pub fn get_proposal(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<multisig::Proposal, ContributorError> {
+        get_proposal(&env, proposal_id)
+    }
+
+    pub fn get_next_proposal_id(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextProposalId)
+            .unwrap_or(0)
+    }
+}
+
+// ── Notification receiver ─────────────────────────────────────
+
+#[contractimpl]
+impl NotificationReceiverTrait for ContributorRegistryContract {
+    fn on_notify(env: Env, notification: Notification) {
+        if notification.event_type == Symbol::new(&env, "deposit") {
+            let (user, _project_id, _amount): (Address, u64, i128) =
+                <(Address, u64, i128)>::from_xdr(&env, &notification.data).unwrap();
+
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::Contributor(user.clone()))
+            {
+                // Never revert the notifying contract over a score update.
+                let _ = Self::apply_reputation_delta(
+                    &env,
+                    &user,
+                    boosts::apply(&env, 1),
+                    REASON_DEPOSIT,
+                    &notification.source,
+                );
+            }
+        }
+    }
+}
+
+// ── Tests ─────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use notification_interface::ReputationHookTrait;
+    use soroban_sdk::token::{StellarAssetClient, TokenClient};
+    use soroban_sdk::{
+        contract,
+        contractimpl,
+        symbol_short,
+        testutils::{Address as TestAddress, Events, Ledger}, // Ledger trait for set_timestamp
+        Address,
+        Bytes,
+        Env,
+        FromVal,
+        IntoVal,
+        Vec,
+    };
+    use storage::{
+        LegacyContributorData, DEFAULT_MAX_HANDLE_LEN, DEFAULT_STREAK_WINDOW, LEADERBOARD_SIZE,
+        MAX_ADMINS, MAX_MERGE_BATCH, MAX_SKILLS, MAX_SNAPSHOT_PAGE, PREF_LEADERBOARD,
+        PREF_PAYOUT_NOTIFICATIONS, PREF_PUBLIC_PROFILE,
+    };
+
+    struct Setup {
+        env: Env,
+        contract: Address,
+        alice: Address,
+        bob: Address,
+        carol: Address,
+    }
+
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+        // env.register() replaces the deprecated env.register_contract()
+        let contract = env.register(ContributorRegistryContract, ());
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+
+        let mut signers = Vec::new(&env);
+        signers.push_back(Signer {
+            address: alice.clone(),
+            weight: 2,
+        });
+        signers.push_back(Signer {
+            address: bob.clone(),
+            weight: 1,
+        });
+        signers.push_back(Signer {
+            address: carol.clone(),
+            weight: 1,
+        });
+
+        let client = ContributorRegistryContractClient::new(&env, &contract);
+        client.initialize(&signers, &3u32);
+
+        Setup {
+            env,
+            contract,
+            alice,
+            bob,
+            carol,
+        }
+    }
+
+    fn register(s: &Setup, handle: &str) -> Address {
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = Address::generate(&s.env);
+        client.register_contributor(
+            &contributor,
+            &soroban_sdk::String::from_str(&s.env, handle),
+            &None,
+        );
+        contributor
+    }
+
+    // ── Initialisation ────────────────────────────────────────
+
+    #[test]
+    fn test_initialize_stores_config() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        // Client methods return values directly — no .unwrap() needed.
+        let config = client.get_multisig_config();
+        assert_eq!(config.threshold, 3);
+        assert_eq!(config.signers.len(), 3);
+    }
+
+    #[test]
+    fn test_double_initialize_fails() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let mut signers = Vec::new(&s.env);
+        signers.push_back(Signer {
+            address: s.alice.clone(),
+            weight: 1,
+        });
+        // try_* variants return Result and are used to assert failure.
+        assert!(client.try_initialize(&signers, &1u32).is_err());
+    }
+
+    #[test]
+    fn test_threshold_above_total_weight_fails() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract = env.register(ContributorRegistryContract, ());
+        let client = ContributorRegistryContractClient::new(&env, &contract);
+        let alice = Address::generate(&env);
+
+        let mut signers = Vec::new(&env);
+        signers.push_back(Signer {
+            address: alice,
+            weight: 1,
+        });
+        assert!(client.try_initialize(&signers, &99u32).is_err());
+    }
+
+    // ── Propose ───────────────────────────────────────────────
+
+    #[test]
+    fn test_propose_counts_proposer_weight() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        let proposal = client.get_proposal(&id);
+
+        assert_eq!(proposal.weight_collected, 2);
+        assert_eq!(proposal.status, ProposalStatus::Pending);
+    }
+
+    #[test]
+    fn test_propose_by_non_signer_fails() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let outsider = Address::generate(&s.env);
+        assert!(client
+            .try_propose(&outsider, &ProposalAction::Upgrade)
+            .is_err());
+    }
+
+    #[test]
+    fn test_single_signer_above_threshold_auto_approves() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract = env.register(ContributorRegistryContract, ());
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+
+        let mut signers = Vec::new(&env);
+        signers.push_back(Signer {
+            address: alice.clone(),
+            weight: 3,
+        });
+        signers.push_back(Signer {
+            address: bob.clone(),
+            weight: 1,
+        });
+
+        let client = ContributorRegistryContractClient::new(&env, &contract);
+        client.initialize(&signers, &3u32);
+
+        let id = client.propose(&alice, &ProposalAction::Upgrade);
+        let proposal = client.get_proposal(&id);
+        assert_eq!(proposal.status, ProposalStatus::Approved);
+    }
+
+    // ── Sign ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_sign_reaches_threshold() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        let status = client.sign(&s.bob, &id);
+        assert_eq!(status, ProposalStatus::Approved);
+    }
+
+    #[test]
+    fn test_double_sign_rejected() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.bob, &ProposalAction::Upgrade);
+        assert!(client.try_sign(&s.bob, &id).is_err());
+    }
+
+    #[test]
+    fn test_non_signer_cannot_sign() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let outsider = Address::generate(&s.env);
+
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        assert!(client.try_sign(&outsider, &id).is_err());
+    }
+
+    #[test]
+    fn test_bob_carol_together_cannot_reach_threshold() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.bob, &ProposalAction::Upgrade);
+        client.sign(&s.carol, &id);
+
+        let proposal = client.get_proposal(&id);
+        assert_eq!(proposal.status, ProposalStatus::Pending);
+    }
+
+    // ── Execute ───────────────────────────────────────────────
+
+    #[test]
+    fn test_consume_approval_executes_after_threshold() {
+        // env.deployer().update_current_contract_wasm() is unavailable in the
+        // test environment, so we verify consume_approval via set_admin, which
+        // exercises the identical code path without hitting the deployer.
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::SetAdmin);
+        client.sign(&s.bob, &id); // threshold reached
+
+        let new_admin = Address::generate(&s.env);
+        client.set_admin(&s.alice, &id, &new_admin);
+
+        // Proposal must be Executed — replay is now impossible.
+        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Executed);
+    }
+
+    #[test]
+    fn test_upgrade_approved_before_execution() {
+        // Verifies the proposal reaches Approved status when threshold is met,
+        // which is the pre-condition for upgrade execution.
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Pending);
+
+        let status = client.sign(&s.bob, &id);
+        assert_eq!(status, ProposalStatus::Approved);
+        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Approved);
+    }
+
+    #[test]
+    fn test_execute_below_threshold_fails() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.bob, &ProposalAction::Upgrade);
+        let wasm_hash = BytesN::from_array(&s.env, &[1u8; 32]);
+        assert!(client.try_upgrade(&s.bob, &id, &wasm_hash).is_err());
+    }
+
+    #[test]
+    fn test_wrong_action_type_rejected() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        client.sign(&s.bob, &id);
+
+        let new_admin = Address::generate(&s.env);
+        assert!(client.try_set_admin(&s.alice, &id, &new_admin).is_err());
+    }
+
+    #[test]
+    fn test_replay_blocked_after_execution() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
+        client.sign(&s.bob, &id);
+        let wasm_hash = BytesN::from_array(&s.env, &[1u8; 32]);
+        let _ = client.try_upgrade(&s.alice, &id, &wasm_hash);
+
+        assert!(client.try_upgrade(&s.alice, &id, &wasm_hash).is_err());
+    }
+
+    // ── update_reputation ─────────────────────────────────────
+
+    #[test]
+    fn test_update_reputation_requires_multisig() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-handle");
+        client.register_contributor(&contributor, &handle, &None);
+
+        let fake_id = 999u64;
+        assert!(client
+            .try_update_reputation(&s.alice, &fake_id, &contributor, &10i64)
+            .is_err());
+    }
+
+    #[test]
+    fn test_update_reputation_succeeds_with_approval() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-handle");
+        client.register_contributor(&contributor, &handle, &None);
+
+        let id = client.propose(&s.alice, &ProposalAction::UpdateReputation);
+        client.sign(&s.bob, &id);
+
+        client.update_reputation(&s.alice, &id, &contributor, &50i64);
+
+        assert_eq!(client.get_reputation(&contributor), 50);
+    }
+
+    // ── add_reputation ────────────────────────────────────────
+
+    #[test]
+    fn test_initialize_sets_bootstrapper_as_admin() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        assert_eq!(client.get_admin(), s.alice);
+    }
+
+    #[test]
+    fn test_add_reputation_saturates_at_zero() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-handle");
+        client.register_contributor(&contributor, &handle, &None);
+
+        assert_eq!(
+            client.add_reputation(&s.alice, &contributor, &30i64, &0u32),
+            30
+        );
+        assert_eq!(
+            client.add_reputation(&s.alice, &contributor, &-10i64, &0u32),
+            20
+        );
+        assert_eq!(
+            client.add_reputation(&s.alice, &contributor, &-100i64, &0u32),
+            0
+        );
+        assert_eq!(
+            client.add_reputation(&s.alice, &contributor, &i64::MIN, &0u32),
+            0
+        );
+    }
+
+    #[test]
+    fn test_add_reputation_clamps_at_max() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-handle");
+        client.register_contributor(&contributor, &handle, &None);
+
+        client.set_max_reputation(&s.alice, &100u64);
+        assert_eq!(client.get_max_reputation(), 100);
+        assert_eq!(
+            client.add_reputation(&s.alice, &contributor, &80i64, &0u32),
+            80
+        );
+        assert_eq!(
+            client.add_reputation(&s.alice, &contributor, &80i64, &0u32),
+            100
+        );
+    }
+
+    #[test]
+    fn test_add_reputation_non_admin_rejected() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-handle");
+        client.register_contributor(&contributor, &handle, &None);
+
+        assert_eq!(
+            client.try_add_reputation(&s.bob, &contributor, &10i64, &0u32),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+    }
+
+    // ── Handle attestation ────────────────────────────────────
+
+    fn attester_key() -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&[7u8; 32])
+    }
+
+    fn sign_payload(env: &Env, key: &ed25519_dalek::SigningKey, payload: &Bytes) -> BytesN<64> {
+        use ed25519_dalek::Signer;
+        let mut buf = [0u8; 256];
+        let len = payload.len() as usize;
+        payload.copy_into_slice(&mut buf[..len]);
+        BytesN::from_array(env, &key.sign(&buf[..len]).to_bytes())
+    }
+
+    #[test]
+    fn test_verify_handle_with_attestation() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = register(&s, "dev-a");
+        let key = attester_key();
+        client.set_attester(
+            &s.alice,
+            &BytesN::from_array(&s.env, &key.verifying_key().to_bytes()),
+        );
+
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-a");
+        let payload = s.env.as_contract(&s.contract, || {
+            attestation::handle_payload(&s.env, &contributor, &handle, 0)
+        });
+        let signature = sign_payload(&s.env, &key, &payload);
+        client.verify_handle(&contributor, &signature);
+        assert!(client.get_contributor(&contributor).verified);
+
+        // Renaming drops the verification.
+        let renamed = soroban_sdk::String::from_str(&s.env, "dev-renamed");
+        client.update_handle(&contributor, &renamed);
+        assert!(!client.get_contributor(&contributor).verified);
+    }
+
+    #[test]
+    fn test_signed_payloads_cannot_be_replayed() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let key = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+        let contributor = account_address(&s.env, &key);
+        let sponsor = Address::generate(&s.env);
+        let handle = String::from_str(&s.env, "new-dev");
+        assert_eq!(client.get_nonce(&contributor), 0);
+
+        let payload = s.env.as_contract(&s.contract, || {
+            attestation::registration_payload(&s.env, &contributor, &handle, 0)
+        });
+        let signature = sign_payload(&s.env, &key, &payload);
+        client.register_for(&sponsor, &contributor, &handle, &signature);
+        assert_eq!(client.get_nonce(&contributor), 1);
+
+        client.remove_contributor(&contributor, &contributor);
+        assert!(client
+            .try_register_for(&sponsor, &contributor, &handle, &signature)
+            .is_err());
+
+        let payload = s.env.as_contract(&s.contract, || {
+            attestation::registration_payload(&s.env, &contributor, &handle, 1)
+        });
+        let signature = sign_payload(&s.env, &key, &payload);
+        client.register_for(&sponsor, &contributor, &handle, &signature);
+
+        let attester = attester_key();
+        client.set_attester(
+            &s.alice,
+            &BytesN::from_array(&s.env, &attester.verifying_key().to_bytes()),
+        );
+        let payload = s.env.as_contract(&s.contract, || {
+            attestation::handle_payload(&s.env, &contributor, &handle, 2)
+        });
+        let attestation = sign_payload(&s.env, &attester, &payload);
+        client.verify_handle(&contributor, &attestation);
+        assert!(client
+            .try_verify_handle(&contributor, &attestation)
+            .is_err());
+    }
+
+    #[test]
+    fn test_verify_handle_rejects_foreign_signature() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = register(&s, "dev-a");
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-a");
+        let payload = s.env.as_contract(&s.contract, || {
+            attestation::handle_payload(&s.env, &contributor, &handle, 0)
+        });
+        let signature = sign_payload(&s.env, &attester_key(), &payload);
+
+        assert_eq!(
+            client.try_verify_handle(&contributor, &signature),
+            Err(Ok(ContributorError::NotConfigured))
+        );
+
+        let other = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+        client.set_attester(
+            &s.alice,
+            &BytesN::from_array(&s.env, &other.verifying_key().to_bytes()),
+        );
+        assert!(client.try_verify_handle(&contributor, &signature).is_err());
+        assert!(!client.get_contributor(&contributor).verified);
+    }
+
+    #[test]
+    fn test_handle_attestation_is_bound_to_registry() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = register(&s, "dev-a");
+        let key = attester_key();
+        client.set_attester(
+            &s.alice,
+            &BytesN::from_array(&s.env, &key.verifying_key().to_bytes()),
+        );
+
+        // Signed for another deployment sharing the same attester key
+        let other_registry = s.env.register(ContributorRegistryContract, ());
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-a");
+        let payload = s.env.as_contract(&other_registry, || {
+            attestation::handle_payload(&s.env, &contributor, &handle, 0)
+        });
+        let signature = sign_payload(&s.env, &key, &payload);
+        assert!(client.try_verify_handle(&contributor, &signature).is_err());
+        assert!(!client.get_contributor(&contributor).verified);
+    }
+
+    // ── Sponsored registration ────────────────────────────────
+
+    /// Classic account address controlled by `key`.
+    fn account_address(env: &Env, key: &ed25519_dalek::SigningKey) -> Address {
+        let mut xdr = Bytes::from_array(env, &[0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0]);
+        xdr.extend_from_array(&key.verifying_key().to_bytes());
+        Address::from_xdr(env, &xdr).unwrap()
+    }
+
+    #[test]
+    fn test_register_for_with_contributor_signature() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let key = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+        let contributor = account_address(&s.env, &key);
+        let sponsor = Address::generate(&s.env);
+        let handle = String::from_str(&s.env, "new-dev");
+
+        let payload = s.env.as_contract(&s.contract, || {
+            attestation::registration_payload(&s.env, &contributor, &handle, 0)
+        });
+        let signature = sign_payload(&s.env, &key, &payload);
+        client.register_for(&sponsor, &contributor, &handle, &signature);
+
+        let events = s.env.events().all();
+        let (_, topics, _) = events.last().unwrap();
+        assert_eq!(
+            topics,
+            (
+                Symbol::new(&s.env, "contributor"),
+                Symbol::new(&s.env, "sponsored"),
+                contributor.clone()
+            )
+                .into_val(&s.env)
+        );
+        assert_eq!(client.get_contributor(&contributor).github_handle, handle);
+        assert_eq!(
+            client.get_contributor_by_github(&handle).address,
+            contributor
+        );
+
+        let payload = s.env.as_contract(&s.contract, || {
+            attestation::registration_payload(&s.env, &contributor, &handle, 1)
+        });
+        let signature = sign_payload(&s.env, &key, &payload);
+        assert_eq!(
+            client.try_register_for(&sponsor, &contributor, &handle, &signature),
+            Err(Ok(ContributorError::ContributorAlreadyExists))
+        );
+        assert_eq!(client.get_nonce(&contributor), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_register_for_rejects_wrong_handle() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let key = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+        let contributor = account_address(&s.env, &key);
+        let signed = String::from_str(&s.env, "new-dev");
+        let payload = s.env.as_contract(&s.contract, || {
+            attestation::registration_payload(&s.env, &contributor, &signed, 0)
+        });
+        let signature = sign_payload(&s.env, &key, &payload);
+
+        let sponsor = Address::generate(&s.env);
+        client.register_for(
+            &sponsor,
+            &contributor,
+            &String::from_str(&s.env, "squatted"),
+            &signature,
+        );
+    }
+
+    #[test]
+    fn test_register_for_rejects_contract_addresses() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let sponsor = Address::generate(&s.env);
+        let signature = BytesN::from_array(&s.env, &[0u8; 64]);
+        assert_eq!(
+            client.try_register_for(
+                &sponsor,
+                &s.contract,
+                &String::from_str(&s.env, "bot"),
+                &signature
+            ),
+            Err(Ok(ContributorError::UnsupportedSigner))
+        );
+    }
+
+    // ── Bans ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_banned_contributor_is_locked() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = register(&s, "dev-a");
+        let moderator = Address::generate(&s.env);
+        client.add_moderator(&s.alice, &moderator);
+
+        client.ban_contributor(&moderator, &contributor);
+        assert!(client.is_banned(&contributor));
+
+        assert_eq!(
+            client.try_add_reputation(&s.alice, &contributor, &5i64, &0u32),
+            Err(Ok(ContributorError::ContributorBanned))
+        );
+        let new_handle = soroban_sdk::String::from_str(&s.env, "dev-b");
+        assert_eq!(
+            client.try_update_handle(&contributor, &new_handle),
+            Err(Ok(ContributorError::ContributorBanned))
+        );
+
+        // Deleting and re-registering does not lift the ban.
+        client.remove_contributor(&contributor, &contributor);
+        assert_eq!(
+            client.try_register_contributor(&contributor, &new_handle, &None),
+            Err(Ok(ContributorError::ContributorBanned))
+        );
+
+        client.unban_contributor(&s.alice, &contributor);
+        assert!(!client.is_banned(&contributor));
+        client.register_contributor(&contributor, &new_handle, &None);
+    }
+
+    #[test]
+    fn test_ban_requires_admin_or_moderator() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = register(&s, "dev-a");
+        assert_eq!(
+            client.try_ban_contributor(&contributor, &contributor),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+    }
+
+    // ── Tiers ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_get_tier_follows_thresholds() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = register(&s, "dev-a");
+
+        // No table configured: everyone is tier 0.
+        assert_eq!(client.get_tier(&contributor), 0);
+
+        let mut thresholds = Vec::new(&s.env);
+        thresholds.push_back(100u64);
+        thresholds.push_back(500u64);
+        thresholds.push_back(1000u64);
+        client.set_tiers(&s.alice, &thresholds);
+
+        assert_eq!(client.get_tier(&contributor), 0);
+        client.add_reputation(&s.alice, &contributor, &100i64, &0u32);
+        assert_eq!(client.get_tier(&contributor), 1);
+        client.add_reputation(&s.alice, &contributor, &450i64, &0u32);
+        assert_eq!(client.get_tier(&contributor), 2);
+        client.add_reputation(&s.alice, &contributor, &5000i64, &0u32);
+        assert_eq!(client.get_tier(&contributor), 3);
+    }
+
+    #[test]
+    fn test_set_tiers_rejects_unsorted_thresholds() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+
+        let mut thresholds = Vec::new(&s.env);
+        thresholds.push_back(500u64);
+        thresholds.push_back(500u64);
+        assert_eq!(
+            client.try_set_tiers(&s.alice, &thresholds),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
+    }
+
+    // ── Batch updates ─────────────────────────────────────────
+
+    #[test]
+    fn test_update_reputation_batch() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let first = register(&s, "dev-a");
+        let second = register(&s, "dev-b");
+
+        let mut updates = Vec::new(&s.env);
+        updates.push_back((first.clone(), 10u64));
+        updates.push_back((second.clone(), 25u64));
+        updates.push_back((first.clone(), 5u64));
+        client.update_reputation_batch(&s.alice, &updates, &0u32);
+
+        assert_eq!(client.get_reputation(&first), 15);
+        assert_eq!(client.get_reputation(&second), 25);
+    }
+
+    #[test]
+    fn test_update_reputation_batch_is_atomic() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let first = register(&s, "dev-a");
+        let unknown = Address::generate(&s.env);
+
+        let mut updates = Vec::new(&s.env);
+        updates.push_back((first.clone(), 10u64));
+        updates.push_back((unknown, 25u64));
+        assert_eq!(
+            client.try_update_reputation_batch(&s.alice, &updates, &0u32),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+        assert_eq!(client.get_reputation(&first), 0);
+    }
+
+    // ── Contribution records ──────────────────────────────────
+
+    #[test]
+    fn test_record_contribution_credits_points() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = register(&s, "dev-a");
+        let moderator = Address::generate(&s.env);
+        client.add_moderator(&s.alice, &moderator);
+
+        let repo = soroban_sdk::String::from_str(&s.env, "lumenpulse/web");
+        let first = client.record_contribution(&moderator, &contributor, &repo, &42, &15);
+        let second = client.record_contribution(&moderator, &contributor, &repo, &43, &5);
+        assert_ne!(first, second);
+
+        assert_eq!(client.get_reputation(&contributor), 20);
+        assert_eq!(client.get_contribution_count(&contributor), 2);
+
+        let record = client.get_contribution(&first);
+        assert_eq!(record.contributor, contributor);
+        assert_eq!(record.repo, repo);
+        assert_eq!(record.pr_number, 42);
+        assert_eq!(record.points, 15);
+        assert_eq!(record.recorded_by, moderator);
+
+        let page = client.get_contributions(&contributor, &1, &10);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().id, second);
+
+        let history = client.get_reputation_history(&contributor, &0, &1);
+        assert_eq!(history.get(0).unwrap().reason_code, REASON_CONTRIBUTION);
+    }
+
+    #[test]
+    fn test_record_contribution_rejects_duplicate_pr() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = register(&s, "dev-a");
+        let repo = soroban_sdk::String::from_str(&s.env, "lumenpulse/web");
+
+        client.record_contribution(&s.alice, &contributor, &repo, &42, &15);
+        assert_eq!(
+            client.try_record_contribution(&s.alice, &contributor, &repo, &42, &15),
+            Err(Ok(ContributorError::ContributionAlreadyRecorded))
+        );
+        let outsider = Address::generate(&s.env);
+        assert_eq!(
+            client.try_record_contribution(&outsider, &contributor, &repo, &43, &15),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        assert_eq!(client.get_reputation(&contributor), 15);
+    }
+
+    // ── Leaderboard ───────────────────────────────────────────
+
+    #[test]
+    fn test_leaderboard_tracks_reputation_changes() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev_a = register(&s, "dev-a");
+        let dev_b = register(&s, "dev-b");
+        let dev_c = register(&s, "dev-c");
+        assert_eq!(client.get_leaderboard().len(), 0);
+
+        client.add_reputation(&s.alice, &dev_a, &10, &REASON_UNSPECIFIED);
+        client.add_reputation(&s.alice, &dev_b, &30, &REASON_UNSPECIFIED);
+        client.add_reputation(&s.alice, &dev_c, &20, &REASON_UNSPECIFIED);
+        let board = client.get_leaderboard();
+        assert_eq!(board.len(), 3);
+        assert_eq!(board.get(0).unwrap().address, dev_b);
+        assert_eq!(board.get(1).unwrap().address, dev_c);
+        assert_eq!(board.get(2).unwrap().address, dev_a);
+
+        client.add_reputation(&s.alice, &dev_a, &25, &REASON_UNSPECIFIED);
+        client.add_reputation(&s.alice, &dev_b, &-30, &REASON_UNSPECIFIED);
+        let board = client.get_leaderboard();
+        assert_eq!(board.len(), 2);
+        assert_eq!(
+            board.get(0).unwrap(),
+            LeaderboardEntry {
+                address: dev_a.clone(),
+                score: 35
+            }
+        );
+        assert_eq!(board.get(1).unwrap().address, dev_c);
+
+        client.ban_contributor(&s.alice, &dev_a);
+        assert_eq!(client.get_leaderboard().len(), 1);
+        client.unban_contributor(&s.alice, &dev_a);
+        assert_eq!(client.get_leaderboard().get(0).unwrap().address, dev_a);
+
+        client.remove_contributor(&s.alice, &dev_c);
+        assert_eq!(client.get_leaderboard().len(), 1);
+    }
+
+    #[test]
+    fn test_leaderboard_is_bounded() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let mut lowest = None;
+        for i in 0..=LEADERBOARD_SIZE {
+            let dev = Address::generate(&s.env);
+            let handle = std::format!("dev-{i}");
+            client.register_contributor(&dev, &String::from_str(&s.env, &handle), &None);
+            client.add_reputation(&s.alice, &dev, &(i as i64 + 1), &REASON_UNSPECIFIED);
+            if i == 0 {
+                lowest = Some(dev);
+            }
+        }
+        let board = client.get_leaderboard();
+        assert_eq!(board.len(), LEADERBOARD_SIZE);
+        assert_eq!(board.get(0).unwrap().score, LEADERBOARD_SIZE as u64 + 1);
+        assert!(board.iter().all(|e| Some(e.address) != lowest));
+    }
+
+    // ── Freeze ────────────────────────────────────────────────
+
+    #[test]
+    fn test_frozen_reputation_rejects_changes() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        let moderator = Address::generate(&s.env);
+        client.add_moderator(&s.alice, &moderator);
+        client.add_reputation(&s.alice, &dev, &10, &REASON_UNSPECIFIED);
+
+        client.freeze_reputation(&moderator, &dev);
+        assert!(client.get_contributor(&dev).frozen);
+        assert_eq!(
+            client.try_add_reputation(&s.alice, &dev, &5, &REASON_UNSPECIFIED),
+            Err(Ok(ContributorError::ReputationFrozen))
+        );
+        let repo = String::from_str(&s.env, "lumenpulse/web");
+        assert_eq!(
+            client.try_record_contribution(&moderator, &dev, &repo, &1, &5),
+            Err(Ok(ContributorError::ReputationFrozen))
+        );
+
+        // Decay skips frozen profiles instead of failing the batch.
+        client.set_decay_config(&s.alice, &60, &5_000);
+        s.env.ledger().with_mut(|l| l.timestamp += 600);
+        assert_eq!(
+            client.decay_reputation(&s.alice, &Vec::from_array(&s.env, [dev.clone()])),
+            0
+        );
+        assert_eq!(client.get_reputation(&dev), 10);
+
+        client.unfreeze_reputation(&moderator, &dev);
+        assert!(!client.get_contributor(&dev).frozen);
+        assert_eq!(
+            client.add_reputation(&s.alice, &dev, &5, &REASON_UNSPECIFIED),
+            15
+        );
+    }
+
+    #[test]
+    fn test_freeze_requires_moderator() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        assert_eq!(
+            client.try_freeze_reputation(&dev, &dev),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_freeze_reputation(&s.alice, &Address::generate(&s.env)),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+    }
+
+    // ── Merge ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_merge_contributors_moves_everything_to_primary() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let primary = register(&s, "dev-a");
+        let duplicate = register(&s, "dev-a-alt");
+        let peer = register(&s, "peer");
+        let other = register(&s, "other");
+        let repo = String::from_str(&s.env, "lumenpulse/web");
+        let rust = Symbol::new(&s.env, "rust");
+        let code = Symbol::new(&s.env, "code");
+
+        client.add_reputation(&s.alice, &primary, &10, &REASON_UNSPECIFIED);
+        client.record_contribution(&s.alice, &duplicate, &repo, &7, &15);
+        client.add_category_reputation(&s.alice, &duplicate, &code, &5, &REASON_UNSPECIFIED);
+        client.add_category_reputation(&s.alice, &primary, &code, &1, &REASON_UNSPECIFIED);
+        client.endorse(&peer, &primary, &rust);
+        client.endorse(&peer, &duplicate, &rust);
+        client.endorse(&other, &duplicate, &rust);
+        client.endorse(&primary, &duplicate, &rust);
+        let streak = client.get_streak(&primary);
+
+        // Merging happens later and is not activity of the primary's own
+        s.env
+            .ledger()
+            .with_mut(|l| l.timestamp += 10 * DEFAULT_STREAK_WINDOW);
+        client.merge_contributors(&s.alice, &primary, &duplicate);
+        assert_eq!(client.get_streak(&primary).longest, streak.longest);
+        assert_eq!(
+            client.get_streak(&primary).last_contribution_timestamp,
+            streak.last_contribution_timestamp
+        );
+        assert_eq!(client.get_contribution_count(&primary), 0);
+        assert!(!client.move_merged_records(&duplicate));
+        assert_eq!(
+            client.try_move_merged_records(&peer),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+
+        assert_eq!(client.get_reputation(&primary), 10 + 15 + 5 + 1);
+        assert_eq!(client.get_category_scores(&primary).get(code), Some(6));
+        assert_eq!(client.get_contribution_count(&primary), 1);
+        assert_eq!(
+            client
+                .get_contributions(&primary, &0, &10)
+                .get(0)
+                .unwrap()
+                .contributor,
+            primary
+        );
+        assert_eq!(client.get_contribution_count(&duplicate), 0);
+        // peer endorsed both and counts once; primary cannot endorse itself.
+        assert_eq!(client.get_endorsement_count(&primary, &rust), 2);
+        assert!(client.has_endorsed(&other, &primary, &rust));
+        assert_eq!(client.get_endorsement_count(&duplicate, &rust), 0);
+
+        assert_eq!(
+            client.try_get_contributor(&duplicate),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+        assert_eq!(client.get_merged_into(&duplicate), Some(primary.clone()));
+        assert_eq!(
+            client.try_register_contributor(&duplicate, &String::from_str(&s.env, "again"), &None),
+            Err(Ok(ContributorError::ContributorMerged))
+        );
+        // The duplicate's handle is free again.
+        client.update_handle(&peer, &String::from_str(&s.env, "dev-a-alt"));
+    }
+
+    #[test]
+    fn test_merged_records_move_in_pages() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let primary = register(&s, "dev-a");
+        let duplicate = register(&s, "dev-a-alt");
+        let repo = String::from_str(&s.env, "lumenpulse/web");
+        for pr in 0..MAX_MERGE_BATCH + 2 {
+            client.record_contribution(&s.alice, &duplicate, &repo, &pr, &1);
+        }
+
+        client.merge_contributors(&s.alice, &primary, &duplicate);
+        assert!(client.move_merged_records(&duplicate));
+        assert_eq!(client.get_contribution_count(&primary), MAX_MERGE_BATCH);
+        assert!(!client.move_merged_records(&duplicate));
+        assert_eq!(client.get_contribution_count(&primary), MAX_MERGE_BATCH + 2);
+        assert_eq!(client.get_contribution_count(&duplicate), 0);
+    }
+
+    #[test]
+    fn test_merge_contributors_requires_admin_and_distinct_profiles() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev_a = register(&s, "dev-a");
+        let dev_b = register(&s, "dev-b");
+        assert_eq!(
+            client.try_merge_contributors(&s.bob, &dev_a, &dev_b),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_merge_contributors(&s.alice, &dev_a, &dev_a),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
+        assert_eq!(
+            client.try_merge_contributors(&s.alice, &dev_a, &Address::generate(&s.env)),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+    }
+
+    // ── Reputation categories ─────────────────────────────────
+
+    #[test]
+    fn test_category_reputation_and_weighted_total() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        let code = Symbol::new(&s.env, "code");
+        let docs = Symbol::new(&s.env, "docs");
+
+        assert_eq!(
+            client.add_category_reputation(&s.alice, &dev, &code, &40, &REASON_CONTRIBUTION),
+            40
+        );
+        client.add_category_reputation(&s.alice, &dev, &docs, &20, &REASON_CONTRIBUTION);
+        client.add_reputation(&s.alice, &dev, &10, &REASON_UNSPECIFIED);
+        assert_eq!(client.get_reputation(&dev), 70);
+        let scores = client.get_category_scores(&dev);
+        assert_eq!(scores.get(code.clone()), Some(40));
+        assert_eq!(scores.get(docs.clone()), Some(20));
+        assert_eq!(client.get_weighted_reputation(&dev), 70);
+
+        // Code counts double, docs half; the 10 uncategorised points stay 1x.
+        let mut weights = Map::new(&s.env);
+        weights.set(code.clone(), 200);
+        weights.set(docs.clone(), 50);
+        client.set_category_weights(&s.alice, &weights);
+        assert_eq!(client.get_weighted_reputation(&dev), 80 + 10 + 10);
+
+        // Category floors at zero and only the applied part moves the total.
+        client.add_category_reputation(&s.alice, &dev, &docs, &-50, &REASON_UNSPECIFIED);
+        assert_eq!(client.get_category_scores(&dev).get(docs), Some(0));
+        assert_eq!(client.get_reputation(&dev), 50);
+    }
+
+    #[test]
+    fn test_category_scores_follow_decay() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        let code = Symbol::new(&s.env, "code");
+        client.add_category_reputation(&s.alice, &dev, &code, &600, &REASON_CONTRIBUTION);
+        client.add_reputation(&s.alice, &dev, &400, &REASON_UNSPECIFIED);
+        let mut weights = Map::new(&s.env);
+        weights.set(code.clone(), 200);
+        client.set_category_weights(&s.alice, &weights);
+
+        let day = 86_400;
+        client.set_decay_config(&s.alice, &day, &1_000);
+        s.env.ledger().with_mut(|l| l.timestamp += 2 * day + 10);
+        client.decay_reputation(&s.alice, &Vec::from_array(&s.env, [dev.clone()]));
+
+        // Both parts lose 19%: 600 -> 486 code, 400 -> 324 uncategorised
+        assert_eq!(client.get_reputation(&dev), 810);
+        assert_eq!(client.get_category_scores(&dev).get(code), Some(486));
+        assert_eq!(client.get_weighted_reputation(&dev), 972 + 324);
+    }
+
+    #[test]
+    fn test_category_scores_stay_within_raw_score() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        let code = Symbol::new(&s.env, "code");
+        let docs = Symbol::new(&s.env, "docs");
+        client.set_max_reputation(&s.alice, &100);
+
+        // The clamp trims the split so it still adds up to the raw score
+        client.add_category_reputation(&s.alice, &dev, &code, &80, &REASON_CONTRIBUTION);
+        client.add_category_reputation(&s.alice, &dev, &docs, &40, &REASON_CONTRIBUTION);
+        assert_eq!(client.get_reputation(&dev), 100);
+        let scores = client.get_category_scores(&dev);
+        assert_eq!(scores.get(code.clone()), Some(66));
+        assert_eq!(scores.get(docs.clone()), Some(33));
+
+        // So does an uncategorised penalty bigger than the uncategorised part
+        client.add_reputation(&s.alice, &dev, &-50, &REASON_UNSPECIFIED);
+        let scores = client.get_category_scores(&dev);
+        assert_eq!(scores.get(code), Some(33));
+        assert_eq!(scores.get(docs), Some(16));
+        assert!(client.get_weighted_reputation(&dev) <= client.get_reputation(&dev));
+    }
+
+    #[test]
+    fn test_category_reputation_rejects_unknown_category() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        assert_eq!(client.get_category_weights().len(), 4);
+        assert_eq!(
+            client.try_add_category_reputation(
+                &s.alice,
+                &dev,
+                &Symbol::new(&s.env, "memes"),
+                &5,
+                &0
+            ),
+            Err(Ok(ContributorError::InvalidCategory))
+        );
+        assert_eq!(
+            client.try_set_category_weights(&s.alice, &Map::new(&s.env)),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
+        assert_eq!(
+            client.try_add_category_reputation(&s.bob, &dev, &Symbol::new(&s.env, "code"), &5, &0),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+    }
+
+    // ── Registration time range ───────────────────────────────
+
+    #[test]
+    fn test_list_registered_between() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let day = REGISTRATION_BUCKET;
+        s.env.ledger().with_mut(|l| l.timestamp = 100 * day);
+        let early = register(&s, "early");
+        s.env.ledger().with_mut(|l| l.timestamp = 103 * day + 50);
+        let mid_a = register(&s, "mid-a");
+        let mid_b = register(&s, "mid-b");
+        s.env.ledger().with_mut(|l| l.timestamp = 110 * day);
+        let late = register(&s, "late");
+
+        let (week, next) = client.list_registered_between(&(101 * day), &(107 * day), &None, &10);
+        assert_eq!(
+            week,
+            Vec::from_array(&s.env, [mid_a.clone(), mid_b.clone()])
+        );
+        assert_eq!(next, None);
+        let (everyone, _) = client.list_registered_between(&(90 * day), &(110 * day), &None, &10);
+        assert_eq!(everyone.len(), 4);
+        assert_eq!(everyone.get(0).unwrap(), early);
+        assert_eq!(everyone.get(3).unwrap(), late);
+
+        // Bounds are exact within a bucket, and pages resume at the cursor.
+        let (tight, _) =
+            client.list_registered_between(&(103 * day + 51), &(104 * day), &None, &10);
+        assert_eq!(tight.len(), 0);
+        let (first, cursor) = client.list_registered_between(&(90 * day), &(110 * day), &None, &2);
+        assert_eq!(
+            first,
+            Vec::from_array(&s.env, [early.clone(), mid_a.clone()])
+        );
+        let (second, cursor) =
+            client.list_registered_between(&(90 * day), &(110 * day), &cursor, &2);
+        assert_eq!(
+            second,
+            Vec::from_array(&s.env, [mid_b.clone(), late.clone()])
+        );
+        assert_eq!(cursor, None);
+
+        client.remove_contributor(&mid_a, &mid_a);
+        let (week, _) = client.list_registered_between(&(101 * day), &(107 * day), &None, &10);
+        assert_eq!(week, Vec::from_array(&s.env, [mid_b]));
+    }
+
+    #[test]
+    fn test_list_registered_between_rejects_bad_ranges() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        assert_eq!(
+            client.try_list_registered_between(&10, &5, &None, &10),
+            Err(Ok(ContributorError::InvalidRange))
+        );
+        assert_eq!(
+            client.try_list_registered_between(
+                &0,
+                &(MAX_RANGE_BUCKETS * REGISTRATION_BUCKET),
+                &None,
+                &10
+            ),
+            Err(Ok(ContributorError::InvalidRange))
+        );
+    }
+
+    // ── Streaks ───────────────────────────────────────────────
+
+    #[test]
+    fn test_streak_extends_per_window_and_resets_on_gap() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        let day = DEFAULT_STREAK_WINDOW;
+        s.env.ledger().with_mut(|l| l.timestamp = 10 * day);
+        assert_eq!(client.get_streak(&dev).current, 0);
+
+        client.add_reputation(&s.alice, &dev, &1, &REASON_UNSPECIFIED);
+        client.add_reputation(&s.alice, &dev, &1, &REASON_UNSPECIFIED);
+        assert_eq!(client.get_streak(&dev).current, 1);
+
+        for _ in 0..3 {
+            s.env.ledger().with_mut(|l| l.timestamp += day);
+            client.add_reputation(&s.alice, &dev, &1, &REASON_UNSPECIFIED);
+        }
+        let streak = client.get_streak(&dev);
+        assert_eq!(streak.current, 4);
+        assert_eq!(streak.longest, 4);
+        assert_eq!(streak.last_contribution_timestamp, 13 * day);
+
+        // Losses never count, and a skipped window breaks the streak.
+        s.env.ledger().with_mut(|l| l.timestamp += day);
+        client.add_reputation(&s.alice, &dev, &-1, &REASON_UNSPECIFIED);
+        s.env.ledger().with_mut(|l| l.timestamp += day);
+        assert_eq!(client.get_streak(&dev).current, 0);
+        client.add_reputation(&s.alice, &dev, &1, &REASON_UNSPECIFIED);
+        let streak = client.get_streak(&dev);
+        assert_eq!(streak.current, 1);
+        assert_eq!(streak.longest, 4);
+    }
+
+    #[test]
+    fn test_streak_window_is_configurable() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        let repo = String::from_str(&s.env, "lumenpulse/web");
+        assert_eq!(
+            client.try_set_streak_window(&s.alice, &0),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
+        client.set_streak_window(&s.alice, &3_600);
+        assert_eq!(client.get_streak_window(), 3_600);
+
+        client.record_contribution(&s.alice, &dev, &repo, &1, &5);
+        s.env.ledger().with_mut(|l| l.timestamp += 3_600);
+        client.record_contribution(&s.alice, &dev, &repo, &2, &5);
+        assert_eq!(client.get_streak(&dev).current, 2);
+    }
+
+    // ── Deactivation ──────────────────────────────────────────
+
+    #[test]
+    fn test_deactivate_hides_from_leaderboard_and_keeps_history() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev_a = register(&s, "dev-a");
+        let dev_b = register(&s, "dev-b");
+        client.add_reputation(&s.alice, &dev_a, &40, &REASON_UNSPECIFIED);
+        client.add_reputation(&s.alice, &dev_b, &10, &REASON_UNSPECIFIED);
+        assert!(client.is_active(&dev_a));
+
+        client.deactivate(&dev_a);
+        assert!(!client.is_active(&dev_a));
+        let board = client.get_leaderboard();
+        assert_eq!(board.len(), 1);
+        assert_eq!(board.get(0).unwrap().address, dev_b);
+
+        // Points still accrue while away, without re-entering the rankings.
+        client.add_reputation(&s.alice, &dev_a, &5, &REASON_UNSPECIFIED);
+        assert_eq!(client.get_leaderboard().len(), 1);
+        assert_eq!(client.get_reputation(&dev_a), 45);
+        assert_eq!(client.get_reputation_history_len(&dev_a), 2);
+
+        client.reactivate(&dev_a);
+        assert!(client.is_active(&dev_a));
+        assert_eq!(client.get_leaderboard().get(0).unwrap().address, dev_a);
+    }
+
+    #[test]
+    fn test_deactivate_is_idempotent() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        client.deactivate(&dev);
+        client.deactivate(&dev);
+        assert_eq!(s.env.events().all().len(), 0);
+        client.reactivate(&dev);
+        client.reactivate(&dev);
+        assert!(client.is_active(&dev));
+        assert!(!client.is_active(&Address::generate(&s.env)));
+    }
+
+    // ── Handle validation ─────────────────────────────────────
+
+    #[test]
+    fn test_register_rejects_malformed_handles() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = Address::generate(&s.env);
+        let try_handle =
+            |h: &str| client.try_register_contributor(&dev, &String::from_str(&s.env, h), &None);
+
+        assert_eq!(
+            try_handle("dev_a"),
+            Err(Ok(ContributorError::HandleInvalidChars))
+        );
+        assert_eq!(
+            try_handle("dev a"),
+            Err(Ok(ContributorError::HandleInvalidChars))
+        );
+        assert_eq!(
+            try_handle("dév"),
+            Err(Ok(ContributorError::HandleInvalidChars))
+        );
+        assert_eq!(
+            try_handle(&"a".repeat(DEFAULT_MAX_HANDLE_LEN as usize + 1)),
+            Err(Ok(ContributorError::HandleTooLong))
+        );
+        client.register_contributor(
+            &dev,
+            &String::from_str(&s.env, &"a".repeat(DEFAULT_MAX_HANDLE_LEN as usize)),
+            &None,
+        );
+        assert_eq!(
+            client.try_update_handle(&dev, &String::from_str(&s.env, "bad/handle")),
+            Err(Ok(ContributorError::HandleInvalidChars))
+        );
+    }
+
+    #[test]
+    fn test_admin_can_change_max_handle_length() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        assert_eq!(client.get_max_handle_length(), DEFAULT_MAX_HANDLE_LEN);
+        client.set_max_handle_length(&s.alice, &5);
+
+        let dev = Address::generate(&s.env);
+        assert_eq!(
+            client.try_register_contributor(&dev, &String::from_str(&s.env, "abcdef"), &None),
+            Err(Ok(ContributorError::HandleTooLong))
+        );
+        client.register_contributor(&dev, &String::from_str(&s.env, "Ab-12"), &None);
+
+        assert_eq!(
+            client.try_set_max_handle_length(&s.alice, &(HANDLE_LEN_LIMIT + 1)),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
+        assert_eq!(
+            client.try_set_max_handle_length(&s.bob, &10),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+    }
+
+    // ── Referrals ─────────────────────────────────────────────
+
+    #[test]
+    fn test_register_with_referrer_counts_referrals() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let mentor = register(&s, "mentor");
+        let dev_a = Address::generate(&s.env);
+        let dev_b = Address::generate(&s.env);
+
+        client.register_contributor(
+            &dev_a,
+            &String::from_str(&s.env, "dev-a"),
+            &Some(mentor.clone()),
+        );
+        let events = s.env.events().all();
+        let (_, topics, _) = events.last().unwrap();
+        assert_eq!(
+            topics,
+            (
+                Symbol::new(&s.env, "contributor"),
+                Symbol::new(&s.env, "referred"),
+                mentor.clone()
+            )
+                .into_val(&s.env)
+        );
+        client.register_contributor(
+            &dev_b,
+            &String::from_str(&s.env, "dev-b"),
+            &Some(mentor.clone()),
+        );
+
+        assert_eq!(client.get_referral_count(&mentor), 2);
+        assert_eq!(client.get_referrer(&dev_a), Some(mentor.clone()));
+        assert_eq!(client.get_referrer(&mentor), None);
+    }
+
+    #[test]
+    fn test_re_registration_starts_clean() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let mentor = register(&s, "mentor");
+        let dev = Address::generate(&s.env);
+        let handle = String::from_str(&s.env, "dev-a");
+        let rust = Symbol::new(&s.env, "rust");
+        client.register_contributor(&dev, &handle, &Some(mentor.clone()));
+        client.add_reputation(&s.alice, &dev, &5, &REASON_UNSPECIFIED);
+        let mut peers = Vec::new(&s.env);
+        for i in 0..MAX_ENDORSEMENT_CLEAR + 2 {
+            let peer = register(
+                &s,
+                ["p0", "p1", "p2", "p3", "p4", "p5", "p6", "p7", "p8", "p9"][i as usize],
+            );
+            client.endorse(&peer, &dev, &rust);
+            peers.push_back(peer);
+        }
+
+        client.remove_contributor(&dev, &dev);
+        assert_eq!(client.get_referral_count(&mentor), 0);
+        assert_eq!(client.get_referrer(&dev), None);
+        assert_eq!(client.get_streak(&dev).longest, 0);
+
+        // Endorsements past the inline budget hold up re-registration
+        assert_eq!(client.get_endorsement_count(&dev, &rust), 2);
+        assert_eq!(
+            client.try_register_contributor(&dev, &handle, &None),
+            Err(Ok(ContributorError::EndorsementsPending))
+        );
+        assert!(!client.purge_endorsements(&dev));
+        assert_eq!(
+            client.try_purge_endorsements(&mentor),
+            Err(Ok(ContributorError::ContributorAlreadyExists))
+        );
+
+        client.register_contributor(&dev, &handle, &Some(mentor.clone()));
+        assert_eq!(client.get_referral_count(&mentor), 1);
+        assert_eq!(client.get_endorsement_count(&dev, &rust), 0);
+        for peer in peers.iter() {
+            assert!(!client.has_endorsed(&peer, &dev, &rust));
+        }
+        assert_eq!(client.endorse(&peers.get_unchecked(0), &dev, &rust), 1);
+    }
+
+    #[test]
+    fn test_register_rejects_unknown_or_self_referrer() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = Address::generate(&s.env);
+        let handle = String::from_str(&s.env, "dev-a");
+        assert_eq!(
+            client.try_register_contributor(&dev, &handle, &Some(Address::generate(&s.env))),
+            Err(Ok(ContributorError::InvalidReferrer))
+        );
+        assert_eq!(
+            client.try_register_contributor(&dev, &handle, &Some(dev.clone())),
+            Err(Ok(ContributorError::InvalidReferrer))
+        );
+        client.register_contributor(&dev, &handle, &None);
+        assert_eq!(client.get_referrer(&dev), None);
+    }
+
+    // ── Registration fee ──────────────────────────────────────
+
+    #[test]
+    fn test_registration_fee_goes_to_treasury() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let issuer = Address::generate(&s.env);
+        let token = s.env.register_stellar_asset_contract_v2(issuer).address();
+        let treasury = Address::generate(&s.env);
+
+        assert_eq!(
+            client.try_set_registration_fee(&s.alice, &token, &50),
+            Err(Ok(ContributorError::NotConfigured))
+        );
+        client.set_treasury(&s.alice, &treasury);
+        client.set_registration_fee(&s.alice, &token, &50);
+        assert_eq!(client.get_registration_fee().unwrap().amount, 50);
+
+        let dev = Address::generate(&s.env);
+        StellarAssetClient::new(&s.env, &token).mint(&dev, &80);
+        client.register_contributor(&dev, &String::from_str(&s.env, "dev-a"), &None);
+        let balances = TokenClient::new(&s.env, &token);
+        assert_eq!(balances.balance(&dev), 30);
+        assert_eq!(balances.balance(&treasury), 50);
+
+        // Without enough balance the registration fails outright.
+        let broke = Address::generate(&s.env);
+        assert!(client
+            .try_register_contributor(&broke, &String::from_str(&s.env, "broke"), &None)
+            .is_err());
+        assert_eq!(
+            client.try_get_contributor(&broke),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+
+        client.set_registration_fee(&s.alice, &token, &0);
+        assert_eq!(client.get_registration_fee(), None);
+        client.register_contributor(&broke, &String::from_str(&s.env, "broke"), &None);
+    }
+
+    #[test]
+    fn test_set_registration_fee_validation() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let token = Address::generate(&s.env);
+        assert_eq!(
+            client.try_set_registration_fee(&s.bob, &token, &10),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_set_registration_fee(&s.alice, &token, &-1),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
+    }
+
+    // ── Delegation ────────────────────────────────────────────
+
+    #[test]
+    fn test_delegation_moves_voting_weight() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev_a = register(&s, "dev-a");
+        let dev_b = register(&s, "dev-b");
+        let dev_c = register(&s, "dev-c");
+        client.add_reputation(&s.alice, &dev_a, &10, &REASON_UNSPECIFIED);
+        client.add_reputation(&s.alice, &dev_b, &20, &REASON_UNSPECIFIED);
+        client.add_reputation(&s.alice, &dev_c, &30, &REASON_UNSPECIFIED);
+
+        client.delegate_reputation(&dev_a, &dev_c);
+        client.delegate_reputation(&dev_b, &dev_c);
+        assert_eq!(client.get_effective_reputation(&dev_c), 60);
+        assert_eq!(client.get_effective_reputation(&dev_a), 0);
+        assert_eq!(client.get_reputation(&dev_a), 10);
+        assert_eq!(client.get_delegate(&dev_a), Some(dev_c.clone()));
+        assert_eq!(client.get_delegators(&dev_c).len(), 2);
+
+        // Re-delegating moves the weight; undelegating restores it.
+        let dev_d = register(&s, "dev-d");
+        client.delegate_reputation(&dev_a, &dev_d);
+        assert_eq!(client.get_effective_reputation(&dev_c), 50);
+        assert_eq!(client.get_effective_reputation(&dev_d), 10);
+        client.undelegate_reputation(&dev_a);
+        assert_eq!(client.get_effective_reputation(&dev_a), 10);
+        assert_eq!(client.get_delegate(&dev_a), None);
+
+        client.remove_contributor(&dev_c, &dev_c);
+        assert_eq!(client.get_delegate(&dev_b), None);
+        assert_eq!(client.get_effective_reputation(&dev_b), 20);
+    }
+
+    #[test]
+    fn test_redelegating_to_same_delegatee_counts_once() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev_a = register(&s, "dev-a");
+        let dev_b = register(&s, "dev-b");
+        client.add_reputation(&s.alice, &dev_a, &10, &REASON_UNSPECIFIED);
+        client.add_reputation(&s.alice, &dev_b, &20, &REASON_UNSPECIFIED);
+
+        client.delegate_reputation(&dev_a, &dev_b);
+        client.delegate_reputation(&dev_a, &dev_b);
+        assert_eq!(client.get_delegators(&dev_b).len(), 1);
+        assert_eq!(client.get_effective_reputation(&dev_b), 30);
+
+        client.undelegate_reputation(&dev_a);
+        assert_eq!(client.get_delegators(&dev_b).len(), 0);
+        assert_eq!(client.get_effective_reputation(&dev_b), 20);
+    }
+
+    #[test]
+    fn test_delegation_rejects_cycles_and_chains() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev_a = register(&s, "dev-a");
+        let dev_b = register(&s, "dev-b");
+        let dev_c = register(&s, "dev-c");
+
+        assert_eq!(
+            client.try_delegate_reputation(&dev_a, &dev_a),
+            Err(Ok(ContributorError::InvalidDelegation))
+        );
+        client.delegate_reputation(&dev_a, &dev_b);
+        assert_eq!(
+            client.try_delegate_reputation(&dev_b, &dev_a),
+            Err(Ok(ContributorError::InvalidDelegation))
+        );
+        assert_eq!(
+            client.try_delegate_reputation(&dev_c, &dev_a),
+            Err(Ok(ContributorError::InvalidDelegation))
+        );
+        assert_eq!(
+            client.try_delegate_reputation(&dev_b, &dev_c),
+            Err(Ok(ContributorError::InvalidDelegation))
+        );
+    }
+
+    // ── Snapshots ─────────────────────────────────────────────
+
+    #[test]
+    fn test_snapshot_epoch_freezes_scores() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev_a = register(&s, "dev-a");
+        let dev_b = register(&s, "dev-b");
+        let dev_c = register(&s, "dev-c");
+        client.add_reputation(&s.alice, &dev_a, &10, &REASON_UNSPECIFIED);
+        client.add_reputation(&s.alice, &dev_b, &20, &REASON_UNSPECIFIED);
+        client.add_reputation(&s.alice, &dev_c, &30, &REASON_UNSPECIFIED);
+
+        assert_eq!(client.get_latest_snapshot_epoch(), None);
+        let epoch = client.snapshot_epoch(&s.alice, &MAX_SNAPSHOT_PAGE).unwrap();
+        assert_eq!(epoch, 0);
+        let snapshot = client.get_snapshot(&epoch);
+        assert_eq!(snapshot.contributor_count, 3);
+        assert_eq!(client.get_snapshot_root(&epoch), snapshot.root);
+
+        // Three leaves: root = H(H(a, b), c), with c carried up one level.
+        let (leaf_a, leaf_b, leaf_c) = s.env.as_contract(&s.contract, || {
+            (
+                snapshots::leaf(&s.env, &dev_a, 10),
+                snapshots::leaf(&s.env, &dev_b, 20),
+                snapshots::leaf(&s.env, &dev_c, 30),
+            )
+        });
+        let ab = s.env.as_contract(&s.contract, || {
+            snapshots::root(
+                &s.env,
+                Vec::from_array(&s.env, [leaf_a.clone(), leaf_b.clone()]),
+            )
+        });
+        let proof_a = Vec::from_array(&s.env, [leaf_b, leaf_c.clone()]);
+        assert!(client.verify_snapshot_proof(&epoch, &dev_a, &10, &proof_a));
+        assert!(!client.verify_snapshot_proof(&epoch, &dev_a, &11, &proof_a));
+        assert!(client.verify_snapshot_proof(&epoch, &dev_c, &30, &Vec::from_array(&s.env, [ab])));
+
+        // Later changes land in a new epoch and leave the old root alone.
+        client.add_reputation(&s.alice, &dev_a, &5, &REASON_UNSPECIFIED);
+        let next = client.snapshot_epoch(&s.alice, &MAX_SNAPSHOT_PAGE).unwrap();
+        assert_eq!(next, 1);
+        assert_eq!(client.get_latest_snapshot_epoch(), Some(1));
+        assert_ne!(client.get_snapshot_root(&next), snapshot.root);
+        assert_eq!(client.get_snapshot_root(&epoch), snapshot.root);
+    }
+
+    #[test]
+    fn test_snapshot_builds_across_pages() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let mut devs = Vec::new(&s.env);
+        let mut leaves = Vec::new(&s.env);
+        for (i, handle) in ["dev-a", "dev-b", "dev-c", "dev-d", "dev-e"]
+            .iter()
+            .enumerate()
+        {
+            let dev = register(&s, handle);
+            devs.push_back(dev.clone());
+            client.add_reputation(&s.alice, &dev, &(i as i64 + 1), &REASON_UNSPECIFIED);
+            leaves.push_back(
+                s.env
+                    .as_contract(&s.contract, || snapshots::leaf(&s.env, &dev, i as u64 + 1)),
+            );
+        }
+
+        assert_eq!(client.snapshot_epoch(&s.alice, &2), None);
+        let build = client.get_snapshot_build().unwrap();
+        assert_eq!((build.cursor, build.total, build.leaf_count), (2, 5, 2));
+        assert_eq!(
+            client.try_get_snapshot(&0),
+            Err(Ok(ContributorError::SnapshotNotFound))
+        );
+
+        // Scores and the list are frozen until the last page is in
+        let (first, last) = (devs.get_unchecked(0), devs.get_unchecked(4));
+        assert_eq!(
+            client.try_add_reputation(&s.alice, &first, &1, &REASON_UNSPECIFIED),
+            Err(Ok(ContributorError::SnapshotInProgress))
+        );
+        assert_eq!(
+            client.try_remove_contributor(&s.alice, &first),
+            Err(Ok(ContributorError::SnapshotInProgress))
+        );
+        assert_eq!(
+            client.try_merge_contributors(&s.alice, &first, &last),
+            Err(Ok(ContributorError::SnapshotInProgress))
+        );
+
+        // Registrations after the build started wait for the next epoch
+        register(&s, "late");
+        assert_eq!(client.snapshot_epoch(&s.alice, &2), None);
+        assert_eq!(client.snapshot_epoch(&s.alice, &2), Some(0));
+        assert_eq!(client.get_snapshot_build(), None);
+        let snapshot = client.get_snapshot(&0);
+        assert_eq!(snapshot.contributor_count, 5);
+        let expected = s
+            .env
+            .as_contract(&s.contract, || snapshots::root(&s.env, leaves));
+        assert_eq!(snapshot.root, expected);
+    }
+
+    #[test]
+    fn test_cancel_snapshot_lifts_freeze() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev_a = register(&s, "dev-a");
+        let dev_b = register(&s, "dev-b");
+        assert_eq!(
+            client.try_cancel_snapshot(&s.alice),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
+
+        assert_eq!(client.snapshot_epoch(&s.alice, &1), None);
+        assert_eq!(
+            client.try_cancel_snapshot(&s.bob),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        client.cancel_snapshot(&s.alice);
+        assert_eq!(client.get_snapshot_build(), None);
+        client.add_reputation(&s.alice, &dev_a, &5, &REASON_UNSPECIFIED);
+
+        // The next build starts over and picks up the new score
+        assert_eq!(client.snapshot_epoch(&s.alice, &MAX_SNAPSHOT_PAGE), Some(0));
+        let leaf_b = s
+            .env
+            .as_contract(&s.contract, || snapshots::leaf(&s.env, &dev_b, 0));
+        let proof = Vec::from_array(&s.env, [leaf_b]);
+        assert!(client.verify_snapshot_proof(&0, &dev_a, &5, &proof));
+    }
+
+    #[test]
+    fn test_snapshot_frontier_matches_full_tree() {
+        let s = setup();
+        s.env.as_contract(&s.contract, || {
+            let mut leaves = Vec::new(&s.env);
+            let mut build = snapshots::SnapshotBuild {
+                cursor: 0,
+                total: 0,
+                leaf_count: 0,
+                frontier: Vec::new(&s.env),
+                started_at: 0,
+            };
+            for score in 0..12u64 {
+                assert_eq!(
+                    snapshots::frontier_root(&s.env, &build),
+                    snapshots::root(&s.env, leaves.clone())
+                );
+                let leaf = snapshots::leaf(&s.env, &s.alice, score);
+                leaves.push_back(leaf.clone());
+                snapshots::push_leaf(&s.env, &mut build, leaf);
+            }
+        });
+    }
+
+    #[test]
+    fn test_snapshot_requires_admin() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        assert_eq!(
+            client.try_snapshot_epoch(&s.bob, &MAX_SNAPSHOT_PAGE),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_get_snapshot_root(&0),
+            Err(Ok(ContributorError::SnapshotNotFound))
+        );
+    }
+
+    // ── Linked handles ────────────────────────────────────────
+
+    #[test]
+    fn test_link_and_unlink_platform_handles() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        let gitlab = Symbol::new(&s.env, "gitlab");
+        let discord = Symbol::new(&s.env, "discord");
+        let handle = String::from_str(&s.env, "dev-a");
+
+        client.link_handle(&dev, &gitlab, &handle);
+        client.link_handle(&dev, &discord, &String::from_str(&s.env, "dev_a#1234"));
+        let all = client.get_handles(&dev);
+        assert_eq!(all.len(), 3);
+        assert_eq!(all.get(gitlab.clone()), Some(handle.clone()));
+        assert_eq!(
+            all.get(Symbol::new(&s.env, "github")),
+            Some(String::from_str(&s.env, "dev-a"))
+        );
+        assert_eq!(
+            client.get_contributor_by_platform(&gitlab, &handle).address,
+            dev
+        );
+        assert_eq!(
+            client
+                .get_contributor_by_platform(
+                    &Symbol::new(&s.env, "github"),
+                    &String::from_str(&s.env, "dev-a")
+                )
+                .address,
+            dev
+        );
+
+        // Relinking a platform frees the previous handle.
+        let renamed = String::from_str(&s.env, "dev-a-renamed");
+        client.link_handle(&dev, &gitlab, &renamed);
+        assert_eq!(
+            client.try_get_contributor_by_platform(&gitlab, &handle),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+
+        client.unlink_handle(&dev, &gitlab);
+        assert_eq!(client.get_handles(&dev).len(), 2);
+        assert_eq!(
+            client.try_unlink_handle(&dev, &gitlab),
+            Err(Ok(ContributorError::NotFound))
+        );
+    }
+
+    #[test]
+    fn test_link_handle_rejects_conflicts() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev_a = register(&s, "dev-a");
+        let dev_b = register(&s, "dev-b");
+        let radicle = Symbol::new(&s.env, "radicle");
+        let handle = String::from_str(&s.env, "did:key:z6Mk");
+
+        client.link_handle(&dev_a, &radicle, &handle);
+        assert_eq!(
+            client.try_link_handle(&dev_b, &radicle, &handle),
+            Err(Ok(ContributorError::PlatformHandleTaken))
+        );
+        assert_eq!(
+            client.try_link_handle(&dev_b, &Symbol::new(&s.env, "github"), &handle),
+            Err(Ok(ContributorError::InvalidPlatform))
+        );
+        assert_eq!(
+            client.try_link_handle(&dev_b, &radicle, &String::from_str(&s.env, "")),
+            Err(Ok(ContributorError::InvalidHandle))
+        );
+
+        client.remove_contributor(&dev_a, &dev_a);
+        client.link_handle(&dev_b, &radicle, &handle);
+        assert_eq!(
+            client
+                .get_contributor_by_platform(&radicle, &handle)
+                .address,
+            dev_b
+        );
+    }
+
+    // ── Profile URI ───────────────────────────────────────────
+
+    #[test]
+    fn test_set_profile_uri_round_trips() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        assert_eq!(client.get_contributor(&dev).profile_uri, None);
+
+        let uri = String::from_str(
+            &s.env,
+            "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        );
+        client.set_profile_uri(&dev, &uri);
+        assert_eq!(client.get_contributor(&dev).profile_uri, Some(uri));
+
+        client.set_profile_uri(&dev, &String::from_str(&s.env, ""));
+        assert_eq!(client.get_contributor(&dev).profile_uri, None);
+    }
+
+    #[test]
+    fn test_set_profile_uri_rejects_oversized() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        let long = "a".repeat(MAX_URI_LEN as usize + 1);
+        assert_eq!(
+            client.try_set_profile_uri(&dev, &String::from_str(&s.env, &long)),
+            Err(Ok(ContributorError::InvalidProfileField))
+        );
+        let outsider = Address::generate(&s.env);
+        assert_eq!(
+            client.try_set_profile_uri(&outsider, &String::from_str(&s.env, "https://x.dev")),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+    }
+
+    // ── Legacy import ─────────────────────────────────────────
+
+    fn legacy_entry(s: &Setup, handle: &str, score: u64) -> ContributorData {
+        ContributorData {
+            address: Address::generate(&s.env),
+            github_handle: String::from_str(&s.env, handle),
+            reputation_score: score,
+            registered_timestamp: 1_600_000_000,
+            verified: true,
+            profile_uri: None,
+            display_name: None,
+            bio: None,
+            active: true,
+            frozen: false,
+        }
+    }
+
+    #[test]
+    fn test_import_contributors_seeds_indexes() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let old_a = legacy_entry(&s, "old-a", 40);
+        let old_b = legacy_entry(&s, "old-b", 90);
+        client.import_contributors(
+            &s.alice,
+            &Vec::from_array(&s.env, [old_a.clone(), old_b.clone()]),
+        );
+
+        assert_eq!(client.get_contributor(&old_a.address), old_a);
+        assert_eq!(
+            client
+                .get_contributor_by_github(&old_b.github_handle)
+                .address,
+            old_b.address
+        );
+        assert_eq!(client.contributor_count(), 2);
+        let board = client.get_leaderboard();
+        assert_eq!(board.get_unchecked(0).address, old_b.address);
+        assert_eq!(
+            client
+                .list_registered_between(&1_600_000_000, &1_600_000_000, &None, &10)
+                .0
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_import_rejects_duplicates_and_closes_on_finalize() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        let mut clash = legacy_entry(&s, "old-a", 0);
+        clash.address = dev;
+        assert_eq!(
+            client.try_import_contributors(&s.alice, &Vec::from_array(&s.env, [clash])),
+            Err(Ok(ContributorError::ContributorAlreadyExists))
+        );
+        let taken = legacy_entry(&s, "dev-a", 0);
+        assert_eq!(
+            client.try_import_contributors(&s.alice, &Vec::from_array(&s.env, [taken])),
+            Err(Ok(ContributorError::GitHubHandleTaken))
+        );
+        let entries = Vec::from_array(&s.env, [legacy_entry(&s, "old-b", 0)]);
+        assert_eq!(
+            client.try_import_contributors(&s.bob, &entries),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+
+        client.finalize_import(&s.alice);
+        assert!(client.is_import_finalized());
+        assert_eq!(
+            client.try_import_contributors(&s.alice, &entries),
+            Err(Ok(ContributorError::ImportFinalized))
+        );
+        assert_eq!(
+            client.try_finalize_import(&s.alice),
+            Err(Ok(ContributorError::ImportFinalized))
+        );
+    }
+
+    #[test]
+    fn test_import_clamps_scores_and_refuses_banned_and_merged() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        client.set_max_reputation(&s.alice, &100);
+        let high = legacy_entry(&s, "old-high", 500);
+        client.import_contributors(&s.alice, &Vec::from_array(&s.env, [high.clone()]));
+        assert_eq!(client.get_reputation(&high.address), 100);
+        assert_eq!(client.get_leaderboard().get_unchecked(0).score, 100);
+
+        let banned = legacy_entry(&s, "old-banned", 0);
+        client.ban_contributor(&s.alice, &banned.address);
+        assert_eq!(
+            client.try_import_contributors(&s.alice, &Vec::from_array(&s.env, [banned])),
+            Err(Ok(ContributorError::ContributorBanned))
+        );
+
+        let primary = register(&s, "dev-a");
+        let duplicate = register(&s, "dev-a-alt");
+        client.merge_contributors(&s.alice, &primary, &duplicate);
+        let mut merged = legacy_entry(&s, "old-merged", 0);
+        merged.address = duplicate;
+        assert_eq!(
+            client.try_import_contributors(&s.alice, &Vec::from_array(&s.env, [merged])),
+            Err(Ok(ContributorError::ContributorMerged))
+        );
+    }
+
+    // ── Layout migration ──────────────────────────────────────
+
+    #[test]
+    fn test_four_field_profiles_read_and_migrate() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let old = Address::generate(&s.env);
+        let handle = String::from_str(&s.env, "old-dev");
+        s.env.as_contract(&s.contract, || {
+            let storage = s.env.storage().persistent();
+            storage.set(
+                &DataKey::Contributor(old.clone()),
+                &LegacyContributorData {
+                    address: old.clone(),
+                    github_handle: handle.clone(),
+                    reputation_score: 70,
+                    registered_timestamp: 1_600_000_000,
+                },
+            );
+            storage.set(&DataKey::GitHubIndex(handle.clone()), &old);
+        });
+
+        // Reads fill in registration defaults before anything is rewritten
+        let profile = client.get_contributor(&old);
+        assert_eq!(profile.reputation_score, 70);
+        assert!(profile.active && !profile.verified && !profile.frozen);
+        assert_eq!(client.contributor_count(), 0);
+
+        assert_eq!(
+            client.try_migrate_contributors(&s.bob, &Vec::from_array(&s.env, [old.clone()])),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        let batch = Vec::from_array(&s.env, [old.clone()]);
+        assert_eq!(client.migrate_contributors(&s.alice, &batch), 1);
+        assert_eq!(client.migrate_contributors(&s.alice, &batch), 0);
+        assert_eq!(client.list_contributors(&0, &10), batch);
+        assert_eq!(client.get_leaderboard().get_unchecked(0).address, old);
+
+        client.add_reputation(&s.alice, &old, &5, &REASON_UNSPECIFIED);
+        assert_eq!(client.get_reputation(&old), 75);
+
+        let stranger = Address::generate(&s.env);
+        assert_eq!(
+            client.try_migrate_contributors(&s.alice, &Vec::from_array(&s.env, [stranger])),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+    }
+
+    // ── Bulk lookup ───────────────────────────────────────────
+
+    #[test]
+    fn test_get_contributors_preserves_order_and_gaps() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev_a = register(&s, "dev-a");
+        let dev_b = register(&s, "dev-b");
+        let stranger = Address::generate(&s.env);
+
+        let profiles = client.get_contributors(&Vec::from_array(
+            &s.env,
+            [dev_b.clone(), stranger, dev_a.clone()],
+        ));
+        assert_eq!(profiles.len(), 3);
+        assert_eq!(profiles.get_unchecked(0).unwrap().address, dev_b);
+        assert_eq!(profiles.get_unchecked(1), None);
+        assert_eq!(profiles.get_unchecked(2).unwrap().address, dev_a);
+
+        let mut many = Vec::new(&s.env);
+        for _ in 0..MAX_PAGE_SIZE + 5 {
+            many.push_back(dev_a.clone());
+        }
+        assert_eq!(client.get_contributors(&many).len(), MAX_PAGE_SIZE);
+    }
+
+    // ── Invite-only registration ──────────────────────────────
+
+    #[test]
+    fn test_invite_only_requires_single_use_approval() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        client.set_invite_only(&s.alice, &true);
+        assert!(client.is_invite_only());
+
+        let dev = Address::generate(&s.env);
+        let handle = String::from_str(&s.env, "dev-a");
+        assert_eq!(
+            client.try_register_contributor(&dev, &handle, &None),
+            Err(Ok(ContributorError::RegistrationNotApproved))
+        );
+
+        client.approve_registration(&s.alice, &dev);
+        assert!(client.is_registration_approved(&dev));
+        client.register_contributor(&dev, &handle, &None);
+        assert!(!client.is_registration_approved(&dev));
+
+        client.set_invite_only(&s.alice, &false);
+        register(&s, "dev-b");
+    }
+
+    #[test]
+    fn test_registration_approval_admin_only_and_revocable() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = Address::generate(&s.env);
+        assert_eq!(
+            client.try_set_invite_only(&s.bob, &true),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_approve_registration(&s.bob, &dev),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+
+        client.set_invite_only(&s.alice, &true);
+        client.approve_registration(&s.alice, &dev);
+        client.revoke_registration(&s.alice, &dev);
+        assert_eq!(
+            client.try_revoke_registration(&s.alice, &dev),
+            Err(Ok(ContributorError::NotFound))
+        );
+        assert_eq!(
+            client.try_register_contributor(&dev, &String::from_str(&s.env, "dev-a"), &None),
+            Err(Ok(ContributorError::RegistrationNotApproved))
+        );
+    }
+
+    // ── Milestones ────────────────────────────────────────────
+
+    fn milestones_reached(s: &Setup) -> std::vec::Vec<u64> {
+        let topic = Symbol::new(&s.env, "milestone");
+        s.env
+            .events()
+            .all()
+            .iter()
+            .filter(|(contract, topics, _)| {
+                *contract == s.contract
+                    && topics.get(1).map(|t| Symbol::from_val(&s.env, &t)) == Some(topic.clone())
+            })
+            .map(|(_, _, data)| {
+                let data: Map<Symbol, u64> = Map::from_val(&s.env, &data);
+                data.get(Symbol::new(&s.env, "threshold")).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_milestone_events_fire_once_per_crossing() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        assert_eq!(
+            client.get_milestones(),
+            Vec::from_array(&s.env, [100u64, 500, 1_000])
+        );
+
+        client.add_reputation(&s.alice, &dev, &99i64, &0u32);
+        assert!(milestones_reached(&s).is_empty());
+
+        client.add_reputation(&s.alice, &dev, &1i64, &0u32);
+        assert_eq!(milestones_reached(&s), [100]);
+
+        client.add_reputation(&s.alice, &dev, &1_000i64, &0u32);
+        assert_eq!(milestones_reached(&s), [500, 1_000]);
+
+        client.add_reputation(&s.alice, &dev, &-600i64, &0u32);
+        assert!(milestones_reached(&s).is_empty());
+    }
+
+    #[test]
+    fn test_set_milestones_validates_order_and_role() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        assert_eq!(
+            client.try_set_milestones(&s.alice, &Vec::from_array(&s.env, [50u64, 50])),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
+        assert_eq!(
+            client.try_set_milestones(&s.bob, &Vec::from_array(&s.env, [50u64])),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        client.set_milestones(&s.alice, &Vec::from_array(&s.env, [10u64, 20]));
+
+        let dev = register(&s, "dev-a");
+        client.add_reputation(&s.alice, &dev, &15i64, &0u32);
+        assert_eq!(milestones_reached(&s), [10]);
+    }
+
+    // ── Reputation gate ───────────────────────────────────────
+
+    #[test]
+    fn test_require_min_reputation() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        client.add_reputation(&s.alice, &dev, &50i64, &0u32);
+
+        client.require_min_reputation(&dev, &50);
+        assert!(client.has_min_reputation(&dev, &50));
+        assert_eq!(
+            client.try_require_min_reputation(&dev, &51),
+            Err(Ok(ContributorError::InsufficientReputation))
+        );
+        assert!(!client.has_min_reputation(&dev, &51));
+        assert!(!client.has_min_reputation(&s.carol, &0));
+
+        client.ban_contributor(&s.alice, &dev);
+        assert_eq!(
+            client.try_require_min_reputation(&dev, &0),
+            Err(Ok(ContributorError::ContributorBanned))
+        );
+    }
+
+    // ── Display name & bio ────────────────────────────────────
+
+    #[test]
+    fn test_update_profile_sets_and_clears_fields() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        let name = String::from_str(&s.env, "Ada L.");
+        let bio = String::from_str(&s.env, "Soroban contracts and docs.");
+
+        client.update_profile(&dev, &name, &bio);
+        let profile = client.get_contributor(&dev);
+        assert_eq!(profile.display_name, Some(name.clone()));
+        assert_eq!(profile.bio, Some(bio));
+
+        client.update_profile(&dev, &name, &String::from_str(&s.env, ""));
+        let profile = client.get_contributor(&dev);
+        assert_eq!(profile.display_name, Some(name));
+        assert_eq!(profile.bio, None);
+    }
+
+    #[test]
+    fn test_update_profile_enforces_length_limits() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        let empty = String::from_str(&s.env, "");
+        let long_name = "n".repeat(MAX_DISPLAY_NAME_LEN as usize + 1);
+        let long_bio = "b".repeat(MAX_BIO_LEN as usize + 1);
+        assert_eq!(
+            client.try_update_profile(&dev, &String::from_str(&s.env, &long_name), &empty),
+            Err(Ok(ContributorError::InvalidProfileField))
+        );
+        assert_eq!(
+            client.try_update_profile(&dev, &empty, &String::from_str(&s.env, &long_bio)),
+            Err(Ok(ContributorError::InvalidProfileField))
+        );
+        let max_bio = "b".repeat(MAX_BIO_LEN as usize);
+        client.update_profile(&dev, &empty, &String::from_str(&s.env, &max_bio));
+    }
+
+    // ── Skill tags ────────────────────────────────────────────
+
+    #[test]
+    fn test_skill_tags_are_searchable() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev_a = register(&s, "dev-a");
+        let dev_b = register(&s, "dev-b");
+        let dev_c = register(&s, "dev-c");
+        let rust = Symbol::new(&s.env, "rust");
+        let design = Symbol::new(&s.env, "design");
+
+        client.add_skill(&dev_a, &rust);
+        client.add_skill(&dev_b, &rust);
+        client.add_skill(&dev_c, &rust);
+        client.add_skill(&dev_a, &design);
+        assert_eq!(
+            client.try_add_skill(&dev_a, &rust),
+            Err(Ok(ContributorError::AlreadyExists))
+        );
+        assert_eq!(
+            client.get_skills(&dev_a),
+            Vec::from_array(&s.env, [rust.clone(), design.clone()])
+        );
+        assert_eq!(client.find_by_skill(&rust, &0, &10).len(), 3);
+        assert_eq!(client.find_by_skill(&rust, &1, &1).len(), 1);
+
+        client.remove_skill(&dev_a, &rust);
+        assert_eq!(
+            client.try_remove_skill(&dev_a, &rust),
+            Err(Ok(ContributorError::NotFound))
+        );
+        let rust_devs = client.find_by_skill(&rust, &0, &10);
+        assert_eq!(rust_devs.len(), 2);
+        assert!(rust_devs.contains(&dev_b) && rust_devs.contains(&dev_c));
+
+        client.remove_contributor(&dev_b, &dev_b);
+        assert_eq!(
+            client.find_by_skill(&rust, &0, &10),
+            Vec::from_array(&s.env, [dev_c])
+        );
+        assert_eq!(client.find_by_skill(&design, &0, &10).len(), 1);
+    }
+
+    #[test]
+    fn test_add_skill_requires_profile_and_respects_cap() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let outsider = Address::generate(&s.env);
+        assert_eq!(
+            client.try_add_skill(&outsider, &Symbol::new(&s.env, "rust")),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+
+        let dev = register(&s, "dev-a");
+        for i in 0..MAX_SKILLS {
+            let tag = std::format!("skill_{i}");
+            client.add_skill(&dev, &Symbol::new(&s.env, &tag));
+        }
+        assert_eq!(
+            client.try_add_skill(&dev, &Symbol::new(&s.env, "one_more")),
+            Err(Ok(ContributorError::LimitExceeded))
+        );
+    }
+
+    // ── Endorsements ──────────────────────────────────────────
+
+    #[test]
+    fn test_endorse_counts_each_endorser_once() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev_a = register(&s, "dev-a");
+        let dev_b = register(&s, "dev-b");
+        let dev_c = register(&s, "dev-c");
+        let rust = Symbol::new(&s.env, "rust");
+        let docs = Symbol::new(&s.env, "docs");
+
+        assert_eq!(client.endorse(&dev_b, &dev_a, &rust), 1);
+        let events = s.env.events().all();
+        let (_, topics, _) = events.last().unwrap();
+        assert_eq!(
+            topics,
+            (
+                Symbol::new(&s.env, "contributor"),
+                Symbol::new(&s.env, "endorsed"),
+                dev_a.clone()
+            )
+                .into_val(&s.env)
+        );
+
+        assert_eq!(client.endorse(&dev_c, &dev_a, &rust), 2);
+        assert_eq!(client.endorse(&dev_b, &dev_a, &docs), 1);
+        assert_eq!(
+            client.try_endorse(&dev_b, &dev_a, &rust),
+            Err(Ok(ContributorError::AlreadyExists))
+        );
+
+        assert_eq!(client.get_endorsement_count(&dev_a, &rust), 2);
+        assert!(client.has_endorsed(&dev_c, &dev_a, &rust));
+        assert!(!client.has_endorsed(&dev_c, &dev_a, &docs));
+        let all = client.get_endorsements(&dev_a);
+        assert_eq!(all.len(), 2);
+        assert_eq!(all.get(0).unwrap(), (rust, 2));
+        assert_eq!(all.get(1).unwrap(), (docs, 1));
+    }
+
+    #[test]
+    fn test_endorse_rejects_self_and_unregistered() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev_a = register(&s, "dev-a");
+        let outsider = Address::generate(&s.env);
+        let rust = Symbol::new(&s.env, "rust");
+
+        assert_eq!(
+            client.try_endorse(&dev_a, &dev_a, &rust),
+            Err(Ok(ContributorError::SelfEndorsement))
+        );
+        assert_eq!(
+            client.try_endorse(&outsider, &dev_a, &rust),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+        assert_eq!(
+            client.try_endorse(&dev_a, &outsider, &rust),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+        assert_eq!(client.get_endorsement_count(&dev_a, &rust), 0);
+    }
+
+    // ── Boost windows ─────────────────────────────────────────
+
+    #[test]
+    fn test_boost_window_scales_additions_only() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        client.set_boost(&s.alice, &1_000, &2_000, &15_000);
+        client.set_boost(&s.alice, &1_500, &1_600, &20_000);
+
+        s.env.ledger().set_timestamp(999);
+        assert_eq!(client.get_boost_multiplier(), 10_000);
+        client.add_reputation(&s.alice, &dev, &10i64, &0u32);
+
+        s.env.ledger().set_timestamp(1_000);
+        client.add_reputation(&s.alice, &dev, &10i64, &0u32);
+        assert_eq!(client.get_reputation(&dev), 25);
+        client.add_reputation(&s.alice, &dev, &-10i64, &0u32);
+        assert_eq!(client.get_reputation(&dev), 15);
+
+        s.env.ledger().set_timestamp(1_550);
+        assert_eq!(client.get_boost_multiplier(), 20_000);
+        client.record_contribution(
+            &s.alice,
+            &dev,
+            &String::from_str(&s.env, "org/repo"),
+            &1,
+            &5,
+        );
+        assert_eq!(client.get_reputation(&dev), 25);
+        assert_eq!(client.get_contribution(&0).points, 5);
+
+        s.env.ledger().set_timestamp(2_000);
+        client.add_reputation(&s.alice, &dev, &10i64, &0u32);
+        assert_eq!(client.get_reputation(&dev), 35);
+    }
+
+    #[test]
+    fn test_set_boost_validates_and_prunes() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        assert_eq!(
+            client.try_set_boost(&s.bob, &0, &10, &15_000),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_set_boost(&s.alice, &10, &10, &15_000),
+            Err(Ok(ContributorError::InvalidRange))
+        );
+        assert_eq!(
+            client.try_set_boost(&s.alice, &0, &10, &9_999),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
+
+        client.set_boost(&s.alice, &0, &10, &15_000);
+        s.env.ledger().set_timestamp(10);
+        client.set_boost(&s.alice, &20, &30, &15_000);
+        assert_eq!(client.get_boosts().len(), 1);
+        client.clear_boosts(&s.alice);
+        assert!(client.get_boosts().is_empty());
+    }
+
+    // ── Update cooldown ───────────────────────────────────────
+
+    #[test]
+    fn test_cooldown_limits_moderators_but_not_admins() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let moderator = Address::generate(&s.env);
+        client.add_moderator(&s.alice, &moderator);
+        let dev = register(&s, "dev-a");
+        let other = register(&s, "dev-b");
+        client.set_update_cooldown(&s.alice, &10);
+        assert_eq!(client.get_update_cooldown(), 10);
+
+        s.env.ledger().set_sequence_number(100);
+        client.add_reputation(&moderator, &dev, &5i64, &0u32);
+        assert_eq!(
+            client.try_add_reputation(&moderator, &dev, &5i64, &0u32),
+            Err(Ok(ContributorError::RateLimited))
+        );
+        assert_eq!(
+            client.try_record_contribution(
+                &moderator,
+                &dev,
+                &String::from_str(&s.env, "org/repo"),
+                &1,
+                &5
+            ),
+            Err(Ok(ContributorError::RateLimited))
+        );
+        client.add_reputation(&moderator, &other, &5i64, &0u32);
+        client.add_reputation(&s.alice, &dev, &5i64, &0u32);
+
+        s.env.ledger().set_sequence_number(109);
+        assert!(client
+            .try_add_reputation(&moderator, &dev, &5i64, &0u32)
+            .is_err());
+        s.env.ledger().set_sequence_number(110);
+        client.add_reputation(&moderator, &dev, &5i64, &0u32);
+        assert_eq!(client.get_reputation(&dev), 15);
+    }
+
+    #[test]
+    fn test_set_update_cooldown_requires_admin() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        assert_eq!(
+            client.try_set_update_cooldown(&s.bob, &10),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        assert_eq!(client.get_update_cooldown(), 0);
+    }
+
+    // ── Reputation hook ───────────────────────────────────────
+
+    #[contract]
+    struct HookRecorder;
+
+    #[contractimpl]
+    impl ReputationHookTrait for HookRecorder {
+        fn on_reputation_changed(env: Env, address: Address, old_score: u64, new_score: u64) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("last"), &(address, old_score, new_score));
+        }
+    }
+
+    #[contract]
+    struct FailingHook;
+
+    #[contractimpl]
+    impl ReputationHookTrait for FailingHook {
+        fn on_reputation_changed(_env: Env, _address: Address, _old: u64, _new: u64) {
+            panic!("hook failed");
+        }
+    }
+
+    #[test]
+    fn test_reputation_hook_receives_changes() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let hook = s.env.register(HookRecorder, ());
+        let dev = register(&s, "dev-a");
+        assert_eq!(
+            client.try_set_reputation_hook(&s.bob, &hook),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        client.set_reputation_hook(&s.alice, &hook);
+        assert_eq!(client.get_reputation_hook(), Some(hook.clone()));
+
+        client.add_reputation(&s.alice, &dev, &25i64, &0u32);
+        client.add_reputation(&s.alice, &dev, &-5i64, &0u32);
+        let last: Option<(Address, u64, u64)> = s.env.as_contract(&hook, || {
+            s.env.storage().instance().get(&symbol_short!("last"))
+        });
+        assert_eq!(last, Some((dev, 25, 20)));
+
+        client.clear_reputation_hook(&s.alice);
+        assert_eq!(client.get_reputation_hook(), None);
+    }
+
+    #[test]
+    fn test_failing_reputation_hook_does_not_block_updates() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let hook = s.env.register(FailingHook, ());
+        let dev = register(&s, "dev-a");
+        client.set_reputation_hook(&s.alice, &hook);
+        assert_eq!(client.add_reputation(&s.alice, &dev, &10i64, &0u32), 10);
+    }
+
+    // ── Preferences ───────────────────────────────────────────
+
+    #[test]
+    fn test_preferences_default_off_and_round_trip() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        assert_eq!(client.get_preferences(&dev), 0);
+
+        let prefs = PREF_PAYOUT_NOTIFICATIONS | PREF_PUBLIC_PROFILE;
+        client.set_preferences(&dev, &prefs);
+        assert_eq!(client.get_preferences(&dev), prefs);
+        assert_eq!(client.get_preferences(&dev) & PREF_LEADERBOARD, 0);
+
+        client.remove_contributor(&dev, &dev);
+        assert_eq!(client.get_preferences(&dev), 0);
+    }
+
+    #[test]
+    fn test_preferences_reject_unknown_bits_and_strangers() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        assert_eq!(
+            client.try_set_preferences(&dev, &(PREF_ALL + 1)),
+            Err(Ok(ContributorError::InvalidProfileField))
+        );
+        assert_eq!(
+            client.try_set_preferences(&s.carol, &PREF_LEADERBOARD),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+    }
+
+    // ── Reason codebook ───────────────────────────────────────
+
+    #[test]
+    fn test_reputation_updates_require_known_reason() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        assert_eq!(
+            client.try_add_reputation(&s.alice, &dev, &10i64, &42u32),
+            Err(Ok(ContributorError::UnknownReason))
+        );
+        assert_eq!(
+            client.try_update_reputation_batch(
+                &s.alice,
+                &Vec::from_array(&s.env, [(dev.clone(), 10u64)]),
+                &42u32
+            ),
+            Err(Ok(ContributorError::UnknownReason))
+        );
+
+        let label = String::from_str(&s.env, "security-fix");
+        client.set_reason(&s.alice, &42, &label);
+        assert_eq!(client.get_reasons().get(42), Some(label));
+        client.add_reputation(&s.alice, &dev, &10i64, &42u32);
+        client.add_reputation(&s.alice, &dev, &1i64, &REASON_CONTRIBUTION);
+        let history = client.get_reputation_history(&dev, &0, &10);
+        assert_eq!(history.get_unchecked(0).reason_code, 42);
+    }
+
+    #[test]
+    fn test_set_reason_validates_input() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let label = String::from_str(&s.env, "bounty");
+        assert_eq!(
+            client.try_set_reason(&s.bob, &10, &label),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_set_reason(&s.alice, &REASON_DECAY, &label),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
+        assert_eq!(
+            client.try_set_reason(&s.alice, &10, &String::from_str(&s.env, "")),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
+        for code in 0..MAX_REASONS {
+            client.set_reason(&s.alice, &(100 + code), &label);
+        }
+        assert_eq!(
+            client.try_set_reason(&s.alice, &10, &label),
+            Err(Ok(ContributorError::LimitExceeded))
+        );
+        client.set_reason(&s.alice, &100, &String::from_str(&s.env, "relabelled"));
+    }
+
+    // ── Reputation history ────────────────────────────────────
+
+    #[test]
+    fn test_reputation_history_records_each_change() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = register(&s, "dev-a");
+        client.set_reason(&s.alice, &7, &String::from_str(&s.env, "bounty"));
+        client.set_reason(&s.alice, &9, &String::from_str(&s.env, "revert"));
+
+        s.env.ledger().set_timestamp(1_000);
+        client.add_reputation(&s.alice, &contributor, &40i64, &7u32);
+        s.env.ledger().set_timestamp(2_000);
+        client.add_reputation(&s.alice, &contributor, &-15i64, &9u32);
+
+        let id = client.propose(&s.alice, &ProposalAction::UpdateReputation);
+        client.sign(&s.bob, &id);
+        client.update_reputation(&s.bob, &id, &contributor, &5i64);
+
+        assert_eq!(client.get_reputation_history_len(&contributor), 3);
+        let history = client.get_reputation_history(&contributor, &0, &10);
+        assert_eq!(
+            history.get(0).unwrap(),
+            ReputationEvent {
+                timestamp: 1_000,
+                delta: 40,
+                reason_code: 7,
+                by: s.alice.clone(),
+            }
+        );
+        assert_eq!(history.get(1).unwrap().delta, -15);
+        assert_eq!(history.get(1).unwrap().reason_code, 9);
+        assert_eq!(history.get(2).unwrap().by, s.bob);
+        assert_eq!(history.get(2).unwrap().reason_code, REASON_UNSPECIFIED);
+
+        let tail = client.get_reputation_history(&contributor, &2, &10);
+        assert_eq!(tail.len(), 1);
+    }
+
+    // ── Decay ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_decay_reduces_stale_scores_per_epoch() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let stale = register(&s, "stale-dev");
+        let active = register(&s, "active-dev");
+        client.add_reputation(&s.alice, &stale, &1_000, &REASON_UNSPECIFIED);
+        client.add_reputation(&s.alice, &active, &1_000, &REASON_UNSPECIFIED);
+
+        let day = 86_400;
+        client.set_decay_config(&s.alice, &day, &1_000);
+
+        s.env.ledger().with_mut(|l| l.timestamp += day);
+        client.add_reputation(&s.alice, &active, &1, &REASON_UNSPECIFIED);
+        s.env.ledger().with_mut(|l| l.timestamp += day + 10);
+
+        let batch = Vec::from_array(&s.env, [stale.clone(), active.clone()]);
+        assert_eq!(client.decay_reputation(&s.alice, &batch), 2);
+        // Two full epochs at 10% each: 1000 -> 900 -> 810.
+        assert_eq!(client.get_reputation(&stale), 810);
+        // One epoch since the last gain: 1001 -> 900.
+        assert_eq!(client.get_reputation(&active), 900);
+
+        // Epochs already applied are not charged twice.
+        assert_eq!(client.decay_reputation(&s.alice, &batch), 0);
+        assert_eq!(client.get_reputation(&stale), 810);
+
+        let history = client.get_reputation_history(&stale, &1, &1);
+        assert_eq!(history.get(0).unwrap().reason_code, REASON_DECAY);
+        assert_eq!(history.get(0).unwrap().delta, -190);
     }
 
-    pub fn get_next_proposal_id(env: Env) -> u64 {
-        env.storage()
-            .instance()
-            .get(&DataKey::NextProposalId)
-            .unwrap_or(0)
+    #[test]
+    fn test_decay_requires_config_and_role() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let dev = register(&s, "dev-a");
+        let batch = Vec::from_array(&s.env, [dev.clone(), Address::generate(&s.env)]);
+
+        assert_eq!(
+            client.try_decay_reputation(&s.alice, &batch),
+            Err(Ok(ContributorError::NotConfigured))
+        );
+        assert_eq!(
+            client.try_set_decay_config(&s.alice, &0, &100),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
+        assert_eq!(
+            client.try_set_decay_config(&s.alice, &60, &10_001),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
+        client.set_decay_config(&s.alice, &60, &100);
+        assert_eq!(client.get_decay_config().rate_bps, 100);
+
+        let outsider = Address::generate(&s.env);
+        assert_eq!(
+            client.try_decay_reputation(&outsider, &batch),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        // Unknown addresses in the batch are skipped rather than failing it.
+        s.env.ledger().with_mut(|l| l.timestamp += 600);
+        assert_eq!(client.decay_reputation(&s.alice, &batch), 0);
     }
-}
 
-// ── Notification receiver ─────────────────────────────────────
+    // ── TTL ───────────────────────────────────────────────────
 
-#[contractimpl]
-impl NotificationReceiverTrait for ContributorRegistryContract {
-    fn on_notify(env: Env, notification: Notification) {
-        if notification.event_type == Symbol::new(&env, "deposit") {
-            let (user, _project_id, _amount): (Address, u64, i128) =
-                <(Address, u64, i128)>::from_xdr(&env, &notification.data).unwrap();
+    #[test]
+    fn test_profile_ttl_bumped_on_write() {
+        use soroban_sdk::testutils::storage::Persistent as _;
+        let s = setup();
+        let contributor = register(&s, "dev-a");
 
-            if let Some(mut contributor) = env
+        let ttl = s.env.as_contract(&s.contract, || {
+            s.env
                 .storage()
                 .persistent()
-                .get::<_, ContributorData>(&DataKey::Contributor(user.clone()))
-            {
-                contributor.reputation_score = contributor.reputation_score.saturating_add(1);
-                env.storage()
-                    .persistent()
-                    .set(&DataKey::Contributor(user), &contributor);
-            }
-        }
+                .get_ttl(&DataKey::Contributor(contributor.clone()))
+        });
+        assert!(ttl >= storage::PERSISTENT_BUMP_THRESHOLD);
     }
-}
 
-// ── Tests ─────────────────────────────────────────────────────
+    #[test]
+    fn test_extend_contributor_ttl() {
+        use soroban_sdk::testutils::storage::Persistent as _;
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = register(&s, "dev-a");
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::{
-        testutils::{Address as TestAddress, Ledger}, // Ledger trait for set_timestamp
-        Address,
-        Env,
-        Vec,
-    };
+        let target = storage::PERSISTENT_BUMP_AMOUNT * 2;
+        client.extend_contributor_ttl(&contributor, &target);
+        let (profile_ttl, index_ttl) = s.env.as_contract(&s.contract, || {
+            let storage = s.env.storage().persistent();
+            (
+                storage.get_ttl(&DataKey::Contributor(contributor.clone())),
+                storage.get_ttl(&DataKey::GitHubIndex(soroban_sdk::String::from_str(
+                    &s.env, "dev-a",
+                ))),
+            )
+        });
+        assert_eq!(profile_ttl, target);
+        assert_eq!(index_ttl, target);
 
-    struct Setup {
-        env: Env,
-        contract: Address,
-        alice: Address,
-        bob: Address,
-        carol: Address,
+        assert_eq!(
+            client.try_extend_contributor_ttl(&contributor, &0),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
     }
 
-    fn setup() -> Setup {
-        let env = Env::default();
-        env.mock_all_auths();
-        // env.register() replaces the deprecated env.register_contract()
-        let contract = env.register(ContributorRegistryContract, ());
-        let alice = Address::generate(&env);
-        let bob = Address::generate(&env);
-        let carol = Address::generate(&env);
+    #[test]
+    fn test_extend_instance_ttl_and_storage_health() {
+        use soroban_sdk::testutils::storage::Instance as _;
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        s.env.ledger().set_sequence_number(1_000);
+        let dev = register(&s, "dev-a");
 
-        let mut signers = Vec::new(&env);
-        signers.push_back(Signer {
-            address: alice.clone(),
-            weight: 2,
-        });
-        signers.push_back(Signer {
-            address: bob.clone(),
-            weight: 1,
-        });
-        signers.push_back(Signer {
-            address: carol.clone(),
-            weight: 1,
-        });
+        let health = client.storage_health();
+        assert_eq!(health.ledger, 1_000);
+        assert_eq!(
+            health.instance_live_until,
+            1_000 + storage::INSTANCE_BUMP_AMOUNT
+        );
+        assert_eq!(health.sample, Some(dev.clone()));
+        assert_eq!(health.sample_live_until, None);
 
-        let client = ContributorRegistryContractClient::new(&env, &contract);
-        client.initialize(&signers, &3u32);
+        let target = storage::INSTANCE_BUMP_AMOUNT * 3;
+        client.extend_instance_ttl(&s.alice, &target);
+        let ttl = s
+            .env
+            .as_contract(&s.contract, || s.env.storage().instance().get_ttl());
+        assert_eq!(ttl, target);
+        client.extend_contributor_ttl(&dev, &target);
+        let health = client.storage_health();
+        assert_eq!(health.instance_live_until, 1_000 + target);
+        assert_eq!(health.sample_live_until, Some(1_000 + target));
 
-        Setup {
-            env,
-            contract,
-            alice,
-            bob,
-            carol,
-        }
+        assert_eq!(
+            client.try_extend_instance_ttl(&s.bob, &target),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_extend_instance_ttl(&s.alice, &0),
+            Err(Ok(ContributorError::InvalidConfig))
+        );
     }
 
-    // ── Initialisation ────────────────────────────────────────
+    // ── Pause ─────────────────────────────────────────────────
 
     #[test]
-    fn test_initialize_stores_config() {
+    fn test_pause_blocks_registration_and_reputation() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
-        // Client methods return values directly — no .unwrap() needed.
-        let config = client.get_multisig_config();
-        assert_eq!(config.threshold, 3);
-        assert_eq!(config.signers.len(), 3);
+        let contributor = register(&s, "dev-a");
+
+        client.pause(&s.alice);
+        assert!(client.is_paused());
+
+        let newcomer = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-b");
+        assert_eq!(
+            client.try_register_contributor(&newcomer, &handle, &None),
+            Err(Ok(ContributorError::ContractPaused))
+        );
+        assert_eq!(
+            client.try_add_reputation(&s.alice, &contributor, &5i64, &0u32),
+            Err(Ok(ContributorError::ContractPaused))
+        );
+
+        let id = client.propose(&s.alice, &ProposalAction::UpdateReputation);
+        client.sign(&s.bob, &id);
+        assert_eq!(
+            client.try_update_reputation(&s.alice, &id, &contributor, &5i64),
+            Err(Ok(ContributorError::ContractPaused))
+        );
+
+        client.unpause(&s.alice);
+        client.update_reputation(&s.alice, &id, &contributor, &5i64);
+        assert_eq!(client.get_reputation(&contributor), 5);
     }
 
     #[test]
-    fn test_double_initialize_fails() {
+    fn test_pause_state_transitions() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
-        let mut signers = Vec::new(&s.env);
-        signers.push_back(Signer {
-            address: s.alice.clone(),
-            weight: 1,
-        });
-        // try_* variants return Result and are used to assert failure.
-        assert!(client.try_initialize(&signers, &1u32).is_err());
-    }
-
-    #[test]
-    fn test_threshold_above_total_weight_fails() {
-        let env = Env::default();
-        env.mock_all_auths();
-        let contract = env.register(ContributorRegistryContract, ());
-        let client = ContributorRegistryContractClient::new(&env, &contract);
-        let alice = Address::generate(&env);
 
-        let mut signers = Vec::new(&env);
-        signers.push_back(Signer {
-            address: alice,
-            weight: 1,
-        });
-        assert!(client.try_initialize(&signers, &99u32).is_err());
+        assert_eq!(
+            client.try_unpause(&s.alice),
+            Err(Ok(ContributorError::ContractNotPaused))
+        );
+        assert_eq!(
+            client.try_pause(&s.bob),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        client.pause(&s.alice);
+        assert_eq!(
+            client.try_pause(&s.alice),
+            Err(Ok(ContributorError::ContractPaused))
+        );
     }
 
-    // ── Propose ───────────────────────────────────────────────
+    // ── Handle updates ────────────────────────────────────────
 
     #[test]
-    fn test_propose_counts_proposer_weight() {
+    fn test_update_handle_moves_index() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = register(&s, "old-name");
+        let old_handle = soroban_sdk::String::from_str(&s.env, "old-name");
+        let new_handle = soroban_sdk::String::from_str(&s.env, "new-name");
 
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
-        let proposal = client.get_proposal(&id);
+        client.update_handle(&contributor, &new_handle);
 
-        assert_eq!(proposal.weight_collected, 2);
-        assert_eq!(proposal.status, ProposalStatus::Pending);
+        let (_, topics, _) = s.env.events().all().last().unwrap();
+        assert_eq!(
+            topics,
+            (
+                Symbol::new(&s.env, "contributor"),
+                Symbol::new(&s.env, "handle_updated"),
+                contributor.clone(),
+            )
+                .into_val(&s.env)
+        );
+
+        assert_eq!(
+            client.get_contributor(&contributor).github_handle,
+            new_handle
+        );
+        assert_eq!(
+            client.get_contributor_by_handle(&new_handle).address,
+            contributor
+        );
+        assert!(client.try_get_contributor_by_handle(&old_handle).is_err());
     }
 
     #[test]
-    fn test_propose_by_non_signer_fails() {
+    fn test_update_handle_rejects_taken_handle() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
-        let outsider = Address::generate(&s.env);
-        assert!(client
-            .try_propose(&outsider, &ProposalAction::Upgrade)
-            .is_err());
+        let first = register(&s, "dev-a");
+        register(&s, "dev-b");
+
+        let taken = soroban_sdk::String::from_str(&s.env, "dev-b");
+        assert_eq!(
+            client.try_update_handle(&first, &taken),
+            Err(Ok(ContributorError::GitHubHandleTaken))
+        );
     }
 
     #[test]
-    fn test_single_signer_above_threshold_auto_approves() {
-        let env = Env::default();
-        env.mock_all_auths();
-        let contract = env.register(ContributorRegistryContract, ());
-        let alice = Address::generate(&env);
-        let bob = Address::generate(&env);
-
-        let mut signers = Vec::new(&env);
-        signers.push_back(Signer {
-            address: alice.clone(),
-            weight: 3,
-        });
-        signers.push_back(Signer {
-            address: bob.clone(),
-            weight: 1,
-        });
+    fn test_release_handle_frees_squatted_name() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let squatter = register(&s, "dev-a");
+        let handle = String::from_str(&s.env, "dev-a");
+        assert_eq!(
+            client.try_release_handle(&s.bob, &handle),
+            Err(Ok(ContributorError::Unauthorized))
+        );
 
-        let client = ContributorRegistryContractClient::new(&env, &contract);
-        client.initialize(&signers, &3u32);
+        client.release_handle(&s.alice, &handle);
+        let profile = client.get_contributor(&squatter);
+        assert!(profile.github_handle.is_empty());
+        assert!(!profile.verified);
+        assert_eq!(
+            client.try_get_contributor_by_github(&handle),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+        assert_eq!(
+            client.try_release_handle(&s.alice, &handle),
+            Err(Ok(ContributorError::NotFound))
+        );
 
-        let id = client.propose(&alice, &ProposalAction::Upgrade);
-        let proposal = client.get_proposal(&id);
-        assert_eq!(proposal.status, ProposalStatus::Approved);
+        let owner = register(&s, "dev-a");
+        assert_eq!(client.get_contributor_by_github(&handle).address, owner);
+        client.update_handle(&squatter, &String::from_str(&s.env, "dev-b"));
+        assert_eq!(client.get_contributor_by_github(&handle).address, owner);
     }
 
-    // ── Sign ──────────────────────────────────────────────────
+    // ── Removal ───────────────────────────────────────────────
 
     #[test]
-    fn test_sign_reaches_threshold() {
+    fn test_contributor_can_remove_self() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let first = register(&s, "dev-a");
+        let second = register(&s, "dev-b");
+        let third = register(&s, "dev-c");
 
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
-        let status = client.sign(&s.bob, &id);
-        assert_eq!(status, ProposalStatus::Approved);
+        client.remove_contributor(&first, &first);
+
+        assert_eq!(
+            client.try_get_contributor(&first),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-a");
+        assert_eq!(
+            client.try_get_contributor_by_github(&handle),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
+        assert_eq!(client.contributor_count(), 2);
+        let page = client.list_contributors(&0, &10);
+        assert!(page.contains(&second));
+        assert!(page.contains(&third));
+
+        // The freed handle and address can register again.
+        client.register_contributor(&first, &handle, &None);
+        assert_eq!(client.contributor_count(), 3);
     }
 
     #[test]
-    fn test_double_sign_rejected() {
+    fn test_admin_can_remove_contributor() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let contributor = register(&s, "dev-a");
+        let stranger = Address::generate(&s.env);
 
-        let id = client.propose(&s.bob, &ProposalAction::Upgrade);
-        assert!(client.try_sign(&s.bob, &id).is_err());
+        assert_eq!(
+            client.try_remove_contributor(&stranger, &contributor),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        client.remove_contributor(&s.alice, &contributor);
+        assert_eq!(client.contributor_count(), 0);
+        assert_eq!(
+            client.try_remove_contributor(&s.alice, &contributor),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
     }
 
+    // ── Admin set ─────────────────────────────────────────────
+
     #[test]
-    fn test_non_signer_cannot_sign() {
+    fn test_admin_set_add_and_remove() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
-        let outsider = Address::generate(&s.env);
+        let ops = Address::generate(&s.env);
+        client.add_admin(&s.alice, &ops);
+        assert_eq!(
+            client.get_admins(),
+            Vec::from_array(&s.env, [s.alice.clone(), ops.clone()])
+        );
+        assert!(client.is_admin(&ops));
+        assert_eq!(
+            client.try_add_admin(&ops, &s.alice),
+            Err(Ok(ContributorError::AlreadyExists))
+        );
 
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
-        assert!(client.try_sign(&outsider, &id).is_err());
+        // Either admin can act on admin-only config.
+        client.set_max_reputation(&ops, &500);
+
+        client.remove_admin(&ops, &s.alice);
+        assert!(!client.is_admin(&s.alice));
+        assert_eq!(client.get_admin(), ops);
+        assert_eq!(
+            client.try_set_max_reputation(&s.alice, &600),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_remove_admin(&ops, &ops),
+            Err(Ok(ContributorError::LastAdmin))
+        );
+        assert_eq!(
+            client.try_remove_admin(&ops, &s.alice),
+            Err(Ok(ContributorError::NotFound))
+        );
     }
 
     #[test]
-    fn test_bob_carol_together_cannot_reach_threshold() {
+    fn test_admin_set_is_bounded() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        for _ in 1..MAX_ADMINS {
+            client.add_admin(&s.alice, &Address::generate(&s.env));
+        }
+        assert_eq!(
+            client.try_add_admin(&s.alice, &Address::generate(&s.env)),
+            Err(Ok(ContributorError::LimitExceeded))
+        );
+    }
 
-        let id = client.propose(&s.bob, &ProposalAction::Upgrade);
-        client.sign(&s.carol, &id);
+    // ── Moderators ────────────────────────────────────────────
 
-        let proposal = client.get_proposal(&id);
-        assert_eq!(proposal.status, ProposalStatus::Pending);
+    #[test]
+    fn test_moderator_can_add_reputation() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let moderator = Address::generate(&s.env);
+
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-handle");
+        client.register_contributor(&contributor, &handle, &None);
+
+        assert_eq!(
+            client.try_add_reputation(&moderator, &contributor, &5i64, &0u32),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+
+        client.add_moderator(&s.alice, &moderator);
+        assert!(client.is_moderator(&moderator));
+        assert_eq!(
+            client.add_reputation(&moderator, &contributor, &5i64, &0u32),
+            5
+        );
+
+        client.remove_moderator(&s.alice, &moderator);
+        assert!(!client.is_moderator(&moderator));
+        assert_eq!(
+            client.try_add_reputation(&moderator, &contributor, &5i64, &0u32),
+            Err(Ok(ContributorError::Unauthorized))
+        );
     }
 
-    // ── Execute ───────────────────────────────────────────────
+    #[test]
+    fn test_only_admin_manages_moderators() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let moderator = Address::generate(&s.env);
+
+        client.add_moderator(&s.alice, &moderator);
+        let other = Address::generate(&s.env);
+        // Moderators cannot appoint further moderators.
+        assert_eq!(
+            client.try_add_moderator(&moderator, &other),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+    }
+
+    // ── Two-step admin transfer ───────────────────────────────
 
     #[test]
-    fn test_consume_approval_executes_after_threshold() {
-        // env.deployer().update_current_contract_wasm() is unavailable in the
-        // test environment, so we verify consume_approval via set_admin, which
-        // exercises the identical code path without hitting the deployer.
+    fn test_two_step_admin_transfer() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let new_admin = Address::generate(&s.env);
 
-        let id = client.propose(&s.alice, &ProposalAction::SetAdmin);
-        client.sign(&s.bob, &id); // threshold reached
+        client.propose_admin(&s.alice, &new_admin);
+        // Role does not move until the nominee accepts.
+        assert_eq!(client.get_admin(), s.alice);
+        assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+        client.accept_admin(&new_admin);
+        assert_eq!(client.get_admin(), new_admin);
+        assert_eq!(client.get_pending_admin(), None);
+    }
 
+    #[test]
+    fn test_accept_admin_requires_nomination() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
         let new_admin = Address::generate(&s.env);
-        client.set_admin(&s.alice, &id, &new_admin);
+        let impostor = Address::generate(&s.env);
 
-        // Proposal must be Executed — replay is now impossible.
-        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Executed);
+        assert_eq!(
+            client.try_accept_admin(&new_admin),
+            Err(Ok(ContributorError::NoPendingAdmin))
+        );
+
+        client.propose_admin(&s.alice, &new_admin);
+        assert_eq!(
+            client.try_accept_admin(&impostor),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_propose_admin(&s.bob, &impostor),
+            Err(Ok(ContributorError::Unauthorized))
+        );
     }
 
     #[test]
-    fn test_upgrade_approved_before_execution() {
-        // Verifies the proposal reaches Approved status when threshold is met,
-        // which is the pre-condition for upgrade execution.
+    fn test_removed_admin_nomination_is_dropped() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let ops = Address::generate(&s.env);
+        let nominee = Address::generate(&s.env);
+        client.add_admin(&s.alice, &ops);
 
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
-        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Pending);
+        client.propose_admin(&ops, &nominee);
+        client.remove_admin(&s.alice, &ops);
+        assert_eq!(client.get_pending_admin(), None);
+        assert_eq!(
+            client.try_accept_admin(&nominee),
+            Err(Ok(ContributorError::NoPendingAdmin))
+        );
+        assert!(!client.is_admin(&nominee));
+    }
 
-        let status = client.sign(&s.bob, &id);
-        assert_eq!(status, ProposalStatus::Approved);
-        assert_eq!(client.get_proposal(&id).status, ProposalStatus::Approved);
+    #[test]
+    fn test_accept_admin_requires_proposer_still_admin() {
+        let s = setup();
+        let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let ops = Address::generate(&s.env);
+        let nominee = Address::generate(&s.env);
+        client.add_admin(&s.alice, &ops);
+        client.propose_admin(&ops, &nominee);
+
+        // The multisig recovery path drops `ops` without touching the nomination
+        s.env.as_contract(&s.contract, || {
+            ContributorRegistryContract::save_admins(
+                &s.env,
+                &Vec::from_array(&s.env, [s.alice.clone()]),
+            );
+        });
+        assert_eq!(
+            client.try_accept_admin(&nominee),
+            Err(Ok(ContributorError::Unauthorized))
+        );
+        assert!(!client.is_admin(&nominee));
     }
 
     #[test]
-    fn test_execute_below_threshold_fails() {
+    fn test_legacy_single_admin_is_migrated() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        let legacy = Address::generate(&s.env);
+        s.env.as_contract(&s.contract, || {
+            let storage = s.env.storage().instance();
+            storage.remove(&DataKey::Admins);
+            storage.set(&DataKey::Admin, &legacy);
+        });
 
-        let id = client.propose(&s.bob, &ProposalAction::Upgrade);
-        let wasm_hash = BytesN::from_array(&s.env, &[1u8; 32]);
-        assert!(client.try_upgrade(&s.bob, &id, &wasm_hash).is_err());
+        assert_eq!(
+            client.get_admins(),
+            Vec::from_array(&s.env, [legacy.clone()])
+        );
+        let ops = Address::generate(&s.env);
+        client.add_admin(&legacy, &ops);
+        assert_eq!(
+            client.get_admins(),
+            Vec::from_array(&s.env, [legacy.clone(), ops])
+        );
+        let has_legacy = s.env.as_contract(&s.contract, || {
+            s.env.storage().instance().has(&DataKey::Admin)
+        });
+        assert!(!has_legacy);
     }
 
+    // ── Handle lookup ─────────────────────────────────────────
+
     #[test]
-    fn test_wrong_action_type_rejected() {
+    fn test_get_contributor_by_handle() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
-        client.sign(&s.bob, &id);
+        let contributor = Address::generate(&s.env);
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-handle");
+        client.register_contributor(&contributor, &handle, &None);
 
-        let new_admin = Address::generate(&s.env);
-        assert!(client.try_set_admin(&s.alice, &id, &new_admin).is_err());
+        let profile = client.get_contributor_by_handle(&handle);
+        assert_eq!(profile.address, contributor);
+
+        let unknown = soroban_sdk::String::from_str(&s.env, "nobody");
+        assert_eq!(
+            client.try_get_contributor_by_handle(&unknown),
+            Err(Ok(ContributorError::ContributorNotFound))
+        );
     }
 
+    // ── Enumeration ───────────────────────────────────────────
+
     #[test]
-    fn test_replay_blocked_after_execution() {
+    fn test_list_contributors_paginates() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
+        assert_eq!(client.contributor_count(), 0);
 
-        let id = client.propose(&s.alice, &ProposalAction::Upgrade);
-        client.sign(&s.bob, &id);
-        let wasm_hash = BytesN::from_array(&s.env, &[1u8; 32]);
-        let _ = client.try_upgrade(&s.alice, &id, &wasm_hash);
+        let mut registered = Vec::new(&s.env);
+        for name in ["dev-a", "dev-b", "dev-c"] {
+            let contributor = Address::generate(&s.env);
+            let handle = soroban_sdk::String::from_str(&s.env, name);
+            client.register_contributor(&contributor, &handle, &None);
+            registered.push_back(contributor);
+        }
+        assert_eq!(client.contributor_count(), 3);
 
-        assert!(client.try_upgrade(&s.alice, &id, &wasm_hash).is_err());
+        let first = client.list_contributors(&0, &2);
+        assert_eq!(first.len(), 2);
+        assert_eq!(first.get(0).unwrap(), registered.get(0).unwrap());
+        assert_eq!(first.get(1).unwrap(), registered.get(1).unwrap());
+
+        let second = client.list_contributors(&2, &2);
+        assert_eq!(second.len(), 1);
+        assert_eq!(second.get(0).unwrap(), registered.get(2).unwrap());
+
+        assert_eq!(client.list_contributors(&5, &2).len(), 0);
     }
 
-    // ── update_reputation ─────────────────────────────────────
+    // ── Events ────────────────────────────────────────────────
 
     #[test]
-    fn test_update_reputation_requires_multisig() {
+    fn test_register_contributor_emits_event() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
         let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "dev_handle");
-        client.register_contributor(&contributor, &handle);
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-handle");
+        client.register_contributor(&contributor, &handle, &None);
 
-        let fake_id = 999u64;
-        assert!(client
-            .try_update_reputation(&s.alice, &fake_id, &contributor, &10i64)
-            .is_err());
+        let (emitter, topics, _) = s.env.events().all().last().unwrap();
+        assert_eq!(emitter, s.contract);
+        assert_eq!(
+            topics,
+            (
+                Symbol::new(&s.env, "contributor"),
+                Symbol::new(&s.env, "registered"),
+                contributor,
+            )
+                .into_val(&s.env)
+        );
     }
 
     #[test]
-    fn test_update_reputation_succeeds_with_approval() {
+    fn test_update_reputation_emits_event() {
         let s = setup();
         let client = ContributorRegistryContractClient::new(&s.env, &s.contract);
 
         let contributor = Address::generate(&s.env);
-        let handle = soroban_sdk::String::from_str(&s.env, "dev_handle");
-        client.register_contributor(&contributor, &handle);
+        let handle = soroban_sdk::String::from_str(&s.env, "dev-handle");
+        client.register_contributor(&contributor, &handle, &None);
 
         let id = client.propose(&s.alice, &ProposalAction::UpdateReputation);
         client.sign(&s.bob, &id);
+        client.update_reputation(&s.alice, &id, &contributor, &25i64);
 
-        client.update_reputation(&s.alice, &id, &contributor, &50i64);
-
-        assert_eq!(client.get_reputation(&contributor), 50);
+        let (_, topics, _) = s.env.events().all().last().unwrap();
+        assert_eq!(
+            topics,
+            (
+                Symbol::new(&s.env, "reputation"),
+                Symbol::new(&s.env, "updated"),
+                contributor,
+            )
+                .into_val(&s.env)
+        );
     }
 
     // ── Cancel & expire ───────────────────────────────────────
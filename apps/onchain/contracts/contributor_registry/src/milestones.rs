@@ -0,0 +1,33 @@
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::events::MilestoneReachedEvent;
+use crate::storage::ConfigKey;
+
+// ── Internal helpers ─────────────────────────────────────────
+
+/// Configured milestone thresholds, ascending. Defaults to 100, 500, 1000.
+pub(crate) fn thresholds(env: &Env) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::Milestones)
+        .unwrap_or(vec![env, 100, 500, 1_000])
+}
+
+/// Emit one event per milestone crossed on the way up from `old_score` to
+/// `new_score`. Dropping back below a milestone and regaining it announces
+/// it again.
+pub(crate) fn announce(env: &Env, address: &Address, old_score: u64, new_score: u64) {
+    if new_score <= old_score {
+        return;
+    }
+    for threshold in thresholds(env).iter() {
+        if old_score < threshold && threshold <= new_score {
+            MilestoneReachedEvent {
+                address: address.clone(),
+                threshold,
+                score: new_score,
+            }
+            .publish(env);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::errors::ContributorError;
+use crate::storage::{DataKey, ProfileKey, MAX_PAGE_SIZE, MAX_SKILLS};
+
+// ── Internal helpers ─────────────────────────────────────────
+
+pub(crate) fn skills_of(env: &Env, address: &Address) -> Vec<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&ProfileKey::Skills(address.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn member_count(env: &Env, skill: &Symbol) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SkillCount(skill.clone()))
+        .unwrap_or(0)
+}
+
+/// Tag `address` with `skill` and append it to the skill's member list.
+pub(crate) fn add(env: &Env, address: &Address, skill: &Symbol) -> Result<(), ContributorError> {
+    let mut tags = skills_of(env, address);
+    if tags.contains(skill) {
+        return Err(ContributorError::AlreadyExists);
+    }
+    if tags.len() >= MAX_SKILLS {
+        return Err(ContributorError::LimitExceeded);
+    }
+    tags.push_back(skill.clone());
+
+    let storage = env.storage().persistent();
+    storage.set(&ProfileKey::Skills(address.clone()), &tags);
+
+    let count = member_count(env, skill);
+    storage.set(&DataKey::SkillMember(skill.clone(), count), address);
+    storage.set(
+        &DataKey::SkillMemberIndex(skill.clone(), address.clone()),
+        &count,
+    );
+    storage.set(&DataKey::SkillCount(skill.clone()), &(count + 1));
+    Ok(())
+}
+
+/// Drop `skill` from `address`, swap-removing it from the member list.
+pub(crate) fn remove(env: &Env, address: &Address, skill: &Symbol) -> Result<(), ContributorError> {
+    let mut tags = skills_of(env, address);
+    let Some(pos) = tags.first_index_of(skill) else {
+        return Err(ContributorError::NotFound);
+    };
+    tags.remove(pos);
+
+    let storage = env.storage().persistent();
+    if tags.is_empty() {
+        storage.remove(&ProfileKey::Skills(address.clone()));
+    } else {
+        storage.set(&ProfileKey::Skills(address.clone()), &tags);
+    }
+
+    let index_key = DataKey::SkillMemberIndex(skill.clone(), address.clone());
+    let index: u32 = storage.get(&index_key).unwrap_or(0);
+    let last = member_count(env, skill) - 1;
+    if index != last {
+        let moved: Address = storage
+            .get(&DataKey::SkillMember(skill.clone(), last))
+            .unwrap();
+        storage.set(&DataKey::SkillMember(skill.clone(), index), &moved);
+        storage.set(&DataKey::SkillMemberIndex(skill.clone(), moved), &index);
+    }
+    storage.remove(&DataKey::SkillMember(skill.clone(), last));
+    storage.remove(&index_key);
+    storage.set(&DataKey::SkillCount(skill.clone()), &last);
+    Ok(())
+}
+
+/// Remove every tag on `address`, used when a profile is deleted.
+pub(crate) fn clear(env: &Env, address: &Address) {
+    for skill in skills_of(env, address).iter() {
+        let _ = remove(env, address, &skill);
+    }
+}
+
+pub(crate) fn find(env: &Env, skill: &Symbol, start: u32, limit: u32) -> Vec<Address> {
+    let count = member_count(env, skill);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+    let mut page = Vec::new(env);
+    for i in start..end {
+        if let Some(address) = env
+            .storage()
+            .persistent()
+            .get::<_, Address>(&DataKey::SkillMember(skill.clone(), i))
+        {
+            page.push_back(address);
+        }
+    }
+    page
+}
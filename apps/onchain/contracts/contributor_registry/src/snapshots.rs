@@ -0,0 +1,222 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Vec};
+
+use crate::errors::ContributorError;
+use crate::events::SnapshotTakenEvent;
+use crate::storage::{read_contributor, DataKey, MAX_SNAPSHOT_PAGE};
+
+// ── Types ────────────────────────────────────────────────────
+
+/// A frozen view of every score at the moment the snapshot was taken.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Snapshot {
+    pub epoch: u32,
+    pub root: BytesN<32>,
+    pub contributor_count: u32,
+    pub timestamp: u64,
+}
+
+/// A snapshot still being built, one page of contributors at a time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotBuild {
+    /// Next enumeration index to read.
+    pub cursor: u32,
+    /// Enumeration length when the build started; later registrations wait
+    /// for the next epoch.
+    pub total: u32,
+    pub leaf_count: u32,
+    /// Root of the complete subtree at each level, where bit `i` of
+    /// `leaf_count` is set; other slots hold zeroes.
+    pub frontier: Vec<BytesN<32>>,
+    pub started_at: u64,
+}
+
+// ── Merkle tree ──────────────────────────────────────────────
+//
+// Leaves are `sha256(xdr((address, score)))`. Interior nodes hash the two
+// children in ascending byte order, so proofs need no left/right flags. An
+// odd node at the end of a level is carried up unchanged.
+
+pub(crate) fn leaf(env: &Env, address: &Address, score: u64) -> BytesN<32> {
+    env.crypto()
+        .sha256(&(address.clone(), score).to_xdr(env))
+        .into()
+}
+
+fn parent(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (lo, hi) = if a.to_array() <= b.to_array() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let mut buf = Bytes::from_array(env, &lo.to_array());
+    buf.extend_from_array(&hi.to_array());
+    env.crypto().sha256(&buf).into()
+}
+
+/// Reference root over a full leaf list, which the paged frontier must match.
+#[cfg(test)]
+pub(crate) fn root(env: &Env, mut level: Vec<BytesN<32>>) -> BytesN<32> {
+    if level.is_empty() {
+        return BytesN::from_array(env, &[0u8; 32]);
+    }
+    while level.len() > 1 {
+        let mut next = Vec::new(env);
+        let mut i = 0;
+        while i < level.len() {
+            let left = level.get_unchecked(i);
+            match level.get(i + 1) {
+                Some(right) => next.push_back(parent(env, &left, &right)),
+                None => next.push_back(left),
+            }
+            i += 2;
+        }
+        level = next;
+    }
+    level.get_unchecked(0)
+}
+
+// Carrying odd nodes up makes the tree left-full, so it can be built from a
+// frontier of complete subtrees, like binary addition. Folding the frontier
+// from the smallest subtree up gives the same root as `root`.
+
+fn zero(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[0u8; 32])
+}
+
+pub(crate) fn push_leaf(env: &Env, build: &mut SnapshotBuild, leaf: BytesN<32>) {
+    let mut node = leaf;
+    let mut level = 0;
+    while build.leaf_count & (1 << level) != 0 {
+        node = parent(env, &build.frontier.get_unchecked(level), &node);
+        build.frontier.set(level, zero(env));
+        level += 1;
+    }
+    if level < build.frontier.len() {
+        build.frontier.set(level, node);
+    } else {
+        build.frontier.push_back(node);
+    }
+    build.leaf_count += 1;
+}
+
+pub(crate) fn frontier_root(env: &Env, build: &SnapshotBuild) -> BytesN<32> {
+    let mut acc: Option<BytesN<32>> = None;
+    for level in 0..build.frontier.len() {
+        if build.leaf_count & (1 << level) == 0 {
+            continue;
+        }
+        let node = build.frontier.get_unchecked(level);
+        acc = Some(match acc {
+            Some(lower) => parent(env, &node, &lower),
+            None => node,
+        });
+    }
+    acc.unwrap_or_else(|| zero(env))
+}
+
+pub(crate) fn verify(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    let mut node = leaf;
+    for sibling in proof.iter() {
+        node = parent(env, &node, &sibling);
+    }
+    node == *root
+}
+
+// ── Storage ──────────────────────────────────────────────────
+
+fn next_epoch(env: &Env) -> u32 {
+    let epoch: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextSnapshotEpoch)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextSnapshotEpoch, &(epoch + 1));
+    epoch
+}
+
+pub(crate) fn latest_epoch(env: &Env) -> Option<u32> {
+    let next: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextSnapshotEpoch)
+        .unwrap_or(0);
+    next.checked_sub(1)
+}
+
+pub(crate) fn in_progress(env: &Env) -> Option<SnapshotBuild> {
+    env.storage().instance().get(&DataKey::SnapshotBuild)
+}
+
+/// Hash the next `limit` `(address, score)` pairs in enumeration order into
+/// the snapshot being built, starting one if none is. Once every contributor
+/// enumerated at the start is in, the root is stored under a fresh epoch ID
+/// and returned. Scores are read as each page is processed, directly from
+/// storage so a page costs two entry reads per contributor and no TTL writes;
+/// the registry refuses score changes, removals and merges meanwhile, so the
+/// pages still add up to one moment.
+pub(crate) fn advance(env: &Env, contributor_count: u32, limit: u32) -> Option<Snapshot> {
+    let mut build = in_progress(env).unwrap_or_else(|| SnapshotBuild {
+        cursor: 0,
+        total: contributor_count,
+        leaf_count: 0,
+        frontier: Vec::new(env),
+        started_at: env.ledger().timestamp(),
+    });
+    let storage = env.storage().persistent();
+    let end = build
+        .cursor
+        .saturating_add(limit.min(MAX_SNAPSHOT_PAGE))
+        .min(build.total);
+    for i in build.cursor..end {
+        let address: Option<Address> = storage.get(&DataKey::ContributorAt(i));
+        let contributor = address.and_then(|a| read_contributor(env, &a));
+        if let Some(contributor) = contributor {
+            let leaf = leaf(env, &contributor.address, contributor.reputation_score);
+            push_leaf(env, &mut build, leaf);
+        }
+    }
+    build.cursor = end;
+    if build.cursor < build.total {
+        env.storage()
+            .instance()
+            .set(&DataKey::SnapshotBuild, &build);
+        return None;
+    }
+    env.storage().instance().remove(&DataKey::SnapshotBuild);
+
+    let snapshot = Snapshot {
+        epoch: next_epoch(env),
+        root: frontier_root(env, &build),
+        contributor_count: build.leaf_count,
+        timestamp: build.started_at,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Snapshot(snapshot.epoch), &snapshot);
+
+    SnapshotTakenEvent {
+        epoch: snapshot.epoch,
+        root: snapshot.root.clone(),
+        contributor_count: snapshot.contributor_count,
+    }
+    .publish(env);
+
+    Some(snapshot)
+}
+
+pub(crate) fn get(env: &Env, epoch: u32) -> Result<Snapshot, ContributorError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Snapshot(epoch))
+        .ok_or(ContributorError::SnapshotNotFound)
+}
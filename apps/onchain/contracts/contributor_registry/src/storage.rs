@@ -1,19 +1,252 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol, TryFromVal, Val};
+
+/// Upper bound on the number of entries returned by a single page query.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Contributors folded into a snapshot per call. Each costs two persistent
+/// reads, which keeps a page well inside one transaction's read footprint.
+pub const MAX_SNAPSHOT_PAGE: u32 = 30;
+
+/// Upper bound on the number of entries accepted by a single batch call.
+pub const MAX_BATCH_SIZE: u32 = 100;
+
+/// Legacy profiles rewritten per `migrate_contributors` call. Each one costs
+/// five persistent writes (profile, two enumeration keys, two bucket keys).
+pub const MAX_MIGRATION_BATCH: u32 = 8;
+
+/// Upper bound on the number of configurable tier thresholds.
+pub const MAX_TIERS: u32 = 16;
+
+/// Upper bound on the number of scheduled boost windows.
+pub const MAX_BOOSTS: u32 = 16;
+
+/// Highest boost multiplier accepted: 10x, in basis points.
+pub const MAX_BOOST_BPS: u32 = 10 * BPS_DENOMINATOR;
+
+/// Upper bound on the number of configurable reputation milestones.
+pub const MAX_MILESTONES: u32 = 16;
+
+/// Number of ranked entries kept in the on-chain leaderboard.
+pub const LEADERBOARD_SIZE: u32 = 50;
+
+/// Upper bound on the number of skill tags a single profile can carry.
+pub const MAX_SKILLS: u32 = 20;
+
+/// Most contribution records and endorsements one `move_merged_records` call
+/// moves. Each costs up to four writes.
+pub const MAX_MERGE_BATCH: u32 = 8;
+
+/// Most received endorsements one call clears, two removals each. Deleting a
+/// profile clears this many inline; `purge_endorsements` pages the rest.
+pub const MAX_ENDORSEMENT_CLEAR: u32 = 8;
+
+/// Category weights are percentages: `WEIGHT_BASE` counts a point once.
+pub const WEIGHT_BASE: u32 = 100;
+
+/// Upper bound on the number of configurable reputation categories.
+pub const MAX_CATEGORIES: u32 = 16;
+
+/// Width of a registration bucket, in seconds.
+pub const REGISTRATION_BUCKET: u64 = 86_400;
+
+/// Widest span `list_registered_between` will walk, in buckets. Every bucket
+/// costs a count read before any entry in it, so together with
+/// `MAX_RANGE_PAGE` this keeps one query inside a transaction's read-entry
+/// limit.
+pub const MAX_RANGE_BUCKETS: u64 = 30;
+
+/// Most entries `list_registered_between` walks per call, matching or not.
+/// Each costs two reads: the bucket entry and the profile behind it.
+pub const MAX_RANGE_PAGE: u32 = 30;
+
+/// Streak windows default to one day.
+pub const DEFAULT_STREAK_WINDOW: u64 = 86_400;
+
+/// Upper bound on the size of the admin set.
+pub const MAX_ADMINS: u32 = 10;
+
+/// GitHub's own username limit, used until the admin configures another.
+pub const DEFAULT_MAX_HANDLE_LEN: u32 = 39;
+
+/// Hard ceiling on the configurable GitHub handle length.
+pub const HANDLE_LEN_LIMIT: u32 = 64;
+
+/// Upper bound on non-GitHub handles linked to a single profile.
+pub const MAX_LINKED_HANDLES: u32 = 8;
+
+/// Upper bound on delegators pointing at a single delegatee.
+pub const MAX_DELEGATORS: u32 = 100;
+
+/// Longest profile URI accepted, in bytes.
+pub const MAX_URI_LEN: u32 = 256;
+
+/// Longest display name accepted, in bytes.
+pub const MAX_DISPLAY_NAME_LEN: u32 = 64;
+
+/// Longest bio accepted, in bytes.
+pub const MAX_BIO_LEN: u32 = 280;
+
+/// Preference bit: the contributor agrees to payout notifications.
+pub const PREF_PAYOUT_NOTIFICATIONS: u32 = 1 << 0;
+
+/// Preference bit: the contributor may be shown on public leaderboards.
+pub const PREF_LEADERBOARD: u32 = 1 << 1;
+
+/// Preference bit: the contributor's profile may be shown publicly.
+pub const PREF_PUBLIC_PROFILE: u32 = 1 << 2;
+
+/// Every preference bit currently defined.
+pub const PREF_ALL: u32 = PREF_PAYOUT_NOTIFICATIONS | PREF_LEADERBOARD | PREF_PUBLIC_PROFILE;
+
+/// Reason code recorded when a change carries no explicit justification.
+pub const REASON_UNSPECIFIED: u32 = 0;
+
+/// Reason code recorded for the automatic +1 on crowdfund deposits.
+pub const REASON_DEPOSIT: u32 = 1;
+
+/// Reason code recorded when points come from a graded pull request.
+pub const REASON_CONTRIBUTION: u32 = 2;
+
+/// Reason code recorded when inactivity decay lowers a score.
+pub const REASON_DECAY: u32 = 3;
+
+/// Decay rates are expressed in basis points of the current score.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Upper bound on the epochs applied to one contributor in a single call.
+/// Anything beyond this is picked up by the next keeper run.
+pub const MAX_DECAY_EPOCHS: u64 = 64;
+
+/// Reason code recorded on the primary profile when a duplicate is merged.
+pub const REASON_MERGE: u32 = 4;
+
+/// Upper bound on the number of admin-defined reason codes.
+pub const MAX_REASONS: u32 = 64;
+
+/// Longest reason label accepted, in bytes.
+pub const MAX_REASON_LABEL_LEN: u32 = 64;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Profiles touched by any read or write are kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Every profile write keeps the registry instance alive for ~30 days.
+pub const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Re-extend the instance once less than ~29 days are known to remain.
+pub const INSTANCE_BUMP_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     // ── Existing keys (unchanged) ─────────────────────────────
-    Admin,
+    Admin, // pre-admin-set deployments; migrated to `Admins` on first write
     Contributor(Address),
     GitHubIndex(String),
 
+    // ── Admin rotation ────────────────────────────────────────
+    Admins,
+    PendingAdmin,
+    Moderator(Address),
+    Paused,
+    Banned(Address),
+
+    // ── Leaderboard ───────────────────────────────────────────
+    TopContributors,
+
+    // ── Reputation history ────────────────────────────────────
+    ReputationHistory(Address, u32),
+    ReputationHistoryLen(Address),
+
+    // ── Contribution records ──────────────────────────────────
+    Contribution(u64),
+    NextContributionId,
+    ContributionOf(Address, u32),
+    ContributionCount(Address),
+    PullRequestIndex(String, u32),
+
+    // ── Endorsements ──────────────────────────────────────────
+    Endorsement(Address, Address, Symbol),
+    EndorsementCount(Address, Symbol),
+    EndorsedSkills(Address),
+    Endorser(Address, Symbol, u32),
+
+    // ── Reputation snapshots ──────────────────────────────────
+    Snapshot(u32),
+    NextSnapshotEpoch,
+    SnapshotBuild,
+
+    // ── Linked platform handles ───────────────────────────────
+    PlatformIndex(Symbol, String),
+
+    // ── Skill tags ────────────────────────────────────────────
+    SkillCount(Symbol),
+    SkillMember(Symbol, u32),
+    SkillMemberIndex(Symbol, Address),
+
+    // ── Enumeration keys ──────────────────────────────────────
+    ContributorCount,
+    ContributorAt(u32),
+    ContributorIndex(Address),
+    RegisteredOn(u64, u32),
+    RegisteredOnCount(u64),
+
     // ── Multisig keys ─────────────────────────────────────────
     MultisigConfig,
     Proposal(u64),
     NextProposalId,
 }
 
+/// Admin settings, all held in instance storage. Variant names must stay
+/// distinct from [`DataKey`] and [`ProfileKey`]: only the name is encoded.
+#[contracttype]
+#[derive(Clone)]
+pub enum ConfigKey {
+    Attester,
+    RegistrationFee,
+    Treasury,
+    MaxReputation,
+    MaxHandleLength,
+    Tiers,
+    CategoryWeights,
+    DecayConfig,
+    StreakWindow,
+    Milestones,
+    InviteOnly,
+    ImportFinalized,
+    Reasons,
+    ReputationHook,
+    UpdateCooldown,
+    InstanceLiveUntil,
+    Boosts,
+}
+
+/// Per-contributor side records kept outside [`ContributorData`].
+#[contracttype]
+#[derive(Clone)]
+pub enum ProfileKey {
+    Referrer(Address),
+    ReferralCount(Address),
+    CategoryScores(Address),
+    LastActivity(Address),
+    Streak(Address),
+    Delegate(Address),
+    Delegators(Address),
+    LinkedHandles(Address),
+    Skills(Address),
+    MergedInto(Address),
+    RegistrationApproved(Address),
+    Nonce(Address),
+    Preferences(Address),
+    LastUpdateLedger(Address),
+    LiveUntil(Address),
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ContributorData {
@@ -21,4 +254,165 @@ pub struct ContributorData {
     pub github_handle: String,
     pub reputation_score: u64,
     pub registered_timestamp: u64,
+    /// Set once the configured attester has vouched for `github_handle`.
+    pub verified: bool,
+    /// IPFS/HTTPS pointer to the off-chain profile document (avatar, bio).
+    pub profile_uri: Option<String>,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    /// Cleared while the contributor is on hiatus; inactive profiles keep
+    /// their history but drop out of rankings and matching.
+    pub active: bool,
+    /// Set by a moderator while a dispute is open; every score change is
+    /// rejected until it is cleared.
+    pub frozen: bool,
+}
+
+/// The four-field profile written before verification, profile fields and
+/// the hiatus and freeze flags existed. Upgraded deployments still hold these
+/// until `migrate_contributors` rewrites them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyContributorData {
+    pub address: Address,
+    pub github_handle: String,
+    pub reputation_score: u64,
+    pub registered_timestamp: u64,
+}
+
+/// Read a profile in either layout. A legacy profile comes back with the
+/// defaults a fresh registration gets; it is stored in the current layout
+/// the next time it is saved.
+pub(crate) fn read_contributor(env: &Env, address: &Address) -> Option<ContributorData> {
+    let raw: Val = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Contributor(address.clone()))?;
+    // Decoding a struct from a map with the wrong field count traps rather
+    // than erroring, so tell the layouts apart by a field only one has.
+    let fields = Map::<Symbol, Val>::try_from_val(env, &raw).ok()?;
+    if fields.contains_key(symbol_short!("verified")) {
+        return ContributorData::try_from_val(env, &raw).ok();
+    }
+    let legacy = LegacyContributorData::try_from_val(env, &raw).ok()?;
+    Some(ContributorData {
+        address: legacy.address,
+        github_handle: legacy.github_handle,
+        reputation_score: legacy.reputation_score,
+        registered_timestamp: legacy.registered_timestamp,
+        verified: false,
+        profile_uri: None,
+        display_name: None,
+        bio: None,
+        active: true,
+        frozen: false,
+    })
+}
+
+/// Sybil-resistance deposit charged on every new registration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistrationFee {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Inactivity decay: every full `epoch_length` seconds without new
+/// reputation removes `rate_bps` basis points of the current score.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecayConfig {
+    pub epoch_length: u64,
+    pub rate_bps: u32,
+}
+
+/// A single entry in a contributor's append-only reputation log.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationEvent {
+    pub timestamp: u64,
+    pub delta: i64,
+    pub reason_code: u32,
+    pub by: Address,
+}
+
+/// Archival outlook for operations. Contracts cannot read TTLs directly, so
+/// each `*_live_until` is the ledger the entry is known to survive to, as
+/// recorded by the last forced extension.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageHealth {
+    pub ledger: u32,
+    pub max_ttl: u32,
+    pub instance_live_until: u32,
+    pub sample: Option<Address>,
+    pub sample_live_until: Option<u32>,
+}
+
+/// Where a `list_registered_between` page stopped: the next entry to read
+/// is `index` within registration bucket `bucket`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RangeCursor {
+    pub bucket: u64,
+    pub index: u32,
+}
+
+pub(crate) fn instance_live_until(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::InstanceLiveUntil)
+        .unwrap_or(0)
+}
+
+/// Force the instance TTL up to `ledgers` and record the new floor.
+pub(crate) fn extend_instance_ttl(env: &Env, ledgers: u32) {
+    let ledgers = ledgers.min(env.storage().max_ttl());
+    env.storage().instance().extend_ttl(ledgers, ledgers);
+    let floor = env.ledger().sequence().saturating_add(ledgers);
+    if floor > instance_live_until(env) {
+        env.storage()
+            .instance()
+            .set(&ConfigKey::InstanceLiveUntil, &floor);
+    }
+}
+
+/// Keep the instance alive, paying for an extension only once the recorded
+/// floor drops under `INSTANCE_BUMP_THRESHOLD` ledgers away.
+pub(crate) fn bump_instance_ttl(env: &Env) {
+    let horizon = env
+        .ledger()
+        .sequence()
+        .saturating_add(INSTANCE_BUMP_THRESHOLD);
+    if instance_live_until(env) < horizon {
+        extend_instance_ttl(env, INSTANCE_BUMP_AMOUNT);
+    }
+}
+
+/// Extend a profile and its handle index entry together so the reverse
+/// lookup never outlives (or dies before) the profile it points at.
+pub(crate) fn bump_contributor_ttl(
+    env: &Env,
+    contributor: &ContributorData,
+    threshold: u32,
+    extend_to: u32,
+) {
+    let storage = env.storage().persistent();
+    storage.extend_ttl(
+        &DataKey::Contributor(contributor.address.clone()),
+        threshold,
+        extend_to,
+    );
+    let index_key = DataKey::GitHubIndex(contributor.github_handle.clone());
+    if storage.has(&index_key) {
+        storage.extend_ttl(&index_key, threshold, extend_to);
+    }
+}
+
+pub(crate) fn append_reputation_history(env: &Env, address: &Address, entry: &ReputationEvent) {
+    let storage = env.storage().persistent();
+    let len_key = DataKey::ReputationHistoryLen(address.clone());
+    let len: u32 = storage.get(&len_key).unwrap_or(0);
+    storage.set(&DataKey::ReputationHistory(address.clone(), len), entry);
+    storage.set(&len_key, &(len + 1));
 }
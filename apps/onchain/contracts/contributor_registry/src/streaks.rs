@@ -0,0 +1,77 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::storage::{ConfigKey, ProfileKey, DEFAULT_STREAK_WINDOW};
+
+// ── Types ────────────────────────────────────────────────────
+
+/// Consecutive streak windows in which a contributor gained reputation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreakData {
+    pub last_contribution_timestamp: u64,
+    pub current: u32,
+    pub longest: u32,
+}
+
+// ── Internal helpers ─────────────────────────────────────────
+//
+// Time is cut into fixed windows of `window` seconds. Any gain inside the
+// window right after the previous one extends the streak, further gains in
+// the same window leave it unchanged, and skipping a window starts over.
+
+pub(crate) fn window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::StreakWindow)
+        .unwrap_or(DEFAULT_STREAK_WINDOW)
+}
+
+fn load(env: &Env, address: &Address) -> Option<StreakData> {
+    env.storage()
+        .persistent()
+        .get(&ProfileKey::Streak(address.clone()))
+}
+
+pub(crate) fn record(env: &Env, address: &Address) {
+    let now = env.ledger().timestamp();
+    let window = window(env);
+    let streak = match load(env, address) {
+        Some(mut streak) => {
+            let last = streak.last_contribution_timestamp / window;
+            let current = now / window;
+            if current == last + 1 {
+                streak.current += 1;
+            } else if current != last {
+                streak.current = 1;
+            }
+            streak.longest = streak.longest.max(streak.current);
+            streak.last_contribution_timestamp = now;
+            streak
+        }
+        None => StreakData {
+            last_contribution_timestamp: now,
+            current: 1,
+            longest: 1,
+        },
+    };
+    env.storage()
+        .persistent()
+        .set(&ProfileKey::Streak(address.clone()), &streak);
+}
+
+/// Streak as of now: a streak whose next window has already closed reads as
+/// zero even before the next gain resets it.
+pub(crate) fn get(env: &Env, address: &Address) -> StreakData {
+    let Some(mut streak) = load(env, address) else {
+        return StreakData {
+            last_contribution_timestamp: 0,
+            current: 0,
+            longest: 0,
+        };
+    };
+    let window = window(env);
+    if env.ledger().timestamp() / window > streak.last_contribution_timestamp / window + 1 {
+        streak.current = 0;
+    }
+    streak
+}
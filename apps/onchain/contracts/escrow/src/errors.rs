@@ -0,0 +1,17 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum EscrowError {
+    EscrowNotFound = 1,
+    Unauthorized = 2,
+    InvalidParties = 3,
+    InvalidAmount = 4,
+    InvalidDeadline = 5,
+    InvalidMetadata = 6,
+    InvalidStatus = 7,
+    DeadlineNotReached = 8,
+    ResolutionNotFound = 9,
+    ArbitrationPending = 10,
+}
@@ -0,0 +1,54 @@
+use soroban_sdk::{contractevent, Address};
+
+/// Emitted when a payer locks funds for a payee.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowCreatedEvent {
+    #[topic]
+    pub payer: Address,
+    #[topic]
+    pub payee: Address,
+    pub escrow_id: u64,
+    pub arbiter: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub deadline: u64,
+}
+
+/// Emitted when the payer releases the funds to the payee.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleasedEvent {
+    #[topic]
+    pub escrow_id: u64,
+    pub amount: i128,
+}
+
+/// Emitted when the funds go back to the payer.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundedEvent {
+    #[topic]
+    pub escrow_id: u64,
+    pub refunded_by: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeRaisedEvent {
+    #[topic]
+    pub escrow_id: u64,
+    pub raised_by: Address,
+}
+
+/// Emitted when the arbiter splits a disputed escrow.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeResolvedEvent {
+    #[topic]
+    pub escrow_id: u64,
+    pub arbiter: Address,
+    pub payee_amount: i128,
+    pub payer_amount: i128,
+}
@@ -0,0 +1,289 @@
+#![no_std]
+
+mod errors;
+mod events;
+mod storage;
+
+use errors::EscrowError;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use storage::{
+    DataKey, Escrow, EscrowStatus, Resolution, ARBITRATION_TIMEOUT, MAX_TEXT_LEN,
+    PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_THRESHOLD,
+};
+
+#[contract]
+pub struct EscrowContract;
+
+#[contractimpl]
+impl EscrowContract {
+    fn load_escrow(env: &Env, escrow_id: u64) -> Result<Escrow, EscrowError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Escrow(escrow_id))
+            .ok_or(EscrowError::EscrowNotFound)
+    }
+
+    /// Write an escrow back and keep it clear of archival
+    fn save_escrow(env: &Env, escrow: &Escrow) {
+        let key = DataKey::Escrow(escrow.id);
+        env.storage().persistent().set(&key, escrow);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    fn validate_text(text: &String) -> Result<(), EscrowError> {
+        if text.is_empty() || text.len() > MAX_TEXT_LEN {
+            return Err(EscrowError::InvalidMetadata);
+        }
+        Ok(())
+    }
+
+    fn pay(env: &Env, escrow: &Escrow, to: &Address, amount: i128) {
+        TokenClient::new(env, &escrow.token).transfer(&env.current_contract_address(), to, &amount);
+    }
+
+    /// Lock `amount` of `token` from `payer` for work `payee` does under
+    /// `terms`. `arbiter` settles any dispute and must be neither party.
+    /// Returns the escrow ID.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_escrow(
+        env: Env,
+        payer: Address,
+        payee: Address,
+        arbiter: Address,
+        token: Address,
+        amount: i128,
+        deadline: u64,
+        terms: String,
+    ) -> Result<u64, EscrowError> {
+        payer.require_auth();
+        if payee == payer || arbiter == payer || arbiter == payee {
+            return Err(EscrowError::InvalidParties);
+        }
+        if amount <= 0 {
+            return Err(EscrowError::InvalidAmount);
+        }
+        if deadline <= env.ledger().timestamp() {
+            return Err(EscrowError::InvalidDeadline);
+        }
+        Self::validate_text(&terms)?;
+
+        TokenClient::new(&env, &token).transfer(&payer, env.current_contract_address(), &amount);
+
+        let escrow_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextEscrowId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextEscrowId, &(escrow_id + 1));
+        let escrow = Escrow {
+            id: escrow_id,
+            payer: payer.clone(),
+            payee: payee.clone(),
+            arbiter: arbiter.clone(),
+            token: token.clone(),
+            amount,
+            deadline,
+            terms,
+            status: EscrowStatus::Funded,
+            disputed_by: None,
+            dispute_reason: None,
+            disputed_at: None,
+            created_at: env.ledger().timestamp(),
+        };
+        Self::save_escrow(&env, &escrow);
+
+        events::EscrowCreatedEvent {
+            payer,
+            payee,
+            escrow_id,
+            arbiter,
+            token,
+            amount,
+            deadline,
+        }
+        .publish(&env);
+        Ok(escrow_id)
+    }
+
+    /// Pay the payee in full. Only the payer may release.
+    pub fn release(env: Env, payer: Address, escrow_id: u64) -> Result<(), EscrowError> {
+        let mut escrow = Self::load_escrow(&env, escrow_id)?;
+        if payer != escrow.payer {
+            return Err(EscrowError::Unauthorized);
+        }
+        payer.require_auth();
+        if escrow.status != EscrowStatus::Funded {
+            return Err(EscrowError::InvalidStatus);
+        }
+
+        escrow.status = EscrowStatus::Released;
+        Self::save_escrow(&env, &escrow);
+        Self::pay(&env, &escrow, &escrow.payee, escrow.amount);
+
+        events::ReleasedEvent {
+            escrow_id,
+            amount: escrow.amount,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Return the funds to the payer. The payee may refund at any time;
+    /// the payer only once the deadline has passed. A dispute the arbiter
+    /// has not settled within `ARBITRATION_TIMEOUT` may be refunded by
+    /// either party.
+    pub fn refund(env: Env, caller: Address, escrow_id: u64) -> Result<(), EscrowError> {
+        let mut escrow = Self::load_escrow(&env, escrow_id)?;
+        if caller != escrow.payer && caller != escrow.payee {
+            return Err(EscrowError::Unauthorized);
+        }
+        caller.require_auth();
+        let now = env.ledger().timestamp();
+        match escrow.status {
+            EscrowStatus::Funded => {
+                if caller == escrow.payer && now <= escrow.deadline {
+                    return Err(EscrowError::DeadlineNotReached);
+                }
+            }
+            EscrowStatus::Disputed => {
+                let disputed_at = escrow.disputed_at.unwrap_or(escrow.created_at);
+                if now <= disputed_at.saturating_add(ARBITRATION_TIMEOUT) {
+                    return Err(EscrowError::ArbitrationPending);
+                }
+            }
+            _ => return Err(EscrowError::InvalidStatus),
+        }
+
+        escrow.status = EscrowStatus::Refunded;
+        Self::save_escrow(&env, &escrow);
+        Self::pay(&env, &escrow, &escrow.payer, escrow.amount);
+
+        events::RefundedEvent {
+            escrow_id,
+            refunded_by: caller,
+            amount: escrow.amount,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Freeze a funded escrow for the arbiter to settle. Either party may
+    /// raise a dispute, with `reason` linking to their case; it also stops
+    /// the payer refunding after the deadline until the arbitration timeout.
+    pub fn dispute(
+        env: Env,
+        caller: Address,
+        escrow_id: u64,
+        reason: String,
+    ) -> Result<(), EscrowError> {
+        let mut escrow = Self::load_escrow(&env, escrow_id)?;
+        if caller != escrow.payer && caller != escrow.payee {
+            return Err(EscrowError::Unauthorized);
+        }
+        caller.require_auth();
+        if escrow.status != EscrowStatus::Funded {
+            return Err(EscrowError::InvalidStatus);
+        }
+        Self::validate_text(&reason)?;
+
+        escrow.status = EscrowStatus::Disputed;
+        escrow.disputed_by = Some(caller.clone());
+        escrow.dispute_reason = Some(reason);
+        escrow.disputed_at = Some(env.ledger().timestamp());
+        Self::save_escrow(&env, &escrow);
+
+        events::DisputeRaisedEvent {
+            escrow_id,
+            raised_by: caller,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Settle a dispute: `payee_amount` goes to the payee and the rest back
+    /// to the payer. The split and `note` are kept as the escrow's
+    /// resolution record. Only the arbiter may resolve.
+    pub fn resolve(
+        env: Env,
+        arbiter: Address,
+        escrow_id: u64,
+        payee_amount: i128,
+        note: String,
+    ) -> Result<Resolution, EscrowError> {
+        let mut escrow = Self::load_escrow(&env, escrow_id)?;
+        if arbiter != escrow.arbiter {
+            return Err(EscrowError::Unauthorized);
+        }
+        arbiter.require_auth();
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(EscrowError::InvalidStatus);
+        }
+        if payee_amount < 0 || payee_amount > escrow.amount {
+            return Err(EscrowError::InvalidAmount);
+        }
+        Self::validate_text(&note)?;
+
+        let payer_amount = escrow.amount - payee_amount;
+        let resolution = Resolution {
+            payee_amount,
+            payer_amount,
+            note,
+            resolved_at: env.ledger().timestamp(),
+        };
+        escrow.status = EscrowStatus::Resolved;
+        Self::save_escrow(&env, &escrow);
+        let resolution_key = DataKey::Resolution(escrow_id);
+        env.storage().persistent().set(&resolution_key, &resolution);
+        env.storage().persistent().extend_ttl(
+            &resolution_key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+        if payee_amount > 0 {
+            Self::pay(&env, &escrow, &escrow.payee, payee_amount);
+        }
+        if payer_amount > 0 {
+            Self::pay(&env, &escrow, &escrow.payer, payer_amount);
+        }
+
+        events::DisputeResolvedEvent {
+            escrow_id,
+            arbiter,
+            payee_amount,
+            payer_amount,
+        }
+        .publish(&env);
+        Ok(resolution)
+    }
+
+    /// Get an escrow by ID
+    pub fn get_escrow(env: Env, escrow_id: u64) -> Result<Escrow, EscrowError> {
+        Self::load_escrow(&env, escrow_id)
+    }
+
+    /// How the arbiter settled a resolved escrow
+    pub fn get_resolution(env: Env, escrow_id: u64) -> Result<Resolution, EscrowError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Resolution(escrow_id))
+            .ok_or(EscrowError::ResolutionNotFound)
+    }
+
+    /// Number of escrows ever created; IDs run from 0 to this count
+    pub fn get_escrow_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextEscrowId)
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test;
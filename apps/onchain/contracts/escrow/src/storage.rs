@@ -0,0 +1,75 @@
+use soroban_sdk::{contracttype, Address, String};
+
+/// Longest terms, dispute or resolution link accepted, in bytes.
+pub const MAX_TEXT_LEN: u32 = 256;
+
+/// How long the arbiter has to settle a dispute before either party may
+/// refund the payer instead: 30 days.
+pub const ARBITRATION_TIMEOUT: u64 = 30 * 86_400;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Escrows touched by any write are kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Escrow(u64),     // escrow id -> Escrow
+    NextEscrowId,    // -> u64
+    Resolution(u64), // escrow id -> Resolution, once the arbiter rules
+}
+
+/// Where an escrow is in its lifecycle.
+///
+/// `Funded` escrows are `Released` to the payee by the payer, or
+/// `Refunded` to the payer by the payee at any time or by the payer after
+/// the deadline. Either party can move a funded escrow to `Disputed`,
+/// which the arbiter settles, leaving it `Resolved`. A dispute the arbiter
+/// leaves for `ARBITRATION_TIMEOUT` can be `Refunded` by either party.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EscrowStatus {
+    Funded,
+    Disputed,
+    Released,
+    Refunded,
+    Resolved,
+}
+
+/// How the arbiter settled a dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Resolution {
+    pub payee_amount: i128,
+    pub payer_amount: i128,
+    /// Link to the arbiter's reasoning.
+    pub note: String,
+    pub resolved_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Escrow {
+    pub id: u64,
+    pub payer: Address,
+    pub payee: Address,
+    pub arbiter: Address,
+    pub token: Address,
+    /// Locked in the contract until release, refund or resolution.
+    pub amount: i128,
+    /// After this timestamp the payer may take back an undisputed escrow.
+    pub deadline: u64,
+    /// Link to the agreed scope of work.
+    pub terms: String,
+    pub status: EscrowStatus,
+    pub disputed_by: Option<Address>,
+    /// Link to the disputing party's case.
+    pub dispute_reason: Option<String>,
+    pub disputed_at: Option<u64>,
+    pub created_at: u64,
+}
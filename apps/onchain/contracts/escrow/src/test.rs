@@ -0,0 +1,257 @@
+use crate::errors::EscrowError;
+use crate::storage::{EscrowStatus, ARBITRATION_TIMEOUT};
+use crate::{EscrowContract, EscrowContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String,
+};
+
+const AMOUNT: i128 = 1_000;
+
+struct Setup<'a> {
+    env: Env,
+    client: EscrowContractClient<'a>,
+    payer: Address,
+    payee: Address,
+    arbiter: Address,
+    token: TokenClient<'a>,
+    deadline: u64,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payer = Address::generate(&env);
+    let payee = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(admin);
+    StellarAssetClient::new(&env, &asset.address()).mint(&payer, &10_000);
+    let token = TokenClient::new(&env, &asset.address());
+
+    let contract_id = env.register(EscrowContract, ());
+    let client = EscrowContractClient::new(&env, &contract_id);
+
+    Setup {
+        deadline: env.ledger().timestamp() + 7 * 86_400,
+        env,
+        client,
+        payer,
+        payee,
+        arbiter,
+        token,
+    }
+}
+
+fn text(s: &Setup, value: &str) -> String {
+    String::from_str(&s.env, value)
+}
+
+fn create(s: &Setup) -> u64 {
+    s.client.create_escrow(
+        &s.payer,
+        &s.payee,
+        &s.arbiter,
+        &s.token.address,
+        &AMOUNT,
+        &s.deadline,
+        &text(s, "ipfs://terms"),
+    )
+}
+
+#[test]
+fn test_create_locks_funds() {
+    let s = setup();
+    let escrow_id = create(&s);
+
+    assert_eq!(escrow_id, 0);
+    assert_eq!(s.client.get_escrow_count(), 1);
+    let escrow = s.client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Funded);
+    assert_eq!(escrow.amount, AMOUNT);
+    assert_eq!(s.token.balance(&s.payer), 9_000);
+    assert_eq!(s.token.balance(&s.client.address), AMOUNT);
+}
+
+#[test]
+fn test_create_validates_input() {
+    let s = setup();
+    let terms = text(&s, "ipfs://terms");
+    let now = s.env.ledger().timestamp();
+
+    assert_eq!(
+        s.client.try_create_escrow(
+            &s.payer,
+            &s.payee,
+            &s.payee,
+            &s.token.address,
+            &AMOUNT,
+            &s.deadline,
+            &terms
+        ),
+        Err(Ok(EscrowError::InvalidParties))
+    );
+    assert_eq!(
+        s.client.try_create_escrow(
+            &s.payer,
+            &s.payee,
+            &s.arbiter,
+            &s.token.address,
+            &0,
+            &s.deadline,
+            &terms
+        ),
+        Err(Ok(EscrowError::InvalidAmount))
+    );
+    assert_eq!(
+        s.client.try_create_escrow(
+            &s.payer,
+            &s.payee,
+            &s.arbiter,
+            &s.token.address,
+            &AMOUNT,
+            &now,
+            &terms
+        ),
+        Err(Ok(EscrowError::InvalidDeadline))
+    );
+}
+
+#[test]
+fn test_release_pays_payee() {
+    let s = setup();
+    let escrow_id = create(&s);
+
+    assert_eq!(
+        s.client.try_release(&s.payee, &escrow_id),
+        Err(Ok(EscrowError::Unauthorized))
+    );
+    s.client.release(&s.payer, &escrow_id);
+
+    assert_eq!(s.token.balance(&s.payee), AMOUNT);
+    assert_eq!(
+        s.client.get_escrow(&escrow_id).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(
+        s.client.try_refund(&s.payee, &escrow_id),
+        Err(Ok(EscrowError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_refund_rules() {
+    let s = setup();
+    let first = create(&s);
+    let second = create(&s);
+
+    // The payer waits for the deadline; the payee can hand back any time
+    assert_eq!(
+        s.client.try_refund(&s.payer, &first),
+        Err(Ok(EscrowError::DeadlineNotReached))
+    );
+    s.client.refund(&s.payee, &first);
+    assert_eq!(s.token.balance(&s.payer), 9_000);
+
+    s.env.ledger().set_timestamp(s.deadline + 1);
+    s.client.refund(&s.payer, &second);
+    assert_eq!(s.token.balance(&s.payer), 10_000);
+    assert_eq!(s.client.get_escrow(&second).status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_dispute_resolved_by_arbiter() {
+    let s = setup();
+    let escrow_id = create(&s);
+    s.client
+        .dispute(&s.payee, &escrow_id, &text(&s, "ipfs://case"));
+
+    // A dispute blocks both release and the deadline refund
+    assert_eq!(
+        s.client.try_release(&s.payer, &escrow_id),
+        Err(Ok(EscrowError::InvalidStatus))
+    );
+    s.env.ledger().set_timestamp(s.deadline + 1);
+    assert_eq!(
+        s.client.try_refund(&s.payer, &escrow_id),
+        Err(Ok(EscrowError::ArbitrationPending))
+    );
+
+    let note = text(&s, "ipfs://ruling");
+    assert_eq!(
+        s.client.try_resolve(&s.payer, &escrow_id, &AMOUNT, &note),
+        Err(Ok(EscrowError::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_resolve(&s.arbiter, &escrow_id, &(AMOUNT + 1), &note),
+        Err(Ok(EscrowError::InvalidAmount))
+    );
+    let resolution = s.client.resolve(&s.arbiter, &escrow_id, &600, &note);
+
+    assert_eq!(resolution.payee_amount, 600);
+    assert_eq!(resolution.payer_amount, 400);
+    assert_eq!(s.token.balance(&s.payee), 600);
+    assert_eq!(s.token.balance(&s.payer), 9_400);
+    let escrow = s.client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Resolved);
+    assert_eq!(escrow.disputed_by, Some(s.payee.clone()));
+    assert_eq!(s.client.get_resolution(&escrow_id), resolution);
+}
+
+#[test]
+fn test_resolve_requires_dispute() {
+    let s = setup();
+    let escrow_id = create(&s);
+
+    assert_eq!(
+        s.client
+            .try_resolve(&s.arbiter, &escrow_id, &0, &text(&s, "ipfs://ruling")),
+        Err(Ok(EscrowError::InvalidStatus))
+    );
+    assert_eq!(
+        s.client.try_get_resolution(&escrow_id),
+        Err(Ok(EscrowError::ResolutionNotFound))
+    );
+    assert_eq!(
+        s.client
+            .try_dispute(&s.arbiter, &escrow_id, &text(&s, "ipfs://case")),
+        Err(Ok(EscrowError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_unresolved_dispute_refunds_after_timeout() {
+    let s = setup();
+    let escrow_id = create(&s);
+    s.client
+        .dispute(&s.payer, &escrow_id, &text(&s, "ipfs://case"));
+    let disputed_at = s.client.get_escrow(&escrow_id).disputed_at.unwrap();
+
+    s.env
+        .ledger()
+        .set_timestamp(disputed_at + ARBITRATION_TIMEOUT);
+    assert_eq!(
+        s.client.try_refund(&s.payee, &escrow_id),
+        Err(Ok(EscrowError::ArbitrationPending))
+    );
+
+    // The arbiter went quiet; either party can now unlock the funds
+    s.env
+        .ledger()
+        .set_timestamp(disputed_at + ARBITRATION_TIMEOUT + 1);
+    s.client.refund(&s.payee, &escrow_id);
+    assert_eq!(s.token.balance(&s.payer), 10_000);
+    assert_eq!(
+        s.client.get_escrow(&escrow_id).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(
+        s.client
+            .try_resolve(&s.arbiter, &escrow_id, &0, &text(&s, "ipfs://ruling")),
+        Err(Ok(EscrowError::InvalidStatus))
+    );
+}
@@ -0,0 +1,23 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum GovernanceError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    Unauthorized = 3,
+    ProposalNotFound = 4,
+    InvalidConfig = 5,
+    InvalidVotingWindow = 6,
+    InvalidTitle = 7,
+    SnapshotNotFound = 8,
+    VotingClosed = 9,
+    AlreadyVoted = 10,
+    InvalidProof = 11,
+    NoVotingPower = 12,
+    InvalidStatus = 13,
+    VotingNotEnded = 14,
+    ProposalNotPassed = 15,
+    BelowProposalThreshold = 16,
+}
@@ -0,0 +1,62 @@
+use soroban_sdk::{contractevent, Address, String};
+
+use crate::storage::VoteChoice;
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+    pub registry: Address,
+}
+
+/// Emitted when the admin changes quorum, threshold or voting period.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigUpdatedEvent {
+    pub quorum: u64,
+    pub pass_threshold_bps: u32,
+    pub min_voting_period: u64,
+    pub proposal_threshold: u64,
+}
+
+/// Emitted when a proposal is created.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCreatedEvent {
+    #[topic]
+    pub proposer: Address,
+    pub proposal_id: u64,
+    pub title_uri: String,
+    pub snapshot_epoch: u32,
+    pub vote_start: u64,
+    pub vote_end: u64,
+}
+
+/// Emitted for every vote cast.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCastEvent {
+    #[topic]
+    pub voter: Address,
+    pub proposal_id: u64,
+    pub choice: VoteChoice,
+    pub power: u64,
+}
+
+/// Emitted once a passed proposal's call has run.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalExecutedEvent {
+    #[topic]
+    pub proposal_id: u64,
+    pub executor: Address,
+}
+
+/// Emitted when the proposer or admin withdraws a proposal.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCancelledEvent {
+    #[topic]
+    pub proposal_id: u64,
+    pub cancelled_by: Address,
+}
@@ -0,0 +1,387 @@
+#![no_std]
+// propose is the only function over the limit and carries its own allow;
+// this covers the argument helper #[contractimpl] generates for it, which
+// has no attributes of its own to put one on.
+#![allow(clippy::too_many_arguments)]
+
+mod errors;
+mod events;
+mod registry;
+mod storage;
+
+use errors::GovernanceError;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Val, Vec};
+use storage::{
+    DataKey, GovernanceConfig, Proposal, ProposalCall, ProposalStatus, VoteChoice, VoteRecord,
+    BPS_DENOMINATOR, MAX_URI_LEN, MAX_VOTING_PERIOD, PERSISTENT_BUMP_AMOUNT,
+    PERSISTENT_BUMP_THRESHOLD,
+};
+
+#[contract]
+pub struct GovernanceContract;
+
+#[contractimpl]
+impl GovernanceContract {
+    /// Check that `admin` is the stored admin and has signed
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), GovernanceError> {
+        let stored_admin = Self::get_admin(env.clone())?;
+        if *admin != stored_admin {
+            return Err(GovernanceError::Unauthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
+
+    fn validate_config(config: &GovernanceConfig) -> Result<(), GovernanceError> {
+        if config.pass_threshold_bps == 0
+            || config.pass_threshold_bps > BPS_DENOMINATOR
+            || config.min_voting_period == 0
+            || config.min_voting_period > MAX_VOTING_PERIOD
+        {
+            return Err(GovernanceError::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    fn load_proposal(env: &Env, proposal_id: u64) -> Result<Proposal, GovernanceError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+            .ok_or(GovernanceError::ProposalNotFound)
+    }
+
+    /// Write a proposal back and keep it clear of archival
+    fn save_proposal(env: &Env, proposal: &Proposal) {
+        let key = DataKey::Proposal(proposal.id);
+        env.storage().persistent().set(&key, proposal);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// Whether a closed vote met quorum and the pass threshold
+    fn passed(proposal: &Proposal, config: &GovernanceConfig) -> bool {
+        let decisive = proposal.votes_for as u128 + proposal.votes_against as u128;
+        let cast = decisive + proposal.votes_abstain as u128;
+        proposal.votes_for > 0
+            && cast >= config.quorum as u128
+            && proposal.votes_for as u128 * BPS_DENOMINATOR as u128
+                >= decisive * config.pass_threshold_bps as u128
+    }
+
+    /// Where `proposal` stands at the current ledger time
+    fn status_of(env: &Env, proposal: &Proposal) -> Result<ProposalStatus, GovernanceError> {
+        if matches!(
+            proposal.status,
+            ProposalStatus::Executed | ProposalStatus::Cancelled
+        ) {
+            return Ok(proposal.status);
+        }
+        let now = env.ledger().timestamp();
+        if now < proposal.vote_start {
+            return Ok(ProposalStatus::Pending);
+        }
+        if now < proposal.vote_end {
+            return Ok(ProposalStatus::Active);
+        }
+        if Self::passed(proposal, &Self::get_config(env.clone())?) {
+            Ok(ProposalStatus::Succeeded)
+        } else {
+            Ok(ProposalStatus::Defeated)
+        }
+    }
+
+    /// Initialize the contract with an admin, the contributor registry
+    /// voting power is read from, and the voting rules
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        registry: Address,
+        config: GovernanceConfig,
+    ) -> Result<(), GovernanceError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(GovernanceError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        Self::validate_config(&config)?;
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Registry, &registry);
+        env.storage().instance().set(&DataKey::Config, &config);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextProposalId, &0u64);
+
+        events::InitializedEvent { admin, registry }.publish(&env);
+        Ok(())
+    }
+
+    /// Replace the voting rules. Proposals still open are decided by the
+    /// new rules when they close.
+    pub fn set_config(
+        env: Env,
+        admin: Address,
+        config: GovernanceConfig,
+    ) -> Result<(), GovernanceError> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_config(&config)?;
+        env.storage().instance().set(&DataKey::Config, &config);
+
+        events::ConfigUpdatedEvent {
+            quorum: config.quorum,
+            pass_threshold_bps: config.pass_threshold_bps,
+            min_voting_period: config.min_voting_period,
+            proposal_threshold: config.proposal_threshold,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Propose running `call` if the vote between `vote_start` and
+    /// `vote_end` passes. Voting power is each voter's score in the
+    /// registry's latest snapshot, pinned now so a proposer cannot pick an
+    /// epoch that suits them. `score` and `proof` show the proposer held at
+    /// least `proposal_threshold` in that snapshot. Returns the proposal ID.
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        title_uri: String,
+        call: ProposalCall,
+        score: u64,
+        proof: Vec<BytesN<32>>,
+        vote_start: u64,
+        vote_end: u64,
+    ) -> Result<u64, GovernanceError> {
+        proposer.require_auth();
+        let config = Self::get_config(env.clone())?;
+        if title_uri.is_empty() || title_uri.len() > MAX_URI_LEN {
+            return Err(GovernanceError::InvalidTitle);
+        }
+        if vote_start < env.ledger().timestamp() || vote_end <= vote_start {
+            return Err(GovernanceError::InvalidVotingWindow);
+        }
+        let period = vote_end - vote_start;
+        if period < config.min_voting_period || period > MAX_VOTING_PERIOD {
+            return Err(GovernanceError::InvalidVotingWindow);
+        }
+        let registry = Self::get_registry(env.clone())?;
+        let snapshot_epoch =
+            registry::latest_snapshot(&env, &registry).ok_or(GovernanceError::SnapshotNotFound)?;
+        if config.proposal_threshold > 0 {
+            if score < config.proposal_threshold {
+                return Err(GovernanceError::BelowProposalThreshold);
+            }
+            if !registry::proves_score(&env, &registry, snapshot_epoch, &proposer, score, &proof) {
+                return Err(GovernanceError::InvalidProof);
+            }
+        }
+
+        let proposal_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextProposalId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextProposalId, &(proposal_id + 1));
+        let proposal = Proposal {
+            id: proposal_id,
+            proposer: proposer.clone(),
+            title_uri: title_uri.clone(),
+            call,
+            snapshot_epoch,
+            vote_start,
+            vote_end,
+            votes_for: 0,
+            votes_against: 0,
+            votes_abstain: 0,
+            status: ProposalStatus::Active,
+        };
+        Self::save_proposal(&env, &proposal);
+
+        events::ProposalCreatedEvent {
+            proposer,
+            proposal_id,
+            title_uri,
+            snapshot_epoch,
+            vote_start,
+            vote_end,
+        }
+        .publish(&env);
+        Ok(proposal_id)
+    }
+
+    /// Cast `voter`'s whole snapshot score on a proposal. `score` and
+    /// `proof` are the voter's leaf and Merkle proof in the snapshot, and
+    /// are checked against the registry. Each address votes once.
+    pub fn vote(
+        env: Env,
+        voter: Address,
+        proposal_id: u64,
+        choice: VoteChoice,
+        score: u64,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), GovernanceError> {
+        voter.require_auth();
+        let mut proposal = Self::load_proposal(&env, proposal_id)?;
+        if Self::status_of(&env, &proposal)? != ProposalStatus::Active {
+            return Err(GovernanceError::VotingClosed);
+        }
+        let vote_key = DataKey::Vote(proposal_id, voter.clone());
+        if env.storage().persistent().has(&vote_key) {
+            return Err(GovernanceError::AlreadyVoted);
+        }
+        if score == 0 {
+            return Err(GovernanceError::NoVotingPower);
+        }
+        let registry = Self::get_registry(env.clone())?;
+        if !registry::proves_score(
+            &env,
+            &registry,
+            proposal.snapshot_epoch,
+            &voter,
+            score,
+            &proof,
+        ) {
+            return Err(GovernanceError::InvalidProof);
+        }
+
+        let tally = match choice {
+            VoteChoice::For => &mut proposal.votes_for,
+            VoteChoice::Against => &mut proposal.votes_against,
+            VoteChoice::Abstain => &mut proposal.votes_abstain,
+        };
+        *tally = tally.saturating_add(score);
+        Self::save_proposal(&env, &proposal);
+        let record = VoteRecord {
+            choice,
+            power: score,
+        };
+        env.storage().persistent().set(&vote_key, &record);
+        env.storage().persistent().extend_ttl(
+            &vote_key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+
+        events::VoteCastEvent {
+            voter,
+            proposal_id,
+            choice,
+            power: score,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Run a succeeded proposal's call. Anyone may trigger it once voting
+    /// has closed; it runs at most once.
+    pub fn execute(env: Env, executor: Address, proposal_id: u64) -> Result<(), GovernanceError> {
+        executor.require_auth();
+        let mut proposal = Self::load_proposal(&env, proposal_id)?;
+        match Self::status_of(&env, &proposal)? {
+            ProposalStatus::Succeeded => {}
+            ProposalStatus::Pending | ProposalStatus::Active => {
+                return Err(GovernanceError::VotingNotEnded)
+            }
+            ProposalStatus::Defeated => return Err(GovernanceError::ProposalNotPassed),
+            _ => return Err(GovernanceError::InvalidStatus),
+        }
+
+        // Record first so the call cannot re-enter and run it again
+        proposal.status = ProposalStatus::Executed;
+        Self::save_proposal(&env, &proposal);
+        env.invoke_contract::<Val>(
+            &proposal.call.contract,
+            &proposal.call.function,
+            proposal.call.args.clone(),
+        );
+
+        events::ProposalExecutedEvent {
+            proposal_id,
+            executor,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Withdraw a proposal that has not been executed. Only its proposer or
+    /// the admin may.
+    pub fn cancel(env: Env, caller: Address, proposal_id: u64) -> Result<(), GovernanceError> {
+        let mut proposal = Self::load_proposal(&env, proposal_id)?;
+        if caller != proposal.proposer && caller != Self::get_admin(env.clone())? {
+            return Err(GovernanceError::Unauthorized);
+        }
+        caller.require_auth();
+        if matches!(
+            proposal.status,
+            ProposalStatus::Executed | ProposalStatus::Cancelled
+        ) {
+            return Err(GovernanceError::InvalidStatus);
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        Self::save_proposal(&env, &proposal);
+
+        events::ProposalCancelledEvent {
+            proposal_id,
+            cancelled_by: caller,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get a proposal by ID, with its stored status
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<Proposal, GovernanceError> {
+        Self::load_proposal(&env, proposal_id)
+    }
+
+    /// Where a proposal stands right now, including whether a closed vote
+    /// succeeded or was defeated
+    pub fn get_status(env: Env, proposal_id: u64) -> Result<ProposalStatus, GovernanceError> {
+        let proposal = Self::load_proposal(&env, proposal_id)?;
+        Self::status_of(&env, &proposal)
+    }
+
+    /// How `voter` voted on a proposal, if they have
+    pub fn get_vote(env: Env, proposal_id: u64, voter: Address) -> Option<VoteRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Vote(proposal_id, voter))
+    }
+
+    /// Number of proposals ever created; IDs run from 0 to this count
+    pub fn get_proposal_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextProposalId)
+            .unwrap_or(0)
+    }
+
+    pub fn get_config(env: Env) -> Result<GovernanceConfig, GovernanceError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(GovernanceError::NotInitialized)
+    }
+
+    pub fn get_registry(env: Env) -> Result<Address, GovernanceError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Registry)
+            .ok_or(GovernanceError::NotInitialized)
+    }
+
+    /// Get admin address
+    pub fn get_admin(env: Env) -> Result<Address, GovernanceError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(GovernanceError::NotInitialized)
+    }
+}
+
+#[cfg(test)]
+mod test;
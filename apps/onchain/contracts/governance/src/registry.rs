@@ -0,0 +1,41 @@
+use soroban_sdk::{contractclient, Address, BytesN, Env, Vec};
+
+/// The slice of the contributor registry's interface this contract reads.
+// Only the generated client is called; the trait itself is never implemented.
+#[allow(dead_code)]
+#[contractclient(name = "RegistryClient")]
+pub trait Registry {
+    fn get_latest_snapshot_epoch(env: Env) -> Option<u32>;
+    fn verify_snapshot_proof(
+        env: Env,
+        epoch: u32,
+        address: Address,
+        score: u64,
+        proof: Vec<BytesN<32>>,
+    ) -> bool;
+}
+
+/// The registry's most recent snapshot, or `None` if it has taken none or
+/// cannot be reached.
+pub fn latest_snapshot(env: &Env, registry: &Address) -> Option<u32> {
+    match RegistryClient::new(env, registry).try_get_latest_snapshot_epoch() {
+        Ok(Ok(epoch)) => epoch,
+        _ => None,
+    }
+}
+
+/// Whether `proof` shows `voter` held `score` in the registry's `epoch`
+/// snapshot; any failure in the registry counts as an invalid proof.
+pub fn proves_score(
+    env: &Env,
+    registry: &Address,
+    epoch: u32,
+    voter: &Address,
+    score: u64,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    matches!(
+        RegistryClient::new(env, registry).try_verify_snapshot_proof(&epoch, voter, &score, proof),
+        Ok(Ok(true))
+    )
+}
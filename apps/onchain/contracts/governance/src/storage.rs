@@ -0,0 +1,105 @@
+use soroban_sdk::{contracttype, Address, String, Symbol, Val, Vec};
+
+/// Pass thresholds are expressed in basis points of the votes cast for or
+/// against a proposal.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Longest voting window a proposal may ask for: 30 days.
+pub const MAX_VOTING_PERIOD: u64 = 30 * 86_400;
+
+/// Longest title URI accepted, in bytes.
+pub const MAX_URI_LEN: u32 = 256;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Proposals touched by any write are kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,              // -> Address
+    Registry,           // -> Address of the contributor registry
+    Config,             // -> GovernanceConfig
+    Proposal(u64),      // proposal id -> Proposal
+    Vote(u64, Address), // (proposal id, voter) -> VoteRecord
+    NextProposalId,     // -> u64
+}
+
+/// Rules every proposal is decided by.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfig {
+    /// Reputation that must be cast, in any direction, for a vote to count.
+    pub quorum: u64,
+    /// Share of for-and-against votes that must be in favour, in basis points.
+    pub pass_threshold_bps: u32,
+    /// Shortest voting window a proposal may ask for, in seconds.
+    pub min_voting_period: u64,
+    /// Snapshot score a proposer must hold to open a proposal.
+    pub proposal_threshold: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VoteChoice {
+    For,
+    Against,
+    /// Counts towards quorum only.
+    Abstain,
+}
+
+/// Where a proposal stands; `Pending` through `Defeated` are derived from
+/// the clock and tallies, the rest are recorded.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    /// Voting has not opened yet.
+    Pending,
+    Active,
+    /// Voting has closed with quorum and the threshold met.
+    Succeeded,
+    Defeated,
+    Executed,
+    Cancelled,
+}
+
+/// A contract call run when a proposal passes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCall {
+    pub contract: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Address,
+    /// Where the full proposal text lives.
+    pub title_uri: String,
+    pub call: ProposalCall,
+    /// Registry snapshot that voting power is read from.
+    pub snapshot_epoch: u32,
+    pub vote_start: u64,
+    pub vote_end: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub votes_abstain: u64,
+    /// Recorded only on execution or cancellation; `get_status` gives the
+    /// live state.
+    pub status: ProposalStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteRecord {
+    pub choice: VoteChoice,
+    pub power: u64,
+}
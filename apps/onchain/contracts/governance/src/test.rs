@@ -0,0 +1,456 @@
+use crate::errors::GovernanceError;
+use crate::storage::{GovernanceConfig, ProposalCall, ProposalStatus, VoteChoice};
+use crate::{GovernanceContract, GovernanceContractClient};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env, IntoVal, String, Symbol, Vec,
+};
+
+/// Stands in for the contributor registry's snapshot views. A proof is
+/// accepted whenever the score matches the one recorded for the epoch, and
+/// the highest epoch scored so far is the latest snapshot.
+#[contract]
+struct MockRegistry;
+
+const LATEST: Symbol = symbol_short!("latest");
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_score(env: Env, epoch: u32, address: Address, score: u64) {
+        let latest = Self::get_latest_snapshot_epoch(env.clone()).map_or(epoch, |l| l.max(epoch));
+        env.storage().instance().set(&LATEST, &latest);
+        env.storage().instance().set(&(epoch, address), &score);
+    }
+
+    pub fn get_latest_snapshot_epoch(env: Env) -> Option<u32> {
+        env.storage().instance().get(&LATEST)
+    }
+
+    pub fn verify_snapshot_proof(
+        env: Env,
+        epoch: u32,
+        address: Address,
+        score: u64,
+        _proof: Vec<BytesN<32>>,
+    ) -> bool {
+        env.storage().instance().get(&(epoch, address)) == Some(score)
+    }
+}
+
+/// Target for proposal calls.
+#[contract]
+struct MockTarget;
+
+#[contractimpl]
+impl MockTarget {
+    pub fn set_value(env: Env, value: u32) -> u32 {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "value"), &value);
+        value
+    }
+
+    pub fn value(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "value"))
+            .unwrap_or(0)
+    }
+}
+
+const PERIOD: u64 = 86_400;
+const THRESHOLD: u64 = 50;
+
+struct Setup<'a> {
+    env: Env,
+    client: GovernanceContractClient<'a>,
+    admin: Address,
+    registry: MockRegistryClient<'a>,
+    target: MockTargetClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = MockRegistryClient::new(&env, &env.register(MockRegistry, ()));
+    let target = MockTargetClient::new(&env, &env.register(MockTarget, ()));
+
+    let contract_id = env.register(GovernanceContract, ());
+    let client = GovernanceContractClient::new(&env, &contract_id);
+    client.initialize(
+        &admin,
+        &registry.address,
+        &GovernanceConfig {
+            quorum: 100,
+            pass_threshold_bps: 5_000,
+            min_voting_period: 3_600,
+            proposal_threshold: THRESHOLD,
+        },
+    );
+
+    Setup {
+        env,
+        client,
+        admin,
+        registry,
+        target,
+    }
+}
+
+/// A voter holding `score` in snapshot epoch 0
+fn voter(s: &Setup, score: u64) -> Address {
+    let address = Address::generate(&s.env);
+    s.registry.set_score(&0, &address, &score);
+    address
+}
+
+/// Propose `set_value(42)` on the target as a proposer holding `score`,
+/// voting open now for `PERIOD`
+fn propose(s: &Setup, proposer: &Address, score: u64) -> u64 {
+    let now = s.env.ledger().timestamp();
+    s.client.propose(
+        proposer,
+        &String::from_str(&s.env, "ipfs://bafy-proposal"),
+        &ProposalCall {
+            contract: s.target.address.clone(),
+            function: Symbol::new(&s.env, "set_value"),
+            args: vec![&s.env, 42u32.into_val(&s.env)],
+        },
+        &score,
+        &no_proof(&s.env),
+        &now,
+        &(now + PERIOD),
+    )
+}
+
+fn no_proof(env: &Env) -> Vec<BytesN<32>> {
+    Vec::new(env)
+}
+
+#[test]
+fn test_propose_and_vote() {
+    let s = setup();
+    let alice = voter(&s, 80);
+    let bob = voter(&s, 30);
+    let proposal_id = propose(&s, &alice, 80);
+
+    assert_eq!(proposal_id, 0);
+    assert_eq!(s.client.get_proposal_count(), 1);
+    assert_eq!(s.client.get_status(&proposal_id), ProposalStatus::Active);
+
+    s.client.vote(
+        &alice,
+        &proposal_id,
+        &VoteChoice::For,
+        &80,
+        &no_proof(&s.env),
+    );
+    s.client.vote(
+        &bob,
+        &proposal_id,
+        &VoteChoice::Against,
+        &30,
+        &no_proof(&s.env),
+    );
+    let proposal = s.client.get_proposal(&proposal_id);
+    assert_eq!(proposal.votes_for, 80);
+    assert_eq!(proposal.votes_against, 30);
+    assert_eq!(s.client.get_vote(&proposal_id, &bob).unwrap().power, 30);
+    assert_eq!(
+        s.client.try_vote(
+            &alice,
+            &proposal_id,
+            &VoteChoice::For,
+            &80,
+            &no_proof(&s.env)
+        ),
+        Err(Ok(GovernanceError::AlreadyVoted))
+    );
+}
+
+#[test]
+fn test_vote_requires_snapshot_proof() {
+    let s = setup();
+    let alice = voter(&s, 80);
+    let stranger = Address::generate(&s.env);
+    let proposal_id = propose(&s, &alice, 80);
+
+    assert_eq!(
+        s.client.try_vote(
+            &alice,
+            &proposal_id,
+            &VoteChoice::For,
+            &81,
+            &no_proof(&s.env)
+        ),
+        Err(Ok(GovernanceError::InvalidProof))
+    );
+    assert_eq!(
+        s.client.try_vote(
+            &stranger,
+            &proposal_id,
+            &VoteChoice::For,
+            &0,
+            &no_proof(&s.env)
+        ),
+        Err(Ok(GovernanceError::NoVotingPower))
+    );
+    // Scores gained after the snapshot do not count
+    s.registry.set_score(&1, &stranger, &500);
+    assert_eq!(
+        s.client.try_vote(
+            &stranger,
+            &proposal_id,
+            &VoteChoice::For,
+            &500,
+            &no_proof(&s.env)
+        ),
+        Err(Ok(GovernanceError::InvalidProof))
+    );
+}
+
+#[test]
+fn test_propose_validates_input() {
+    let s = setup();
+    let now = s.env.ledger().timestamp();
+    let title = String::from_str(&s.env, "ipfs://bafy-proposal");
+    let call = ProposalCall {
+        contract: s.target.address.clone(),
+        function: Symbol::new(&s.env, "set_value"),
+        args: vec![&s.env, 1u32.into_val(&s.env)],
+    };
+    let proof = no_proof(&s.env);
+
+    // No snapshot has been taken yet
+    let early = Address::generate(&s.env);
+    assert_eq!(
+        s.client
+            .try_propose(&early, &title, &call, &80, &proof, &now, &(now + PERIOD)),
+        Err(Ok(GovernanceError::SnapshotNotFound))
+    );
+
+    let alice = voter(&s, 80);
+    assert_eq!(
+        s.client
+            .try_propose(&alice, &title, &call, &80, &proof, &now, &(now + 60)),
+        Err(Ok(GovernanceError::InvalidVotingWindow))
+    );
+    assert_eq!(
+        s.client.try_propose(
+            &alice,
+            &String::from_str(&s.env, ""),
+            &call,
+            &80,
+            &proof,
+            &now,
+            &(now + PERIOD)
+        ),
+        Err(Ok(GovernanceError::InvalidTitle))
+    );
+}
+
+#[test]
+fn test_propose_threshold_and_latest_snapshot() {
+    let s = setup();
+    let alice = voter(&s, 80);
+    let bob = voter(&s, THRESHOLD - 1);
+    let now = s.env.ledger().timestamp();
+    let title = String::from_str(&s.env, "ipfs://bafy-proposal");
+    let call = ProposalCall {
+        contract: s.target.address.clone(),
+        function: Symbol::new(&s.env, "set_value"),
+        args: vec![&s.env, 1u32.into_val(&s.env)],
+    };
+    let proof = no_proof(&s.env);
+
+    assert_eq!(
+        s.client.try_propose(
+            &bob,
+            &title,
+            &call,
+            &(THRESHOLD - 1),
+            &proof,
+            &now,
+            &(now + PERIOD)
+        ),
+        Err(Ok(GovernanceError::BelowProposalThreshold))
+    );
+    // Claiming more than the snapshot holds fails the proof
+    assert_eq!(
+        s.client.try_propose(
+            &bob,
+            &title,
+            &call,
+            &THRESHOLD,
+            &proof,
+            &now,
+            &(now + PERIOD)
+        ),
+        Err(Ok(GovernanceError::InvalidProof))
+    );
+
+    // A newer snapshot is picked up, and old standing no longer counts
+    s.registry.set_score(&1, &bob, &THRESHOLD);
+    assert_eq!(
+        s.client
+            .try_propose(&alice, &title, &call, &80, &proof, &now, &(now + PERIOD)),
+        Err(Ok(GovernanceError::InvalidProof))
+    );
+    let proposal_id = s.client.propose(
+        &bob,
+        &title,
+        &call,
+        &THRESHOLD,
+        &proof,
+        &now,
+        &(now + PERIOD),
+    );
+    assert_eq!(s.client.get_proposal(&proposal_id).snapshot_epoch, 1);
+}
+
+#[test]
+fn test_execute_passed_proposal() {
+    let s = setup();
+    let alice = voter(&s, 80);
+    let bob = voter(&s, 30);
+    let proposal_id = propose(&s, &alice, 80);
+    s.client.vote(
+        &alice,
+        &proposal_id,
+        &VoteChoice::For,
+        &80,
+        &no_proof(&s.env),
+    );
+    s.client.vote(
+        &bob,
+        &proposal_id,
+        &VoteChoice::Against,
+        &30,
+        &no_proof(&s.env),
+    );
+
+    assert_eq!(
+        s.client.try_execute(&bob, &proposal_id),
+        Err(Ok(GovernanceError::VotingNotEnded))
+    );
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + PERIOD);
+    assert_eq!(s.client.get_status(&proposal_id), ProposalStatus::Succeeded);
+    assert_eq!(
+        s.client
+            .try_vote(&bob, &proposal_id, &VoteChoice::For, &30, &no_proof(&s.env)),
+        Err(Ok(GovernanceError::VotingClosed))
+    );
+
+    s.client.execute(&bob, &proposal_id);
+    assert_eq!(s.target.value(), 42);
+    assert_eq!(s.client.get_status(&proposal_id), ProposalStatus::Executed);
+    assert_eq!(
+        s.client.try_execute(&bob, &proposal_id),
+        Err(Ok(GovernanceError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_quorum_and_threshold() {
+    let s = setup();
+    let alice = voter(&s, 60);
+    let bob = voter(&s, 60);
+    let carol = voter(&s, 90);
+
+    // Below quorum: 60 cast of 100 needed
+    let thin = propose(&s, &alice, 60);
+    s.client
+        .vote(&alice, &thin, &VoteChoice::For, &60, &no_proof(&s.env));
+
+    // Quorum met via abstain, but 60 for vs 90 against misses 50%
+    let contested = propose(&s, &alice, 60);
+    s.client
+        .vote(&alice, &contested, &VoteChoice::For, &60, &no_proof(&s.env));
+    s.client.vote(
+        &bob,
+        &contested,
+        &VoteChoice::Abstain,
+        &60,
+        &no_proof(&s.env),
+    );
+    s.client.vote(
+        &carol,
+        &contested,
+        &VoteChoice::Against,
+        &90,
+        &no_proof(&s.env),
+    );
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + PERIOD);
+    assert_eq!(s.client.get_status(&thin), ProposalStatus::Defeated);
+    assert_eq!(s.client.get_status(&contested), ProposalStatus::Defeated);
+    assert_eq!(
+        s.client.try_execute(&alice, &thin),
+        Err(Ok(GovernanceError::ProposalNotPassed))
+    );
+
+    // Lowering the bar applies to closed, unexecuted proposals too
+    s.client.set_config(
+        &s.admin,
+        &GovernanceConfig {
+            quorum: 50,
+            pass_threshold_bps: 5_000,
+            min_voting_period: 3_600,
+            proposal_threshold: THRESHOLD,
+        },
+    );
+    assert_eq!(s.client.get_status(&thin), ProposalStatus::Succeeded);
+}
+
+#[test]
+fn test_cancel() {
+    let s = setup();
+    let alice = voter(&s, 80);
+    let stranger = Address::generate(&s.env);
+    let proposal_id = propose(&s, &alice, 80);
+
+    assert_eq!(
+        s.client.try_cancel(&stranger, &proposal_id),
+        Err(Ok(GovernanceError::Unauthorized))
+    );
+    s.client.cancel(&s.admin, &proposal_id);
+    assert_eq!(s.client.get_status(&proposal_id), ProposalStatus::Cancelled);
+    assert_eq!(
+        s.client.try_vote(
+            &alice,
+            &proposal_id,
+            &VoteChoice::For,
+            &80,
+            &no_proof(&s.env)
+        ),
+        Err(Ok(GovernanceError::VotingClosed))
+    );
+}
+
+#[test]
+fn test_config_validation() {
+    let s = setup();
+    let bad = GovernanceConfig {
+        quorum: 100,
+        pass_threshold_bps: 10_001,
+        min_voting_period: 3_600,
+        proposal_threshold: THRESHOLD,
+    };
+    assert_eq!(
+        s.client.try_set_config(&s.admin, &bad),
+        Err(Ok(GovernanceError::InvalidConfig))
+    );
+    assert_eq!(
+        s.client.try_initialize(&s.admin, &s.registry.address, &bad),
+        Err(Ok(GovernanceError::AlreadyInitialized))
+    );
+    assert_eq!(s.client.get_config().quorum, 100);
+    assert_eq!(s.client.get_registry(), s.registry.address);
+}
@@ -0,0 +1,18 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum GrantError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    Unauthorized = 3,
+    GrantNotFound = 4,
+    MilestoneNotFound = 5,
+    InvalidAmount = 6,
+    InvalidMilestones = 7,
+    InvalidStatus = 8,
+    AlreadyApproved = 9,
+    InvalidEvidence = 10,
+    InvalidThreshold = 11,
+}
@@ -0,0 +1,83 @@
+use soroban_sdk::{contractevent, Address, String};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+    pub treasury: Address,
+}
+
+/// Emitted when the admin adds or removes a committee member.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewerUpdatedEvent {
+    #[topic]
+    pub reviewer: Address,
+    pub enabled: bool,
+}
+
+/// Emitted when a grant is created and its tranches escrowed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantCreatedEvent {
+    #[topic]
+    pub grantee: Address,
+    pub grant_id: u64,
+    pub token: Address,
+    pub total_amount: i128,
+    pub milestone_count: u32,
+}
+
+/// Emitted when the grantee submits evidence for a milestone.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneSubmittedEvent {
+    #[topic]
+    pub grantee: Address,
+    pub grant_id: u64,
+    pub milestone_idx: u32,
+    pub evidence_uri: String,
+}
+
+/// Emitted for each approval of a submitted milestone.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneApprovedEvent {
+    #[topic]
+    pub reviewer: Address,
+    pub grant_id: u64,
+    pub milestone_idx: u32,
+    pub approvals: u32,
+}
+
+/// Emitted when a submission is sent back to the grantee.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneRejectedEvent {
+    #[topic]
+    pub reviewer: Address,
+    pub grant_id: u64,
+    pub milestone_idx: u32,
+}
+
+/// Emitted when enough approvals release a tranche to the grantee.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrancheReleasedEvent {
+    #[topic]
+    pub grantee: Address,
+    pub grant_id: u64,
+    pub milestone_idx: u32,
+    pub amount: i128,
+}
+
+/// Emitted when the admin stops a grant early.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantTerminatedEvent {
+    #[topic]
+    pub grantee: Address,
+    pub grant_id: u64,
+    pub released_amount: i128,
+    pub returned_amount: i128,
+}
@@ -0,0 +1,446 @@
+#![no_std]
+
+mod errors;
+mod events;
+mod storage;
+
+use errors::GrantError;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use storage::{
+    DataKey, Grant, GrantStatus, Milestone, MilestoneStatus, MAX_MILESTONES, MAX_REVIEWERS,
+    MAX_URI_LEN, PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_THRESHOLD,
+};
+
+#[contract]
+pub struct GrantsContract;
+
+#[contractimpl]
+impl GrantsContract {
+    /// Check that `admin` is the stored admin and has signed
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), GrantError> {
+        let stored_admin = Self::get_admin(env.clone())?;
+        if *admin != stored_admin {
+            return Err(GrantError::Unauthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
+
+    /// `reviewer` must be the admin or a committee member, and have signed
+    fn require_reviewer(env: &Env, reviewer: &Address) -> Result<(), GrantError> {
+        let admin = Self::get_admin(env.clone())?;
+        if *reviewer != admin && !Self::is_reviewer(env.clone(), reviewer.clone()) {
+            return Err(GrantError::Unauthorized);
+        }
+        reviewer.require_auth();
+        Ok(())
+    }
+
+    fn load_grant(env: &Env, grant_id: u64) -> Result<Grant, GrantError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Grant(grant_id))
+            .ok_or(GrantError::GrantNotFound)
+    }
+
+    /// Write a grant back and keep it clear of archival
+    fn save_grant(env: &Env, grant: &Grant) {
+        let key = DataKey::Grant(grant.id);
+        env.storage().persistent().set(&key, grant);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    fn load_milestone(env: &Env, grant_id: u64, idx: u32) -> Result<Milestone, GrantError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Milestone(grant_id, idx))
+            .ok_or(GrantError::MilestoneNotFound)
+    }
+
+    fn save_milestone(env: &Env, grant_id: u64, idx: u32, milestone: &Milestone) {
+        let key = DataKey::Milestone(grant_id, idx);
+        env.storage().persistent().set(&key, milestone);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// Load an active grant with the milestone at `idx`
+    fn load_active(env: &Env, grant_id: u64, idx: u32) -> Result<(Grant, Milestone), GrantError> {
+        let grant = Self::load_grant(env, grant_id)?;
+        if grant.status != GrantStatus::Active {
+            return Err(GrantError::InvalidStatus);
+        }
+        let milestone = Self::load_milestone(env, grant_id, idx)?;
+        Ok((grant, milestone))
+    }
+
+    /// Initialize the contract with an admin and the treasury that takes
+    /// back funds from terminated grants
+    pub fn initialize(env: Env, admin: Address, treasury: Address) -> Result<(), GrantError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(GrantError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+        env.storage().instance().set(&DataKey::NextGrantId, &0u64);
+        env.storage()
+            .instance()
+            .set(&DataKey::ApprovalThreshold, &1u32);
+
+        events::InitializedEvent { admin, treasury }.publish(&env);
+        Ok(())
+    }
+
+    /// Change where funds from terminated grants are returned
+    pub fn set_treasury(env: Env, admin: Address, treasury: Address) -> Result<(), GrantError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+        Ok(())
+    }
+
+    /// Add `reviewer` to the approval committee, or remove them. Removal is
+    /// refused if it would leave too few reviewers to reach the threshold.
+    pub fn set_reviewer(
+        env: Env,
+        admin: Address,
+        reviewer: Address,
+        enabled: bool,
+    ) -> Result<(), GrantError> {
+        Self::require_admin(&env, &admin)?;
+        if reviewer == admin {
+            return Err(GrantError::Unauthorized);
+        }
+        let key = DataKey::Reviewer(reviewer.clone());
+        let was_enabled = env.storage().persistent().has(&key);
+        let count = Self::get_reviewer_count(env.clone());
+        if enabled && !was_enabled {
+            if count >= MAX_REVIEWERS {
+                return Err(GrantError::InvalidThreshold);
+            }
+            env.storage().persistent().set(&key, &true);
+            env.storage()
+                .instance()
+                .set(&DataKey::ReviewerCount, &(count + 1));
+        } else if !enabled && was_enabled {
+            // The admin always counts as one approver
+            if count < Self::get_approval_threshold(env.clone()) {
+                return Err(GrantError::InvalidThreshold);
+            }
+            env.storage().persistent().remove(&key);
+            env.storage()
+                .instance()
+                .set(&DataKey::ReviewerCount, &(count - 1));
+        }
+
+        events::ReviewerUpdatedEvent { reviewer, enabled }.publish(&env);
+        Ok(())
+    }
+
+    /// Whether `address` sits on the approval committee
+    pub fn is_reviewer(env: Env, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Reviewer(address))
+            .unwrap_or(false)
+    }
+
+    /// Number of committee members, not counting the admin
+    pub fn get_reviewer_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReviewerCount)
+            .unwrap_or(0)
+    }
+
+    /// Require `threshold` distinct approvals, from the admin and committee,
+    /// before a tranche is released
+    pub fn set_approval_threshold(
+        env: Env,
+        admin: Address,
+        threshold: u32,
+    ) -> Result<(), GrantError> {
+        Self::require_admin(&env, &admin)?;
+        if threshold == 0 || threshold > Self::get_reviewer_count(env.clone()) + 1 {
+            return Err(GrantError::InvalidThreshold);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ApprovalThreshold, &threshold);
+        Ok(())
+    }
+
+    /// Approvals needed to release a tranche
+    pub fn get_approval_threshold(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ApprovalThreshold)
+            .unwrap_or(1)
+    }
+
+    /// Create a grant paid out in `tranches`, one per milestone, escrowing
+    /// their sum from the admin up-front. Returns the grant ID.
+    pub fn create_grant(
+        env: Env,
+        admin: Address,
+        grantee: Address,
+        token: Address,
+        tranches: Vec<i128>,
+    ) -> Result<u64, GrantError> {
+        Self::require_admin(&env, &admin)?;
+        if tranches.is_empty() || tranches.len() > MAX_MILESTONES {
+            return Err(GrantError::InvalidMilestones);
+        }
+        let mut total_amount: i128 = 0;
+        for amount in tranches.iter() {
+            if amount <= 0 {
+                return Err(GrantError::InvalidAmount);
+            }
+            total_amount = total_amount
+                .checked_add(amount)
+                .ok_or(GrantError::InvalidAmount)?;
+        }
+
+        TokenClient::new(&env, &token).transfer(
+            &admin,
+            env.current_contract_address(),
+            &total_amount,
+        );
+
+        let grant_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextGrantId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextGrantId, &(grant_id + 1));
+        let milestone_count = tranches.len();
+        for (idx, amount) in tranches.iter().enumerate() {
+            let milestone = Milestone {
+                amount,
+                status: MilestoneStatus::Pending,
+                evidence_uri: None,
+                approvals: Vec::new(&env),
+            };
+            Self::save_milestone(&env, grant_id, idx as u32, &milestone);
+        }
+        let grant = Grant {
+            id: grant_id,
+            grantee: grantee.clone(),
+            token: token.clone(),
+            total_amount,
+            released_amount: 0,
+            milestone_count,
+            status: GrantStatus::Active,
+            created_at: env.ledger().timestamp(),
+        };
+        Self::save_grant(&env, &grant);
+
+        events::GrantCreatedEvent {
+            grantee,
+            grant_id,
+            token,
+            total_amount,
+            milestone_count,
+        }
+        .publish(&env);
+        Ok(grant_id)
+    }
+
+    /// Submit evidence that milestone `idx` is done, for the committee to
+    /// review. A rejected milestone may be resubmitted.
+    pub fn submit_milestone(
+        env: Env,
+        grantee: Address,
+        grant_id: u64,
+        idx: u32,
+        evidence_uri: String,
+    ) -> Result<(), GrantError> {
+        let (grant, mut milestone) = Self::load_active(&env, grant_id, idx)?;
+        if grantee != grant.grantee {
+            return Err(GrantError::Unauthorized);
+        }
+        grantee.require_auth();
+        if milestone.status != MilestoneStatus::Pending
+            && milestone.status != MilestoneStatus::Rejected
+        {
+            return Err(GrantError::InvalidStatus);
+        }
+        if evidence_uri.is_empty() || evidence_uri.len() > MAX_URI_LEN {
+            return Err(GrantError::InvalidEvidence);
+        }
+
+        milestone.status = MilestoneStatus::Submitted;
+        milestone.evidence_uri = Some(evidence_uri.clone());
+        Self::save_milestone(&env, grant_id, idx, &milestone);
+
+        events::MilestoneSubmittedEvent {
+            grantee,
+            grant_id,
+            milestone_idx: idx,
+            evidence_uri,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Approve a submitted milestone. Once the approval threshold is met
+    /// its tranche is released to the grantee. Returns whether it was.
+    pub fn approve_milestone(
+        env: Env,
+        reviewer: Address,
+        grant_id: u64,
+        idx: u32,
+    ) -> Result<bool, GrantError> {
+        Self::require_reviewer(&env, &reviewer)?;
+        let (mut grant, mut milestone) = Self::load_active(&env, grant_id, idx)?;
+        if milestone.status != MilestoneStatus::Submitted {
+            return Err(GrantError::InvalidStatus);
+        }
+        if milestone.approvals.contains(&reviewer) {
+            return Err(GrantError::AlreadyApproved);
+        }
+
+        milestone.approvals.push_back(reviewer.clone());
+        let approvals = milestone.approvals.len();
+        let released = approvals >= Self::get_approval_threshold(env.clone());
+        if released {
+            milestone.status = MilestoneStatus::Approved;
+            grant.released_amount += milestone.amount;
+            if grant.released_amount == grant.total_amount {
+                grant.status = GrantStatus::Completed;
+            }
+            Self::save_grant(&env, &grant);
+        }
+        Self::save_milestone(&env, grant_id, idx, &milestone);
+
+        events::MilestoneApprovedEvent {
+            reviewer,
+            grant_id,
+            milestone_idx: idx,
+            approvals,
+        }
+        .publish(&env);
+
+        if released {
+            TokenClient::new(&env, &grant.token).transfer(
+                &env.current_contract_address(),
+                &grant.grantee,
+                &milestone.amount,
+            );
+            events::TrancheReleasedEvent {
+                grantee: grant.grantee,
+                grant_id,
+                milestone_idx: idx,
+                amount: milestone.amount,
+            }
+            .publish(&env);
+        }
+        Ok(released)
+    }
+
+    /// Send a submitted milestone back to the grantee, discarding any
+    /// approvals it had collected
+    pub fn reject_milestone(
+        env: Env,
+        reviewer: Address,
+        grant_id: u64,
+        idx: u32,
+    ) -> Result<(), GrantError> {
+        Self::require_reviewer(&env, &reviewer)?;
+        let (_, mut milestone) = Self::load_active(&env, grant_id, idx)?;
+        if milestone.status != MilestoneStatus::Submitted {
+            return Err(GrantError::InvalidStatus);
+        }
+
+        milestone.status = MilestoneStatus::Rejected;
+        milestone.approvals = Vec::new(&env);
+        Self::save_milestone(&env, grant_id, idx, &milestone);
+
+        events::MilestoneRejectedEvent {
+            reviewer,
+            grant_id,
+            milestone_idx: idx,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Stop an active grant. Tranches already released stay with the
+    /// grantee; everything still escrowed goes back to the treasury.
+    /// Returns the amount returned.
+    pub fn terminate_grant(env: Env, admin: Address, grant_id: u64) -> Result<i128, GrantError> {
+        Self::require_admin(&env, &admin)?;
+        let mut grant = Self::load_grant(&env, grant_id)?;
+        if grant.status != GrantStatus::Active {
+            return Err(GrantError::InvalidStatus);
+        }
+
+        let returned_amount = grant.total_amount - grant.released_amount;
+        grant.status = GrantStatus::Terminated;
+        Self::save_grant(&env, &grant);
+
+        let treasury = Self::get_treasury(env.clone())?;
+        TokenClient::new(&env, &grant.token).transfer(
+            &env.current_contract_address(),
+            &treasury,
+            &returned_amount,
+        );
+
+        events::GrantTerminatedEvent {
+            grantee: grant.grantee,
+            grant_id,
+            released_amount: grant.released_amount,
+            returned_amount,
+        }
+        .publish(&env);
+        Ok(returned_amount)
+    }
+
+    /// Get a grant by ID
+    pub fn get_grant(env: Env, grant_id: u64) -> Result<Grant, GrantError> {
+        Self::load_grant(&env, grant_id)
+    }
+
+    /// Get milestone `idx` of a grant
+    pub fn get_milestone(env: Env, grant_id: u64, idx: u32) -> Result<Milestone, GrantError> {
+        Self::load_grant(&env, grant_id)?;
+        Self::load_milestone(&env, grant_id, idx)
+    }
+
+    /// Number of grants ever created; IDs run from 0 to this count
+    pub fn get_grant_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextGrantId)
+            .unwrap_or(0)
+    }
+
+    /// Get treasury address
+    pub fn get_treasury(env: Env) -> Result<Address, GrantError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Treasury)
+            .ok_or(GrantError::NotInitialized)
+    }
+
+    /// Get admin address
+    pub fn get_admin(env: Env) -> Result<Address, GrantError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(GrantError::NotInitialized)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,78 @@
+use soroban_sdk::{contracttype, Address, String, Vec};
+
+/// Upper bound on tranches in a single grant.
+pub const MAX_MILESTONES: u32 = 20;
+
+/// Upper bound on committee members besides the admin.
+pub const MAX_REVIEWERS: u32 = 10;
+
+/// Longest evidence URI accepted, in bytes.
+pub const MAX_URI_LEN: u32 = 256;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Grants touched by any write are kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,               // -> Address
+    Treasury,            // -> Address receiving funds from terminated grants
+    Reviewer(Address),   // -> bool, committee member
+    ReviewerCount,       // -> u32
+    ApprovalThreshold,   // -> u32 approvals needed to release a tranche
+    Grant(u64),          // grant id -> Grant
+    Milestone(u64, u32), // (grant id, index) -> Milestone
+    NextGrantId,         // -> u64
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GrantStatus {
+    Active,
+    /// Every tranche has been released.
+    Completed,
+    /// Stopped by the admin; unreleased tranches went back to the treasury.
+    Terminated,
+}
+
+/// `Pending -> Submitted -> Approved`, with `Submitted -> Rejected` sending
+/// the grantee back to resubmit.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MilestoneStatus {
+    Pending,
+    Submitted,
+    Approved,
+    Rejected,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grant {
+    pub id: u64,
+    pub grantee: Address,
+    pub token: Address,
+    /// Sum of every tranche, escrowed when the grant is created.
+    pub total_amount: i128,
+    pub released_amount: i128,
+    pub milestone_count: u32,
+    pub status: GrantStatus,
+    pub created_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    pub amount: i128,
+    pub status: MilestoneStatus,
+    /// Where reviewers find the grantee's proof of delivery.
+    pub evidence_uri: Option<String>,
+    /// Reviewers who approved the current submission.
+    pub approvals: Vec<Address>,
+}
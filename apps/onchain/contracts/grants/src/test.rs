@@ -0,0 +1,264 @@
+use crate::errors::GrantError;
+use crate::storage::{GrantStatus, MilestoneStatus};
+use crate::{GrantsContract, GrantsContractClient};
+use soroban_sdk::{
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, String,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: GrantsContractClient<'a>,
+    admin: Address,
+    treasury: Address,
+    grantee: Address,
+    token: TokenClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let grantee = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    StellarAssetClient::new(&env, &asset.address()).mint(&admin, &10_000);
+    let token = TokenClient::new(&env, &asset.address());
+
+    let contract_id = env.register(GrantsContract, ());
+    let client = GrantsContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &treasury);
+
+    Setup {
+        env,
+        client,
+        admin,
+        treasury,
+        grantee,
+        token,
+    }
+}
+
+/// A three-tranche grant of 1_000 + 2_000 + 3_000
+fn create(s: &Setup) -> u64 {
+    s.client.create_grant(
+        &s.admin,
+        &s.grantee,
+        &s.token.address,
+        &vec![&s.env, 1_000, 2_000, 3_000],
+    )
+}
+
+fn evidence(env: &Env) -> String {
+    String::from_str(env, "ipfs://bafy-milestone-report")
+}
+
+#[test]
+fn test_create_escrows_every_tranche() {
+    let s = setup();
+    let grant_id = create(&s);
+
+    assert_eq!(grant_id, 0);
+    assert_eq!(s.client.get_grant_count(), 1);
+    assert_eq!(s.token.balance(&s.admin), 4_000);
+    assert_eq!(s.token.balance(&s.client.address), 6_000);
+    let grant = s.client.get_grant(&grant_id);
+    assert_eq!(grant.total_amount, 6_000);
+    assert_eq!(grant.milestone_count, 3);
+    assert_eq!(grant.status, GrantStatus::Active);
+    let milestone = s.client.get_milestone(&grant_id, &2);
+    assert_eq!(milestone.amount, 3_000);
+    assert_eq!(milestone.status, MilestoneStatus::Pending);
+}
+
+#[test]
+fn test_create_rejects_bad_tranches() {
+    let s = setup();
+    let token = &s.token.address;
+
+    assert_eq!(
+        s.client
+            .try_create_grant(&s.admin, &s.grantee, token, &vec![&s.env]),
+        Err(Ok(GrantError::InvalidMilestones))
+    );
+    assert_eq!(
+        s.client
+            .try_create_grant(&s.admin, &s.grantee, token, &vec![&s.env, 100, 0]),
+        Err(Ok(GrantError::InvalidAmount))
+    );
+    assert_eq!(
+        s.client
+            .try_create_grant(&s.grantee, &s.grantee, token, &vec![&s.env, 100]),
+        Err(Ok(GrantError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_approval_releases_tranche() {
+    let s = setup();
+    let grant_id = create(&s);
+
+    assert_eq!(
+        s.client.try_approve_milestone(&s.admin, &grant_id, &0),
+        Err(Ok(GrantError::InvalidStatus))
+    );
+    s.client
+        .submit_milestone(&s.grantee, &grant_id, &0, &evidence(&s.env));
+    let milestone = s.client.get_milestone(&grant_id, &0);
+    assert_eq!(milestone.status, MilestoneStatus::Submitted);
+    assert_eq!(milestone.evidence_uri, Some(evidence(&s.env)));
+
+    assert!(s.client.approve_milestone(&s.admin, &grant_id, &0));
+    assert_eq!(s.token.balance(&s.grantee), 1_000);
+    assert_eq!(s.client.get_grant(&grant_id).released_amount, 1_000);
+    assert_eq!(
+        s.client.get_milestone(&grant_id, &0).status,
+        MilestoneStatus::Approved
+    );
+    assert_eq!(
+        s.client
+            .try_submit_milestone(&s.grantee, &grant_id, &0, &evidence(&s.env)),
+        Err(Ok(GrantError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_last_tranche_completes_grant() {
+    let s = setup();
+    let grant_id = create(&s);
+
+    for idx in 0..3u32 {
+        s.client
+            .submit_milestone(&s.grantee, &grant_id, &idx, &evidence(&s.env));
+        s.client.approve_milestone(&s.admin, &grant_id, &idx);
+    }
+    assert_eq!(s.token.balance(&s.grantee), 6_000);
+    assert_eq!(s.client.get_grant(&grant_id).status, GrantStatus::Completed);
+    assert_eq!(
+        s.client.try_terminate_grant(&s.admin, &grant_id),
+        Err(Ok(GrantError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_committee_threshold() {
+    let s = setup();
+    let grant_id = create(&s);
+    let alice = Address::generate(&s.env);
+    let bob = Address::generate(&s.env);
+    let stranger = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client.try_set_approval_threshold(&s.admin, &2),
+        Err(Ok(GrantError::InvalidThreshold))
+    );
+    s.client.set_reviewer(&s.admin, &alice, &true);
+    s.client.set_reviewer(&s.admin, &bob, &true);
+    assert_eq!(s.client.get_reviewer_count(), 2);
+    s.client.set_approval_threshold(&s.admin, &2);
+
+    s.client
+        .submit_milestone(&s.grantee, &grant_id, &0, &evidence(&s.env));
+    assert_eq!(
+        s.client.try_approve_milestone(&stranger, &grant_id, &0),
+        Err(Ok(GrantError::Unauthorized))
+    );
+    assert!(!s.client.approve_milestone(&alice, &grant_id, &0));
+    assert_eq!(
+        s.client.try_approve_milestone(&alice, &grant_id, &0),
+        Err(Ok(GrantError::AlreadyApproved))
+    );
+    assert_eq!(s.token.balance(&s.grantee), 0);
+    assert!(s.client.approve_milestone(&bob, &grant_id, &0));
+    assert_eq!(s.token.balance(&s.grantee), 1_000);
+
+    // Dropping to one reviewer still leaves two approvers with the admin
+    s.client.set_reviewer(&s.admin, &bob, &false);
+    assert_eq!(
+        s.client.try_set_reviewer(&s.admin, &alice, &false),
+        Err(Ok(GrantError::InvalidThreshold))
+    );
+}
+
+#[test]
+fn test_rejection_clears_approvals() {
+    let s = setup();
+    let grant_id = create(&s);
+    let alice = Address::generate(&s.env);
+    s.client.set_reviewer(&s.admin, &alice, &true);
+    s.client.set_approval_threshold(&s.admin, &2);
+
+    s.client
+        .submit_milestone(&s.grantee, &grant_id, &0, &evidence(&s.env));
+    s.client.approve_milestone(&alice, &grant_id, &0);
+    s.client.reject_milestone(&s.admin, &grant_id, &0);
+    let milestone = s.client.get_milestone(&grant_id, &0);
+    assert_eq!(milestone.status, MilestoneStatus::Rejected);
+    assert_eq!(milestone.approvals.len(), 0);
+
+    s.client
+        .submit_milestone(&s.grantee, &grant_id, &0, &evidence(&s.env));
+    assert!(!s.client.approve_milestone(&s.admin, &grant_id, &0));
+    assert!(s.client.approve_milestone(&alice, &grant_id, &0));
+}
+
+#[test]
+fn test_only_grantee_submits() {
+    let s = setup();
+    let grant_id = create(&s);
+    let stranger = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client
+            .try_submit_milestone(&stranger, &grant_id, &0, &evidence(&s.env)),
+        Err(Ok(GrantError::Unauthorized))
+    );
+    assert_eq!(
+        s.client
+            .try_submit_milestone(&s.grantee, &grant_id, &0, &String::from_str(&s.env, "")),
+        Err(Ok(GrantError::InvalidEvidence))
+    );
+    assert_eq!(
+        s.client
+            .try_submit_milestone(&s.grantee, &grant_id, &3, &evidence(&s.env)),
+        Err(Ok(GrantError::MilestoneNotFound))
+    );
+}
+
+#[test]
+fn test_partial_termination_returns_unspent() {
+    let s = setup();
+    let grant_id = create(&s);
+
+    s.client
+        .submit_milestone(&s.grantee, &grant_id, &0, &evidence(&s.env));
+    s.client.approve_milestone(&s.admin, &grant_id, &0);
+    s.client
+        .submit_milestone(&s.grantee, &grant_id, &1, &evidence(&s.env));
+
+    assert_eq!(s.client.terminate_grant(&s.admin, &grant_id), 5_000);
+    assert_eq!(s.token.balance(&s.grantee), 1_000);
+    assert_eq!(s.token.balance(&s.treasury), 5_000);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert_eq!(
+        s.client.get_grant(&grant_id).status,
+        GrantStatus::Terminated
+    );
+    assert_eq!(
+        s.client.try_approve_milestone(&s.admin, &grant_id, &1),
+        Err(Ok(GrantError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_initialize_twice_fails() {
+    let s = setup();
+    assert_eq!(
+        s.client.try_initialize(&s.admin, &s.treasury),
+        Err(Ok(GrantError::AlreadyInitialized))
+    );
+    assert_eq!(s.client.get_admin(), s.admin);
+    assert_eq!(s.client.get_treasury(), s.treasury);
+}
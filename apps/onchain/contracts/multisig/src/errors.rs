@@ -0,0 +1,18 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MultisigError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    Unauthorized = 3,
+    InvalidConfig = 4,
+    TooManySigners = 5,
+    ProposalNotFound = 6,
+    InvalidStatus = 7,
+    ProposalExpired = 8,
+    AlreadyConfirmed = 9,
+    NotConfirmed = 10,
+    BelowThreshold = 11,
+}
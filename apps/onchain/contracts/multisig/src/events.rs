@@ -0,0 +1,45 @@
+use soroban_sdk::{contractevent, Address};
+
+/// Emitted on initialisation and whenever the signer set is replaced.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignersUpdatedEvent {
+    pub signer_count: u32,
+    pub threshold: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCreatedEvent {
+    #[topic]
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub expires_at: u64,
+}
+
+/// Emitted when a signer confirms a proposal, or withdraws a confirmation.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfirmationUpdatedEvent {
+    #[topic]
+    pub proposal_id: u64,
+    pub signer: Address,
+    pub confirmed: bool,
+    pub confirmations: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalExecutedEvent {
+    #[topic]
+    pub proposal_id: u64,
+    pub executor: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCancelledEvent {
+    #[topic]
+    pub proposal_id: u64,
+    pub cancelled_by: Address,
+}
@@ -0,0 +1,279 @@
+#![no_std]
+
+mod errors;
+mod events;
+mod storage;
+
+use errors::MultisigError;
+use soroban_sdk::{contract, contractimpl, Address, Env, Val, Vec};
+use storage::{
+    DataKey, MultisigConfig, Proposal, ProposalAction, ProposalStatus, MAX_SIGNERS,
+    PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_THRESHOLD, PROPOSAL_TTL_SECS,
+};
+
+#[contract]
+pub struct MultisigContract;
+
+#[contractimpl]
+impl MultisigContract {
+    /// `signer` must be in the current signer set and have signed
+    fn require_signer(env: &Env, signer: &Address) -> Result<MultisigConfig, MultisigError> {
+        let config = Self::get_config(env.clone())?;
+        if !config.signers.contains(signer) {
+            return Err(MultisigError::Unauthorized);
+        }
+        signer.require_auth();
+        Ok(config)
+    }
+
+    fn validate_config(signers: &Vec<Address>, threshold: u32) -> Result<(), MultisigError> {
+        if signers.len() > MAX_SIGNERS {
+            return Err(MultisigError::TooManySigners);
+        }
+        if threshold == 0 || threshold > signers.len() {
+            return Err(MultisigError::InvalidConfig);
+        }
+        for (i, signer) in signers.iter().enumerate() {
+            if signers.first_index_of(&signer) != Some(i as u32) {
+                return Err(MultisigError::InvalidConfig);
+            }
+        }
+        Ok(())
+    }
+
+    fn write_config(env: &Env, signers: Vec<Address>, threshold: u32) {
+        let signer_count = signers.len();
+        env.storage()
+            .instance()
+            .set(&DataKey::Config, &MultisigConfig { signers, threshold });
+
+        events::SignersUpdatedEvent {
+            signer_count,
+            threshold,
+        }
+        .publish(env);
+    }
+
+    fn load_proposal(env: &Env, proposal_id: u64) -> Result<Proposal, MultisigError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+            .ok_or(MultisigError::ProposalNotFound)
+    }
+
+    /// Write a proposal back and keep it clear of archival
+    fn save_proposal(env: &Env, proposal: &Proposal) {
+        let key = DataKey::Proposal(proposal.id);
+        env.storage().persistent().set(&key, proposal);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// Load a proposal that is still pending and has not expired
+    fn load_open(env: &Env, proposal_id: u64) -> Result<Proposal, MultisigError> {
+        let proposal = Self::load_proposal(env, proposal_id)?;
+        if proposal.status != ProposalStatus::Pending {
+            return Err(MultisigError::InvalidStatus);
+        }
+        if env.ledger().timestamp() > proposal.expires_at {
+            return Err(MultisigError::ProposalExpired);
+        }
+        Ok(proposal)
+    }
+
+    /// Confirmations on `proposal` from addresses still in the signer set
+    fn valid_confirmations(proposal: &Proposal, config: &MultisigConfig) -> u32 {
+        proposal
+            .confirmations
+            .iter()
+            .filter(|signer| config.signers.contains(signer))
+            .count() as u32
+    }
+
+    /// Set up the signer set; `threshold` of them must confirm every action
+    pub fn initialize(
+        env: Env,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), MultisigError> {
+        if env.storage().instance().has(&DataKey::Config) {
+            return Err(MultisigError::AlreadyInitialized);
+        }
+        Self::validate_config(&signers, threshold)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::NextProposalId, &0u64);
+        Self::write_config(&env, signers, threshold);
+        Ok(())
+    }
+
+    /// Propose an action. The proposer's own confirmation is recorded with
+    /// it. Returns the proposal ID.
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        action: ProposalAction,
+    ) -> Result<u64, MultisigError> {
+        Self::require_signer(&env, &proposer)?;
+        if let ProposalAction::SetSigners(signers, threshold) = &action {
+            Self::validate_config(signers, *threshold)?;
+        }
+
+        let proposal_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextProposalId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextProposalId, &(proposal_id + 1));
+        let now = env.ledger().timestamp();
+        let mut confirmations = Vec::new(&env);
+        confirmations.push_back(proposer.clone());
+        let proposal = Proposal {
+            id: proposal_id,
+            action,
+            proposer: proposer.clone(),
+            created_at: now,
+            expires_at: now + PROPOSAL_TTL_SECS,
+            status: ProposalStatus::Pending,
+            confirmations,
+        };
+        Self::save_proposal(&env, &proposal);
+
+        events::ProposalCreatedEvent {
+            proposal_id,
+            proposer,
+            expires_at: proposal.expires_at,
+        }
+        .publish(&env);
+        Ok(proposal_id)
+    }
+
+    /// Add `signer`'s confirmation to a pending proposal. Returns the
+    /// number of valid confirmations it now has.
+    pub fn confirm(env: Env, signer: Address, proposal_id: u64) -> Result<u32, MultisigError> {
+        let config = Self::require_signer(&env, &signer)?;
+        let mut proposal = Self::load_open(&env, proposal_id)?;
+        if proposal.confirmations.contains(&signer) {
+            return Err(MultisigError::AlreadyConfirmed);
+        }
+
+        proposal.confirmations.push_back(signer.clone());
+        Self::save_proposal(&env, &proposal);
+        let confirmations = Self::valid_confirmations(&proposal, &config);
+
+        events::ConfirmationUpdatedEvent {
+            proposal_id,
+            signer,
+            confirmed: true,
+            confirmations,
+        }
+        .publish(&env);
+        Ok(confirmations)
+    }
+
+    /// Withdraw `signer`'s confirmation before the proposal is executed.
+    /// Returns the number of valid confirmations left.
+    pub fn revoke(env: Env, signer: Address, proposal_id: u64) -> Result<u32, MultisigError> {
+        let config = Self::require_signer(&env, &signer)?;
+        let mut proposal = Self::load_open(&env, proposal_id)?;
+        let idx = proposal
+            .confirmations
+            .first_index_of(&signer)
+            .ok_or(MultisigError::NotConfirmed)?;
+
+        proposal.confirmations.remove(idx);
+        Self::save_proposal(&env, &proposal);
+        let confirmations = Self::valid_confirmations(&proposal, &config);
+
+        events::ConfirmationUpdatedEvent {
+            proposal_id,
+            signer,
+            confirmed: false,
+            confirmations,
+        }
+        .publish(&env);
+        Ok(confirmations)
+    }
+
+    /// Carry out a proposal once enough current signers have confirmed.
+    /// Any signer may execute; each proposal runs at most once.
+    pub fn execute(env: Env, executor: Address, proposal_id: u64) -> Result<(), MultisigError> {
+        let config = Self::require_signer(&env, &executor)?;
+        let mut proposal = Self::load_open(&env, proposal_id)?;
+        if Self::valid_confirmations(&proposal, &config) < config.threshold {
+            return Err(MultisigError::BelowThreshold);
+        }
+
+        // Record first so the call cannot re-enter and run it again
+        proposal.status = ProposalStatus::Executed;
+        Self::save_proposal(&env, &proposal);
+        match proposal.action {
+            ProposalAction::Invoke(contract, function, args) => {
+                env.invoke_contract::<Val>(&contract, &function, args);
+            }
+            ProposalAction::SetSigners(signers, threshold) => {
+                // Re-checked in case the set changed since it was proposed
+                Self::validate_config(&signers, threshold)?;
+                Self::write_config(&env, signers, threshold);
+            }
+        }
+
+        events::ProposalExecutedEvent {
+            proposal_id,
+            executor,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Withdraw a pending proposal. Only its proposer may, while still a
+    /// signer; anyone else who objects just declines to confirm, so no single
+    /// key can veto what the others agree on.
+    pub fn cancel(env: Env, signer: Address, proposal_id: u64) -> Result<(), MultisigError> {
+        Self::require_signer(&env, &signer)?;
+        let mut proposal = Self::load_proposal(&env, proposal_id)?;
+        if proposal.proposer != signer {
+            return Err(MultisigError::Unauthorized);
+        }
+        if proposal.status != ProposalStatus::Pending {
+            return Err(MultisigError::InvalidStatus);
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        Self::save_proposal(&env, &proposal);
+
+        events::ProposalCancelledEvent {
+            proposal_id,
+            cancelled_by: signer,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<Proposal, MultisigError> {
+        Self::load_proposal(&env, proposal_id)
+    }
+
+    /// Number of proposals ever created; IDs run from 0 to this count
+    pub fn get_proposal_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextProposalId)
+            .unwrap_or(0)
+    }
+
+    pub fn get_config(env: Env) -> Result<MultisigConfig, MultisigError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(MultisigError::NotInitialized)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,68 @@
+use soroban_sdk::{contracttype, Address, Symbol, Val, Vec};
+
+/// Proposals expire after 72 hours if they are never executed.
+pub const PROPOSAL_TTL_SECS: u64 = 72 * 60 * 60;
+
+/// Hard cap on the signer set size to keep iteration costs bounded.
+pub const MAX_SIGNERS: u32 = 20;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Proposals touched by any write are kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Config,         // -> MultisigConfig
+    Proposal(u64),  // proposal id -> Proposal
+    NextProposalId, // -> u64
+}
+
+/// The M-of-N signer set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigConfig {
+    pub signers: Vec<Address>,
+    /// Confirmations from current signers needed to execute.
+    pub threshold: u32,
+}
+
+/// What a proposal does once executed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalAction {
+    /// Call `function` on `contract` with `args`, authorised as this
+    /// contract. This is how the admin role of other contracts is used.
+    Invoke(Address, Symbol, Vec<Val>),
+    /// Replace the signer set and threshold. Kept separate from `Invoke`
+    /// because a contract cannot call back into itself.
+    SetSigners(Vec<Address>, u32),
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ProposalStatus {
+    Pending = 0,
+    Executed = 1,
+    Cancelled = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub id: u64,
+    pub action: ProposalAction,
+    pub proposer: Address,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub status: ProposalStatus,
+    /// Signers who have confirmed. Only those still in the signer set count
+    /// towards the threshold.
+    pub confirmations: Vec<Address>,
+}
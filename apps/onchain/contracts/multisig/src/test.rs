@@ -0,0 +1,235 @@
+use crate::errors::MultisigError;
+use crate::storage::{ProposalAction, ProposalStatus, PROPOSAL_TTL_SECS};
+use crate::{MultisigContract, MultisigContractClient};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, IntoVal, Symbol,
+};
+
+/// A contract administered by the multisig.
+#[contract]
+struct MockTarget;
+
+#[contractimpl]
+impl MockTarget {
+    pub fn init(env: Env, admin: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("admin"), &admin);
+    }
+
+    pub fn set_value(env: Env, value: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("admin"))
+            .unwrap();
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&symbol_short!("value"), &value);
+    }
+
+    pub fn value(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("value"))
+            .unwrap_or(0)
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    client: MultisigContractClient<'a>,
+    alice: Address,
+    bob: Address,
+    carol: Address,
+    target: MockTargetClient<'a>,
+}
+
+/// A 2-of-3 multisig that is the target's admin
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let contract_id = env.register(MultisigContract, ());
+    let client = MultisigContractClient::new(&env, &contract_id);
+    client.initialize(&vec![&env, alice.clone(), bob.clone(), carol.clone()], &2);
+
+    let target = MockTargetClient::new(&env, &env.register(MockTarget, ()));
+    target.init(&contract_id);
+
+    Setup {
+        env,
+        client,
+        alice,
+        bob,
+        carol,
+        target,
+    }
+}
+
+fn set_value(s: &Setup, value: u32) -> ProposalAction {
+    ProposalAction::Invoke(
+        s.target.address.clone(),
+        Symbol::new(&s.env, "set_value"),
+        vec![&s.env, value.into_val(&s.env)],
+    )
+}
+
+#[test]
+fn test_threshold_gates_execution() {
+    let s = setup();
+    let proposal_id = s.client.propose(&s.alice, &set_value(&s, 7));
+    assert_eq!(proposal_id, 0);
+
+    assert_eq!(
+        s.client.try_execute(&s.alice, &proposal_id),
+        Err(Ok(MultisigError::BelowThreshold))
+    );
+    assert_eq!(s.client.confirm(&s.bob, &proposal_id), 2);
+    s.client.execute(&s.carol, &proposal_id);
+
+    assert_eq!(s.target.value(), 7);
+    assert_eq!(
+        s.client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+    assert_eq!(
+        s.client.try_execute(&s.carol, &proposal_id),
+        Err(Ok(MultisigError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_only_signers_act() {
+    let s = setup();
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_propose(&stranger, &set_value(&s, 1)),
+        Err(Ok(MultisigError::Unauthorized))
+    );
+
+    let proposal_id = s.client.propose(&s.alice, &set_value(&s, 1));
+    assert_eq!(
+        s.client.try_confirm(&stranger, &proposal_id),
+        Err(Ok(MultisigError::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_confirm(&s.alice, &proposal_id),
+        Err(Ok(MultisigError::AlreadyConfirmed))
+    );
+}
+
+#[test]
+fn test_revoke_confirmation() {
+    let s = setup();
+    let proposal_id = s.client.propose(&s.alice, &set_value(&s, 3));
+    s.client.confirm(&s.bob, &proposal_id);
+
+    assert_eq!(s.client.revoke(&s.bob, &proposal_id), 1);
+    assert_eq!(
+        s.client.try_revoke(&s.bob, &proposal_id),
+        Err(Ok(MultisigError::NotConfirmed))
+    );
+    assert_eq!(
+        s.client.try_execute(&s.alice, &proposal_id),
+        Err(Ok(MultisigError::BelowThreshold))
+    );
+}
+
+#[test]
+fn test_rotate_signers() {
+    let s = setup();
+    let dave = Address::generate(&s.env);
+    let rotation = ProposalAction::SetSigners(vec![&s.env, s.alice.clone(), dave.clone()], 2);
+    let proposal_id = s.client.propose(&s.alice, &rotation);
+    s.client.confirm(&s.carol, &proposal_id);
+    s.client.execute(&s.alice, &proposal_id);
+
+    let config = s.client.get_config();
+    assert_eq!(config.threshold, 2);
+    assert_eq!(config.signers.len(), 2);
+
+    // Bob is out, and his confirmation no longer counts
+    let pending = s.client.propose(&dave, &set_value(&s, 9));
+    assert_eq!(
+        s.client.try_confirm(&s.bob, &pending),
+        Err(Ok(MultisigError::Unauthorized))
+    );
+    s.client.confirm(&s.alice, &pending);
+    s.client.execute(&dave, &pending);
+    assert_eq!(s.target.value(), 9);
+}
+
+#[test]
+fn test_removed_signer_confirmations_lapse() {
+    let s = setup();
+    let stale = s.client.propose(&s.bob, &set_value(&s, 5));
+
+    let rotation = ProposalAction::SetSigners(vec![&s.env, s.alice.clone(), s.carol.clone()], 2);
+    let rotate_id = s.client.propose(&s.alice, &rotation);
+    s.client.confirm(&s.carol, &rotate_id);
+    s.client.execute(&s.carol, &rotate_id);
+
+    // Only Alice's confirmation is left on the stale proposal
+    s.client.confirm(&s.alice, &stale);
+    assert_eq!(
+        s.client.try_execute(&s.alice, &stale),
+        Err(Ok(MultisigError::BelowThreshold))
+    );
+    assert_eq!(s.client.confirm(&s.carol, &stale), 2);
+}
+
+#[test]
+fn test_expiry_and_cancel() {
+    let s = setup();
+    let proposal_id = s.client.propose(&s.alice, &set_value(&s, 1));
+    let cancelled = s.client.propose(&s.alice, &set_value(&s, 2));
+
+    // Only the proposer can withdraw it
+    assert_eq!(
+        s.client.try_cancel(&s.bob, &cancelled),
+        Err(Ok(MultisigError::Unauthorized))
+    );
+    s.client.cancel(&s.alice, &cancelled);
+    assert_eq!(
+        s.client.try_confirm(&s.bob, &cancelled),
+        Err(Ok(MultisigError::InvalidStatus))
+    );
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + PROPOSAL_TTL_SECS + 1);
+    assert_eq!(
+        s.client.try_confirm(&s.bob, &proposal_id),
+        Err(Ok(MultisigError::ProposalExpired))
+    );
+}
+
+#[test]
+fn test_config_validation() {
+    let s = setup();
+    assert_eq!(
+        s.client.try_propose(
+            &s.alice,
+            &ProposalAction::SetSigners(vec![&s.env, s.alice.clone()], 2)
+        ),
+        Err(Ok(MultisigError::InvalidConfig))
+    );
+    assert_eq!(
+        s.client.try_propose(
+            &s.alice,
+            &ProposalAction::SetSigners(vec![&s.env, s.alice.clone(), s.alice.clone()], 1)
+        ),
+        Err(Ok(MultisigError::InvalidConfig))
+    );
+    assert_eq!(
+        s.client.try_initialize(&vec![&s.env, s.alice.clone()], &1),
+        Err(Ok(MultisigError::AlreadyInitialized))
+    );
+}
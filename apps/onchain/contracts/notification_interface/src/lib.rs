@@ -14,3 +14,8 @@ pub struct Notification {
 pub trait NotificationReceiverTrait {
     fn on_notify(env: Env, notification: Notification);
 }
+
+#[contractclient(name = "ReputationHookClient")]
+pub trait ReputationHookTrait {
+    fn on_reputation_changed(env: Env, address: Address, old_score: u64, new_score: u64);
+}
@@ -0,0 +1,22 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RoundError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    Unauthorized = 3,
+    InvalidWindow = 4,
+    InvalidAmount = 5,
+    InvalidMetadata = 6,
+    TooManyProjects = 7,
+    ProjectNotFound = 8,
+    RoundNotOpen = 9,
+    RoundNotEnded = 10,
+    AlreadyFinalized = 11,
+    NotFinalized = 12,
+    InsufficientReputation = 13,
+    AlreadyClaimed = 14,
+    SelfDonation = 15,
+}
@@ -0,0 +1,58 @@
+use soroban_sdk::{contractevent, Address};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+    pub token: Address,
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+/// Emitted when the matching pool grows.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchingFundedEvent {
+    #[topic]
+    pub funder: Address,
+    pub amount: i128,
+    pub pool: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectRegisteredEvent {
+    #[topic]
+    pub owner: Address,
+    pub project_id: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationEvent {
+    #[topic]
+    pub donor: Address,
+    #[topic]
+    pub project_id: u32,
+    pub amount: i128,
+}
+
+/// Emitted with a project's match when it claims.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchAllocatedEvent {
+    #[topic]
+    pub project_id: u32,
+    pub donations: i128,
+    pub matched: i128,
+}
+
+/// Emitted when a project owner collects donations plus match.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundsClaimedEvent {
+    #[topic]
+    pub owner: Address,
+    pub project_id: u32,
+    pub amount: i128,
+}
@@ -0,0 +1,408 @@
+#![no_std]
+
+mod errors;
+mod events;
+mod registry;
+mod storage;
+
+use errors::RoundError;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use storage::{
+    DataKey, Project, RoundConfig, MAX_PROJECTS, MAX_URI_LEN, PERSISTENT_BUMP_AMOUNT,
+    PERSISTENT_BUMP_THRESHOLD,
+};
+
+/// Integer square root, rounded down
+fn isqrt(n: i128) -> i128 {
+    if n < 2 {
+        return n.max(0);
+    }
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+#[contract]
+pub struct QfRoundContract;
+
+#[contractimpl]
+impl QfRoundContract {
+    /// Check that `admin` is the stored admin and has signed
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), RoundError> {
+        let stored_admin = Self::get_admin(env.clone())?;
+        if *admin != stored_admin {
+            return Err(RoundError::Unauthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
+
+    fn load_project(env: &Env, project_id: u32) -> Result<Project, RoundError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Project(project_id))
+            .ok_or(RoundError::ProjectNotFound)
+    }
+
+    /// Write a project back and keep it clear of archival
+    fn save_project(env: &Env, project: &Project) {
+        let key = DataKey::Project(project.id);
+        env.storage().persistent().set(&key, project);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// A project's quadratic score: `(Σ √cᵢ)² − Σ cᵢ`, the match it would
+    /// get with an unlimited pool
+    fn qf_score(project: &Project) -> i128 {
+        (project.sqrt_sum * project.sqrt_sum - project.donations).max(0)
+    }
+
+    fn instance_i128(env: &Env, key: &DataKey) -> i128 {
+        env.storage().instance().get(key).unwrap_or(0)
+    }
+
+    /// `project`'s share of the matching pool at the current total score
+    fn match_of(env: &Env, project: &Project) -> i128 {
+        let total_score = Self::instance_i128(env, &DataKey::TotalScore);
+        if total_score == 0 {
+            return 0;
+        }
+        Self::get_matching_pool(env.clone()) * Self::qf_score(project) / total_score
+    }
+
+    /// Initialize the round. Donations are accepted between `start_time`
+    /// and `end_time` in `token`, from donors with at least
+    /// `min_reputation` in `registry`.
+    pub fn initialize(env: Env, admin: Address, config: RoundConfig) -> Result<(), RoundError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(RoundError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        if config.end_time <= config.start_time {
+            return Err(RoundError::InvalidWindow);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Round, &config);
+        env.storage().instance().set(&DataKey::MatchingPool, &0i128);
+        env.storage().instance().set(&DataKey::ProjectCount, &0u32);
+
+        events::InitializedEvent {
+            admin,
+            token: config.token,
+            start_time: config.start_time,
+            end_time: config.end_time,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Add to the matching pool. Anyone may, until the round is finalized.
+    pub fn fund_matching(env: Env, funder: Address, amount: i128) -> Result<i128, RoundError> {
+        funder.require_auth();
+        let config = Self::get_round(env.clone())?;
+        if Self::is_finalized(env.clone()) {
+            return Err(RoundError::AlreadyFinalized);
+        }
+        if amount <= 0 {
+            return Err(RoundError::InvalidAmount);
+        }
+
+        TokenClient::new(&env, &config.token).transfer(
+            &funder,
+            env.current_contract_address(),
+            &amount,
+        );
+        let pool = Self::get_matching_pool(env.clone()) + amount;
+        env.storage().instance().set(&DataKey::MatchingPool, &pool);
+
+        events::MatchingFundedEvent {
+            funder,
+            amount,
+            pool,
+        }
+        .publish(&env);
+        Ok(pool)
+    }
+
+    /// Enter a project in the round; open until donations close. Returns
+    /// the project ID.
+    pub fn register_project(
+        env: Env,
+        owner: Address,
+        metadata_uri: String,
+    ) -> Result<u32, RoundError> {
+        owner.require_auth();
+        let config = Self::get_round(env.clone())?;
+        if env.ledger().timestamp() >= config.end_time {
+            return Err(RoundError::RoundNotOpen);
+        }
+        if metadata_uri.is_empty() || metadata_uri.len() > MAX_URI_LEN {
+            return Err(RoundError::InvalidMetadata);
+        }
+        let project_id = Self::get_project_count(env.clone());
+        if project_id >= MAX_PROJECTS {
+            return Err(RoundError::TooManyProjects);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ProjectCount, &(project_id + 1));
+        let project = Project {
+            id: project_id,
+            owner: owner.clone(),
+            metadata_uri,
+            donations: 0,
+            donor_count: 0,
+            sqrt_sum: 0,
+            matched: 0,
+            claimed: false,
+        };
+        Self::save_project(&env, &project);
+
+        events::ProjectRegisteredEvent { owner, project_id }.publish(&env);
+        Ok(project_id)
+    }
+
+    /// Donate `amount` to a project while the round is open. The donor must
+    /// meet the round's registry reputation floor and cannot fund their own
+    /// project.
+    pub fn donate(
+        env: Env,
+        donor: Address,
+        project_id: u32,
+        amount: i128,
+    ) -> Result<(), RoundError> {
+        donor.require_auth();
+        let config = Self::get_round(env.clone())?;
+        let now = env.ledger().timestamp();
+        if now < config.start_time || now >= config.end_time {
+            return Err(RoundError::RoundNotOpen);
+        }
+        if amount <= 0 {
+            return Err(RoundError::InvalidAmount);
+        }
+        let mut project = Self::load_project(&env, project_id)?;
+        if donor == project.owner {
+            return Err(RoundError::SelfDonation);
+        }
+        if registry::reputation_of(&env, &config.registry, &donor) < config.min_reputation {
+            return Err(RoundError::InsufficientReputation);
+        }
+
+        TokenClient::new(&env, &config.token).transfer(
+            &donor,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        // Only each donor's total counts, so splitting a gift into many
+        // small donations earns no extra match
+        let donated_key = DataKey::Donated(project_id, donor.clone());
+        let before: i128 = env.storage().persistent().get(&donated_key).unwrap_or(0);
+        let after = before + amount;
+        if before == 0 {
+            project.donor_count += 1;
+        }
+        let old_score = Self::qf_score(&project);
+        project.donations += amount;
+        project.sqrt_sum += isqrt(after) - isqrt(before);
+        Self::save_project(&env, &project);
+        // Kept current so neither finalizing nor claiming walks every project
+        let total_score =
+            Self::instance_i128(&env, &DataKey::TotalScore) + Self::qf_score(&project) - old_score;
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalScore, &total_score);
+        env.storage().persistent().set(&donated_key, &after);
+        env.storage().persistent().extend_ttl(
+            &donated_key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+
+        events::DonationEvent {
+            donor,
+            project_id,
+            amount,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Close the round. Each project's share of the matching pool is in
+    /// proportion to its quadratic score and is worked out when it claims.
+    /// Anyone may trigger it after `end_time`. If nobody qualified for a
+    /// match the whole pool goes back to the admin now; otherwise rounding
+    /// dust follows with the last claim.
+    pub fn finalize(env: Env) -> Result<(), RoundError> {
+        let config = Self::get_round(env.clone())?;
+        if env.ledger().timestamp() < config.end_time {
+            return Err(RoundError::RoundNotEnded);
+        }
+        if Self::is_finalized(env.clone()) {
+            return Err(RoundError::AlreadyFinalized);
+        }
+        env.storage().instance().set(&DataKey::Finalized, &true);
+
+        let pool = Self::get_matching_pool(env.clone());
+        if Self::instance_i128(&env, &DataKey::TotalScore) == 0 && pool > 0 {
+            TokenClient::new(&env, &config.token).transfer(
+                &env.current_contract_address(),
+                &Self::get_admin(env.clone())?,
+                &pool,
+            );
+        }
+        Ok(())
+    }
+
+    /// Pay a project's donations plus its match to its owner once the
+    /// round is finalized. Returns the amount paid.
+    pub fn claim(env: Env, owner: Address, project_id: u32) -> Result<i128, RoundError> {
+        let mut project = Self::load_project(&env, project_id)?;
+        if owner != project.owner {
+            return Err(RoundError::Unauthorized);
+        }
+        owner.require_auth();
+        if !Self::is_finalized(env.clone()) {
+            return Err(RoundError::NotFinalized);
+        }
+        if project.claimed {
+            return Err(RoundError::AlreadyClaimed);
+        }
+
+        let config = Self::get_round(env.clone())?;
+        let token = TokenClient::new(&env, &config.token);
+        project.matched = Self::match_of(&env, &project);
+        project.claimed = true;
+        Self::save_project(&env, &project);
+        let amount = project.donations + project.matched;
+        if amount > 0 {
+            token.transfer(&env.current_contract_address(), &owner, &amount);
+        }
+
+        let paid = Self::instance_i128(&env, &DataKey::MatchPaid) + project.matched;
+        env.storage().instance().set(&DataKey::MatchPaid, &paid);
+        let claimed_count: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ClaimedCount)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::ClaimedCount, &claimed_count);
+        // The last claim sends the rounding dust back to the admin
+        let dust = Self::get_matching_pool(env.clone()) - paid;
+        if claimed_count == Self::get_project_count(env.clone())
+            && dust > 0
+            && Self::instance_i128(&env, &DataKey::TotalScore) > 0
+        {
+            token.transfer(
+                &env.current_contract_address(),
+                &Self::get_admin(env.clone())?,
+                &dust,
+            );
+        }
+
+        events::MatchAllocatedEvent {
+            project_id,
+            donations: project.donations,
+            matched: project.matched,
+        }
+        .publish(&env);
+        events::FundsClaimedEvent {
+            owner,
+            project_id,
+            amount,
+        }
+        .publish(&env);
+        Ok(amount)
+    }
+
+    /// Lower or raise the donor reputation floor before the round opens
+    pub fn set_min_reputation(
+        env: Env,
+        admin: Address,
+        min_reputation: u64,
+    ) -> Result<(), RoundError> {
+        Self::require_admin(&env, &admin)?;
+        let mut config = Self::get_round(env.clone())?;
+        if env.ledger().timestamp() >= config.start_time {
+            return Err(RoundError::RoundNotOpen);
+        }
+        config.min_reputation = min_reputation;
+        env.storage().instance().set(&DataKey::Round, &config);
+        Ok(())
+    }
+
+    /// The match a project would get if the round were finalized now, or
+    /// has been given once claimed
+    pub fn estimate_match(env: Env, project_id: u32) -> Result<i128, RoundError> {
+        let project = Self::load_project(&env, project_id)?;
+        if project.claimed {
+            return Ok(project.matched);
+        }
+        Ok(Self::match_of(&env, &project))
+    }
+
+    pub fn get_project(env: Env, project_id: u32) -> Result<Project, RoundError> {
+        Self::load_project(&env, project_id)
+    }
+
+    /// Number of projects registered; IDs run from 0 to this count
+    pub fn get_project_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProjectCount)
+            .unwrap_or(0)
+    }
+
+    /// What `donor` has given a project in total
+    pub fn get_donation(env: Env, project_id: u32, donor: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Donated(project_id, donor))
+            .unwrap_or(0)
+    }
+
+    pub fn get_matching_pool(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MatchingPool)
+            .unwrap_or(0)
+    }
+
+    pub fn is_finalized(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Finalized)
+            .unwrap_or(false)
+    }
+
+    pub fn get_round(env: Env) -> Result<RoundConfig, RoundError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Round)
+            .ok_or(RoundError::NotInitialized)
+    }
+
+    /// Get admin address
+    pub fn get_admin(env: Env) -> Result<Address, RoundError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(RoundError::NotInitialized)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,18 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// The slice of the contributor registry's interface this contract reads.
+// Only the generated client is called; the trait itself is never implemented.
+#[allow(dead_code)]
+#[contractclient(name = "RegistryClient")]
+pub trait Registry {
+    fn get_reputation(env: Env, contributor: Address) -> u64;
+}
+
+/// The contributor's score in `registry`; unregistered addresses, or any
+/// failure in the registry, count as zero.
+pub fn reputation_of(env: &Env, registry: &Address, contributor: &Address) -> u64 {
+    match RegistryClient::new(env, registry).try_get_reputation(contributor) {
+        Ok(Ok(score)) => score,
+        _ => 0,
+    }
+}
@@ -0,0 +1,60 @@
+use soroban_sdk::{contracttype, Address, String};
+
+/// Upper bound on projects in one round.
+pub const MAX_PROJECTS: u32 = 40;
+
+/// Longest metadata URI accepted, in bytes.
+pub const MAX_URI_LEN: u32 = 256;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Round data touched by any write is kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,                 // -> Address
+    Round,                 // -> RoundConfig
+    MatchingPool,          // -> i128 funded for matching
+    Finalized,             // -> bool
+    TotalScore,            // -> i128 sum of every project's quadratic score
+    MatchPaid,             // -> i128 matching paid out so far
+    ClaimedCount,          // -> u32 projects that have claimed
+    Project(u32),          // project id -> Project
+    ProjectCount,          // -> u32
+    Donated(u32, Address), // (project id, donor) -> i128 donated in total
+}
+
+/// The round's fixed parameters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundConfig {
+    /// Contributor registry donor reputation is read from.
+    pub registry: Address,
+    /// Asset donations and matching are paid in.
+    pub token: Address,
+    pub start_time: u64,
+    pub end_time: u64,
+    /// Donors need at least this registry score; the round's sybil filter.
+    pub min_reputation: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Project {
+    pub id: u32,
+    pub owner: Address,
+    pub metadata_uri: String,
+    pub donations: i128,
+    pub donor_count: u32,
+    /// Sum over donors of the square root of what each gave in total.
+    pub sqrt_sum: i128,
+    /// Share of the matching pool, set when the project claims.
+    pub matched: i128,
+    pub claimed: bool,
+}
@@ -0,0 +1,279 @@
+use crate::errors::RoundError;
+use crate::storage::RoundConfig;
+use crate::{QfRoundContract, QfRoundContractClient};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String,
+};
+
+#[contracttype]
+enum MockKey {
+    Reputation(Address),
+}
+
+/// Stands in for the contributor registry's reputation lookup
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_reputation(env: Env, contributor: Address, score: u64) {
+        env.storage()
+            .persistent()
+            .set(&MockKey::Reputation(contributor), &score);
+    }
+
+    pub fn get_reputation(env: Env, contributor: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&MockKey::Reputation(contributor))
+            .unwrap_or(0)
+    }
+}
+
+const MIN_REPUTATION: u64 = 10;
+
+struct Setup<'a> {
+    env: Env,
+    client: QfRoundContractClient<'a>,
+    registry: MockRegistryClient<'a>,
+    admin: Address,
+    token: TokenClient<'a>,
+    minter: StellarAssetClient<'a>,
+    start: u64,
+    end: u64,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let minter = StellarAssetClient::new(&env, &asset.address());
+    let token = TokenClient::new(&env, &asset.address());
+    let registry = MockRegistryClient::new(&env, &env.register(MockRegistry, ()));
+
+    let contract_id = env.register(QfRoundContract, ());
+    let client = QfRoundContractClient::new(&env, &contract_id);
+    let start = 2_000;
+    let end = 3_000;
+    client.initialize(
+        &admin,
+        &RoundConfig {
+            registry: registry.address.clone(),
+            token: asset.address(),
+            start_time: start,
+            end_time: end,
+            min_reputation: MIN_REPUTATION,
+        },
+    );
+
+    Setup {
+        env,
+        client,
+        registry,
+        admin,
+        token,
+        minter,
+        start,
+        end,
+    }
+}
+
+fn project(s: &Setup) -> (Address, u32) {
+    let owner = Address::generate(&s.env);
+    let uri = String::from_str(&s.env, "ipfs://project");
+    let project_id = s.client.register_project(&owner, &uri);
+    (owner, project_id)
+}
+
+/// A funded donor with enough reputation to take part
+fn donor(s: &Setup) -> Address {
+    let donor = Address::generate(&s.env);
+    s.minter.mint(&donor, &10_000);
+    s.registry.set_reputation(&donor, &MIN_REPUTATION);
+    donor
+}
+
+fn fund(s: &Setup, amount: i128) {
+    let funder = Address::generate(&s.env);
+    s.minter.mint(&funder, &amount);
+    s.client.fund_matching(&funder, &amount);
+}
+
+fn warp(s: &Setup, to: u64) {
+    s.env.ledger().set_timestamp(to);
+}
+
+#[test]
+fn test_initialize_rejects_bad_window_and_twice() {
+    let s = setup();
+    let config = s.client.get_round();
+    assert_eq!(
+        s.client.try_initialize(&s.admin, &config),
+        Err(Ok(RoundError::AlreadyInitialized))
+    );
+
+    let other = QfRoundContractClient::new(&s.env, &s.env.register(QfRoundContract, ()));
+    let inverted = RoundConfig {
+        start_time: config.end_time,
+        end_time: config.start_time,
+        ..config
+    };
+    assert_eq!(
+        other.try_initialize(&s.admin, &inverted),
+        Err(Ok(RoundError::InvalidWindow))
+    );
+}
+
+#[test]
+fn test_many_small_donors_outmatch_one_large() {
+    let s = setup();
+    fund(&s, 1_000);
+    let (_, broad) = project(&s);
+    let (_, narrow) = project(&s);
+    warp(&s, s.start);
+
+    // 400 from four donors against 400 from one
+    for _ in 0..4 {
+        s.client.donate(&donor(&s), &broad, &100);
+    }
+    s.client.donate(&donor(&s), &narrow, &400);
+
+    assert_eq!(s.client.get_project(&broad).donor_count, 4);
+    assert_eq!(s.client.estimate_match(&broad), 1_000);
+    assert_eq!(s.client.estimate_match(&narrow), 0);
+}
+
+#[test]
+fn test_split_donations_earn_no_extra_match() {
+    let s = setup();
+    let (_, project_id) = project(&s);
+    warp(&s, s.start);
+    let donor = donor(&s);
+
+    for _ in 0..4 {
+        s.client.donate(&donor, &project_id, &100);
+    }
+
+    let project = s.client.get_project(&project_id);
+    assert_eq!(project.donations, 400);
+    assert_eq!(project.donor_count, 1);
+    assert_eq!(project.sqrt_sum, 20);
+    assert_eq!(s.client.get_donation(&project_id, &donor), 400);
+}
+
+#[test]
+fn test_donate_checks_window_reputation_and_owner() {
+    let s = setup();
+    let (owner, project_id) = project(&s);
+    let donor = donor(&s);
+
+    assert_eq!(
+        s.client.try_donate(&donor, &project_id, &100),
+        Err(Ok(RoundError::RoundNotOpen))
+    );
+    warp(&s, s.start);
+
+    let newcomer = Address::generate(&s.env);
+    s.minter.mint(&newcomer, &1_000);
+    s.registry.set_reputation(&newcomer, &(MIN_REPUTATION - 1));
+    assert_eq!(
+        s.client.try_donate(&newcomer, &project_id, &100),
+        Err(Ok(RoundError::InsufficientReputation))
+    );
+    s.registry.set_reputation(&owner, &MIN_REPUTATION);
+    assert_eq!(
+        s.client.try_donate(&owner, &project_id, &100),
+        Err(Ok(RoundError::SelfDonation))
+    );
+    assert_eq!(
+        s.client.try_donate(&donor, &project_id, &0),
+        Err(Ok(RoundError::InvalidAmount))
+    );
+
+    warp(&s, s.end);
+    assert_eq!(
+        s.client.try_donate(&donor, &project_id, &100),
+        Err(Ok(RoundError::RoundNotOpen))
+    );
+    assert_eq!(
+        s.client
+            .try_register_project(&owner, &String::from_str(&s.env, "ipfs://late")),
+        Err(Ok(RoundError::RoundNotOpen))
+    );
+}
+
+#[test]
+fn test_finalize_and_claim() {
+    let s = setup();
+    fund(&s, 1_000);
+    let (broad_owner, broad) = project(&s);
+    let (narrow_owner, narrow) = project(&s);
+    warp(&s, s.start);
+    for _ in 0..4 {
+        s.client.donate(&donor(&s), &broad, &100);
+    }
+    for _ in 0..2 {
+        s.client.donate(&donor(&s), &narrow, &100);
+    }
+
+    assert_eq!(s.client.try_finalize(), Err(Ok(RoundError::RoundNotEnded)));
+    assert_eq!(
+        s.client.try_claim(&broad_owner, &broad),
+        Err(Ok(RoundError::NotFinalized))
+    );
+    warp(&s, s.end);
+    s.client.finalize();
+    assert!(s.client.is_finalized());
+    assert_eq!(
+        s.client.try_finalize(),
+        Err(Ok(RoundError::AlreadyFinalized))
+    );
+
+    // Scores 1_200 and 200
+    assert_eq!(s.client.estimate_match(&broad), 857);
+    assert_eq!(s.client.estimate_match(&narrow), 142);
+
+    assert_eq!(
+        s.client.try_claim(&narrow_owner, &broad),
+        Err(Ok(RoundError::Unauthorized))
+    );
+    assert_eq!(s.client.claim(&broad_owner, &broad), 1_257);
+    assert_eq!(s.token.balance(&s.admin), 0);
+    assert_eq!(s.client.claim(&narrow_owner, &narrow), 342);
+    assert_eq!(s.client.get_project(&broad).matched, 857);
+    assert_eq!(s.client.get_project(&narrow).matched, 142);
+    assert_eq!(s.token.balance(&broad_owner), 1_257);
+    // The unit of rounding dust goes back to the admin with the last claim
+    assert_eq!(s.token.balance(&s.admin), 1);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert_eq!(
+        s.client.try_claim(&broad_owner, &broad),
+        Err(Ok(RoundError::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_unmatched_pool_returns_to_admin() {
+    let s = setup();
+    fund(&s, 1_000);
+    let (owner, project_id) = project(&s);
+    warp(&s, s.start);
+    // A single donor gives a zero quadratic score
+    s.client.donate(&donor(&s), &project_id, &500);
+
+    warp(&s, s.end);
+    s.client.finalize();
+
+    assert_eq!(s.token.balance(&s.admin), 1_000);
+    assert_eq!(s.client.claim(&owner, &project_id), 500);
+    assert_eq!(
+        s.client.try_fund_matching(&s.admin, &1),
+        Err(Ok(RoundError::AlreadyFinalized))
+    );
+}
@@ -0,0 +1,18 @@
+use soroban_sdk::{contractclient, Address, Env, Symbol};
+
+/// The slice of the badges contract's interface this contract reads.
+// Only the generated client is called; the trait itself is never implemented.
+#[allow(dead_code)]
+#[contractclient(name = "BadgesClient")]
+pub trait Badges {
+    fn has_badge(env: Env, owner: Address, badge_type: Symbol) -> bool;
+}
+
+/// Whether `owner` holds `badge_type`; any failure in the badges contract
+/// counts as not holding it.
+pub fn holds(env: &Env, badges: &Address, owner: &Address, badge_type: &Symbol) -> bool {
+    matches!(
+        BadgesClient::new(env, badges).try_has_badge(owner, badge_type),
+        Ok(Ok(true))
+    )
+}
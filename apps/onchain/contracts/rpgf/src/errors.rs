@@ -0,0 +1,25 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RetroError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    Unauthorized = 3,
+    InvalidConfig = 4,
+    InvalidAmount = 5,
+    InvalidMetadata = 6,
+    NotBadgeHolder = 7,
+    AlreadyNominated = 8,
+    TooManyNominations = 9,
+    NominationNotFound = 10,
+    NominationsClosed = 11,
+    VotingNotOpen = 12,
+    OverBudget = 13,
+    SelfVote = 14,
+    VotingNotEnded = 15,
+    AlreadyFinalized = 16,
+    NotFinalized = 17,
+    AlreadyPaid = 18,
+}
@@ -0,0 +1,56 @@
+use soroban_sdk::{contractevent, Address};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+    pub vesting: Address,
+    pub nomination_end: u64,
+    pub voting_end: u64,
+}
+
+/// Emitted when the payout budget grows.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BudgetFundedEvent {
+    #[topic]
+    pub funder: Address,
+    pub amount: i128,
+    pub budget: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NominatedEvent {
+    #[topic]
+    pub contributor: Address,
+    pub nominated_by: Address,
+    pub nomination_id: u32,
+}
+
+/// Emitted whenever a voter casts or replaces their ballot.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BallotCastEvent {
+    #[topic]
+    pub voter: Address,
+    pub points: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FinalizedEvent {
+    pub total_votes: u64,
+    pub budget: i128,
+}
+
+/// Emitted when a payout is pushed into the vesting wallet.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutEvent {
+    #[topic]
+    pub contributor: Address,
+    pub nomination_id: u32,
+    pub amount: i128,
+    pub schedule_id: u32,
+}
@@ -0,0 +1,426 @@
+#![no_std]
+
+mod badges;
+mod errors;
+mod events;
+mod storage;
+mod vesting;
+
+use errors::RetroError;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Env, String, TryFromVal, Val, Vec};
+use storage::{
+    Allocation, DataKey, Nomination, RoundConfig, MAX_NOMINATIONS, MAX_URI_LEN,
+    PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_THRESHOLD,
+};
+use vesting::{VestingClient, VestingCurve};
+
+#[contract]
+pub struct RetroFundingContract;
+
+#[contractimpl]
+impl RetroFundingContract {
+    /// `account` must hold the round's voter badge and have signed
+    fn require_badge_holder(
+        env: &Env,
+        config: &RoundConfig,
+        account: &Address,
+    ) -> Result<(), RetroError> {
+        account.require_auth();
+        if !badges::holds(env, &config.badges, account, &config.voter_badge) {
+            return Err(RetroError::NotBadgeHolder);
+        }
+        Ok(())
+    }
+
+    fn load_nomination(env: &Env, nomination_id: u32) -> Result<Nomination, RetroError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Nomination(nomination_id))
+            .ok_or(RetroError::NominationNotFound)
+    }
+
+    /// Write a nomination back and keep it clear of archival
+    fn save_nomination(env: &Env, nomination: &Nomination) {
+        let key = DataKey::Nomination(nomination.id);
+        env.storage().persistent().set(&key, nomination);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    fn instance_get<V: TryFromVal<Env, Val> + Default>(env: &Env, key: &DataKey) -> V {
+        env.storage().instance().get(key).unwrap_or_default()
+    }
+
+    /// The nomination's share of the budget, pro rata to its votes
+    fn payout_of(env: &Env, nomination: &Nomination) -> i128 {
+        let total_votes = Self::get_total_votes(env.clone());
+        if total_votes == 0 {
+            return 0;
+        }
+        Self::get_budget(env.clone()) * nomination.votes as i128 / total_votes as i128
+    }
+
+    fn set_total_votes(env: &Env, total_votes: u64) {
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalVotes, &total_votes);
+    }
+
+    /// Initialize the round. Badge holders nominate until `nomination_end`
+    /// and vote until `voting_end`; payouts vest linearly over
+    /// `payout_duration` through `vesting`, in which this contract must be a
+    /// schedule creator.
+    pub fn initialize(env: Env, admin: Address, config: RoundConfig) -> Result<(), RetroError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(RetroError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        if config.voting_end <= config.nomination_end
+            || config.points_per_voter == 0
+            || config.payout_duration == 0
+        {
+            return Err(RetroError::InvalidConfig);
+        }
+        // The wallet funds schedules in its own token only
+        match VestingClient::new(&env, &config.vesting).try_get_token() {
+            Ok(Ok(token)) if token == config.token => {}
+            _ => return Err(RetroError::InvalidConfig),
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Round, &config);
+        env.storage().instance().set(&DataKey::Budget, &0i128);
+        env.storage()
+            .instance()
+            .set(&DataKey::NominationCount, &0u32);
+        Self::set_total_votes(&env, 0);
+
+        events::InitializedEvent {
+            admin,
+            vesting: config.vesting,
+            nomination_end: config.nomination_end,
+            voting_end: config.voting_end,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Add to the payout budget. Anyone may, until the round is finalized.
+    pub fn fund(env: Env, funder: Address, amount: i128) -> Result<i128, RetroError> {
+        funder.require_auth();
+        let config = Self::get_round(env.clone())?;
+        if Self::is_finalized(env.clone()) {
+            return Err(RetroError::AlreadyFinalized);
+        }
+        if amount <= 0 {
+            return Err(RetroError::InvalidAmount);
+        }
+
+        TokenClient::new(&env, &config.token).transfer(
+            &funder,
+            env.current_contract_address(),
+            &amount,
+        );
+        let budget = Self::get_budget(env.clone()) + amount;
+        env.storage().instance().set(&DataKey::Budget, &budget);
+
+        events::BudgetFundedEvent {
+            funder,
+            amount,
+            budget,
+        }
+        .publish(&env);
+        Ok(budget)
+    }
+
+    /// Nominate `contributor` for past work described at `description_uri`.
+    /// Any badge holder may, before nominations close; each contributor is
+    /// nominated at most once. Returns the nomination ID.
+    pub fn nominate(
+        env: Env,
+        nominator: Address,
+        contributor: Address,
+        description_uri: String,
+    ) -> Result<u32, RetroError> {
+        let config = Self::get_round(env.clone())?;
+        Self::require_badge_holder(&env, &config, &nominator)?;
+        if env.ledger().timestamp() >= config.nomination_end {
+            return Err(RetroError::NominationsClosed);
+        }
+        if description_uri.is_empty() || description_uri.len() > MAX_URI_LEN {
+            return Err(RetroError::InvalidMetadata);
+        }
+        let nominated_key = DataKey::Nominated(contributor.clone());
+        if env.storage().persistent().has(&nominated_key) {
+            return Err(RetroError::AlreadyNominated);
+        }
+        let nomination_id = Self::get_nomination_count(env.clone());
+        if nomination_id >= MAX_NOMINATIONS {
+            return Err(RetroError::TooManyNominations);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::NominationCount, &(nomination_id + 1));
+        env.storage()
+            .persistent()
+            .set(&nominated_key, &nomination_id);
+        Self::save_nomination(
+            &env,
+            &Nomination {
+                id: nomination_id,
+                contributor: contributor.clone(),
+                nominated_by: nominator.clone(),
+                description_uri,
+                votes: 0,
+                payout: 0,
+                schedule_id: None,
+            },
+        );
+
+        events::NominatedEvent {
+            contributor,
+            nominated_by: nominator,
+            nomination_id,
+        }
+        .publish(&env);
+        Ok(nomination_id)
+    }
+
+    /// Spread up to the round's `points_per_voter` across nominations.
+    /// Casting again replaces the voter's earlier ballot. Voters cannot
+    /// back their own nomination. Returns the points cast.
+    pub fn vote(env: Env, voter: Address, allocations: Vec<Allocation>) -> Result<u32, RetroError> {
+        let config = Self::get_round(env.clone())?;
+        Self::require_badge_holder(&env, &config, &voter)?;
+        let now = env.ledger().timestamp();
+        if now < config.nomination_end || now >= config.voting_end {
+            return Err(RetroError::VotingNotOpen);
+        }
+
+        let mut points: u32 = 0;
+        for allocation in allocations.iter() {
+            if allocation.points == 0 {
+                return Err(RetroError::InvalidAmount);
+            }
+            points = points
+                .checked_add(allocation.points)
+                .ok_or(RetroError::OverBudget)?;
+            let nomination = Self::load_nomination(&env, allocation.nomination_id)?;
+            if nomination.contributor == voter {
+                return Err(RetroError::SelfVote);
+            }
+        }
+        if points > config.points_per_voter {
+            return Err(RetroError::OverBudget);
+        }
+
+        let ballot_key = DataKey::Ballot(voter.clone());
+        let mut total_votes = Self::get_total_votes(env.clone());
+        let mut backed: u32 = Self::instance_get(&env, &DataKey::Backed);
+        let previous: Vec<Allocation> = env
+            .storage()
+            .persistent()
+            .get(&ballot_key)
+            .unwrap_or(Vec::new(&env));
+        for allocation in previous.iter() {
+            let mut nomination = Self::load_nomination(&env, allocation.nomination_id)?;
+            nomination.votes -= allocation.points as u64;
+            total_votes -= allocation.points as u64;
+            if nomination.votes == 0 {
+                backed -= 1;
+            }
+            Self::save_nomination(&env, &nomination);
+        }
+        for allocation in allocations.iter() {
+            let mut nomination = Self::load_nomination(&env, allocation.nomination_id)?;
+            if nomination.votes == 0 {
+                backed += 1;
+            }
+            nomination.votes += allocation.points as u64;
+            total_votes += allocation.points as u64;
+            Self::save_nomination(&env, &nomination);
+        }
+        Self::set_total_votes(&env, total_votes);
+        env.storage().instance().set(&DataKey::Backed, &backed);
+        env.storage().persistent().set(&ballot_key, &allocations);
+        env.storage().persistent().extend_ttl(
+            &ballot_key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+
+        events::BallotCastEvent { voter, points }.publish(&env);
+        Ok(points)
+    }
+
+    /// Close voting, fixing the budget and tallies. Each nomination's share,
+    /// pro rata to its votes, is worked out when it is distributed, so
+    /// closing costs the same however many nominations there are. If nobody
+    /// voted the whole budget goes back to the admin. Anyone may trigger it
+    /// after `voting_end`.
+    pub fn finalize(env: Env) -> Result<(), RetroError> {
+        let config = Self::get_round(env.clone())?;
+        if env.ledger().timestamp() < config.voting_end {
+            return Err(RetroError::VotingNotEnded);
+        }
+        if Self::is_finalized(env.clone()) {
+            return Err(RetroError::AlreadyFinalized);
+        }
+
+        let budget = Self::get_budget(env.clone());
+        let total_votes = Self::get_total_votes(env.clone());
+        env.storage().instance().set(&DataKey::Finalized, &true);
+        if total_votes == 0 && budget > 0 {
+            TokenClient::new(&env, &config.token).transfer(
+                &env.current_contract_address(),
+                &Self::get_admin(env.clone())?,
+                &budget,
+            );
+        }
+
+        events::FinalizedEvent {
+            total_votes,
+            budget,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Push a finalized nomination's payout into the vesting wallet as a
+    /// linear schedule starting now. Anyone may trigger it, once per
+    /// nomination; the last backed nomination paid out also returns the
+    /// rounding dust to the admin. Returns the vesting schedule ID.
+    pub fn distribute(env: Env, nomination_id: u32) -> Result<u32, RetroError> {
+        let config = Self::get_round(env.clone())?;
+        if !Self::is_finalized(env.clone()) {
+            return Err(RetroError::NotFinalized);
+        }
+        let mut nomination = Self::load_nomination(&env, nomination_id)?;
+        if nomination.schedule_id.is_some() {
+            return Err(RetroError::AlreadyPaid);
+        }
+        nomination.payout = Self::payout_of(&env, &nomination);
+        if nomination.payout == 0 {
+            return Err(RetroError::InvalidAmount);
+        }
+
+        // The wallet funds new schedules from deposited surplus first, so
+        // paying it directly means it never pulls from this contract
+        let contract = env.current_contract_address();
+        TokenClient::new(&env, &config.token).transfer(
+            &contract,
+            &config.vesting,
+            &nomination.payout,
+        );
+        let schedule_id = VestingClient::new(&env, &config.vesting).create_vesting(
+            &contract,
+            &nomination.contributor,
+            &nomination.payout,
+            &env.ledger().timestamp(),
+            &config.payout_duration,
+            &0,
+            &VestingCurve::Linear,
+            &false,
+        );
+        nomination.schedule_id = Some(schedule_id);
+        Self::save_nomination(&env, &nomination);
+
+        let distributed: i128 =
+            Self::instance_get::<i128>(&env, &DataKey::Distributed) + nomination.payout;
+        let count: u32 = Self::instance_get::<u32>(&env, &DataKey::DistributedCount) + 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::Distributed, &distributed);
+        env.storage()
+            .instance()
+            .set(&DataKey::DistributedCount, &count);
+        let dust = Self::get_budget(env.clone()) - distributed;
+        if count == Self::instance_get::<u32>(&env, &DataKey::Backed) && dust > 0 {
+            TokenClient::new(&env, &config.token).transfer(
+                &contract,
+                &Self::get_admin(env.clone())?,
+                &dust,
+            );
+        }
+
+        events::PayoutEvent {
+            contributor: nomination.contributor,
+            nomination_id,
+            amount: nomination.payout,
+            schedule_id,
+        }
+        .publish(&env);
+        Ok(schedule_id)
+    }
+
+    pub fn get_nomination(env: Env, nomination_id: u32) -> Result<Nomination, RetroError> {
+        Self::load_nomination(&env, nomination_id)
+    }
+
+    /// What the nomination is paid on distribution at the current tallies;
+    /// final once the round is finalized
+    pub fn estimate_payout(env: Env, nomination_id: u32) -> Result<i128, RetroError> {
+        let nomination = Self::load_nomination(&env, nomination_id)?;
+        if nomination.schedule_id.is_some() {
+            return Ok(nomination.payout);
+        }
+        Ok(Self::payout_of(&env, &nomination))
+    }
+
+    /// Number of nominations made; IDs run from 0 to this count
+    pub fn get_nomination_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NominationCount)
+            .unwrap_or(0)
+    }
+
+    /// The ballot `voter` last cast, empty if none
+    pub fn get_ballot(env: Env, voter: Address) -> Vec<Allocation> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Ballot(voter))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Points cast across every ballot
+    pub fn get_total_votes(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalVotes)
+            .unwrap_or(0)
+    }
+
+    pub fn get_budget(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::Budget).unwrap_or(0)
+    }
+
+    pub fn is_finalized(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Finalized)
+            .unwrap_or(false)
+    }
+
+    pub fn get_round(env: Env) -> Result<RoundConfig, RetroError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Round)
+            .ok_or(RetroError::NotInitialized)
+    }
+
+    /// Get admin address
+    pub fn get_admin(env: Env) -> Result<Address, RetroError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(RetroError::NotInitialized)
+    }
+}
+
+#[cfg(test)]
+mod test;
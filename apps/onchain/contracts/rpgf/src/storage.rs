@@ -0,0 +1,78 @@
+use soroban_sdk::{contracttype, Address, String, Symbol};
+
+/// Upper bound on nominations in one round.
+pub const MAX_NOMINATIONS: u32 = 100;
+
+/// Longest nomination description URI accepted, in bytes.
+pub const MAX_URI_LEN: u32 = 256;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Round data touched by any write is kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,              // -> Address
+    Round,              // -> RoundConfig
+    Budget,             // -> i128 funded for payouts
+    TotalVotes,         // -> u64 points cast across all ballots
+    Finalized,          // -> bool
+    Nomination(u32),    // nomination id -> Nomination
+    NominationCount,    // -> u32
+    Nominated(Address), // contributor -> nomination id
+    Ballot(Address),    // voter -> Vec<Allocation>
+    Backed,             // -> u32 nominations holding at least one vote
+    Distributed,        // -> i128 paid into the vesting wallet so far
+    DistributedCount,   // -> u32 nominations paid out so far
+}
+
+/// The round's fixed parameters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundConfig {
+    /// Badges contract whose holders nominate and vote.
+    pub badges: Address,
+    /// Badge type that grants a vote.
+    pub voter_badge: Symbol,
+    /// Vesting wallet payouts are streamed through; this contract must be
+    /// one of its schedule creators.
+    pub vesting: Address,
+    /// Asset the budget is paid in; must be the vesting wallet's token.
+    pub token: Address,
+    /// Nominations close, and voting opens, at this time.
+    pub nomination_end: u64,
+    pub voting_end: u64,
+    /// Points each voter may spread across nominations.
+    pub points_per_voter: u32,
+    /// Length in seconds of each payout's linear vesting schedule.
+    pub payout_duration: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Nomination {
+    pub id: u32,
+    pub contributor: Address,
+    pub nominated_by: Address,
+    /// What the contribution was, usually a link to a writeup.
+    pub description_uri: String,
+    pub votes: u64,
+    /// Share of the budget, set when distributed.
+    pub payout: i128,
+    /// Vesting wallet schedule the payout was pushed into.
+    pub schedule_id: Option<u32>,
+}
+
+/// Points a ballot gives one nomination.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Allocation {
+    pub nomination_id: u32,
+    pub points: u32,
+}
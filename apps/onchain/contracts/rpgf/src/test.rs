@@ -0,0 +1,382 @@
+// The mock's generated client mirrors create_vesting's full parameter list.
+#![allow(clippy::too_many_arguments)]
+
+use crate::errors::RetroError;
+use crate::storage::{Allocation, RoundConfig};
+use crate::vesting::VestingCurve;
+use crate::{RetroFundingContract, RetroFundingContractClient};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String, Symbol, Vec,
+};
+
+#[contracttype]
+enum MockKey {
+    Badge(Address, Symbol),
+    Token,
+    Schedules,
+}
+
+/// Stands in for the badges contract's holder lookup
+#[contract]
+pub struct MockBadges;
+
+#[contractimpl]
+impl MockBadges {
+    pub fn award(env: Env, owner: Address, badge_type: Symbol) {
+        env.storage()
+            .persistent()
+            .set(&MockKey::Badge(owner, badge_type), &true);
+    }
+
+    pub fn has_badge(env: Env, owner: Address, badge_type: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&MockKey::Badge(owner, badge_type))
+    }
+}
+
+/// Stands in for the vesting wallet; records every schedule created
+#[contract]
+pub struct MockVesting;
+
+#[contractimpl]
+impl MockVesting {
+    pub fn __constructor(env: Env, token: Address) {
+        env.storage().instance().set(&MockKey::Token, &token);
+    }
+
+    pub fn create_vesting(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        amount: i128,
+        _start_time: u64,
+        duration: u64,
+        _cliff_duration: u64,
+        curve: VestingCurve,
+        _revocable: bool,
+    ) -> u32 {
+        admin.require_auth();
+        assert_eq!(curve, VestingCurve::Linear);
+        let mut schedules = Self::schedules(env.clone());
+        schedules.push_back((beneficiary, amount, duration));
+        env.storage()
+            .instance()
+            .set(&MockKey::Schedules, &schedules);
+        schedules.len() - 1
+    }
+
+    pub fn get_token(env: Env) -> Address {
+        env.storage().instance().get(&MockKey::Token).unwrap()
+    }
+
+    pub fn schedules(env: Env) -> Vec<(Address, i128, u64)> {
+        env.storage()
+            .instance()
+            .get(&MockKey::Schedules)
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+const VOTER_BADGE: Symbol = symbol_short!("voter");
+const POINTS: u32 = 100;
+const PAYOUT_DURATION: u64 = 30 * 24 * 3600;
+
+struct Setup<'a> {
+    env: Env,
+    client: RetroFundingContractClient<'a>,
+    badges: MockBadgesClient<'a>,
+    vesting: MockVestingClient<'a>,
+    admin: Address,
+    token: TokenClient<'a>,
+    minter: StellarAssetClient<'a>,
+    nomination_end: u64,
+    voting_end: u64,
+}
+
+fn config(s: &Setup) -> RoundConfig {
+    s.client.get_round()
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let minter = StellarAssetClient::new(&env, &asset.address());
+    let token = TokenClient::new(&env, &asset.address());
+    let badges = MockBadgesClient::new(&env, &env.register(MockBadges, ()));
+    let vesting = MockVestingClient::new(&env, &env.register(MockVesting, (asset.address(),)));
+
+    let contract_id = env.register(RetroFundingContract, ());
+    let client = RetroFundingContractClient::new(&env, &contract_id);
+    let nomination_end = 2_000;
+    let voting_end = 3_000;
+    client.initialize(
+        &admin,
+        &RoundConfig {
+            badges: badges.address.clone(),
+            voter_badge: VOTER_BADGE,
+            vesting: vesting.address.clone(),
+            token: asset.address(),
+            nomination_end,
+            voting_end,
+            points_per_voter: POINTS,
+            payout_duration: PAYOUT_DURATION,
+        },
+    );
+    let funder = Address::generate(&env);
+    minter.mint(&funder, &1_000);
+    client.fund(&funder, &1_000);
+
+    Setup {
+        env,
+        client,
+        badges,
+        vesting,
+        admin,
+        token,
+        minter,
+        nomination_end,
+        voting_end,
+    }
+}
+
+fn badge_holder(s: &Setup) -> Address {
+    let holder = Address::generate(&s.env);
+    s.badges.award(&holder, &VOTER_BADGE);
+    holder
+}
+
+fn nominate(s: &Setup, nominator: &Address) -> (Address, u32) {
+    let contributor = Address::generate(&s.env);
+    let uri = String::from_str(&s.env, "ipfs://writeup");
+    let nomination_id = s.client.nominate(nominator, &contributor, &uri);
+    (contributor, nomination_id)
+}
+
+fn ballot(s: &Setup, votes: &[(u32, u32)]) -> Vec<Allocation> {
+    let mut allocations = Vec::new(&s.env);
+    for (nomination_id, points) in votes {
+        allocations.push_back(Allocation {
+            nomination_id: *nomination_id,
+            points: *points,
+        });
+    }
+    allocations
+}
+
+fn warp(s: &Setup, to: u64) {
+    s.env.ledger().set_timestamp(to);
+}
+
+#[test]
+fn test_initialize_validates_config() {
+    let s = setup();
+    let other = RetroFundingContractClient::new(&s.env, &s.env.register(RetroFundingContract, ()));
+
+    let inverted = RoundConfig {
+        voting_end: s.nomination_end,
+        ..config(&s)
+    };
+    assert_eq!(
+        other.try_initialize(&s.admin, &inverted),
+        Err(Ok(RetroError::InvalidConfig))
+    );
+    // The budget token has to be the one the vesting wallet pays out
+    let wrong_token = RoundConfig {
+        token: Address::generate(&s.env),
+        ..config(&s)
+    };
+    assert_eq!(
+        other.try_initialize(&s.admin, &wrong_token),
+        Err(Ok(RetroError::InvalidConfig))
+    );
+    assert_eq!(
+        s.client.try_initialize(&s.admin, &config(&s)),
+        Err(Ok(RetroError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_nominate_requires_badge_and_window() {
+    let s = setup();
+    let holder = badge_holder(&s);
+    let (contributor, nomination_id) = nominate(&s, &holder);
+    assert_eq!(nomination_id, 0);
+    assert_eq!(s.client.get_nomination(&0).contributor, contributor);
+
+    let uri = String::from_str(&s.env, "ipfs://writeup");
+    let outsider = Address::generate(&s.env);
+    assert_eq!(
+        s.client
+            .try_nominate(&outsider, &Address::generate(&s.env), &uri),
+        Err(Ok(RetroError::NotBadgeHolder))
+    );
+    assert_eq!(
+        s.client.try_nominate(&holder, &contributor, &uri),
+        Err(Ok(RetroError::AlreadyNominated))
+    );
+
+    warp(&s, s.nomination_end);
+    assert_eq!(
+        s.client
+            .try_nominate(&holder, &Address::generate(&s.env), &uri),
+        Err(Ok(RetroError::NominationsClosed))
+    );
+}
+
+#[test]
+fn test_vote_checks_budget_and_self_vote() {
+    let s = setup();
+    let voter = badge_holder(&s);
+    let (_, first) = nominate(&s, &voter);
+    let (_, second) = nominate(&s, &voter);
+    // The voter has been nominated too
+    let uri = String::from_str(&s.env, "ipfs://writeup");
+    let own = s.client.nominate(&badge_holder(&s), &voter, &uri);
+
+    assert_eq!(
+        s.client.try_vote(&voter, &ballot(&s, &[(first, 10)])),
+        Err(Ok(RetroError::VotingNotOpen))
+    );
+    warp(&s, s.nomination_end);
+
+    assert_eq!(
+        s.client
+            .try_vote(&voter, &ballot(&s, &[(first, 60), (second, 41)])),
+        Err(Ok(RetroError::OverBudget))
+    );
+    assert_eq!(
+        s.client.try_vote(&voter, &ballot(&s, &[(own, 10)])),
+        Err(Ok(RetroError::SelfVote))
+    );
+    assert_eq!(
+        s.client
+            .try_vote(&Address::generate(&s.env), &ballot(&s, &[(first, 10)])),
+        Err(Ok(RetroError::NotBadgeHolder))
+    );
+    assert_eq!(
+        s.client.try_vote(&voter, &ballot(&s, &[(7, 10)])),
+        Err(Ok(RetroError::NominationNotFound))
+    );
+
+    warp(&s, s.voting_end);
+    assert_eq!(
+        s.client.try_vote(&voter, &ballot(&s, &[(first, 10)])),
+        Err(Ok(RetroError::VotingNotOpen))
+    );
+}
+
+#[test]
+fn test_recast_ballot_replaces_previous() {
+    let s = setup();
+    let voter = badge_holder(&s);
+    let (_, first) = nominate(&s, &voter);
+    let (_, second) = nominate(&s, &voter);
+    warp(&s, s.nomination_end);
+
+    s.client
+        .vote(&voter, &ballot(&s, &[(first, 70), (second, 30)]));
+    assert_eq!(s.client.vote(&voter, &ballot(&s, &[(second, 50)])), 50);
+
+    assert_eq!(s.client.get_nomination(&first).votes, 0);
+    assert_eq!(s.client.get_nomination(&second).votes, 50);
+    assert_eq!(s.client.get_total_votes(), 50);
+    assert_eq!(s.client.get_ballot(&voter), ballot(&s, &[(second, 50)]));
+}
+
+#[test]
+fn test_finalize_and_distribute_through_vesting() {
+    let s = setup();
+    let (alice, bob) = (badge_holder(&s), badge_holder(&s));
+    let (first_contributor, first) = nominate(&s, &alice);
+    let (_, second) = nominate(&s, &alice);
+    let (_, third) = nominate(&s, &alice);
+    warp(&s, s.nomination_end);
+    s.client.vote(&alice, &ballot(&s, &[(first, 100)]));
+    s.client
+        .vote(&bob, &ballot(&s, &[(first, 50), (second, 50)]));
+
+    assert_eq!(s.client.try_finalize(), Err(Ok(RetroError::VotingNotEnded)));
+    assert_eq!(
+        s.client.try_distribute(&first),
+        Err(Ok(RetroError::NotFinalized))
+    );
+    warp(&s, s.voting_end);
+    s.client.finalize();
+    assert_eq!(
+        s.client.try_finalize(),
+        Err(Ok(RetroError::AlreadyFinalized))
+    );
+
+    // 150 and 50 of 200 points split evenly, so nothing returns to the admin
+    assert_eq!(s.client.estimate_payout(&first), 750);
+    assert_eq!(s.client.estimate_payout(&second), 250);
+    assert_eq!(s.token.balance(&s.admin), 0);
+
+    assert_eq!(s.client.distribute(&first), 0);
+    assert_eq!(s.client.distribute(&second), 1);
+    assert_eq!(s.client.get_nomination(&first).payout, 750);
+    assert_eq!(s.client.get_nomination(&second).payout, 250);
+    assert_eq!(
+        s.client.try_distribute(&first),
+        Err(Ok(RetroError::AlreadyPaid))
+    );
+    assert_eq!(
+        s.client.try_distribute(&third),
+        Err(Ok(RetroError::InvalidAmount))
+    );
+
+    assert_eq!(s.client.get_nomination(&first).schedule_id, Some(0));
+    assert_eq!(
+        s.vesting.schedules().get(0),
+        Some((first_contributor, 750, PAYOUT_DURATION))
+    );
+    assert_eq!(s.token.balance(&s.vesting.address), 1_000);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_budget_returns_to_admin_without_votes() {
+    let s = setup();
+    nominate(&s, &badge_holder(&s));
+    warp(&s, s.voting_end);
+    s.client.finalize();
+
+    assert_eq!(s.token.balance(&s.admin), 1_000);
+    assert_eq!(s.client.estimate_payout(&0), 0);
+    s.minter.mint(&s.admin, &1);
+    assert_eq!(
+        s.client.try_fund(&s.admin, &1),
+        Err(Ok(RetroError::AlreadyFinalized))
+    );
+}
+
+#[test]
+fn test_rounding_dust_returns_with_last_payout() {
+    let s = setup();
+    let voter = badge_holder(&s);
+    let (_, first) = nominate(&s, &voter);
+    let (_, second) = nominate(&s, &voter);
+    let (_, third) = nominate(&s, &voter);
+    nominate(&s, &voter);
+    warp(&s, s.nomination_end);
+    s.client
+        .vote(&voter, &ballot(&s, &[(first, 1), (second, 1), (third, 1)]));
+    warp(&s, s.voting_end);
+    s.client.finalize();
+
+    // 333 each; the unbacked fourth nomination is not waited on
+    s.client.distribute(&first);
+    s.client.distribute(&second);
+    assert_eq!(s.token.balance(&s.admin), 0);
+    s.client.distribute(&third);
+    assert_eq!(s.token.balance(&s.admin), 1);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
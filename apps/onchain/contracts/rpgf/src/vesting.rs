@@ -0,0 +1,30 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+/// Mirrors the vesting wallet's curve type. Only the variant payouts use is
+/// declared; it encodes the same as the wallet's own.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VestingCurve {
+    Linear,
+}
+
+/// The slice of the vesting wallet's interface payouts go through.
+// Only the generated client is called; the trait itself is never implemented.
+#[allow(dead_code)]
+#[contractclient(name = "VestingClient")]
+pub trait Vesting {
+    #[allow(clippy::too_many_arguments)]
+    fn create_vesting(
+        env: Env,
+        creator: Address,
+        beneficiary: Address,
+        amount: i128,
+        start_time: u64,
+        duration: u64,
+        cliff_duration: u64,
+        curve: VestingCurve,
+        revocable: bool,
+    ) -> u32;
+
+    fn get_token(env: Env) -> Address;
+}
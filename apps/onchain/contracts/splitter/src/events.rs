@@ -0,0 +1,37 @@
+use soroban_sdk::{contractevent, Address};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+}
+
+/// Emitted when a payee's weight is set; zero removes them.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SharesUpdatedEvent {
+    #[topic]
+    pub payee: Address,
+    pub shares: u32,
+    pub total_shares: u32,
+}
+
+/// Emitted when tokens are deposited and credited to payees.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositedEvent {
+    #[topic]
+    pub from: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Emitted when a payee pulls their balance.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawnEvent {
+    #[topic]
+    pub payee: Address,
+    pub token: Address,
+    pub amount: i128,
+}
@@ -0,0 +1,275 @@
+#![no_std]
+
+mod errors;
+mod events;
+mod storage;
+
+use errors::SplitterError;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+use storage::{
+    DataKey, MAX_PAYEES, MAX_TOKENS, PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_THRESHOLD, SCALE,
+};
+
+#[contract]
+pub struct SplitterContract;
+
+#[contractimpl]
+impl SplitterContract {
+    /// Check that `admin` is the stored admin and has signed
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), SplitterError> {
+        let stored_admin = Self::get_admin(env.clone())?;
+        if *admin != stored_admin {
+            return Err(SplitterError::Unauthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
+
+    fn read_i128(env: &Env, key: &DataKey) -> i128 {
+        env.storage().persistent().get(key).unwrap_or(0)
+    }
+
+    /// Write a persistent amount and keep it clear of archival
+    fn write_i128(env: &Env, key: &DataKey, amount: i128) {
+        env.storage().persistent().set(key, &amount);
+        env.storage().persistent().extend_ttl(
+            key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// Scaled amount `payee` is owed in `token`: what was settled plus their
+    /// `shares` of everything released per share since their checkpoint
+    fn accrued(env: &Env, payee: &Address, token: &Address, shares: u32) -> i128 {
+        let per_share = Self::read_i128(env, &DataKey::PerShare(token.clone()));
+        let checkpoint = Self::read_i128(env, &DataKey::Checkpoint(payee.clone(), token.clone()));
+        Self::read_i128(env, &DataKey::Owed(payee.clone(), token.clone()))
+            + shares as i128 * (per_share - checkpoint)
+    }
+
+    /// Fold what `payee` accrued at `shares` into their settled balance and
+    /// move their checkpoint up to the current accumulator
+    fn settle(env: &Env, payee: &Address, token: &Address, shares: u32) -> i128 {
+        let owed = Self::accrued(env, payee, token, shares);
+        let per_share = Self::read_i128(env, &DataKey::PerShare(token.clone()));
+        Self::write_i128(
+            env,
+            &DataKey::Checkpoint(payee.clone(), token.clone()),
+            per_share,
+        );
+        Self::write_i128(env, &DataKey::Owed(payee.clone(), token.clone()), owed);
+        owed
+    }
+
+    /// Every token ever deposited
+    fn tokens(env: &Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Tokens)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Initialize the contract with an admin address
+    pub fn initialize(env: Env, admin: Address) -> Result<(), SplitterError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(SplitterError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::Payees, &Vec::<Address>::new(&env));
+        env.storage().instance().set(&DataKey::TotalShares, &0u32);
+
+        events::InitializedEvent { admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Give `payee` `shares` of every future deposit; zero removes them.
+    /// The payee is settled in every token at their old weight first, so
+    /// everything accrued before the change stays withdrawable.
+    pub fn set_shares(
+        env: Env,
+        admin: Address,
+        payee: Address,
+        shares: u32,
+    ) -> Result<(), SplitterError> {
+        Self::require_admin(&env, &admin)?;
+        let mut payees = Self::get_payees(env.clone());
+        let old = Self::get_shares(env.clone(), payee.clone());
+        match (old, shares) {
+            (0, 0) => {}
+            (0, _) => {
+                if payees.len() >= MAX_PAYEES {
+                    return Err(SplitterError::TooManyPayees);
+                }
+                payees.push_back(payee.clone());
+            }
+            (_, 0) => {
+                if let Some(idx) = payees.first_index_of(&payee) {
+                    payees.remove(idx);
+                }
+            }
+            _ => {}
+        }
+
+        let total_shares = (Self::get_total_shares(env.clone()) - old)
+            .checked_add(shares)
+            .ok_or(SplitterError::InvalidAmount)?;
+        for token in Self::tokens(&env).iter() {
+            Self::settle(&env, &payee, &token, old);
+        }
+        let key = DataKey::Shares(payee.clone());
+        if shares == 0 {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &shares);
+        }
+        env.storage().instance().set(&DataKey::Payees, &payees);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalShares, &total_shares);
+
+        events::SharesUpdatedEvent {
+            payee,
+            shares,
+            total_shares,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Deposit `amount` of `token` for the payees, pro rata to their shares.
+    /// Anyone may deposit. Only the token's per-share accumulator moves, so
+    /// a deposit costs the same however many payees there are; each payee's
+    /// cut is settled when they withdraw or their shares change. Rounding
+    /// remainders are carried into the next deposit of the same token.
+    pub fn deposit(
+        env: Env,
+        from: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), SplitterError> {
+        from.require_auth();
+        if amount <= 0 {
+            return Err(SplitterError::InvalidAmount);
+        }
+        let total_shares = Self::get_total_shares(env.clone());
+        if total_shares == 0 {
+            return Err(SplitterError::NoPayees);
+        }
+
+        let mut tokens = Self::tokens(&env);
+        if !tokens.contains(&token) {
+            if tokens.len() >= MAX_TOKENS {
+                return Err(SplitterError::TooManyTokens);
+            }
+            tokens.push_back(token.clone());
+            env.storage().instance().set(&DataKey::Tokens, &tokens);
+        }
+
+        TokenClient::new(&env, &token).transfer(&from, env.current_contract_address(), &amount);
+
+        let dust_key = DataKey::Dust(token.clone());
+        let pool = amount
+            .checked_mul(SCALE)
+            .ok_or(SplitterError::InvalidAmount)?
+            + Self::read_i128(&env, &dust_key);
+        let released = pool / total_shares as i128;
+        let per_share_key = DataKey::PerShare(token.clone());
+        Self::write_i128(
+            &env,
+            &per_share_key,
+            Self::read_i128(&env, &per_share_key) + released,
+        );
+        Self::write_i128(&env, &dust_key, pool - released * total_shares as i128);
+        let deposited_key = DataKey::Deposited(token.clone());
+        Self::write_i128(
+            &env,
+            &deposited_key,
+            Self::read_i128(&env, &deposited_key) + amount,
+        );
+
+        events::DepositedEvent {
+            from,
+            token,
+            amount,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Pull everything credited to `payee` in `token`. Removed payees keep
+    /// what they accrued. Returns the amount paid.
+    pub fn withdraw(env: Env, payee: Address, token: Address) -> Result<i128, SplitterError> {
+        payee.require_auth();
+        let shares = Self::get_shares(env.clone(), payee.clone());
+        let owed = Self::settle(&env, &payee, &token, shares);
+        let amount = owed / SCALE;
+        if amount == 0 {
+            return Err(SplitterError::NothingToWithdraw);
+        }
+
+        // The sub-unit remainder stays credited
+        Self::write_i128(
+            &env,
+            &DataKey::Owed(payee.clone(), token.clone()),
+            owed - amount * SCALE,
+        );
+        TokenClient::new(&env, &token).transfer(&env.current_contract_address(), &payee, &amount);
+
+        events::WithdrawnEvent {
+            payee,
+            token,
+            amount,
+        }
+        .publish(&env);
+        Ok(amount)
+    }
+
+    /// What `payee` can withdraw in `token` right now
+    pub fn get_balance(env: Env, payee: Address, token: Address) -> i128 {
+        let shares = Self::get_shares(env.clone(), payee.clone());
+        Self::accrued(&env, &payee, &token, shares) / SCALE
+    }
+
+    /// Every payee with a non-zero share, in the order they were added
+    pub fn get_payees(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Payees)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_shares(env: Env, payee: Address) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Shares(payee))
+            .unwrap_or(0)
+    }
+
+    pub fn get_total_shares(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalShares)
+            .unwrap_or(0)
+    }
+
+    /// Total ever deposited in `token`
+    pub fn get_total_deposited(env: Env, token: Address) -> i128 {
+        Self::read_i128(&env, &DataKey::Deposited(token))
+    }
+
+    /// Get admin address
+    pub fn get_admin(env: Env) -> Result<Address, SplitterError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(SplitterError::NotInitialized)
+    }
+}
+
+#[cfg(test)]
+mod test;
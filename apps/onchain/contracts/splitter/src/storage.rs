@@ -0,0 +1,36 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Upper bound on payees.
+pub const MAX_PAYEES: u32 = 50;
+
+/// Upper bound on distinct tokens deposited. A share change settles the
+/// payee in every one of them, two writes each.
+pub const MAX_TOKENS: u32 = 16;
+
+/// Fixed-point scale of the per-share accumulators and the balances settled
+/// from them, so sub-unit amounts are carried rather than lost.
+pub const SCALE: i128 = 1_000_000_000;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Balances touched by any write are kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,                        // -> Address
+    Payees,                       // -> Vec<Address> with a non-zero share
+    Shares(Address),              // payee -> u32 weight
+    TotalShares,                  // -> u32 sum of every payee's weight
+    Owed(Address, Address),       // (payee, token) -> i128 settled, not yet withdrawn, scaled
+    Dust(Address),                // token -> i128 scaled remainder carried to the next deposit
+    Deposited(Address),           // token -> i128 deposited, ever
+    Tokens,                       // -> Vec<Address> every token deposited
+    PerShare(Address),            // token -> i128 released per share, ever, scaled
+    Checkpoint(Address, Address), // (payee, token) -> i128 PerShare at last settlement
+}
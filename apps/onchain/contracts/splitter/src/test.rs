@@ -0,0 +1,157 @@
+use crate::errors::SplitterError;
+use crate::storage::MAX_PAYEES;
+use crate::{SplitterContract, SplitterContractClient};
+use soroban_sdk::{
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, Vec,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: SplitterContractClient<'a>,
+    admin: Address,
+    depositor: Address,
+    token: TokenClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    StellarAssetClient::new(&env, &asset.address()).mint(&depositor, &10_000);
+    let token = TokenClient::new(&env, &asset.address());
+
+    let contract_id = env.register(SplitterContract, ());
+    let client = SplitterContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    Setup {
+        env,
+        client,
+        admin,
+        depositor,
+        token,
+    }
+}
+
+#[test]
+fn test_deposit_splits_pro_rata() {
+    let s = setup();
+    let alice = Address::generate(&s.env);
+    let bob = Address::generate(&s.env);
+    s.client.set_shares(&s.admin, &alice, &3);
+    s.client.set_shares(&s.admin, &bob, &1);
+    assert_eq!(s.client.get_total_shares(), 4);
+
+    s.client.deposit(&s.depositor, &s.token.address, &1_000);
+    assert_eq!(s.client.get_balance(&alice, &s.token.address), 750);
+    assert_eq!(s.client.get_balance(&bob, &s.token.address), 250);
+
+    assert_eq!(s.client.withdraw(&alice, &s.token.address), 750);
+    assert_eq!(s.token.balance(&alice), 750);
+    assert_eq!(s.client.get_balance(&alice, &s.token.address), 0);
+    assert_eq!(
+        s.client.try_withdraw(&alice, &s.token.address),
+        Err(Ok(SplitterError::NothingToWithdraw))
+    );
+    assert_eq!(s.client.get_total_deposited(&s.token.address), 1_000);
+}
+
+#[test]
+fn test_rounding_dust_carries_over() {
+    let s = setup();
+    let payees = [
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+    ];
+    for payee in payees.iter() {
+        s.client.set_shares(&s.admin, payee, &1);
+    }
+
+    s.client.deposit(&s.depositor, &s.token.address, &100);
+    assert_eq!(s.client.get_balance(&payees[0], &s.token.address), 33);
+    // 1 left over joins the next deposit: 101 + 1 splits 34 each
+    s.client.deposit(&s.depositor, &s.token.address, &101);
+    for payee in payees.iter() {
+        assert_eq!(s.client.get_balance(payee, &s.token.address), 67);
+    }
+    assert_eq!(s.token.balance(&s.client.address), 201);
+}
+
+#[test]
+fn test_share_change_keeps_accrued() {
+    let s = setup();
+    let alice = Address::generate(&s.env);
+    let bob = Address::generate(&s.env);
+    s.client.set_shares(&s.admin, &alice, &1);
+    s.client.set_shares(&s.admin, &bob, &1);
+    s.client.deposit(&s.depositor, &s.token.address, &1_000);
+
+    // Alice is removed and Bob's weight changes; neither re-prices the past
+    s.client.set_shares(&s.admin, &alice, &0);
+    s.client.set_shares(&s.admin, &bob, &5);
+    assert_eq!(s.client.get_payees().len(), 1);
+    s.client.deposit(&s.depositor, &s.token.address, &1_000);
+
+    assert_eq!(s.client.get_balance(&alice, &s.token.address), 500);
+    assert_eq!(s.client.get_balance(&bob, &s.token.address), 1_500);
+    assert_eq!(s.client.withdraw(&alice, &s.token.address), 500);
+}
+
+#[test]
+fn test_deposit_needs_payees_and_amount() {
+    let s = setup();
+    assert_eq!(
+        s.client.try_deposit(&s.depositor, &s.token.address, &100),
+        Err(Ok(SplitterError::NoPayees))
+    );
+    s.client
+        .set_shares(&s.admin, &Address::generate(&s.env), &1);
+    assert_eq!(
+        s.client.try_deposit(&s.depositor, &s.token.address, &0),
+        Err(Ok(SplitterError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_only_admin_sets_shares() {
+    let s = setup();
+    let payee = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_set_shares(&s.depositor, &payee, &1),
+        Err(Ok(SplitterError::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_initialize(&s.admin),
+        Err(Ok(SplitterError::AlreadyInitialized))
+    );
+    assert_eq!(s.client.get_admin(), s.admin);
+}
+
+#[test]
+fn test_deposit_with_full_payee_list() {
+    let s = setup();
+    let mut payees = Vec::new(&s.env);
+    for _ in 0..MAX_PAYEES {
+        let payee = Address::generate(&s.env);
+        s.client.set_shares(&s.admin, &payee, &1);
+        payees.push_back(payee);
+    }
+
+    s.client.deposit(&s.depositor, &s.token.address, &1_000);
+    s.client.deposit(&s.depositor, &s.token.address, &1_000);
+    for payee in payees.iter() {
+        assert_eq!(s.client.get_balance(&payee, &s.token.address), 40);
+    }
+    assert_eq!(
+        s.client
+            .withdraw(&payees.get_unchecked(0), &s.token.address),
+        40
+    );
+    assert_eq!(s.token.balance(&s.client.address), 1_960);
+}
@@ -0,0 +1,18 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum StakingError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    Unauthorized = 3,
+    InvalidConfig = 4,
+    InvalidAmount = 5,
+    InvalidLockOption = 6,
+    PositionNotFound = 7,
+    NothingToClaim = 8,
+    EmergencyActive = 9,
+    EmergencyNotActive = 10,
+    RewardsSwept = 11,
+}
@@ -0,0 +1,76 @@
+use soroban_sdk::{contractevent, Address};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+    pub stake_token: Address,
+    pub reward_token: Address,
+}
+
+/// Emitted when the admin starts or tops up a reward period.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardsNotifiedEvent {
+    pub amount: i128,
+    pub reward_rate: i128,
+    pub period_finish: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakedEvent {
+    #[topic]
+    pub staker: Address,
+    pub position_id: u32,
+    pub amount: i128,
+    pub unlock_at: u64,
+    pub weight: i128,
+}
+
+/// Emitted when a position's weight is recomputed for a new tier.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierRefreshedEvent {
+    #[topic]
+    pub staker: Address,
+    pub position_id: u32,
+    pub tier: u32,
+    pub weight: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardsClaimedEvent {
+    #[topic]
+    pub staker: Address,
+    pub position_id: u32,
+    pub amount: i128,
+}
+
+/// Emitted when a position exits; `penalty` is non-zero for early exits.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnstakedEvent {
+    #[topic]
+    pub staker: Address,
+    pub position_id: u32,
+    pub returned: i128,
+    pub penalty: i128,
+    pub forfeited_rewards: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyUpdatedEvent {
+    pub active: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawnEvent {
+    #[topic]
+    pub staker: Address,
+    pub position_id: u32,
+    pub amount: i128,
+}
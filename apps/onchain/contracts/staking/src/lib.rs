@@ -0,0 +1,553 @@
+#![no_std]
+
+mod errors;
+mod events;
+mod registry;
+mod storage;
+
+use errors::StakingError;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+use storage::{
+    DataKey, LockOption, Position, RewardState, StakingConfig, BPS_DENOMINATOR, MAX_LOCK_OPTIONS,
+    MAX_TIER_BOOSTS, PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_THRESHOLD, REWARD_PRECISION,
+};
+
+#[contract]
+pub struct StakingContract;
+
+#[contractimpl]
+impl StakingContract {
+    /// Check that `admin` is the stored admin and has signed
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), StakingError> {
+        let stored_admin = Self::get_admin(env.clone())?;
+        if *admin != stored_admin {
+            return Err(StakingError::Unauthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
+
+    fn require_not_emergency(env: &Env) -> Result<(), StakingError> {
+        if Self::is_emergency(env.clone()) {
+            return Err(StakingError::EmergencyActive);
+        }
+        Ok(())
+    }
+
+    fn rewards_swept(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::RewardsSwept)
+            .unwrap_or(false)
+    }
+
+    fn load_position(
+        env: &Env,
+        staker: &Address,
+        position_id: u32,
+    ) -> Result<Position, StakingError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Position(staker.clone(), position_id))
+            .ok_or(StakingError::PositionNotFound)
+    }
+
+    /// Write a position back and keep it clear of archival
+    fn save_position(env: &Env, position: &Position) {
+        let key = DataKey::Position(position.owner.clone(), position.id);
+        env.storage().persistent().set(&key, position);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    fn remove_position(env: &Env, position: &Position) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Position(position.owner.clone(), position.id));
+    }
+
+    fn set_total_staked(env: &Env, total_staked: i128) {
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalStaked, &total_staked);
+    }
+
+    fn save_rewards(env: &Env, state: &RewardState) {
+        env.storage().instance().set(&DataKey::Rewards, state);
+    }
+
+    /// `state` with the accumulator brought up to the present. Nothing is
+    /// distributed while no weight is staked.
+    fn accrued_state(env: &Env) -> RewardState {
+        let mut state = Self::get_reward_state(env.clone());
+        let until = env.ledger().timestamp().min(state.period_finish);
+        if until > state.last_update {
+            if state.total_weight > 0 {
+                state.acc_reward_per_weight +=
+                    state.reward_rate * (until - state.last_update) as i128 * REWARD_PRECISION
+                        / state.total_weight;
+            }
+            state.last_update = until;
+        }
+        state
+    }
+
+    /// Bring the accumulator up to date and store it
+    fn update_rewards(env: &Env) -> RewardState {
+        let state = Self::accrued_state(env);
+        Self::save_rewards(env, &state);
+        state
+    }
+
+    /// Move everything `position` has earned so far into `accrued`
+    fn settle(position: &mut Position, state: &RewardState) {
+        let earned = position.weight * state.acc_reward_per_weight / REWARD_PRECISION;
+        position.accrued += earned - position.reward_debt;
+        position.reward_debt = earned;
+    }
+
+    /// `amount` scaled by its lock multiplier and the tier boost for `tier`.
+    /// Tiers past the end of the boost table get its last entry.
+    fn weight_of(env: &Env, amount: i128, multiplier_bps: u32, tier: u32) -> i128 {
+        let boosts = Self::get_tier_boosts(env.clone());
+        let boost_bps = if boosts.is_empty() {
+            0
+        } else {
+            boosts.get(tier.min(boosts.len() - 1)).unwrap_or(0)
+        };
+        amount * multiplier_bps as i128 * (BPS_DENOMINATOR + boost_bps as i128)
+            / (BPS_DENOMINATOR * BPS_DENOMINATOR)
+    }
+
+    fn pay(env: &Env, token: &Address, to: &Address, amount: i128) {
+        if amount > 0 {
+            TokenClient::new(env, token).transfer(&env.current_contract_address(), to, &amount);
+        }
+    }
+
+    /// Initialize the contract. Early exits keep `early_exit_penalty_bps`
+    /// of principal for the treasury.
+    pub fn initialize(env: Env, admin: Address, config: StakingConfig) -> Result<(), StakingError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(StakingError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        if config.early_exit_penalty_bps as i128 > BPS_DENOMINATOR {
+            return Err(StakingError::InvalidConfig);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Config, &config);
+        Self::save_rewards(&env, &RewardState::default());
+        Self::set_total_staked(&env, 0);
+
+        events::InitializedEvent {
+            admin,
+            stake_token: config.stake_token,
+            reward_token: config.reward_token,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Set the lock lengths stakers may choose from. Existing positions
+    /// keep the terms they were opened with.
+    pub fn set_lock_options(
+        env: Env,
+        admin: Address,
+        options: Vec<LockOption>,
+    ) -> Result<(), StakingError> {
+        Self::require_admin(&env, &admin)?;
+        if options.is_empty() || options.len() > MAX_LOCK_OPTIONS {
+            return Err(StakingError::InvalidConfig);
+        }
+        for option in options.iter() {
+            if option.duration == 0 || (option.multiplier_bps as i128) < BPS_DENOMINATOR {
+                return Err(StakingError::InvalidConfig);
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::LockOptions, &options);
+        Ok(())
+    }
+
+    /// Set how much extra weight each registry tier earns, in basis points:
+    /// entry `i` applies to tier `i`, and higher tiers use the last entry.
+    /// `[0, 1_000, 2_500]` gives a tier 2 contributor 1.25x weight.
+    pub fn set_tier_boosts(env: Env, admin: Address, boosts: Vec<u32>) -> Result<(), StakingError> {
+        Self::require_admin(&env, &admin)?;
+        if boosts.len() > MAX_TIER_BOOSTS {
+            return Err(StakingError::InvalidConfig);
+        }
+        env.storage().instance().set(&DataKey::TierBoosts, &boosts);
+        Ok(())
+    }
+
+    /// Fund `amount` of rewards to stream over the next `duration` seconds,
+    /// on top of whatever the current period has not yet paid out
+    pub fn notify_rewards(
+        env: Env,
+        admin: Address,
+        amount: i128,
+        duration: u64,
+    ) -> Result<(), StakingError> {
+        Self::require_admin(&env, &admin)?;
+        Self::require_not_emergency(&env)?;
+        if amount <= 0 || duration == 0 {
+            return Err(StakingError::InvalidAmount);
+        }
+        let config = Self::get_config(env.clone())?;
+
+        let mut state = Self::accrued_state(&env);
+        let now = env.ledger().timestamp();
+        let leftover = if now < state.period_finish {
+            state.reward_rate * (state.period_finish - now) as i128
+        } else {
+            0
+        };
+        let reward_rate = (amount + leftover) / duration as i128;
+        if reward_rate == 0 {
+            return Err(StakingError::InvalidAmount);
+        }
+        TokenClient::new(&env, &config.reward_token).transfer(
+            &admin,
+            env.current_contract_address(),
+            &amount,
+        );
+        state.reward_rate = reward_rate;
+        state.last_update = now;
+        state.period_finish = now + duration;
+        Self::save_rewards(&env, &state);
+
+        events::RewardsNotifiedEvent {
+            amount,
+            reward_rate,
+            period_finish: state.period_finish,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Lock `amount` for the lock option at `option_index`. The position's
+    /// weight is the amount times the option's multiplier and the staker's
+    /// registry tier boost. Returns the position ID.
+    pub fn stake(
+        env: Env,
+        staker: Address,
+        amount: i128,
+        option_index: u32,
+    ) -> Result<u32, StakingError> {
+        staker.require_auth();
+        let config = Self::get_config(env.clone())?;
+        Self::require_not_emergency(&env)?;
+        if amount <= 0 {
+            return Err(StakingError::InvalidAmount);
+        }
+        let option = Self::get_lock_options(env.clone())
+            .get(option_index)
+            .ok_or(StakingError::InvalidLockOption)?;
+
+        TokenClient::new(&env, &config.stake_token).transfer(
+            &staker,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        let mut state = Self::update_rewards(&env);
+        let tier = registry::tier_of(&env, &config.registry, &staker);
+        let weight = Self::weight_of(&env, amount, option.multiplier_bps, tier);
+        let count_key = DataKey::PositionCount(staker.clone());
+        let position_id: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&count_key, &(position_id + 1));
+        let position = Position {
+            id: position_id,
+            owner: staker.clone(),
+            amount,
+            multiplier_bps: option.multiplier_bps,
+            unlock_at: env.ledger().timestamp() + option.duration,
+            tier,
+            weight,
+            reward_debt: weight * state.acc_reward_per_weight / REWARD_PRECISION,
+            accrued: 0,
+        };
+        Self::save_position(&env, &position);
+        state.total_weight += weight;
+        Self::save_rewards(&env, &state);
+        Self::set_total_staked(&env, Self::total_staked(env.clone()) + amount);
+
+        events::StakedEvent {
+            staker,
+            position_id,
+            amount,
+            unlock_at: position.unlock_at,
+            weight,
+        }
+        .publish(&env);
+        Ok(position_id)
+    }
+
+    /// Re-read the staker's registry tier and reweight the position, after
+    /// settling what it earned at the old weight. Anyone may call, so a
+    /// staker who drops a tier can't keep the higher boost. Returns the new
+    /// tier.
+    pub fn refresh_tier(env: Env, staker: Address, position_id: u32) -> Result<u32, StakingError> {
+        let config = Self::get_config(env.clone())?;
+        Self::require_not_emergency(&env)?;
+        let mut position = Self::load_position(&env, &staker, position_id)?;
+
+        let mut state = Self::update_rewards(&env);
+        Self::settle(&mut position, &state);
+        let tier = registry::tier_of(&env, &config.registry, &staker);
+        let weight = Self::weight_of(&env, position.amount, position.multiplier_bps, tier);
+        state.total_weight += weight - position.weight;
+        position.tier = tier;
+        position.weight = weight;
+        position.reward_debt = weight * state.acc_reward_per_weight / REWARD_PRECISION;
+        Self::save_position(&env, &position);
+        Self::save_rewards(&env, &state);
+
+        events::TierRefreshedEvent {
+            staker,
+            position_id,
+            tier,
+            weight,
+        }
+        .publish(&env);
+        Ok(tier)
+    }
+
+    /// Pay out the rewards a position has earned. Returns the amount paid.
+    pub fn claim(env: Env, staker: Address, position_id: u32) -> Result<i128, StakingError> {
+        staker.require_auth();
+        let config = Self::get_config(env.clone())?;
+        Self::require_not_emergency(&env)?;
+        let mut position = Self::load_position(&env, &staker, position_id)?;
+
+        let state = Self::update_rewards(&env);
+        Self::settle(&mut position, &state);
+        let amount = position.accrued;
+        if amount == 0 {
+            return Err(StakingError::NothingToClaim);
+        }
+        position.accrued = 0;
+        Self::save_position(&env, &position);
+        Self::pay(&env, &config.reward_token, &staker, amount);
+
+        events::RewardsClaimedEvent {
+            staker,
+            position_id,
+            amount,
+        }
+        .publish(&env);
+        Ok(amount)
+    }
+
+    /// Close a position. Once unlocked the staker gets back their principal
+    /// and every reward earned. Leaving early costs the early-exit penalty
+    /// on principal and forfeits unclaimed rewards, both sent to the
+    /// treasury. Returns the principal returned.
+    pub fn unstake(env: Env, staker: Address, position_id: u32) -> Result<i128, StakingError> {
+        staker.require_auth();
+        let config = Self::get_config(env.clone())?;
+        Self::require_not_emergency(&env)?;
+        let mut position = Self::load_position(&env, &staker, position_id)?;
+
+        let mut state = Self::update_rewards(&env);
+        Self::settle(&mut position, &state);
+        state.total_weight -= position.weight;
+        Self::save_rewards(&env, &state);
+        Self::remove_position(&env, &position);
+        Self::set_total_staked(&env, Self::total_staked(env.clone()) - position.amount);
+
+        let early = env.ledger().timestamp() < position.unlock_at;
+        let (penalty, forfeited_rewards) = if early {
+            (
+                position.amount * config.early_exit_penalty_bps as i128 / BPS_DENOMINATOR,
+                position.accrued,
+            )
+        } else {
+            (0, 0)
+        };
+        let returned = position.amount - penalty;
+        Self::pay(&env, &config.stake_token, &staker, returned);
+        Self::pay(&env, &config.stake_token, &config.treasury, penalty);
+        if early {
+            Self::pay(
+                &env,
+                &config.reward_token,
+                &config.treasury,
+                forfeited_rewards,
+            );
+        } else {
+            Self::pay(&env, &config.reward_token, &staker, position.accrued);
+        }
+
+        events::UnstakedEvent {
+            staker,
+            position_id,
+            returned,
+            penalty,
+            forfeited_rewards,
+        }
+        .publish(&env);
+        Ok(returned)
+    }
+
+    /// Halt staking, claims and reward funding so stakers can pull out
+    /// their principal with `emergency_withdraw`, or lift the halt. The halt
+    /// is permanent once `sweep_rewards` has run, since the rewards still
+    /// owed to open positions are gone.
+    pub fn set_emergency(env: Env, admin: Address, active: bool) -> Result<(), StakingError> {
+        Self::require_admin(&env, &admin)?;
+        if !active && Self::rewards_swept(&env) {
+            return Err(StakingError::RewardsSwept);
+        }
+        env.storage().instance().set(&DataKey::Emergency, &active);
+
+        events::EmergencyUpdatedEvent { active }.publish(&env);
+        Ok(())
+    }
+
+    /// While the emergency halt is on, take back a position's full
+    /// principal regardless of its lock, forfeiting its rewards. Skips the
+    /// registry and reward payouts entirely. Returns the amount withdrawn.
+    pub fn emergency_withdraw(
+        env: Env,
+        staker: Address,
+        position_id: u32,
+    ) -> Result<i128, StakingError> {
+        staker.require_auth();
+        let config = Self::get_config(env.clone())?;
+        if !Self::is_emergency(env.clone()) {
+            return Err(StakingError::EmergencyNotActive);
+        }
+        let position = Self::load_position(&env, &staker, position_id)?;
+
+        let mut state = Self::update_rewards(&env);
+        state.total_weight -= position.weight;
+        Self::save_rewards(&env, &state);
+        Self::remove_position(&env, &position);
+        Self::set_total_staked(&env, Self::total_staked(env.clone()) - position.amount);
+        Self::pay(&env, &config.stake_token, &staker, position.amount);
+
+        events::EmergencyWithdrawnEvent {
+            staker,
+            position_id,
+            amount: position.amount,
+        }
+        .publish(&env);
+        Ok(position.amount)
+    }
+
+    /// While the emergency halt is on, send every reward token the contract
+    /// holds beyond stakers' principal to the treasury, which keeps the halt
+    /// on for good. Returns the amount swept.
+    pub fn sweep_rewards(env: Env, admin: Address) -> Result<i128, StakingError> {
+        Self::require_admin(&env, &admin)?;
+        if !Self::is_emergency(env.clone()) {
+            return Err(StakingError::EmergencyNotActive);
+        }
+        let config = Self::get_config(env.clone())?;
+
+        let mut amount =
+            TokenClient::new(&env, &config.reward_token).balance(&env.current_contract_address());
+        if config.reward_token == config.stake_token {
+            amount = (amount - Self::total_staked(env.clone())).max(0);
+        }
+        Self::pay(&env, &config.reward_token, &config.treasury, amount);
+        env.storage().instance().set(&DataKey::RewardsSwept, &true);
+        let mut state = Self::accrued_state(&env);
+        state.period_finish = state.last_update;
+        state.reward_rate = 0;
+        Self::save_rewards(&env, &state);
+        Ok(amount)
+    }
+
+    pub fn get_position(
+        env: Env,
+        staker: Address,
+        position_id: u32,
+    ) -> Result<Position, StakingError> {
+        Self::load_position(&env, &staker, position_id)
+    }
+
+    /// Number of positions `staker` has opened; closed ones no longer load
+    pub fn get_position_count(env: Env, staker: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PositionCount(staker))
+            .unwrap_or(0)
+    }
+
+    /// Rewards a position could claim right now
+    pub fn pending_rewards(
+        env: Env,
+        staker: Address,
+        position_id: u32,
+    ) -> Result<i128, StakingError> {
+        let mut position = Self::load_position(&env, &staker, position_id)?;
+        Self::settle(&mut position, &Self::accrued_state(&env));
+        Ok(position.accrued)
+    }
+
+    pub fn get_lock_options(env: Env) -> Vec<LockOption> {
+        env.storage()
+            .instance()
+            .get(&DataKey::LockOptions)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the tier boost table; empty until the admin sets one
+    pub fn get_tier_boosts(env: Env) -> Vec<u32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::TierBoosts)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_reward_state(env: Env) -> RewardState {
+        env.storage()
+            .instance()
+            .get(&DataKey::Rewards)
+            .unwrap_or_default()
+    }
+
+    /// Principal currently locked across every position
+    pub fn total_staked(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalStaked)
+            .unwrap_or(0)
+    }
+
+    pub fn is_emergency(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Emergency)
+            .unwrap_or(false)
+    }
+
+    pub fn get_config(env: Env) -> Result<StakingConfig, StakingError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(StakingError::NotInitialized)
+    }
+
+    /// Get admin address
+    pub fn get_admin(env: Env) -> Result<Address, StakingError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(StakingError::NotInitialized)
+    }
+}
+
+#[cfg(test)]
+mod test;
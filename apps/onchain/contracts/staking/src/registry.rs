@@ -0,0 +1,18 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// The slice of the contributor registry's interface this contract reads.
+// Only the generated client is called; the trait itself is never implemented.
+#[allow(dead_code)]
+#[contractclient(name = "RegistryClient")]
+pub trait Registry {
+    fn get_tier(env: Env, address: Address) -> u32;
+}
+
+/// The contributor's tier in `registry`; unregistered addresses, or any
+/// failure in the registry, count as tier 0 so staking never gets stuck.
+pub fn tier_of(env: &Env, registry: &Address, contributor: &Address) -> u32 {
+    match RegistryClient::new(env, registry).try_get_tier(contributor) {
+        Ok(Ok(tier)) => tier,
+        _ => 0,
+    }
+}
@@ -0,0 +1,91 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Fixed-point scale of the reward accumulator.
+pub const REWARD_PRECISION: i128 = 1_000_000_000_000;
+
+/// Basis point denominator for multipliers, boosts and the penalty.
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Most lock options or tier boost entries that may be configured.
+pub const MAX_LOCK_OPTIONS: u32 = 10;
+pub const MAX_TIER_BOOSTS: u32 = 16;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Positions touched by any write are kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,                  // -> Address
+    Config,                 // -> StakingConfig
+    LockOptions,            // -> Vec<LockOption>
+    TierBoosts,             // -> Vec<u32> bps added to weight, by tier
+    Rewards,                // -> RewardState
+    TotalStaked,            // -> i128 principal held for stakers
+    Emergency,              // -> bool
+    RewardsSwept,           // -> bool, set once sweep_rewards has run
+    Position(Address, u32), // (staker, position id) -> Position
+    PositionCount(Address), // staker -> u32
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakingConfig {
+    /// The project token stakers lock.
+    pub stake_token: Address,
+    /// Token rewards are paid in; may be the stake token.
+    pub reward_token: Address,
+    /// Contributor registry tiers are read from.
+    pub registry: Address,
+    /// Receives early-exit penalties and forfeited rewards.
+    pub treasury: Address,
+    /// Share of principal kept when a position exits before unlocking.
+    pub early_exit_penalty_bps: u32,
+}
+
+/// A lock length stakers may choose and the weight it earns.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockOption {
+    pub duration: u64,
+    /// Weight per staked unit, at least 10_000 (1x).
+    pub multiplier_bps: u32,
+}
+
+/// Global reward accrual. Rewards stream at `reward_rate` until
+/// `period_finish`, split by weight through `acc_reward_per_weight`.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RewardState {
+    pub reward_rate: i128,
+    pub period_finish: u64,
+    pub last_update: u64,
+    /// Rewards per unit of weight since launch, scaled by
+    /// `REWARD_PRECISION`.
+    pub acc_reward_per_weight: i128,
+    pub total_weight: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Position {
+    pub id: u32,
+    pub owner: Address,
+    pub amount: i128,
+    pub multiplier_bps: u32,
+    pub unlock_at: u64,
+    /// Registry tier the weight was last computed with.
+    pub tier: u32,
+    /// `amount` scaled by the lock multiplier and tier boost.
+    pub weight: i128,
+    /// Accumulator value already accounted for, times `weight`.
+    pub reward_debt: i128,
+    /// Settled rewards not yet claimed.
+    pub accrued: i128,
+}
@@ -0,0 +1,312 @@
+use crate::errors::StakingError;
+use crate::storage::{LockOption, StakingConfig};
+use crate::{StakingContract, StakingContractClient};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+
+#[contracttype]
+enum MockKey {
+    Tier(Address),
+}
+
+/// Stands in for the contributor registry's tier lookup
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_tier(env: Env, address: Address, tier: u32) {
+        env.storage()
+            .persistent()
+            .set(&MockKey::Tier(address), &tier);
+    }
+
+    pub fn get_tier(env: Env, address: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&MockKey::Tier(address))
+            .unwrap_or(0)
+    }
+}
+
+const MONTH: u64 = 30 * 86_400;
+/// 100 reward tokens a second for 1_000 seconds
+const REWARDS: i128 = 100_000;
+const REWARD_PERIOD: u64 = 1_000;
+
+struct Setup<'a> {
+    env: Env,
+    client: StakingContractClient<'a>,
+    registry: MockRegistryClient<'a>,
+    admin: Address,
+    treasury: Address,
+    stake_token: TokenClient<'a>,
+    reward_token: TokenClient<'a>,
+    stake_minter: StellarAssetClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let stake_asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let reward_asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let stake_minter = StellarAssetClient::new(&env, &stake_asset.address());
+    StellarAssetClient::new(&env, &reward_asset.address()).mint(&admin, &1_000_000);
+    let registry = MockRegistryClient::new(&env, &env.register(MockRegistry, ()));
+
+    let contract_id = env.register(StakingContract, ());
+    let client = StakingContractClient::new(&env, &contract_id);
+    client.initialize(
+        &admin,
+        &StakingConfig {
+            stake_token: stake_asset.address(),
+            reward_token: reward_asset.address(),
+            registry: registry.address.clone(),
+            treasury: treasury.clone(),
+            early_exit_penalty_bps: 1_000,
+        },
+    );
+    // 1x for a month, 2x for three; tier 1 and up add half again
+    client.set_lock_options(
+        &admin,
+        &vec![
+            &env,
+            LockOption {
+                duration: MONTH,
+                multiplier_bps: 10_000,
+            },
+            LockOption {
+                duration: 3 * MONTH,
+                multiplier_bps: 20_000,
+            },
+        ],
+    );
+    client.set_tier_boosts(&admin, &vec![&env, 0, 5_000]);
+
+    Setup {
+        stake_token: TokenClient::new(&env, &stake_asset.address()),
+        reward_token: TokenClient::new(&env, &reward_asset.address()),
+        env,
+        client,
+        registry,
+        admin,
+        treasury,
+        stake_minter,
+    }
+}
+
+fn staker(s: &Setup, tier: u32) -> Address {
+    let staker = Address::generate(&s.env);
+    s.stake_minter.mint(&staker, &10_000);
+    s.registry.set_tier(&staker, &tier);
+    staker
+}
+
+fn start_rewards(s: &Setup) {
+    s.client.notify_rewards(&s.admin, &REWARDS, &REWARD_PERIOD);
+}
+
+fn warp(s: &Setup, by: u64) {
+    let now = s.env.ledger().timestamp();
+    s.env.ledger().set_timestamp(now + by);
+}
+
+#[test]
+fn test_weight_scales_with_lock_and_tier() {
+    let s = setup();
+    let alice = staker(&s, 0);
+    let bob = staker(&s, 1);
+
+    let alice_id = s.client.stake(&alice, &1_000, &0);
+    let bob_id = s.client.stake(&bob, &1_000, &1);
+
+    assert_eq!(s.client.get_position(&alice, &alice_id).weight, 1_000);
+    // 2x lock and 1.5x tier boost
+    let bob_position = s.client.get_position(&bob, &bob_id);
+    assert_eq!(bob_position.weight, 3_000);
+    assert_eq!(bob_position.tier, 1);
+    assert_eq!(bob_position.unlock_at, 1_000 + 3 * MONTH);
+    assert_eq!(s.client.get_reward_state().total_weight, 4_000);
+    assert_eq!(s.client.total_staked(), 2_000);
+    assert_eq!(s.stake_token.balance(&s.client.address), 2_000);
+}
+
+#[test]
+fn test_rewards_split_by_weight() {
+    let s = setup();
+    let alice = staker(&s, 0);
+    let bob = staker(&s, 1);
+    let alice_id = s.client.stake(&alice, &1_000, &0);
+    let bob_id = s.client.stake(&bob, &1_000, &1);
+    start_rewards(&s);
+
+    warp(&s, REWARD_PERIOD / 2);
+    assert_eq!(s.client.pending_rewards(&alice, &alice_id), 12_500);
+    // Accrual stops at the end of the period
+    warp(&s, REWARD_PERIOD);
+    assert_eq!(s.client.pending_rewards(&alice, &alice_id), 25_000);
+    assert_eq!(s.client.pending_rewards(&bob, &bob_id), 75_000);
+
+    assert_eq!(s.client.claim(&alice, &alice_id), 25_000);
+    assert_eq!(s.reward_token.balance(&alice), 25_000);
+    assert_eq!(
+        s.client.try_claim(&alice, &alice_id),
+        Err(Ok(StakingError::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_unstake_after_unlock_pays_everything() {
+    let s = setup();
+    let alice = staker(&s, 0);
+    let position_id = s.client.stake(&alice, &1_000, &0);
+    start_rewards(&s);
+
+    warp(&s, MONTH);
+    assert_eq!(s.client.unstake(&alice, &position_id), 1_000);
+
+    assert_eq!(s.stake_token.balance(&alice), 10_000);
+    assert_eq!(s.reward_token.balance(&alice), REWARDS);
+    assert_eq!(s.client.total_staked(), 0);
+    assert_eq!(
+        s.client.try_get_position(&alice, &position_id),
+        Err(Ok(StakingError::PositionNotFound))
+    );
+}
+
+#[test]
+fn test_early_exit_penalty() {
+    let s = setup();
+    let alice = staker(&s, 0);
+    let position_id = s.client.stake(&alice, &1_000, &0);
+    start_rewards(&s);
+    warp(&s, REWARD_PERIOD / 2);
+
+    assert_eq!(s.client.unstake(&alice, &position_id), 900);
+
+    assert_eq!(s.stake_token.balance(&alice), 9_900);
+    assert_eq!(s.stake_token.balance(&s.treasury), 100);
+    // Unclaimed rewards are forfeited to the treasury too
+    assert_eq!(s.reward_token.balance(&alice), 0);
+    assert_eq!(s.reward_token.balance(&s.treasury), 50_000);
+}
+
+#[test]
+fn test_refresh_tier_reweights_after_settling() {
+    let s = setup();
+    let alice = staker(&s, 0);
+    let bob = staker(&s, 0);
+    let alice_id = s.client.stake(&alice, &1_000, &0);
+    s.client.stake(&bob, &1_000, &0);
+    start_rewards(&s);
+
+    warp(&s, REWARD_PERIOD / 2);
+    s.registry.set_tier(&alice, &1);
+    assert_eq!(s.client.refresh_tier(&alice, &alice_id), 1);
+    assert_eq!(s.client.get_position(&alice, &alice_id).weight, 1_500);
+    assert_eq!(s.client.get_reward_state().total_weight, 2_500);
+
+    // Half the period at 1/2 of the weight, half at 3/5
+    warp(&s, REWARD_PERIOD / 2);
+    assert_eq!(s.client.pending_rewards(&alice, &alice_id), 25_000 + 30_000);
+}
+
+#[test]
+fn test_emergency_withdraw() {
+    let s = setup();
+    let alice = staker(&s, 0);
+    let position_id = s.client.stake(&alice, &1_000, &1);
+    start_rewards(&s);
+    warp(&s, REWARD_PERIOD / 2);
+
+    assert_eq!(
+        s.client.try_emergency_withdraw(&alice, &position_id),
+        Err(Ok(StakingError::EmergencyNotActive))
+    );
+    s.client.set_emergency(&s.admin, &true);
+    assert_eq!(
+        s.client.try_stake(&alice, &1_000, &0),
+        Err(Ok(StakingError::EmergencyActive))
+    );
+    assert_eq!(
+        s.client.try_claim(&alice, &position_id),
+        Err(Ok(StakingError::EmergencyActive))
+    );
+
+    // Full principal despite the lock, no rewards
+    assert_eq!(s.client.emergency_withdraw(&alice, &position_id), 1_000);
+    assert_eq!(s.stake_token.balance(&alice), 10_000);
+    assert_eq!(s.reward_token.balance(&alice), 0);
+
+    assert_eq!(s.client.sweep_rewards(&s.admin), REWARDS);
+    assert_eq!(s.reward_token.balance(&s.treasury), REWARDS);
+}
+
+#[test]
+fn test_sweep_makes_emergency_permanent() {
+    let s = setup();
+    let alice = staker(&s, 0);
+    let bob = staker(&s, 0);
+    let alice_id = s.client.stake(&alice, &1_000, &0);
+    let bob_id = s.client.stake(&bob, &1_000, &0);
+    start_rewards(&s);
+    warp(&s, REWARD_PERIOD);
+
+    s.client.set_emergency(&s.admin, &true);
+    s.client.emergency_withdraw(&alice, &alice_id);
+    assert_eq!(s.client.sweep_rewards(&s.admin), REWARDS);
+
+    // Bob's accrued rewards went with the sweep, so claims can't come back
+    assert_eq!(
+        s.client.try_set_emergency(&s.admin, &false),
+        Err(Ok(StakingError::RewardsSwept))
+    );
+    assert!(s.client.is_emergency());
+    assert_eq!(
+        s.client.try_claim(&bob, &bob_id),
+        Err(Ok(StakingError::EmergencyActive))
+    );
+    assert_eq!(s.client.emergency_withdraw(&bob, &bob_id), 1_000);
+    assert_eq!(s.stake_token.balance(&bob), 10_000);
+    assert_eq!(s.reward_token.balance(&bob), 0);
+}
+
+#[test]
+fn test_config_validation() {
+    let s = setup();
+    let alice = staker(&s, 0);
+
+    assert_eq!(
+        s.client.try_stake(&alice, &1_000, &2),
+        Err(Ok(StakingError::InvalidLockOption))
+    );
+    assert_eq!(
+        s.client.try_set_lock_options(
+            &s.admin,
+            &vec![
+                &s.env,
+                LockOption {
+                    duration: MONTH,
+                    multiplier_bps: 9_999,
+                },
+            ],
+        ),
+        Err(Ok(StakingError::InvalidConfig))
+    );
+    assert_eq!(
+        s.client.try_set_tier_boosts(&alice, &vec![&s.env, 0]),
+        Err(Ok(StakingError::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_notify_rewards(&s.admin, &999, &REWARD_PERIOD),
+        Err(Ok(StakingError::InvalidAmount))
+    );
+}
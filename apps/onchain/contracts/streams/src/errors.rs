@@ -0,0 +1,15 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum StreamError {
+    StreamNotFound = 1,
+    Unauthorized = 2,
+    InvalidRate = 3,
+    InvalidTimeframe = 4,
+    AmountOverflow = 5,
+    InvalidStatus = 6,
+    NothingToWithdraw = 7,
+    InvalidRecipient = 8,
+}
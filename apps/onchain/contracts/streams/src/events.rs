@@ -0,0 +1,37 @@
+use soroban_sdk::{contractevent, Address};
+
+/// Emitted when a stream is created and its deposit escrowed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamCreatedEvent {
+    #[topic]
+    pub sender: Address,
+    #[topic]
+    pub recipient: Address,
+    pub stream_id: u64,
+    pub token: Address,
+    pub rate_per_second: i128,
+    pub start_time: u64,
+    pub stop_time: u64,
+}
+
+/// Emitted when the recipient withdraws what has streamed so far.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawnEvent {
+    #[topic]
+    pub recipient: Address,
+    pub stream_id: u64,
+    pub amount: i128,
+}
+
+/// Emitted when a stream is stopped early and settled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamCancelledEvent {
+    #[topic]
+    pub stream_id: u64,
+    pub cancelled_by: Address,
+    pub recipient_amount: i128,
+    pub sender_refund: i128,
+}
@@ -0,0 +1,214 @@
+#![no_std]
+
+mod errors;
+mod events;
+mod storage;
+
+use errors::StreamError;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Env};
+use storage::{DataKey, Stream, StreamStatus, PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_THRESHOLD};
+
+#[contract]
+pub struct StreamsContract;
+
+#[contractimpl]
+impl StreamsContract {
+    fn load_stream(env: &Env, stream_id: u64) -> Result<Stream, StreamError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Stream(stream_id))
+            .ok_or(StreamError::StreamNotFound)
+    }
+
+    /// Write a stream back and keep it clear of archival
+    fn save_stream(env: &Env, stream: &Stream) {
+        let key = DataKey::Stream(stream.id);
+        env.storage().persistent().set(&key, stream);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// Everything streamed to the recipient by `time`, withdrawn or not
+    fn streamed_at(stream: &Stream, time: u64) -> i128 {
+        let until = match stream.cancelled_at {
+            Some(cancelled_at) => cancelled_at.min(time),
+            None => time,
+        }
+        .min(stream.stop_time);
+        if until <= stream.start_time {
+            return 0;
+        }
+        stream.rate_per_second * (until - stream.start_time) as i128
+    }
+
+    fn pay(env: &Env, stream: &Stream, to: &Address, amount: i128) {
+        TokenClient::new(env, &stream.token).transfer(&env.current_contract_address(), to, &amount);
+    }
+
+    /// Stream `rate_per_second` of `token` from `sender` to `recipient`
+    /// between `start_time` and `stop_time`, escrowing the whole amount
+    /// now. Returns the stream ID.
+    pub fn create_stream(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token: Address,
+        rate_per_second: i128,
+        start_time: u64,
+        stop_time: u64,
+    ) -> Result<u64, StreamError> {
+        sender.require_auth();
+        if recipient == sender || recipient == env.current_contract_address() {
+            return Err(StreamError::InvalidRecipient);
+        }
+        if rate_per_second <= 0 {
+            return Err(StreamError::InvalidRate);
+        }
+        if start_time < env.ledger().timestamp() || stop_time <= start_time {
+            return Err(StreamError::InvalidTimeframe);
+        }
+        let deposit = rate_per_second
+            .checked_mul((stop_time - start_time) as i128)
+            .ok_or(StreamError::AmountOverflow)?;
+
+        TokenClient::new(&env, &token).transfer(&sender, env.current_contract_address(), &deposit);
+
+        let stream_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextStreamId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextStreamId, &(stream_id + 1));
+        let stream = Stream {
+            id: stream_id,
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+            token: token.clone(),
+            rate_per_second,
+            start_time,
+            stop_time,
+            deposit,
+            withdrawn: 0,
+            status: StreamStatus::Active,
+            cancelled_at: None,
+        };
+        Self::save_stream(&env, &stream);
+
+        events::StreamCreatedEvent {
+            sender,
+            recipient,
+            stream_id,
+            token,
+            rate_per_second,
+            start_time,
+            stop_time,
+        }
+        .publish(&env);
+        Ok(stream_id)
+    }
+
+    /// Pay the recipient everything streamed and not yet withdrawn.
+    /// Returns the amount paid.
+    pub fn withdraw(env: Env, recipient: Address, stream_id: u64) -> Result<i128, StreamError> {
+        let mut stream = Self::load_stream(&env, stream_id)?;
+        if recipient != stream.recipient {
+            return Err(StreamError::Unauthorized);
+        }
+        recipient.require_auth();
+        if stream.status != StreamStatus::Active {
+            return Err(StreamError::InvalidStatus);
+        }
+        let amount = Self::streamed_at(&stream, env.ledger().timestamp()) - stream.withdrawn;
+        if amount == 0 {
+            return Err(StreamError::NothingToWithdraw);
+        }
+
+        stream.withdrawn += amount;
+        if stream.withdrawn == stream.deposit {
+            stream.status = StreamStatus::Completed;
+        }
+        Self::save_stream(&env, &stream);
+        Self::pay(&env, &stream, &recipient, amount);
+
+        events::WithdrawnEvent {
+            recipient,
+            stream_id,
+            amount,
+        }
+        .publish(&env);
+        Ok(amount)
+    }
+
+    /// Stop a stream now. The recipient is paid what has streamed and the
+    /// sender refunded the rest. Either party may cancel. Returns the
+    /// sender's refund.
+    pub fn cancel_stream(env: Env, caller: Address, stream_id: u64) -> Result<i128, StreamError> {
+        let mut stream = Self::load_stream(&env, stream_id)?;
+        if caller != stream.sender && caller != stream.recipient {
+            return Err(StreamError::Unauthorized);
+        }
+        caller.require_auth();
+        if stream.status != StreamStatus::Active {
+            return Err(StreamError::InvalidStatus);
+        }
+        let now = env.ledger().timestamp();
+        if now >= stream.stop_time {
+            // Nothing left to stop; the recipient just withdraws
+            return Err(StreamError::InvalidStatus);
+        }
+
+        stream.cancelled_at = Some(now);
+        let streamed = Self::streamed_at(&stream, now);
+        let recipient_amount = streamed - stream.withdrawn;
+        let sender_refund = stream.deposit - streamed;
+        stream.withdrawn = streamed;
+        stream.status = StreamStatus::Cancelled;
+        Self::save_stream(&env, &stream);
+        if recipient_amount > 0 {
+            Self::pay(&env, &stream, &stream.recipient, recipient_amount);
+        }
+        if sender_refund > 0 {
+            Self::pay(&env, &stream, &stream.sender, sender_refund);
+        }
+
+        events::StreamCancelledEvent {
+            stream_id,
+            cancelled_by: caller,
+            recipient_amount,
+            sender_refund,
+        }
+        .publish(&env);
+        Ok(sender_refund)
+    }
+
+    /// Get a stream by ID
+    pub fn get_stream(env: Env, stream_id: u64) -> Result<Stream, StreamError> {
+        Self::load_stream(&env, stream_id)
+    }
+
+    /// What the recipient could withdraw right now
+    pub fn withdrawable(env: Env, stream_id: u64) -> Result<i128, StreamError> {
+        let stream = Self::load_stream(&env, stream_id)?;
+        if stream.status != StreamStatus::Active {
+            return Ok(0);
+        }
+        Ok(Self::streamed_at(&stream, env.ledger().timestamp()) - stream.withdrawn)
+    }
+
+    /// Number of streams ever created; IDs run from 0 to this count
+    pub fn get_stream_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextStreamId)
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,46 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Streams touched by any write are kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Stream(u64),  // stream id -> Stream
+    NextStreamId, // -> u64
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StreamStatus {
+    Active,
+    /// Stopped early; both sides have been settled pro rata.
+    Cancelled,
+    /// Ran to `stop_time` and the recipient has withdrawn everything.
+    Completed,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stream {
+    pub id: u64,
+    pub sender: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub rate_per_second: i128,
+    pub start_time: u64,
+    pub stop_time: u64,
+    /// `rate_per_second * (stop_time - start_time)`, escrowed from the
+    /// sender when the stream is created.
+    pub deposit: i128,
+    pub withdrawn: i128,
+    pub status: StreamStatus,
+    /// Set on cancellation; nothing streams after it.
+    pub cancelled_at: Option<u64>,
+}
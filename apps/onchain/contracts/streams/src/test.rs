@@ -0,0 +1,184 @@
+use crate::errors::StreamError;
+use crate::storage::StreamStatus;
+use crate::{StreamsContract, StreamsContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+
+/// 10 tokens a second for 1_000 seconds
+const RATE: i128 = 10;
+const LENGTH: u64 = 1_000;
+
+struct Setup<'a> {
+    env: Env,
+    client: StreamsContractClient<'a>,
+    sender: Address,
+    recipient: Address,
+    token: TokenClient<'a>,
+    start: u64,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(admin);
+    StellarAssetClient::new(&env, &asset.address()).mint(&sender, &100_000);
+    let token = TokenClient::new(&env, &asset.address());
+
+    let contract_id = env.register(StreamsContract, ());
+    let client = StreamsContractClient::new(&env, &contract_id);
+
+    Setup {
+        start: env.ledger().timestamp() + 100,
+        env,
+        client,
+        sender,
+        recipient,
+        token,
+    }
+}
+
+fn create(s: &Setup) -> u64 {
+    s.client.create_stream(
+        &s.sender,
+        &s.recipient,
+        &s.token.address,
+        &RATE,
+        &s.start,
+        &(s.start + LENGTH),
+    )
+}
+
+fn warp(s: &Setup, to: u64) {
+    s.env.ledger().set_timestamp(to);
+}
+
+#[test]
+fn test_create_escrows_deposit() {
+    let s = setup();
+    let stream_id = create(&s);
+
+    assert_eq!(stream_id, 0);
+    assert_eq!(s.client.get_stream_count(), 1);
+    let stream = s.client.get_stream(&stream_id);
+    assert_eq!(stream.deposit, 10_000);
+    assert_eq!(stream.status, StreamStatus::Active);
+    assert_eq!(s.token.balance(&s.sender), 90_000);
+    assert_eq!(s.token.balance(&s.client.address), 10_000);
+}
+
+#[test]
+fn test_create_validates_input() {
+    let s = setup();
+    let now = s.env.ledger().timestamp();
+    let token = &s.token.address;
+
+    assert_eq!(
+        s.client
+            .try_create_stream(&s.sender, &s.sender, token, &RATE, &now, &(now + 10)),
+        Err(Ok(StreamError::InvalidRecipient))
+    );
+    assert_eq!(
+        s.client
+            .try_create_stream(&s.sender, &s.recipient, token, &0, &now, &(now + 10)),
+        Err(Ok(StreamError::InvalidRate))
+    );
+    assert_eq!(
+        s.client
+            .try_create_stream(&s.sender, &s.recipient, token, &RATE, &now, &now),
+        Err(Ok(StreamError::InvalidTimeframe))
+    );
+    assert_eq!(
+        s.client.try_create_stream(
+            &s.sender,
+            &s.recipient,
+            token,
+            &i128::MAX,
+            &now,
+            &(now + 10)
+        ),
+        Err(Ok(StreamError::AmountOverflow))
+    );
+}
+
+#[test]
+fn test_withdraw_accrues_per_second() {
+    let s = setup();
+    let stream_id = create(&s);
+
+    assert_eq!(s.client.withdrawable(&stream_id), 0);
+    assert_eq!(
+        s.client.try_withdraw(&s.recipient, &stream_id),
+        Err(Ok(StreamError::NothingToWithdraw))
+    );
+
+    warp(&s, s.start + 250);
+    assert_eq!(s.client.withdrawable(&stream_id), 2_500);
+    assert_eq!(s.client.withdraw(&s.recipient, &stream_id), 2_500);
+    warp(&s, s.start + 251);
+    assert_eq!(s.client.withdraw(&s.recipient, &stream_id), 10);
+    assert_eq!(s.token.balance(&s.recipient), 2_510);
+    assert_eq!(
+        s.client.try_withdraw(&s.sender, &stream_id),
+        Err(Ok(StreamError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_stream_completes_at_stop_time() {
+    let s = setup();
+    let stream_id = create(&s);
+
+    warp(&s, s.start + LENGTH + 500);
+    assert_eq!(s.client.withdraw(&s.recipient, &stream_id), 10_000);
+    assert_eq!(
+        s.client.get_stream(&stream_id).status,
+        StreamStatus::Completed
+    );
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert_eq!(
+        s.client.try_cancel_stream(&s.sender, &stream_id),
+        Err(Ok(StreamError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_cancel_settles_pro_rata() {
+    let s = setup();
+    let stream_id = create(&s);
+
+    warp(&s, s.start + 300);
+    s.client.withdraw(&s.recipient, &stream_id);
+    warp(&s, s.start + 400);
+    assert_eq!(s.client.cancel_stream(&s.sender, &stream_id), 6_000);
+
+    assert_eq!(s.token.balance(&s.recipient), 4_000);
+    assert_eq!(s.token.balance(&s.sender), 96_000);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    let stream = s.client.get_stream(&stream_id);
+    assert_eq!(stream.status, StreamStatus::Cancelled);
+    assert_eq!(stream.cancelled_at, Some(s.start + 400));
+    assert_eq!(s.client.withdrawable(&stream_id), 0);
+}
+
+#[test]
+fn test_cancel_before_start_refunds_everything() {
+    let s = setup();
+    let stream_id = create(&s);
+    let stranger = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client.try_cancel_stream(&stranger, &stream_id),
+        Err(Ok(StreamError::Unauthorized))
+    );
+    assert_eq!(s.client.cancel_stream(&s.recipient, &stream_id), 10_000);
+    assert_eq!(s.token.balance(&s.sender), 100_000);
+    assert_eq!(s.token.balance(&s.recipient), 0);
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::{contractevent, Address};
+
+use crate::storage::OperationAction;
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+    pub min_delay: u64,
+}
+
+/// Emitted when an operation is queued, with the full action so it can be
+/// reviewed before it becomes executable.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationScheduledEvent {
+    #[topic]
+    pub operation_id: u64,
+    pub action: OperationAction,
+    pub ready_at: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationExecutedEvent {
+    #[topic]
+    pub operation_id: u64,
+    pub executor: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationCancelledEvent {
+    #[topic]
+    pub operation_id: u64,
+}
@@ -0,0 +1,206 @@
+#![no_std]
+
+mod errors;
+mod events;
+mod storage;
+
+use errors::TimelockError;
+use soroban_sdk::{contract, contractimpl, Address, Env, Val};
+use storage::{
+    DataKey, Operation, OperationAction, OperationStatus, GRACE_PERIOD, MAX_DELAY,
+    PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_THRESHOLD,
+};
+
+#[contract]
+pub struct TimelockContract;
+
+#[contractimpl]
+impl TimelockContract {
+    /// Check that `admin` is the stored admin and has signed
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), TimelockError> {
+        let stored_admin = Self::get_admin(env.clone())?;
+        if *admin != stored_admin {
+            return Err(TimelockError::Unauthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
+
+    fn load_operation(env: &Env, operation_id: u64) -> Result<Operation, TimelockError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Operation(operation_id))
+            .ok_or(TimelockError::OperationNotFound)
+    }
+
+    /// Write an operation back and keep it clear of archival
+    fn save_operation(env: &Env, operation: &Operation) {
+        let key = DataKey::Operation(operation.id);
+        env.storage().persistent().set(&key, operation);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// Initialize the contract. `admin` schedules and cancels operations
+    /// and is meant to be the multisig; every operation waits at least
+    /// `min_delay` seconds.
+    pub fn initialize(env: Env, admin: Address, min_delay: u64) -> Result<(), TimelockError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(TimelockError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        if min_delay > MAX_DELAY {
+            return Err(TimelockError::InvalidDelay);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::MinDelay, &min_delay);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextOperationId, &0u64);
+
+        events::InitializedEvent { admin, min_delay }.publish(&env);
+        Ok(())
+    }
+
+    /// Queue `action` to become executable after `delay` seconds, which
+    /// must be at least the minimum delay. Returns the operation ID.
+    pub fn schedule(
+        env: Env,
+        admin: Address,
+        action: OperationAction,
+        delay: u64,
+    ) -> Result<u64, TimelockError> {
+        Self::require_admin(&env, &admin)?;
+        if delay < Self::get_min_delay(env.clone()) || delay > MAX_DELAY {
+            return Err(TimelockError::InvalidDelay);
+        }
+        if let OperationAction::SetMinDelay(min_delay) = action {
+            if min_delay > MAX_DELAY {
+                return Err(TimelockError::InvalidDelay);
+            }
+        }
+
+        let operation_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextOperationId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextOperationId, &(operation_id + 1));
+        let now = env.ledger().timestamp();
+        let operation = Operation {
+            id: operation_id,
+            action: action.clone(),
+            scheduled_at: now,
+            ready_at: now + delay,
+            status: OperationStatus::Queued,
+        };
+        Self::save_operation(&env, &operation);
+
+        events::OperationScheduledEvent {
+            operation_id,
+            action,
+            ready_at: operation.ready_at,
+        }
+        .publish(&env);
+        Ok(operation_id)
+    }
+
+    /// Run a queued operation once its delay has passed. Anyone may
+    /// execute; an operation not executed within `GRACE_PERIOD` of becoming
+    /// ready goes stale and must be scheduled again.
+    pub fn execute(env: Env, executor: Address, operation_id: u64) -> Result<(), TimelockError> {
+        executor.require_auth();
+        let mut operation = Self::load_operation(&env, operation_id)?;
+        if operation.status != OperationStatus::Queued {
+            return Err(TimelockError::InvalidStatus);
+        }
+        let now = env.ledger().timestamp();
+        if now < operation.ready_at {
+            return Err(TimelockError::NotReady);
+        }
+        if now > operation.ready_at + GRACE_PERIOD {
+            return Err(TimelockError::OperationStale);
+        }
+
+        // Record first so the call cannot re-enter and run it again
+        operation.status = OperationStatus::Executed;
+        Self::save_operation(&env, &operation);
+        match operation.action {
+            OperationAction::Invoke(contract, function, args) => {
+                env.invoke_contract::<Val>(&contract, &function, args);
+            }
+            OperationAction::SetMinDelay(min_delay) => {
+                env.storage().instance().set(&DataKey::MinDelay, &min_delay);
+            }
+            OperationAction::SetAdmin(admin) => {
+                env.storage().instance().set(&DataKey::Admin, &admin);
+            }
+        }
+
+        events::OperationExecutedEvent {
+            operation_id,
+            executor,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Drop a queued operation before it runs
+    pub fn cancel(env: Env, admin: Address, operation_id: u64) -> Result<(), TimelockError> {
+        Self::require_admin(&env, &admin)?;
+        let mut operation = Self::load_operation(&env, operation_id)?;
+        if operation.status != OperationStatus::Queued {
+            return Err(TimelockError::InvalidStatus);
+        }
+
+        operation.status = OperationStatus::Cancelled;
+        Self::save_operation(&env, &operation);
+
+        events::OperationCancelledEvent { operation_id }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_operation(env: Env, operation_id: u64) -> Result<Operation, TimelockError> {
+        Self::load_operation(&env, operation_id)
+    }
+
+    /// Whether a queued operation can be executed right now
+    pub fn is_ready(env: Env, operation_id: u64) -> Result<bool, TimelockError> {
+        let operation = Self::load_operation(&env, operation_id)?;
+        let now = env.ledger().timestamp();
+        Ok(operation.status == OperationStatus::Queued
+            && now >= operation.ready_at
+            && now <= operation.ready_at + GRACE_PERIOD)
+    }
+
+    /// Number of operations ever scheduled; IDs run from 0 to this count
+    pub fn get_operation_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextOperationId)
+            .unwrap_or(0)
+    }
+
+    pub fn get_min_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinDelay)
+            .unwrap_or(0)
+    }
+
+    /// Get admin address
+    pub fn get_admin(env: Env) -> Result<Address, TimelockError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(TimelockError::NotInitialized)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,59 @@
+use soroban_sdk::{contracttype, Address, Symbol, Val, Vec};
+
+/// Longest delay that may be configured or requested: 30 days.
+pub const MAX_DELAY: u64 = 30 * 86_400;
+
+/// How long a ready operation stays executable before it goes stale: 14 days.
+pub const GRACE_PERIOD: u64 = 14 * 86_400;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Operations touched by any write are kept alive for ~60 days, enough to
+/// outlast the longest delay plus the grace period.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 60 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~59 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,           // -> Address allowed to schedule and cancel, e.g. the multisig
+    MinDelay,        // -> u64 seconds every operation must wait
+    Operation(u64),  // operation id -> Operation
+    NextOperationId, // -> u64
+}
+
+/// What an operation does once executed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OperationAction {
+    /// Call `function` on `contract` with `args`, authorised as this
+    /// contract, which holds the admin role of the contracts it guards.
+    Invoke(Address, Symbol, Vec<Val>),
+    /// Change the minimum delay. The timelock cannot call back into itself,
+    /// so its own settings get dedicated actions.
+    SetMinDelay(u64),
+    SetAdmin(Address),
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OperationStatus {
+    Queued = 0,
+    Executed = 1,
+    Cancelled = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation {
+    pub id: u64,
+    pub action: OperationAction,
+    pub scheduled_at: u64,
+    /// Earliest execution time.
+    pub ready_at: u64,
+    pub status: OperationStatus,
+}
@@ -0,0 +1,171 @@
+use crate::errors::TimelockError;
+use crate::storage::{OperationAction, OperationStatus, GRACE_PERIOD};
+use crate::{TimelockContract, TimelockContractClient};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, IntoVal, Symbol,
+};
+
+/// A contract administered by the timelock.
+#[contract]
+struct MockTarget;
+
+#[contractimpl]
+impl MockTarget {
+    pub fn init(env: Env, admin: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("admin"), &admin);
+    }
+
+    pub fn set_value(env: Env, value: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("admin"))
+            .unwrap();
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&symbol_short!("value"), &value);
+    }
+
+    pub fn value(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("value"))
+            .unwrap_or(0)
+    }
+}
+
+const DELAY: u64 = 2 * 86_400;
+
+struct Setup<'a> {
+    env: Env,
+    client: TimelockContractClient<'a>,
+    admin: Address,
+    target: MockTargetClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(TimelockContract, ());
+    let client = TimelockContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &DELAY);
+
+    let target = MockTargetClient::new(&env, &env.register(MockTarget, ()));
+    target.init(&contract_id);
+
+    Setup {
+        env,
+        client,
+        admin,
+        target,
+    }
+}
+
+fn set_value(s: &Setup, value: u32) -> OperationAction {
+    OperationAction::Invoke(
+        s.target.address.clone(),
+        Symbol::new(&s.env, "set_value"),
+        vec![&s.env, value.into_val(&s.env)],
+    )
+}
+
+fn warp(s: &Setup, by: u64) {
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + by);
+}
+
+#[test]
+fn test_execute_after_delay() {
+    let s = setup();
+    let anyone = Address::generate(&s.env);
+    let operation_id = s.client.schedule(&s.admin, &set_value(&s, 7), &DELAY);
+
+    assert!(!s.client.is_ready(&operation_id));
+    assert_eq!(
+        s.client.try_execute(&anyone, &operation_id),
+        Err(Ok(TimelockError::NotReady))
+    );
+    warp(&s, DELAY);
+    assert!(s.client.is_ready(&operation_id));
+    s.client.execute(&anyone, &operation_id);
+
+    assert_eq!(s.target.value(), 7);
+    assert_eq!(
+        s.client.get_operation(&operation_id).status,
+        OperationStatus::Executed
+    );
+    assert_eq!(
+        s.client.try_execute(&anyone, &operation_id),
+        Err(Ok(TimelockError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_schedule_enforces_min_delay() {
+    let s = setup();
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.client
+            .try_schedule(&s.admin, &set_value(&s, 1), &(DELAY - 1)),
+        Err(Ok(TimelockError::InvalidDelay))
+    );
+    assert_eq!(
+        s.client.try_schedule(&stranger, &set_value(&s, 1), &DELAY),
+        Err(Ok(TimelockError::Unauthorized))
+    );
+    assert_eq!(s.client.get_operation_count(), 0);
+}
+
+#[test]
+fn test_cancel_and_stale() {
+    let s = setup();
+    let cancelled = s.client.schedule(&s.admin, &set_value(&s, 1), &DELAY);
+    let stale = s.client.schedule(&s.admin, &set_value(&s, 2), &DELAY);
+
+    s.client.cancel(&s.admin, &cancelled);
+    warp(&s, DELAY + GRACE_PERIOD + 1);
+    assert_eq!(
+        s.client.try_execute(&s.admin, &cancelled),
+        Err(Ok(TimelockError::InvalidStatus))
+    );
+    assert_eq!(
+        s.client.try_execute(&s.admin, &stale),
+        Err(Ok(TimelockError::OperationStale))
+    );
+    assert_eq!(s.target.value(), 0);
+}
+
+#[test]
+fn test_own_settings_go_through_the_queue() {
+    let s = setup();
+    let multisig = Address::generate(&s.env);
+
+    let delay_op = s
+        .client
+        .schedule(&s.admin, &OperationAction::SetMinDelay(DELAY * 2), &DELAY);
+    let admin_op = s.client.schedule(
+        &s.admin,
+        &OperationAction::SetAdmin(multisig.clone()),
+        &DELAY,
+    );
+    assert_eq!(s.client.get_min_delay(), DELAY);
+
+    warp(&s, DELAY);
+    s.client.execute(&s.admin, &delay_op);
+    s.client.execute(&s.admin, &admin_op);
+    assert_eq!(s.client.get_min_delay(), DELAY * 2);
+    assert_eq!(s.client.get_admin(), multisig);
+    assert_eq!(
+        s.client
+            .try_schedule(&s.admin, &set_value(&s, 1), &(DELAY * 2)),
+        Err(Ok(TimelockError::Unauthorized))
+    );
+}
@@ -0,0 +1,20 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TreasuryError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    Unauthorized = 3,
+    InvalidConfig = 4,
+    InvalidAmount = 5,
+    InvalidMemo = 6,
+    TokenNotAllowed = 7,
+    NoRole = 8,
+    OverRoleLimit = 9,
+    OverEpochBudget = 10,
+    SpendNotFound = 11,
+    InvalidStatus = 12,
+    NotReady = 13,
+}
@@ -0,0 +1,82 @@
+use soroban_sdk::{contractevent, Address, String, Symbol};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+    pub epoch_length: u64,
+    pub timelock_delay: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenPolicyUpdatedEvent {
+    #[topic]
+    pub token: Address,
+    pub epoch_budget: i128,
+    pub timelock_threshold: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleUpdatedEvent {
+    #[topic]
+    pub account: Address,
+    pub role: Option<Symbol>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleLimitUpdatedEvent {
+    #[topic]
+    pub role: Symbol,
+    #[topic]
+    pub token: Address,
+    pub limit: i128,
+}
+
+/// Emitted for every deposit into the treasury.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InflowEvent {
+    #[topic]
+    pub token: Address,
+    #[topic]
+    pub from: Address,
+    pub amount: i128,
+    pub memo: String,
+}
+
+/// Emitted for every payment out of the treasury.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutflowEvent {
+    #[topic]
+    pub token: Address,
+    #[topic]
+    pub to: Address,
+    pub spend_id: u64,
+    pub spender: Address,
+    pub amount: i128,
+    pub memo: String,
+}
+
+/// Emitted when a spend above the threshold is queued behind the timelock.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendQueuedEvent {
+    #[topic]
+    pub spend_id: u64,
+    pub spender: Address,
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub ready_at: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendCancelledEvent {
+    #[topic]
+    pub spend_id: u64,
+}
@@ -0,0 +1,435 @@
+#![no_std]
+
+mod errors;
+mod events;
+mod storage;
+
+use errors::TreasuryError;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol};
+use storage::{
+    DataKey, Spend, SpendStatus, TokenPolicy, TreasuryConfig, MAX_DELAY, MAX_MEMO_LEN,
+    PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_THRESHOLD,
+};
+
+#[contract]
+pub struct TreasuryContract;
+
+#[contractimpl]
+impl TreasuryContract {
+    /// Check that `admin` is the stored admin and has signed
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), TreasuryError> {
+        let stored_admin = Self::get_admin(env.clone())?;
+        if *admin != stored_admin {
+            return Err(TreasuryError::Unauthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
+
+    fn validate_memo(memo: &String) -> Result<(), TreasuryError> {
+        if memo.len() > MAX_MEMO_LEN {
+            return Err(TreasuryError::InvalidMemo);
+        }
+        Ok(())
+    }
+
+    fn load_spend(env: &Env, spend_id: u64) -> Result<Spend, TreasuryError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Spend(spend_id))
+            .ok_or(TreasuryError::SpendNotFound)
+    }
+
+    /// Write a spend back and keep it clear of archival
+    fn save_spend(env: &Env, spend: &Spend) {
+        let key = DataKey::Spend(spend.id);
+        env.storage().persistent().set(&key, spend);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    fn set_spent(env: &Env, token: &Address, epoch: u64, spent: i128) {
+        let key = DataKey::Spent(token.clone(), epoch);
+        env.storage().persistent().set(&key, &spent);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    fn spender_spent(env: &Env, spender: &Address, token: &Address, epoch: u64) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SpentBy(spender.clone(), token.clone(), epoch))
+            .unwrap_or(0)
+    }
+
+    fn set_spender_spent(env: &Env, spender: &Address, token: &Address, epoch: u64, spent: i128) {
+        let key = DataKey::SpentBy(spender.clone(), token.clone(), epoch);
+        env.storage().persistent().set(&key, &spent);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// Pay out a spend and log the outflow
+    fn pay(env: &Env, spend: &Spend) {
+        TokenClient::new(env, &spend.token).transfer(
+            &env.current_contract_address(),
+            &spend.to,
+            &spend.amount,
+        );
+
+        events::OutflowEvent {
+            token: spend.token.clone(),
+            to: spend.to.clone(),
+            spend_id: spend.id,
+            spender: spend.spender.clone(),
+            amount: spend.amount,
+            memo: spend.memo.clone(),
+        }
+        .publish(env);
+    }
+
+    /// Initialize the treasury. Budgets reset every `epoch_length` seconds
+    /// from now; spends above a token's threshold wait `timelock_delay`.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        epoch_length: u64,
+        timelock_delay: u64,
+    ) -> Result<(), TreasuryError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(TreasuryError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        if epoch_length == 0 || timelock_delay > MAX_DELAY {
+            return Err(TreasuryError::InvalidConfig);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(
+            &DataKey::Config,
+            &TreasuryConfig {
+                epoch_length,
+                timelock_delay,
+            },
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::StartedAt, &env.ledger().timestamp());
+        env.storage().instance().set(&DataKey::NextSpendId, &0u64);
+
+        events::InitializedEvent {
+            admin,
+            epoch_length,
+            timelock_delay,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Allow `token` to be spent under `policy`, or change its policy.
+    /// Changes apply to spends made from now on.
+    pub fn set_token_policy(
+        env: Env,
+        admin: Address,
+        token: Address,
+        policy: TokenPolicy,
+    ) -> Result<(), TreasuryError> {
+        Self::require_admin(&env, &admin)?;
+        if policy.epoch_budget < 0 || policy.timelock_threshold < 0 {
+            return Err(TreasuryError::InvalidConfig);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::TokenPolicy(token.clone()), &policy);
+
+        events::TokenPolicyUpdatedEvent {
+            token,
+            epoch_budget: policy.epoch_budget,
+            timelock_threshold: policy.timelock_threshold,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Give `account` a spending role, or with `None` take it away
+    pub fn set_role(
+        env: Env,
+        admin: Address,
+        account: Address,
+        role: Option<Symbol>,
+    ) -> Result<(), TreasuryError> {
+        Self::require_admin(&env, &admin)?;
+        let key = DataKey::Role(account.clone());
+        match &role {
+            Some(role) => env.storage().persistent().set(&key, role),
+            None => env.storage().persistent().remove(&key),
+        }
+
+        events::RoleUpdatedEvent { account, role }.publish(&env);
+        Ok(())
+    }
+
+    /// Cap any single spend of `token` by holders of `role` at `limit`.
+    /// A limit of zero stops the role spending the token.
+    pub fn set_role_limit(
+        env: Env,
+        admin: Address,
+        role: Symbol,
+        token: Address,
+        limit: i128,
+    ) -> Result<(), TreasuryError> {
+        Self::require_admin(&env, &admin)?;
+        if limit < 0 {
+            return Err(TreasuryError::InvalidAmount);
+        }
+        let key = DataKey::RoleLimit(role.clone(), token.clone());
+        if limit == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &limit);
+        }
+
+        events::RoleLimitUpdatedEvent { role, token, limit }.publish(&env);
+        Ok(())
+    }
+
+    /// Pay `amount` of `token` into the treasury. Deposits are the logged
+    /// way in; tokens sent straight to the contract still count towards
+    /// its balance but leave no inflow record.
+    pub fn deposit(
+        env: Env,
+        from: Address,
+        token: Address,
+        amount: i128,
+        memo: String,
+    ) -> Result<(), TreasuryError> {
+        Self::get_admin(env.clone())?;
+        from.require_auth();
+        if amount <= 0 {
+            return Err(TreasuryError::InvalidAmount);
+        }
+        Self::validate_memo(&memo)?;
+
+        TokenClient::new(&env, &token).transfer(&from, env.current_contract_address(), &amount);
+
+        events::InflowEvent {
+            token,
+            from,
+            amount,
+            memo,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Pay `amount` of `token` to `to`. The spender's role must allow that
+    /// much in one spend, and it is charged to the current epoch's budget.
+    /// Once the spender's total for the epoch passes the token's timelock
+    /// threshold, spends are queued instead of paid, so splitting a large
+    /// payment does not dodge the delay. Returns the spend ID.
+    pub fn spend(
+        env: Env,
+        spender: Address,
+        token: Address,
+        to: Address,
+        amount: i128,
+        memo: String,
+    ) -> Result<u64, TreasuryError> {
+        spender.require_auth();
+        let config = Self::get_config(env.clone())?;
+        if amount <= 0 {
+            return Err(TreasuryError::InvalidAmount);
+        }
+        Self::validate_memo(&memo)?;
+        let policy = Self::get_token_policy(env.clone(), token.clone())?;
+        let role = Self::get_role(env.clone(), spender.clone()).ok_or(TreasuryError::NoRole)?;
+        if amount > Self::get_role_limit(env.clone(), role, token.clone()) {
+            return Err(TreasuryError::OverRoleLimit);
+        }
+        let epoch = Self::get_epoch(env.clone())?;
+        let spent = Self::epoch_spent(env.clone(), token.clone(), epoch);
+        if amount > policy.epoch_budget - spent {
+            return Err(TreasuryError::OverEpochBudget);
+        }
+        Self::set_spent(&env, &token, epoch, spent + amount);
+        let spent_by = Self::spender_spent(&env, &spender, &token, epoch) + amount;
+        Self::set_spender_spent(&env, &spender, &token, epoch, spent_by);
+
+        let spend_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextSpendId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextSpendId, &(spend_id + 1));
+        let now = env.ledger().timestamp();
+        let queued = spent_by > policy.timelock_threshold;
+        let mut spend = Spend {
+            id: spend_id,
+            spender,
+            token,
+            to,
+            amount,
+            memo,
+            epoch,
+            created_at: now,
+            ready_at: if queued {
+                now + config.timelock_delay
+            } else {
+                now
+            },
+            status: SpendStatus::Queued,
+        };
+
+        if queued {
+            Self::save_spend(&env, &spend);
+            events::SpendQueuedEvent {
+                spend_id,
+                spender: spend.spender,
+                token: spend.token,
+                to: spend.to,
+                amount,
+                ready_at: spend.ready_at,
+            }
+            .publish(&env);
+        } else {
+            spend.status = SpendStatus::Executed;
+            Self::save_spend(&env, &spend);
+            Self::pay(&env, &spend);
+        }
+        Ok(spend_id)
+    }
+
+    /// Pay out a queued spend once its timelock has passed. Anyone may.
+    pub fn execute_spend(env: Env, spend_id: u64) -> Result<(), TreasuryError> {
+        let mut spend = Self::load_spend(&env, spend_id)?;
+        if spend.status != SpendStatus::Queued {
+            return Err(TreasuryError::InvalidStatus);
+        }
+        if env.ledger().timestamp() < spend.ready_at {
+            return Err(TreasuryError::NotReady);
+        }
+
+        spend.status = SpendStatus::Executed;
+        Self::save_spend(&env, &spend);
+        Self::pay(&env, &spend);
+        Ok(())
+    }
+
+    /// Drop a queued spend and give its amount back to the epoch budget, and
+    /// the spender's running total, it was charged to
+    pub fn cancel_spend(env: Env, admin: Address, spend_id: u64) -> Result<(), TreasuryError> {
+        Self::require_admin(&env, &admin)?;
+        let mut spend = Self::load_spend(&env, spend_id)?;
+        if spend.status != SpendStatus::Queued {
+            return Err(TreasuryError::InvalidStatus);
+        }
+
+        spend.status = SpendStatus::Cancelled;
+        Self::save_spend(&env, &spend);
+        let spent = Self::epoch_spent(env.clone(), spend.token.clone(), spend.epoch);
+        Self::set_spent(&env, &spend.token, spend.epoch, spent - spend.amount);
+        let spent_by = Self::spender_spent(&env, &spend.spender, &spend.token, spend.epoch);
+        Self::set_spender_spent(
+            &env,
+            &spend.spender,
+            &spend.token,
+            spend.epoch,
+            spent_by - spend.amount,
+        );
+
+        events::SpendCancelledEvent { spend_id }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_spend(env: Env, spend_id: u64) -> Result<Spend, TreasuryError> {
+        Self::load_spend(&env, spend_id)
+    }
+
+    /// Number of spends ever made; IDs run from 0 to this count
+    pub fn get_spend_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextSpendId)
+            .unwrap_or(0)
+    }
+
+    /// The budget epoch the current ledger time falls in
+    pub fn get_epoch(env: Env) -> Result<u64, TreasuryError> {
+        let config = Self::get_config(env.clone())?;
+        let started_at: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::StartedAt)
+            .ok_or(TreasuryError::NotInitialized)?;
+        Ok((env.ledger().timestamp() - started_at) / config.epoch_length)
+    }
+
+    /// How much of `token` has been charged to `epoch`, queued spends
+    /// included
+    pub fn epoch_spent(env: Env, token: Address, epoch: u64) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Spent(token, epoch))
+            .unwrap_or(0)
+    }
+
+    /// What can still be spent of `token` this epoch
+    pub fn remaining_budget(env: Env, token: Address) -> Result<i128, TreasuryError> {
+        let policy = Self::get_token_policy(env.clone(), token.clone())?;
+        let epoch = Self::get_epoch(env.clone())?;
+        Ok((policy.epoch_budget - Self::epoch_spent(env, token, epoch)).max(0))
+    }
+
+    /// The treasury's holdings of `token`
+    pub fn balance(env: Env, token: Address) -> i128 {
+        TokenClient::new(&env, &token).balance(&env.current_contract_address())
+    }
+
+    pub fn get_token_policy(env: Env, token: Address) -> Result<TokenPolicy, TreasuryError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::TokenPolicy(token))
+            .ok_or(TreasuryError::TokenNotAllowed)
+    }
+
+    pub fn get_role(env: Env, account: Address) -> Option<Symbol> {
+        env.storage().persistent().get(&DataKey::Role(account))
+    }
+
+    /// Largest single spend of `token` holders of `role` may make
+    pub fn get_role_limit(env: Env, role: Symbol, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RoleLimit(role, token))
+            .unwrap_or(0)
+    }
+
+    pub fn get_config(env: Env) -> Result<TreasuryConfig, TreasuryError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(TreasuryError::NotInitialized)
+    }
+
+    /// Get admin address
+    pub fn get_admin(env: Env) -> Result<Address, TreasuryError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(TreasuryError::NotInitialized)
+    }
+}
+
+#[cfg(test)]
+mod test;
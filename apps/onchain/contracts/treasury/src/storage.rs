@@ -0,0 +1,80 @@
+use soroban_sdk::{contracttype, Address, String, Symbol};
+
+/// Longest memo accepted on a deposit or spend, in bytes.
+pub const MAX_MEMO_LEN: u32 = 256;
+
+/// Longest timelock delay that may be configured: 30 days.
+pub const MAX_DELAY: u64 = 30 * 86_400;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Spends and accounting touched by any write are kept alive for ~60 days,
+/// enough to outlast the longest timelock delay.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 60 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~59 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,                          // -> Address setting policy, e.g. the multisig
+    Config,                         // -> TreasuryConfig
+    StartedAt,                      // -> u64 timestamp epoch 0 began
+    TokenPolicy(Address),           // token -> TokenPolicy; unset tokens can't be spent
+    Role(Address),                  // spender -> Symbol role
+    RoleLimit(Symbol, Address),     // (role, token) -> i128 largest single spend
+    Spent(Address, u64),            // (token, epoch) -> i128 charged to that epoch
+    SpentBy(Address, Address, u64), // (spender, token, epoch) -> i128 charged by them
+    Spend(u64),                     // spend id -> Spend
+    NextSpendId,                    // -> u64
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryConfig {
+    /// Length of a budget epoch in seconds.
+    pub epoch_length: u64,
+    /// How long spends above a token's timelock threshold wait.
+    pub timelock_delay: u64,
+}
+
+/// Spending rules for one token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenPolicy {
+    /// Most that may be spent in one epoch, queued spends included.
+    pub epoch_budget: i128,
+    /// Once a spender's spends in an epoch add up to more than this, the one
+    /// that crosses it and every later one wait out the timelock delay.
+    pub timelock_threshold: i128,
+}
+
+/// `Queued` spends become `Executed` once their delay passes, unless the
+/// admin `Cancelled` them first. Spends under the threshold are executed
+/// as soon as they are made.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SpendStatus {
+    Queued,
+    Executed,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Spend {
+    pub id: u64,
+    pub spender: Address,
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub memo: String,
+    /// Epoch whose budget the spend was charged to.
+    pub epoch: u64,
+    pub created_at: u64,
+    /// Earliest time a queued spend can be executed.
+    pub ready_at: u64,
+    pub status: SpendStatus,
+}
@@ -0,0 +1,237 @@
+use crate::errors::TreasuryError;
+use crate::storage::{SpendStatus, TokenPolicy};
+use crate::{TreasuryContract, TreasuryContractClient};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String, Symbol,
+};
+
+const EPOCH: u64 = 7 * 86_400;
+const DELAY: u64 = 2 * 86_400;
+const OPS: Symbol = symbol_short!("ops");
+
+struct Setup<'a> {
+    env: Env,
+    client: TreasuryContractClient<'a>,
+    admin: Address,
+    spender: Address,
+    token: TokenClient<'a>,
+    other_token: TokenClient<'a>,
+}
+
+fn new_token<'a>(env: &Env, admin: &Address, holder: &Address) -> TokenClient<'a> {
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    StellarAssetClient::new(env, &asset.address()).mint(holder, &10_000);
+    TokenClient::new(env, &asset.address())
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let token = new_token(&env, &admin, &admin);
+    let other_token = new_token(&env, &admin, &admin);
+
+    let contract_id = env.register(TreasuryContract, ());
+    let client = TreasuryContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &EPOCH, &DELAY);
+    let memo = String::from_str(&env, "seed");
+    client.deposit(&admin, &token.address, &5_000, &memo);
+    client.deposit(&admin, &other_token.address, &5_000, &memo);
+    client.set_token_policy(
+        &admin,
+        &token.address,
+        &TokenPolicy {
+            epoch_budget: 1_000,
+            timelock_threshold: 500,
+        },
+    );
+    client.set_role(&admin, &spender, &Some(OPS));
+    client.set_role_limit(&admin, &OPS, &token.address, &600);
+
+    Setup {
+        env,
+        client,
+        admin,
+        spender,
+        token,
+        other_token,
+    }
+}
+
+fn spend(s: &Setup, amount: i128, to: &Address) -> Result<u64, TreasuryError> {
+    match s.client.try_spend(
+        &s.spender,
+        &s.token.address,
+        to,
+        &amount,
+        &String::from_str(&s.env, "invoice"),
+    ) {
+        Ok(Ok(spend_id)) => Ok(spend_id),
+        Err(Ok(err)) => Err(err),
+        _ => panic!("unexpected spend failure"),
+    }
+}
+
+#[test]
+fn test_deposits_hold_multiple_tokens() {
+    let s = setup();
+
+    assert_eq!(s.client.balance(&s.token.address), 5_000);
+    assert_eq!(s.client.balance(&s.other_token.address), 5_000);
+    assert_eq!(s.token.balance(&s.admin), 5_000);
+    assert_eq!(
+        s.client.try_deposit(
+            &s.admin,
+            &s.token.address,
+            &0,
+            &String::from_str(&s.env, "")
+        ),
+        Err(Ok(TreasuryError::InvalidAmount))
+    );
+    // Holding a token doesn't make it spendable without a policy
+    assert_eq!(
+        s.client.try_spend(
+            &s.spender,
+            &s.other_token.address,
+            &s.admin,
+            &1,
+            &String::from_str(&s.env, "")
+        ),
+        Err(Ok(TreasuryError::TokenNotAllowed))
+    );
+}
+
+#[test]
+fn test_spend_enforces_role_limits() {
+    let s = setup();
+    let vendor = Address::generate(&s.env);
+
+    let spend_id = spend(&s, 300, &vendor).unwrap();
+    assert_eq!(s.token.balance(&vendor), 300);
+    assert_eq!(s.client.get_spend(&spend_id).status, SpendStatus::Executed);
+    assert_eq!(spend(&s, 601, &vendor), Err(TreasuryError::OverRoleLimit));
+
+    s.client.set_role(&s.admin, &s.spender, &None);
+    assert_eq!(spend(&s, 100, &vendor), Err(TreasuryError::NoRole));
+    assert_eq!(
+        s.client
+            .try_set_role_limit(&s.spender, &OPS, &s.token.address, &1_000),
+        Err(Ok(TreasuryError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_epoch_budget_resets() {
+    let s = setup();
+    let vendor = Address::generate(&s.env);
+
+    spend(&s, 400, &vendor).unwrap();
+    spend(&s, 400, &vendor).unwrap();
+    assert_eq!(s.client.remaining_budget(&s.token.address), 200);
+    assert_eq!(spend(&s, 300, &vendor), Err(TreasuryError::OverEpochBudget));
+
+    s.env.ledger().set_timestamp(1_000 + EPOCH);
+    assert_eq!(s.client.get_epoch(), 1);
+    assert_eq!(s.client.remaining_budget(&s.token.address), 1_000);
+    spend(&s, 300, &vendor).unwrap();
+    assert_eq!(s.client.epoch_spent(&s.token.address, &0), 800);
+    assert_eq!(s.client.epoch_spent(&s.token.address, &1), 300);
+}
+
+#[test]
+fn test_large_spend_waits_for_timelock() {
+    let s = setup();
+    let vendor = Address::generate(&s.env);
+
+    let spend_id = spend(&s, 550, &vendor).unwrap();
+    let queued = s.client.get_spend(&spend_id);
+    assert_eq!(queued.status, SpendStatus::Queued);
+    assert_eq!(queued.ready_at, 1_000 + DELAY);
+    assert_eq!(s.token.balance(&vendor), 0);
+    // Queued spends still hold their share of the budget
+    assert_eq!(s.client.remaining_budget(&s.token.address), 450);
+
+    assert_eq!(
+        s.client.try_execute_spend(&spend_id),
+        Err(Ok(TreasuryError::NotReady))
+    );
+    s.env.ledger().set_timestamp(1_000 + DELAY);
+    s.client.execute_spend(&spend_id);
+
+    assert_eq!(s.token.balance(&vendor), 550);
+    assert_eq!(s.client.get_spend(&spend_id).status, SpendStatus::Executed);
+    assert_eq!(
+        s.client.try_execute_spend(&spend_id),
+        Err(Ok(TreasuryError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_cancel_queued_spend_restores_budget() {
+    let s = setup();
+    let vendor = Address::generate(&s.env);
+    let spend_id = spend(&s, 550, &vendor).unwrap();
+
+    assert_eq!(
+        s.client.try_cancel_spend(&s.spender, &spend_id),
+        Err(Ok(TreasuryError::Unauthorized))
+    );
+    s.client.cancel_spend(&s.admin, &spend_id);
+
+    assert_eq!(s.client.get_spend(&spend_id).status, SpendStatus::Cancelled);
+    assert_eq!(s.client.remaining_budget(&s.token.address), 1_000);
+    s.env.ledger().set_timestamp(1_000 + DELAY);
+    assert_eq!(
+        s.client.try_execute_spend(&spend_id),
+        Err(Ok(TreasuryError::InvalidStatus))
+    );
+    assert_eq!(s.client.balance(&s.token.address), 5_000);
+}
+
+#[test]
+fn test_initialize_validates_config() {
+    let s = setup();
+    assert_eq!(
+        s.client.try_initialize(&s.admin, &EPOCH, &DELAY),
+        Err(Ok(TreasuryError::AlreadyInitialized))
+    );
+
+    let other = TreasuryContractClient::new(&s.env, &s.env.register(TreasuryContract, ()));
+    assert_eq!(
+        other.try_initialize(&s.admin, &0, &DELAY),
+        Err(Ok(TreasuryError::InvalidConfig))
+    );
+    assert_eq!(
+        other.try_initialize(&s.admin, &EPOCH, &(31 * 86_400)),
+        Err(Ok(TreasuryError::InvalidConfig))
+    );
+}
+
+#[test]
+fn test_split_spends_still_hit_timelock() {
+    let s = setup();
+    let vendor = Address::generate(&s.env);
+
+    // 300 + 300 crosses the 500 threshold on the second spend
+    let first = spend(&s, 300, &vendor).unwrap();
+    let second = spend(&s, 300, &vendor).unwrap();
+    assert_eq!(s.client.get_spend(&first).status, SpendStatus::Executed);
+    assert_eq!(s.client.get_spend(&second).status, SpendStatus::Queued);
+    assert_eq!(s.token.balance(&vendor), 300);
+
+    // Cancelling frees the spender's allowance again
+    s.client.cancel_spend(&s.admin, &second);
+    let third = spend(&s, 200, &vendor).unwrap();
+    assert_eq!(s.client.get_spend(&third).status, SpendStatus::Executed);
+
+    // The running total starts over each epoch
+    s.env.ledger().set_timestamp(1_000 + EPOCH);
+    let fourth = spend(&s, 500, &vendor).unwrap();
+    assert_eq!(s.client.get_spend(&fourth).status, SpendStatus::Executed);
+}
@@ -13,4 +13,33 @@ pub enum VestingError {
     InvalidStartTime = 7,
     NothingToClaim = 8,
     InsufficientBalance = 9,
+    NotRevocable = 10,
+    AlreadyRevoked = 11,
+    TreasuryNotSet = 12,
+    InvalidBatch = 13,
+    InvalidBeneficiary = 14,
+    InvalidMilestone = 15,
+    MilestoneAlreadyConfirmed = 16,
+    ExceedsClaimable = 17,
+    ClaimsPaused = 18,
+    ScheduleFrozen = 19,
+    ClawbackNotScheduled = 20,
+    ClawbackPending = 21,
+    ClawbackTimelocked = 22,
+    TemplateNotFound = 23,
+    InvalidTemplate = 24,
+    NoPendingAdmin = 25,
+    InvalidBoost = 26,
+    NoPendingExtension = 27,
+    InvalidFee = 28,
+    ScheduleStarted = 29,
+    InvalidTtl = 30,
+    ZeroAmount = 31,
+    ZeroDuration = 32,
+    AmountOverflow = 33,
+    DuplicateSchedule = 34,
+    TimelineOverflow = 35,
+    GracePeriodActive = 36,
+    NotRevoked = 37,
+    PositionsNotTransferable = 38,
 }
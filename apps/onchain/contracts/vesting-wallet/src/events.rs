@@ -1,13 +1,18 @@
-use soroban_sdk::{contractevent, Address, BytesN};
+use soroban_sdk::{contractevent, Address, BytesN, String};
 
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VestingCreatedEvent {
     #[topic]
     pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub token: Address,
     pub amount: i128,
     pub start_time: u64,
     pub duration: u64,
+    pub cliff_duration: u64,
+    pub revocable: bool,
+    pub created_at: u64,
 }
 
 #[contractevent]
@@ -15,8 +20,217 @@ pub struct VestingCreatedEvent {
 pub struct TokensClaimedEvent {
     #[topic]
     pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub recipient: Address,
+    /// Gross amount taken off the schedule, including `fee`.
     pub amount_claimed: i128,
+    /// Part of `amount_claimed` sent to the claim fee treasury.
+    pub fee: i128,
     pub remaining: i128,
+    pub claimed_at: u64,
+}
+
+/// Emitted when the admin sweeps what a beneficiary left unclaimed after a
+/// revocation's grace period.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestedSweptEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub amount: i128,
+}
+
+/// Emitted when the admin cancels a schedule before it starts.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingCancelledEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub refunded: i128,
+}
+
+/// Emitted when the admin revokes a schedule and claws back the unvested part.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingRevokedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub vested_amount: i128,
+    pub returned_amount: i128,
+    pub revoked_at: u64,
+}
+
+/// Emitted when the admin gives notice of a clawback. The schedule is revoked
+/// (emitting [`VestingRevokedEvent`]) once `executable_at` has passed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClawbackScheduledEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub executable_at: u64,
+}
+
+/// Emitted when a pending clawback is called off.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClawbackCancelledEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+}
+
+/// Emitted when a beneficiary approves or removes a claim operator.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperatorUpdatedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    #[topic]
+    pub operator: Address,
+    pub approved: bool,
+}
+
+/// Emitted when a beneficiary opts into or out of keeper claims.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoClaimUpdatedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub enabled: bool,
+}
+
+/// Emitted when the admin registers a new schedule template.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateCreatedEvent {
+    #[topic]
+    pub template_id: u32,
+    pub name: String,
+    pub duration: u64,
+    pub cliff_duration: u64,
+}
+
+/// Emitted when the admin pauses or resumes all claims.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimsPausedEvent {
+    #[topic]
+    pub admin: Address,
+    pub paused: bool,
+}
+
+/// Emitted when the admin freezes or unfreezes a single schedule.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleFrozenEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub frozen: bool,
+}
+
+/// Emitted when the admin flags or unflags a schedule for reputation boosts.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationBoostSetEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub boosted: bool,
+}
+
+/// Emitted when the admin tops up an existing schedule.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingIncreasedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub extra_amount: i128,
+    pub new_total: i128,
+}
+
+/// Emitted when the admin offers a schedule a longer duration.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExtensionProposedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub old_duration: u64,
+    pub new_duration: u64,
+}
+
+/// Emitted when a beneficiary accepts a proposed extension.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingExtendedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub old_duration: u64,
+    pub new_duration: u64,
+}
+
+/// Emitted when tokens are deposited into the unallocated pool.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositedEvent {
+    #[topic]
+    pub funder: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Emitted when the admin recovers tokens not owed to any schedule.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensRescuedEvent {
+    #[topic]
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// Emitted when a vesting position changes hands via `transfer_position`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PositionTransferredEvent {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub position: u32,
+}
+
+/// Emitted when a milestone tranche is confirmed and becomes claimable.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneConfirmedEvent {
+    #[topic]
+    pub beneficiary: Address,
+    pub schedule_id: u32,
+    pub milestone_idx: u32,
+    pub amount: i128,
+    pub confirmed_at: u64,
+}
+
+/// Emitted when a schedule moves to a new beneficiary address. The schedule
+/// is re-filed under the new address with a fresh ID.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeneficiaryChangedEvent {
+    #[topic]
+    pub old_beneficiary: Address,
+    #[topic]
+    pub new_beneficiary: Address,
+    pub old_schedule_id: u32,
+    pub new_schedule_id: u32,
+    pub changed_at: u64,
 }
 
 #[contractevent]
@@ -27,6 +241,15 @@ pub struct UpgradedEvent {
     pub new_wasm_hash: BytesN<32>,
 }
 
+/// Emitted when the admin offers the role to a new address.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferProposedEvent {
+    #[topic]
+    pub current_admin: Address,
+    pub pending_admin: Address,
+}
+
 /// Emitted when the admin role is transferred to a new address.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1,39 +1,603 @@
 #![no_std]
+// create_vesting is the only function over the limit and carries its own
+// allow; this covers the argument helper #[contractimpl] generates for it,
+// which has no attributes of its own to put one on.
+#![allow(clippy::too_many_arguments)]
 
 mod errors;
 mod events;
+mod registry;
 mod storage;
 mod token;
 
 use errors::VestingError;
-use events::{AdminChangedEvent, UpgradedEvent};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
-use storage::{DataKey, VestingData};
-use token::transfer;
+use events::{AdminChangedEvent, AdminTransferProposedEvent, ClaimsPausedEvent, UpgradedEvent};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Map, String, Symbol, Vec};
+use storage::{
+    ClaimFee, ClaimRecord, DataKey, LegacyDataKey, LegacyVestingData, NewVesting, ProgramStats,
+    Solvency, VestingCurve, VestingData, VestingStats, VestingTemplate, BPS_DENOMINATOR,
+    DEFAULT_CLAWBACK_DELAY, DEFAULT_REVOCATION_GRACE, MAX_BATCH_SIZE, MAX_CLAIM_BATCH,
+    MAX_CLAIM_FEE_BPS, MAX_MILESTONES, MAX_PAGE_SIZE, MAX_TEMPLATE_NAME_LEN, MAX_TIER_BOOSTS,
+    MAX_TTL_CLAIMS, MAX_UNLOCK_POINTS, PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_THRESHOLD,
+};
+use token::{balance, transfer};
 
 #[contract]
 pub struct VestingWalletContract;
 
 #[contractimpl]
 impl VestingWalletContract {
-    /// Helper function to calculate claimable amount for a vesting schedule
-    /// This is used by both get_claimable and claim to ensure consistency
-    fn calculate_claimable_amount(current_time: u64, vesting: &VestingData) -> i128 {
-        if current_time < vesting.start_time {
-            // Vesting hasn't started yet
+    /// Total amount vested at `current_time`: `total_amount * elapsed /
+    /// duration`, capped at `total_amount`, with `elapsed` rounded down to a
+    /// whole period for periodic curves. Nothing vests before the cliff; at
+    /// the cliff everything accrued since `start_time` unlocks at once.
+    /// Milestone schedules ignore time past the cliff and vest exactly their
+    /// confirmed tranches.
+    fn vested_at(current_time: u64, vesting: &VestingData) -> i128 {
+        // A revoked schedule stops accruing at the revocation time
+        let current_time = match vesting.revoked_at {
+            Some(revoked_at) => current_time.min(revoked_at),
+            None => current_time,
+        };
+        if current_time < vesting.start_time.saturating_add(vesting.cliff_duration) {
+            // Vesting hasn't started yet or the cliff hasn't passed
             0
-        } else if current_time >= vesting.start_time + vesting.duration {
-            // Vesting period has ended, all tokens are available
-            vesting.total_amount - vesting.claimed_amount
+        } else if let VestingCurve::Milestones(tranches) = &vesting.curve {
+            let mut vested: i128 = 0;
+            for (idx, amount) in tranches.iter().enumerate() {
+                if vesting.confirmed_milestones & (1 << idx) != 0 {
+                    vested += amount;
+                }
+            }
+            vested
+        } else if current_time >= vesting.start_time.saturating_add(vesting.duration) {
+            // Vesting period has ended, everything is vested
+            vesting.total_amount
+        } else {
+            let time_elapsed = match vesting.curve {
+                VestingCurve::Linear => current_time - vesting.start_time,
+                VestingCurve::Periodic(period) => {
+                    (current_time - vesting.start_time) / period * period
+                }
+                VestingCurve::CliffOnly | VestingCurve::Milestones(_) => return 0,
+            };
+            // Linear unlock; split the product when total * elapsed would
+            // overflow so very large grants still vest exactly
+            let duration = vesting.duration as i128;
+            match vesting.total_amount.checked_mul(time_elapsed as i128) {
+                Some(product) => product / duration,
+                None => {
+                    let whole = vesting.total_amount / duration;
+                    let rest = (vesting.total_amount % duration) as u128;
+                    let partial = rest * time_elapsed as u128 / vesting.duration as u128;
+                    whole * time_elapsed as i128 + partial as i128
+                }
+            }
+        }
+    }
+
+    /// Duration checks shared by new schedules and templates
+    fn validate_timeline(
+        duration: u64,
+        cliff_duration: u64,
+        curve: &VestingCurve,
+    ) -> Result<(), VestingError> {
+        // Validate duration; the cliff must fall within the schedule
+        if duration == 0 {
+            return Err(VestingError::ZeroDuration);
+        }
+        if cliff_duration > duration {
+            return Err(VestingError::InvalidDuration);
+        }
+        if let VestingCurve::Periodic(period) = curve {
+            if *period == 0 || *period > duration {
+                return Err(VestingError::InvalidDuration);
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_new_vesting(env: &Env, entry: &NewVesting) -> Result<(), VestingError> {
+        // Validate amount
+        if entry.amount == 0 {
+            return Err(VestingError::ZeroAmount);
+        }
+        if entry.amount < 0 {
+            return Err(VestingError::InvalidAmount);
+        }
+
+        Self::validate_timeline(entry.duration, entry.cliff_duration, &entry.curve)?;
+        if entry.start_time.checked_add(entry.duration).is_none() {
+            return Err(VestingError::TimelineOverflow);
+        }
+        if let VestingCurve::Milestones(tranches) = &entry.curve {
+            {
+                if tranches.is_empty() || tranches.len() > MAX_MILESTONES {
+                    return Err(VestingError::InvalidMilestone);
+                }
+                let mut sum: i128 = 0;
+                for amount in tranches.iter() {
+                    if amount <= 0 {
+                        return Err(VestingError::InvalidAmount);
+                    }
+                    sum = sum
+                        .checked_add(amount)
+                        .ok_or(VestingError::AmountOverflow)?;
+                }
+                if sum != entry.amount {
+                    return Err(VestingError::InvalidAmount);
+                }
+            }
+        }
+
+        // Validate start time against the configured backdate/future window
+        let now = env.ledger().timestamp();
+        let (max_backdate, max_future_start) = Self::get_start_bounds(env.clone());
+        if entry.start_time < now.saturating_sub(max_backdate)
+            || entry.start_time > now.saturating_add(max_future_start)
+        {
+            return Err(VestingError::InvalidStartTime);
+        }
+
+        // The same grant twice is almost always a resubmitted transaction
+        let token = Self::resolve_token(env, entry)?;
+        for vesting in Self::schedules(env, &entry.beneficiary).iter() {
+            if vesting.revoked_at.is_none() && Self::same_terms(&vesting, entry, &token) {
+                return Err(VestingError::DuplicateSchedule);
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether `vesting` was created from exactly these terms
+    fn same_terms(vesting: &VestingData, entry: &NewVesting, token: &Address) -> bool {
+        vesting.beneficiary == entry.beneficiary
+            && vesting.token == *token
+            && vesting.total_amount == entry.amount
+            && vesting.start_time == entry.start_time
+            && vesting.duration == entry.duration
+            && vesting.cliff_duration == entry.cliff_duration
+            && vesting.curve == entry.curve
+    }
+
+    fn total_committed(env: &Env, token: &Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Committed(token.clone()))
+            .unwrap_or(0)
+    }
+
+    fn set_total_committed(env: &Env, token: &Address, committed: i128) {
+        env.storage()
+            .instance()
+            .set(&DataKey::Committed(token.clone()), &committed);
+    }
+
+    /// Token a new schedule is paid in: its own, or the contract default
+    fn resolve_token(env: &Env, entry: &NewVesting) -> Result<Address, VestingError> {
+        match &entry.token {
+            Some(token) => Ok(token.clone()),
+            None => Self::get_token(env.clone()),
+        }
+    }
+
+    /// Reserve `amount` for new schedules, topping the contract up from
+    /// `funder` by whatever the unallocated surplus does not cover. Fails if
+    /// the contract would still owe more than it holds.
+    fn commit_funds(
+        env: &Env,
+        token: &Address,
+        funder: &Address,
+        amount: i128,
+    ) -> Result<(), VestingError> {
+        let contract = env.current_contract_address();
+        let committed = Self::total_committed(env, token);
+        let surplus = (balance(env, token, &contract) - committed).max(0);
+        let shortfall = amount - surplus.min(amount);
+        if shortfall > 0 {
+            transfer(env, token, funder, &contract, &shortfall);
+        }
+
+        let committed = committed
+            .checked_add(amount)
+            .ok_or(VestingError::AmountOverflow)?;
+        if committed > balance(env, token, &contract) {
+            return Err(VestingError::InsufficientBalance);
+        }
+        Self::set_total_committed(env, token, committed);
+        Ok(())
+    }
+
+    /// Drop `amount` from the commitments once it is paid out or returned
+    fn release_funds(env: &Env, token: &Address, amount: i128) {
+        Self::set_total_committed(env, token, Self::total_committed(env, token) - amount);
+    }
+
+    /// Allocate the next schedule ID for this beneficiary
+    fn next_schedule_id(env: &Env, beneficiary: &Address) -> u32 {
+        let count_key = DataKey::VestingCount(beneficiary.clone());
+        let schedule_id: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&count_key, &(schedule_id + 1));
+        schedule_id
+    }
+
+    /// Point the global index slot `position` at a schedule
+    fn index_schedule(env: &Env, position: u32, beneficiary: &Address, schedule_id: u32) {
+        let storage = env.storage().persistent();
+        storage.set(
+            &DataKey::ScheduleAt(position),
+            &(beneficiary.clone(), schedule_id),
+        );
+        storage.set(
+            &DataKey::SchedulePosition(beneficiary.clone(), schedule_id),
+            &position,
+        );
+    }
+
+    /// Write an already validated and funded schedule, returning its ID
+    fn store_new_vesting(env: &Env, entry: NewVesting, token: Address) -> u32 {
+        let schedule_id = Self::next_schedule_id(env, &entry.beneficiary);
+        let position = Self::get_schedule_count(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::ScheduleCount, &(position + 1));
+        Self::index_schedule(env, position, &entry.beneficiary, schedule_id);
+        Self::adjust_open(env, &entry.beneficiary, true);
+
+        // Create vesting data
+        let mut vesting = VestingData {
+            beneficiary: entry.beneficiary,
+            schedule_id,
+            token,
+            total_amount: entry.amount,
+            start_time: entry.start_time,
+            duration: entry.duration,
+            cliff_duration: entry.cliff_duration,
+            curve: entry.curve,
+            claimed_amount: 0,
+            confirmed_milestones: 0,
+            revocable: entry.revocable,
+            frozen: false,
+            revoked_at: None,
+            reputation_boosted: false,
+            sweepable_at: None,
+            swept: false,
+            program: None,
+        };
+        Self::save_vesting(env, &vesting);
+        if let Some(program) = entry.program {
+            vesting = Self::tag_program(env, position, vesting, Some(program));
+        }
+
+        // Emit VestingCreated event
+        events::VestingCreatedEvent {
+            beneficiary: vesting.beneficiary.clone(),
+            schedule_id,
+            token: vesting.token.clone(),
+            amount: vesting.total_amount,
+            start_time: vesting.start_time,
+            duration: vesting.duration,
+            cliff_duration: vesting.cliff_duration,
+            revocable: vesting.revocable,
+            created_at: env.ledger().timestamp(),
+        }
+        .publish(env);
+
+        schedule_id
+    }
+
+    fn load_vesting(
+        env: &Env,
+        beneficiary: &Address,
+        schedule_id: u32,
+    ) -> Result<VestingData, VestingError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Vesting(beneficiary.clone(), schedule_id))
+            .ok_or(VestingError::VestingNotFound)
+    }
+
+    /// Every schedule held by `beneficiary`, in ID order
+    fn schedules(env: &Env, beneficiary: &Address) -> Vec<VestingData> {
+        let mut schedules = Vec::new(env);
+        for schedule_id in 0..Self::get_vesting_count(env.clone(), beneficiary.clone()) {
+            if let Ok(vesting) = Self::load_vesting(env, beneficiary, schedule_id) {
+                schedules.push_back(vesting);
+            }
+        }
+        schedules
+    }
+
+    /// Write a schedule back and keep it clear of archival
+    fn save_vesting(env: &Env, vesting: &VestingData) {
+        let key = DataKey::Vesting(vesting.beneficiary.clone(), vesting.schedule_id);
+        env.storage().persistent().set(&key, vesting);
+        env.storage().persistent().extend_ttl(
+            &key,
+            PERSISTENT_BUMP_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+
+    /// What a revoked schedule has handed back to the treasury so far
+    fn returned_amount(vesting: &VestingData) -> i128 {
+        match vesting.revoked_at {
+            Some(_) if vesting.swept => vesting.total_amount - vesting.claimed_amount,
+            Some(revoked_at) => vesting.total_amount - Self::revoked_vested(revoked_at, vesting),
+            None => 0,
+        }
+    }
+
+    /// Apply `update` to the stats of the schedule's program, if it has one
+    fn update_program_stats(
+        env: &Env,
+        vesting: &VestingData,
+        update: impl FnOnce(&mut ProgramStats),
+    ) {
+        if let Some(program) = &vesting.program {
+            let key = DataKey::ProgramStats(program.clone());
+            let mut stats: ProgramStats = env.storage().persistent().get(&key).unwrap_or_default();
+            update(&mut stats);
+            env.storage().persistent().set(&key, &stats);
+        }
+    }
+
+    /// Move the schedule at `position` out of its current program, if any,
+    /// and into `program`, carrying its totals along. Returns the saved
+    /// schedule.
+    fn tag_program(
+        env: &Env,
+        position: u32,
+        mut vesting: VestingData,
+        program: Option<Symbol>,
+    ) -> VestingData {
+        let storage = env.storage().persistent();
+        let allocated = vesting.total_amount;
+        let claimed = vesting.claimed_amount;
+        let returned = Self::returned_amount(&vesting);
+
+        if let Some(old) = vesting.program.clone() {
+            // Swap the last member into the vacated slot
+            let count: u32 = storage
+                .get(&DataKey::ProgramCount(old.clone()))
+                .unwrap_or(0);
+            let slot: u32 = storage.get(&DataKey::ProgramSlot(position)).unwrap_or(0);
+            let last = count - 1;
+            if slot != last {
+                let moved: u32 = storage
+                    .get(&DataKey::ProgramAt(old.clone(), last))
+                    .unwrap_or(0);
+                storage.set(&DataKey::ProgramAt(old.clone(), slot), &moved);
+                storage.set(&DataKey::ProgramSlot(moved), &slot);
+            }
+            storage.remove(&DataKey::ProgramAt(old.clone(), last));
+            storage.remove(&DataKey::ProgramSlot(position));
+            storage.set(&DataKey::ProgramCount(old), &last);
+            Self::update_program_stats(env, &vesting, |stats| {
+                stats.schedules -= 1;
+                stats.total_allocated -= allocated;
+                stats.total_claimed -= claimed;
+                stats.total_revoked -= returned;
+            });
+        }
+
+        if let Some(new) = program.clone() {
+            let count: u32 = storage
+                .get(&DataKey::ProgramCount(new.clone()))
+                .unwrap_or(0);
+            storage.set(&DataKey::ProgramAt(new.clone(), count), &position);
+            storage.set(&DataKey::ProgramSlot(position), &count);
+            storage.set(&DataKey::ProgramCount(new), &(count + 1));
+        }
+        vesting.program = program;
+        Self::update_program_stats(env, &vesting, |stats| {
+            stats.schedules += 1;
+            stats.total_allocated += allocated;
+            stats.total_claimed += claimed;
+            stats.total_revoked += returned;
+        });
+        Self::save_vesting(env, &vesting);
+        vesting
+    }
+
+    /// Track that one of `beneficiary`'s schedules started (`opened`) or
+    /// stopped owing tokens, keeping the active beneficiary count current
+    fn adjust_open(env: &Env, beneficiary: &Address, opened: bool) {
+        let key = DataKey::OpenSchedules(beneficiary.clone());
+        let open: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        let active: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ActiveBeneficiaries)
+            .unwrap_or(0);
+        let (open, active) = match (opened, open) {
+            (true, 0) => (1, active + 1),
+            (true, _) => (open + 1, active),
+            (false, 0) => return,
+            (false, 1) => (0, active.saturating_sub(1)),
+            (false, _) => (open - 1, active),
+        };
+        if open == 0 {
+            env.storage().persistent().remove(&key);
         } else {
-            // Calculate linearly vested amount
-            let time_elapsed = current_time - vesting.start_time;
-            let total_vested = (vesting.total_amount as u128)
-                .checked_mul(time_elapsed as u128)
-                .and_then(|x| x.checked_div(vesting.duration as u128))
-                .unwrap_or(0) as i128;
-            total_vested - vesting.claimed_amount
+            env.storage().persistent().set(&key, &open);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ActiveBeneficiaries, &active);
+    }
+
+    /// Append to the schedule's claim history. History is keyed by the
+    /// schedule's global position so it follows `change_beneficiary`.
+    fn record_claim(env: &Env, vesting: &VestingData, amount: i128, recipient: &Address) {
+        let storage = env.storage().persistent();
+        let position: Option<u32> = storage.get(&DataKey::SchedulePosition(
+            vesting.beneficiary.clone(),
+            vesting.schedule_id,
+        ));
+        let Some(position) = position else {
+            return;
+        };
+        let count: u32 = storage.get(&DataKey::ClaimCount(position)).unwrap_or(0);
+        let record = ClaimRecord {
+            timestamp: env.ledger().timestamp(),
+            amount,
+            recipient: recipient.clone(),
+        };
+        storage.set(&DataKey::Claim(position, count), &record);
+        storage.set(&DataKey::ClaimCount(position), &(count + 1));
+    }
+
+    /// Pay `amount` out of `vesting` to `recipient`, less any claim fee, and
+    /// record the claim
+    fn pay_out(env: &Env, vesting: &mut VestingData, amount: i128, recipient: &Address) {
+        let token = &vesting.token;
+        let contract = env.current_contract_address();
+        let fee = match Self::get_claim_fee(env.clone()) {
+            Some(claim_fee) => {
+                let fee = amount * claim_fee.fee_bps as i128 / BPS_DENOMINATOR as i128;
+                if fee > 0 {
+                    transfer(env, token, &contract, &claim_fee.treasury, &fee);
+                }
+                fee
+            }
+            None => 0,
+        };
+        transfer(env, token, &contract, recipient, &(amount - fee));
+        Self::release_funds(env, token, amount);
+        let claimed = Self::total_claimed(env.clone(), token.clone()) + amount;
+        env.storage()
+            .instance()
+            .set(&DataKey::Claimed(token.clone()), &claimed);
+        vesting.claimed_amount += amount;
+        Self::save_vesting(env, vesting);
+        Self::update_program_stats(env, vesting, |stats| stats.total_claimed += amount);
+        Self::record_claim(env, vesting, amount, recipient);
+        if Self::outstanding_amount(vesting) == 0 {
+            Self::adjust_open(env, &vesting.beneficiary, false);
         }
+
+        events::TokensClaimedEvent {
+            beneficiary: vesting.beneficiary.clone(),
+            schedule_id: vesting.schedule_id,
+            recipient: recipient.clone(),
+            amount_claimed: amount,
+            fee,
+            remaining: Self::outstanding_amount(vesting),
+            claimed_at: env.ledger().timestamp(),
+        }
+        .publish(env);
+    }
+
+    fn require_claims_open(env: &Env) -> Result<(), VestingError> {
+        if Self::is_claims_paused(env.clone()) {
+            return Err(VestingError::ClaimsPaused);
+        }
+        Ok(())
+    }
+
+    fn set_frozen(
+        env: &Env,
+        admin: &Address,
+        beneficiary: Address,
+        schedule_id: u32,
+        frozen: bool,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(env, admin)?;
+        let mut vesting = Self::load_vesting(env, &beneficiary, schedule_id)?;
+        vesting.frozen = frozen;
+        Self::save_vesting(env, &vesting);
+
+        events::ScheduleFrozenEvent {
+            beneficiary,
+            schedule_id,
+            frozen,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// Check that `admin` is the stored admin and has signed
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), VestingError> {
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(VestingError::NotInitialized)?;
+        if *admin != stored_admin {
+            return Err(VestingError::Unauthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
+
+    /// Check that `creator` is the admin or a schedule creator and has signed
+    fn require_creator(env: &Env, creator: &Address) -> Result<(), VestingError> {
+        let stored_admin = Self::get_admin(env.clone())?;
+        if *creator != stored_admin && !Self::is_schedule_creator(env.clone(), creator.clone()) {
+            return Err(VestingError::Unauthorized);
+        }
+        creator.require_auth();
+        Ok(())
+    }
+
+    /// The schedule as it vests right now: a reputation-boosted schedule has
+    /// its duration and cliff cut by the beneficiary's tier boost, any other
+    /// schedule is returned unchanged.
+    fn effective_vesting(env: &Env, vesting: &VestingData) -> VestingData {
+        let mut effective = vesting.clone();
+        if !vesting.reputation_boosted {
+            return effective;
+        }
+        let Some(registry) = Self::get_reputation_registry(env.clone()) else {
+            return effective;
+        };
+        let boosts = Self::get_tier_boosts(env.clone());
+        if boosts.is_empty() {
+            return effective;
+        }
+        let tier = registry::tier_of(env, &registry, &vesting.beneficiary);
+        // Tiers past the end of the table get its last entry
+        let cut_bps = boosts.get(tier.min(boosts.len() - 1)).unwrap_or(0);
+        let keep_bps = (BPS_DENOMINATOR - cut_bps) as u128;
+        let scale = |seconds: u64| (seconds as u128 * keep_bps / BPS_DENOMINATOR as u128) as u64;
+        effective.duration = scale(vesting.duration).max(1);
+        effective.cliff_duration = scale(vesting.cliff_duration);
+        effective
+    }
+
+    /// What a schedule revoked at `revoked_at` keeps for the beneficiary. A
+    /// boosted schedule can already have paid out more than its settled
+    /// timeline vests if the tier dropped since the last claim; that stays
+    /// with the beneficiary rather than coming out of other collateral.
+    fn revoked_vested(revoked_at: u64, vesting: &VestingData) -> i128 {
+        Self::vested_at(revoked_at, vesting).max(vesting.claimed_amount)
+    }
+
+    /// Tokens the contract still owes on a schedule: everything unclaimed, or
+    /// only the vested-but-unclaimed part once revoked.
+    fn outstanding_amount(vesting: &VestingData) -> i128 {
+        if vesting.swept {
+            return 0;
+        }
+        match vesting.revoked_at {
+            Some(revoked_at) => Self::revoked_vested(revoked_at, vesting) - vesting.claimed_amount,
+            None => vesting.total_amount - vesting.claimed_amount,
+        }
+    }
+
+    /// Helper function to calculate claimable amount for a vesting schedule
+    /// This is used by both get_claimable and claim to ensure consistency
+    /// A boosted schedule whose beneficiary has since dropped a tier can
+    /// briefly sit below what was already claimed; that reads as zero.
+    fn calculate_claimable_amount(env: &Env, current_time: u64, vesting: &VestingData) -> i128 {
+        if vesting.swept {
+            return 0;
+        }
+        let vested = Self::vested_at(current_time, &Self::effective_vesting(env, vesting));
+        (vested - vesting.claimed_amount).max(0)
     }
 
     /// Initialize the contract with an admin address and token address
@@ -50,206 +614,1756 @@ impl VestingWalletContract {
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Token, &token);
 
-        Ok(())
+        Ok(())
+    }
+
+    /// Create a vesting schedule for a beneficiary and return its ID. A
+    /// beneficiary can hold any number of schedules side by side. Nothing can
+    /// be claimed until `cliff_duration` seconds after `start_time`; after
+    /// that, `curve` decides how the grant unlocks. The admin or any schedule
+    /// creator may call it. The grant is funded from deposited surplus first;
+    /// only the shortfall is pulled from `creator`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_vesting(
+        env: Env,
+        creator: Address,
+        beneficiary: Address,
+        amount: i128,
+        start_time: u64,
+        duration: u64,
+        cliff_duration: u64,
+        curve: VestingCurve,
+        revocable: bool,
+    ) -> Result<u32, VestingError> {
+        Self::require_creator(&env, &creator)?;
+
+        let entry = NewVesting {
+            beneficiary,
+            token: None,
+            amount,
+            start_time,
+            duration,
+            cliff_duration,
+            curve,
+            revocable,
+            program: None,
+        };
+        Self::validate_new_vesting(&env, &entry)?;
+
+        // Get token address
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(VestingError::NotInitialized)?;
+
+        Self::commit_funds(&env, &token, &creator, amount)?;
+
+        Ok(Self::store_new_vesting(&env, entry, token))
+    }
+
+    /// Let `creator` create and fund schedules, as another contract paying
+    /// out through this wallet would, without the rest of the admin role.
+    pub fn set_schedule_creator(
+        env: Env,
+        admin: Address,
+        creator: Address,
+        allowed: bool,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        let key = DataKey::ScheduleCreator(creator);
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    pub fn is_schedule_creator(env: Env, creator: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ScheduleCreator(creator))
+            .unwrap_or(false)
+    }
+
+    /// Move a schedule stored under the single-schedule layout to the
+    /// current one, as the beneficiary's next schedule ID (0 on a deployment
+    /// that has just been upgraded). Its claimed amount carries over, and the
+    /// unclaimed remainder, which the contract already holds, is counted
+    /// towards the token's commitments. Returns the new schedule ID.
+    pub fn migrate_legacy_vesting(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+    ) -> Result<u32, VestingError> {
+        Self::require_admin(&env, &admin)?;
+        let legacy_key = LegacyDataKey::Vesting(beneficiary.clone());
+        let legacy: LegacyVestingData = env
+            .storage()
+            .persistent()
+            .get(&legacy_key)
+            .ok_or(VestingError::VestingNotFound)?;
+        let token = Self::get_token(env.clone())?;
+        let entry = NewVesting {
+            beneficiary,
+            token: None,
+            amount: legacy.total_amount,
+            start_time: legacy.start_time,
+            duration: legacy.duration,
+            cliff_duration: 0,
+            curve: VestingCurve::Linear,
+            revocable: false,
+            program: None,
+        };
+        let schedule_id = Self::store_new_vesting(&env, entry, token.clone());
+
+        let mut vesting = Self::load_vesting(&env, &legacy.beneficiary, schedule_id)?;
+        vesting.claimed_amount = legacy.claimed_amount;
+        Self::save_vesting(&env, &vesting);
+        let outstanding = Self::outstanding_amount(&vesting);
+        if outstanding == 0 {
+            Self::adjust_open(&env, &vesting.beneficiary, false);
+        }
+        let committed = Self::total_committed(&env, &token)
+            .checked_add(outstanding)
+            .ok_or(VestingError::AmountOverflow)?;
+        Self::set_total_committed(&env, &token, committed);
+        let claimed = Self::total_claimed(env.clone(), token.clone()) + legacy.claimed_amount;
+        env.storage()
+            .instance()
+            .set(&DataKey::Claimed(token), &claimed);
+        env.storage().persistent().remove(&legacy_key);
+
+        Ok(schedule_id)
+    }
+
+    /// Bound how far `start_time` may lie from the creation time: up to
+    /// `max_backdate` seconds in the past for retroactive grants, and up to
+    /// `max_future_start` seconds ahead. A backdated schedule is created
+    /// with everything accrued since its start already claimable.
+    pub fn set_start_bounds(
+        env: Env,
+        admin: Address,
+        max_backdate: u64,
+        max_future_start: u64,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxBackdate, &max_backdate);
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxFutureStart, &max_future_start);
+        Ok(())
+    }
+
+    /// `(max_backdate, max_future_start)` in seconds. By default starts can't
+    /// be backdated and may lie arbitrarily far ahead.
+    pub fn get_start_bounds(env: Env) -> (u64, u64) {
+        let storage = env.storage().instance();
+        (
+            storage.get(&DataKey::MaxBackdate).unwrap_or(0),
+            storage.get(&DataKey::MaxFutureStart).unwrap_or(u64::MAX),
+        )
+    }
+
+    /// Register reusable schedule terms and return the template ID. Milestone
+    /// curves are per-grant and cannot be templated.
+    pub fn create_template(
+        env: Env,
+        admin: Address,
+        name: String,
+        duration: u64,
+        cliff_duration: u64,
+        curve: VestingCurve,
+        revocable: bool,
+    ) -> Result<u32, VestingError> {
+        Self::require_admin(&env, &admin)?;
+        if name.is_empty() || name.len() > MAX_TEMPLATE_NAME_LEN {
+            return Err(VestingError::InvalidTemplate);
+        }
+        if let VestingCurve::Milestones(_) = curve {
+            return Err(VestingError::InvalidTemplate);
+        }
+        Self::validate_timeline(duration, cliff_duration, &curve)?;
+
+        let template_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TemplateCount)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::TemplateCount, &(template_id + 1));
+        let template = VestingTemplate {
+            name,
+            duration,
+            cliff_duration,
+            curve,
+            revocable,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Template(template_id), &template);
+
+        events::TemplateCreatedEvent {
+            template_id,
+            name: template.name,
+            duration,
+            cliff_duration,
+        }
+        .publish(&env);
+
+        Ok(template_id)
+    }
+
+    /// Get a schedule template
+    pub fn get_template(env: Env, template_id: u32) -> Result<VestingTemplate, VestingError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Template(template_id))
+            .ok_or(VestingError::TemplateNotFound)
+    }
+
+    /// Create a schedule on a template's terms, starting now, and return its
+    /// ID. Authorized and funded like `create_vesting`.
+    pub fn create_vesting_from_template(
+        env: Env,
+        creator: Address,
+        beneficiary: Address,
+        amount: i128,
+        template_id: u32,
+    ) -> Result<u32, VestingError> {
+        Self::require_creator(&env, &creator)?;
+        let template = Self::get_template(env.clone(), template_id)?;
+        let entry = NewVesting {
+            beneficiary,
+            token: None,
+            amount,
+            start_time: env.ledger().timestamp(),
+            duration: template.duration,
+            cliff_duration: template.cliff_duration,
+            curve: template.curve,
+            revocable: template.revocable,
+            program: None,
+        };
+        Self::validate_new_vesting(&env, &entry)?;
+
+        let token = Self::get_token(env.clone())?;
+        Self::commit_funds(&env, &token, &creator, amount)?;
+        Ok(Self::store_new_vesting(&env, entry, token))
+    }
+
+    /// Create many schedules at once, funded by a single transfer per token
+    /// of their combined amount (less any deposited surplus). Every entry is
+    /// validated before anything is written, so the batch either lands whole
+    /// or not at all. Open to the admin and schedule creators. Returns the
+    /// new schedule IDs in entry order.
+    pub fn create_vestings(
+        env: Env,
+        creator: Address,
+        entries: Vec<NewVesting>,
+    ) -> Result<Vec<u32>, VestingError> {
+        Self::require_creator(&env, &creator)?;
+
+        if entries.is_empty() || entries.len() > MAX_BATCH_SIZE {
+            return Err(VestingError::InvalidBatch);
+        }
+
+        let mut totals: Map<Address, i128> = Map::new(&env);
+        let mut tokens: Vec<Address> = Vec::new(&env);
+        for (idx, entry) in entries.iter().enumerate() {
+            Self::validate_new_vesting(&env, &entry)?;
+            let token = Self::resolve_token(&env, &entry)?;
+            for (earlier_idx, mut earlier) in entries.iter().take(idx).enumerate() {
+                // Compare terms only; the token is compared once resolved
+                earlier.token = entry.token.clone();
+                earlier.revocable = entry.revocable;
+                earlier.program = entry.program.clone();
+                if earlier == entry && tokens.get_unchecked(earlier_idx as u32) == token {
+                    return Err(VestingError::DuplicateSchedule);
+                }
+            }
+            let total = totals
+                .get(token.clone())
+                .unwrap_or(0)
+                .checked_add(entry.amount)
+                .ok_or(VestingError::AmountOverflow)?;
+            totals.set(token.clone(), total);
+            tokens.push_back(token);
+        }
+
+        for (token, total) in totals.iter() {
+            Self::commit_funds(&env, &token, &creator, total)?;
+        }
+
+        let mut ids = Vec::new(&env);
+        for entry in entries.iter() {
+            let token = Self::resolve_token(&env, &entry)?;
+            ids.push_back(Self::store_new_vesting(&env, entry, token));
+        }
+        Ok(ids)
+    }
+
+    /// Require (or stop requiring) the admin to co-sign every
+    /// `change_beneficiary` call.
+    pub fn set_change_approval(
+        env: Env,
+        admin: Address,
+        required: bool,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::ChangeNeedsApproval, &required);
+        Ok(())
+    }
+
+    /// Whether beneficiary changes currently need the admin's signature
+    pub fn change_needs_approval(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::ChangeNeedsApproval)
+            .unwrap_or(false)
+    }
+
+    /// Move a schedule to a new address, e.g. after a wallet rotation. The
+    /// current beneficiary must sign, and so must the admin when approval is
+    /// required. The schedule keeps its terms and claim progress but gets a
+    /// new ID under `new_beneficiary`, which is returned.
+    pub fn change_beneficiary(
+        env: Env,
+        current_beneficiary: Address,
+        new_beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<u32, VestingError> {
+        let admin = Self::get_admin(env.clone())?;
+        current_beneficiary.require_auth();
+        if Self::change_needs_approval(env.clone()) {
+            admin.require_auth();
+        }
+        Self::move_schedule(&env, current_beneficiary, new_beneficiary, schedule_id)
+    }
+
+    /// Re-home a schedule under `new_beneficiary`, keeping its position in
+    /// the global index. Authorization is the caller's job.
+    fn move_schedule(
+        env: &Env,
+        current_beneficiary: Address,
+        new_beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<u32, VestingError> {
+        if new_beneficiary == current_beneficiary {
+            return Err(VestingError::InvalidBeneficiary);
+        }
+
+        let mut vesting = Self::load_vesting(env, &current_beneficiary, schedule_id)?;
+        if vesting.frozen {
+            return Err(VestingError::ScheduleFrozen);
+        }
+        // Moving the schedule would slip out from under the notice
+        if env.storage().persistent().has(&DataKey::PendingClawback(
+            current_beneficiary.clone(),
+            schedule_id,
+        )) {
+            return Err(VestingError::ClawbackPending);
+        }
+        let old_position = DataKey::SchedulePosition(current_beneficiary.clone(), schedule_id);
+        let position: Option<u32> = env.storage().persistent().get(&old_position);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Vesting(current_beneficiary.clone(), schedule_id));
+        env.storage().persistent().remove(&old_position);
+        // An offer made to the old beneficiary does not carry over
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingExtension(
+                current_beneficiary.clone(),
+                schedule_id,
+            ));
+
+        let new_schedule_id = Self::next_schedule_id(env, &new_beneficiary);
+        if let Some(position) = position {
+            Self::index_schedule(env, position, &new_beneficiary, new_schedule_id);
+        }
+        vesting.beneficiary = new_beneficiary.clone();
+        vesting.schedule_id = new_schedule_id;
+        Self::save_vesting(env, &vesting);
+        if Self::outstanding_amount(&vesting) > 0 {
+            Self::adjust_open(env, &current_beneficiary, false);
+            Self::adjust_open(env, &new_beneficiary, true);
+        }
+
+        events::BeneficiaryChangedEvent {
+            old_beneficiary: current_beneficiary,
+            new_beneficiary,
+            old_schedule_id: schedule_id,
+            new_schedule_id,
+            changed_at: env.ledger().timestamp(),
+        }
+        .publish(env);
+
+        Ok(new_schedule_id)
+    }
+
+    /// Allow (or stop allowing) beneficiaries to hand their positions to
+    /// anyone with `transfer_position`, e.g. to sell a long-dated grant.
+    pub fn set_positions_transferable(
+        env: Env,
+        admin: Address,
+        transferable: bool,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::PositionsTransferable, &transferable);
+        Ok(())
+    }
+
+    /// Whether `transfer_position` is currently enabled
+    pub fn positions_transferable(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::PositionsTransferable)
+            .unwrap_or(false)
+    }
+
+    /// Transfer the schedule at global `position` from its beneficiary to
+    /// `to`, with its terms and claim progress. Unlike `change_beneficiary`
+    /// this never needs the admin's signature, only the transferability
+    /// flag. Returns the schedule's new ID under `to`.
+    pub fn transfer_position(
+        env: Env,
+        from: Address,
+        to: Address,
+        position: u32,
+    ) -> Result<u32, VestingError> {
+        if !Self::positions_transferable(env.clone()) {
+            return Err(VestingError::PositionsNotTransferable);
+        }
+        from.require_auth();
+        let (owner, schedule_id) = Self::position_owner(env.clone(), position)?;
+        if owner != from {
+            return Err(VestingError::Unauthorized);
+        }
+        let new_schedule_id = Self::move_schedule(&env, from.clone(), to.clone(), schedule_id)?;
+
+        events::PositionTransferredEvent { from, to, position }.publish(&env);
+        Ok(new_schedule_id)
+    }
+
+    /// The beneficiary and schedule ID currently holding global `position`
+    pub fn position_owner(env: Env, position: u32) -> Result<(Address, u32), VestingError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ScheduleAt(position))
+            .ok_or(VestingError::VestingNotFound)
+    }
+
+    /// Let `oracle` confirm milestones alongside the admin, or remove the
+    /// oracle with `None`.
+    pub fn set_milestone_oracle(
+        env: Env,
+        admin: Address,
+        oracle: Option<Address>,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        match oracle {
+            Some(oracle) => env
+                .storage()
+                .instance()
+                .set(&DataKey::MilestoneOracle, &oracle),
+            None => env.storage().instance().remove(&DataKey::MilestoneOracle),
+        }
+        Ok(())
+    }
+
+    /// Get the milestone oracle, if one is set
+    pub fn get_milestone_oracle(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::MilestoneOracle)
+    }
+
+    /// Unlock tranche `milestone_idx` of a milestone schedule. `caller` must
+    /// be the admin or the configured milestone oracle. Returns the tranche
+    /// amount, which is claimable once the cliff has passed.
+    pub fn confirm_milestone(
+        env: Env,
+        caller: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+        milestone_idx: u32,
+    ) -> Result<i128, VestingError> {
+        let admin = Self::get_admin(env.clone())?;
+        if caller != admin && Some(caller.clone()) != Self::get_milestone_oracle(env.clone()) {
+            return Err(VestingError::Unauthorized);
+        }
+        caller.require_auth();
+
+        let mut vesting = Self::load_vesting(&env, &beneficiary, schedule_id)?;
+        let VestingCurve::Milestones(tranches) = &vesting.curve else {
+            return Err(VestingError::InvalidMilestone);
+        };
+        let amount = tranches
+            .get(milestone_idx)
+            .ok_or(VestingError::InvalidMilestone)?;
+        if vesting.revoked_at.is_some() {
+            return Err(VestingError::AlreadyRevoked);
+        }
+        let bit = 1u32 << milestone_idx;
+        if vesting.confirmed_milestones & bit != 0 {
+            return Err(VestingError::MilestoneAlreadyConfirmed);
+        }
+        vesting.confirmed_milestones |= bit;
+        Self::save_vesting(&env, &vesting);
+
+        events::MilestoneConfirmedEvent {
+            beneficiary,
+            schedule_id,
+            milestone_idx,
+            amount,
+            confirmed_at: env.ledger().timestamp(),
+        }
+        .publish(&env);
+
+        Ok(amount)
+    }
+
+    /// Grow a schedule's total by `extra_amount` without touching its start,
+    /// cliff or duration, so the extra tokens vest on the original timeline.
+    /// Milestone schedules cannot be topped up as their tranches are fixed.
+    /// Returns the new total.
+    pub fn increase_vesting(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+        extra_amount: i128,
+    ) -> Result<i128, VestingError> {
+        Self::require_admin(&env, &admin)?;
+        if extra_amount <= 0 {
+            return Err(VestingError::InvalidAmount);
+        }
+        let mut vesting = Self::load_vesting(&env, &beneficiary, schedule_id)?;
+        if vesting.revoked_at.is_some() {
+            return Err(VestingError::AlreadyRevoked);
+        }
+        if let VestingCurve::Milestones(_) = vesting.curve {
+            return Err(VestingError::InvalidMilestone);
+        }
+        if Self::outstanding_amount(&vesting) == 0 {
+            Self::adjust_open(&env, &beneficiary, true);
+        }
+        vesting.total_amount = vesting
+            .total_amount
+            .checked_add(extra_amount)
+            .ok_or(VestingError::AmountOverflow)?;
+
+        Self::commit_funds(&env, &vesting.token, &admin, extra_amount)?;
+        Self::save_vesting(&env, &vesting);
+        Self::update_program_stats(&env, &vesting, |stats| {
+            stats.total_allocated += extra_amount
+        });
+
+        events::VestingIncreasedEvent {
+            beneficiary,
+            schedule_id,
+            extra_amount,
+            new_total: vesting.total_amount,
+        }
+        .publish(&env);
+
+        Ok(vesting.total_amount)
+    }
+
+    /// Offer to stretch a schedule to `new_duration` seconds from its start.
+    /// Nothing changes until the beneficiary calls `accept_extension`; a new
+    /// proposal replaces any earlier one.
+    pub fn propose_extension(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+        new_duration: u64,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        let vesting = Self::load_vesting(&env, &beneficiary, schedule_id)?;
+        if vesting.revoked_at.is_some() {
+            return Err(VestingError::AlreadyRevoked);
+        }
+        if new_duration <= vesting.duration {
+            return Err(VestingError::InvalidDuration);
+        }
+        Self::validate_timeline(new_duration, vesting.cliff_duration, &vesting.curve)?;
+        env.storage().persistent().set(
+            &DataKey::PendingExtension(beneficiary.clone(), schedule_id),
+            &new_duration,
+        );
+
+        events::ExtensionProposedEvent {
+            beneficiary,
+            schedule_id,
+            old_duration: vesting.duration,
+            new_duration,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Agree to the pending extension on one of the beneficiary's schedules.
+    /// `new_duration` must match the proposal, so a replaced offer can't be
+    /// accepted by mistake.
+    pub fn accept_extension(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+        new_duration: u64,
+    ) -> Result<(), VestingError> {
+        beneficiary.require_auth();
+        let key = DataKey::PendingExtension(beneficiary.clone(), schedule_id);
+        let proposed: u64 = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(VestingError::NoPendingExtension)?;
+        if proposed != new_duration {
+            return Err(VestingError::InvalidDuration);
+        }
+        let mut vesting = Self::load_vesting(&env, &beneficiary, schedule_id)?;
+        let old_duration = vesting.duration;
+        vesting.duration = new_duration;
+        Self::save_vesting(&env, &vesting);
+        env.storage().persistent().remove(&key);
+
+        events::VestingExtendedEvent {
+            beneficiary,
+            schedule_id,
+            old_duration,
+            new_duration,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// The duration offered by a pending extension on the schedule, if any
+    pub fn get_pending_extension(env: Env, beneficiary: Address, schedule_id: u32) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingExtension(beneficiary, schedule_id))
+    }
+
+    /// Halt every claim, e.g. during an incident
+    pub fn pause_claims(env: Env, admin: Address) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::ClaimsPaused, &true);
+        ClaimsPausedEvent {
+            admin,
+            paused: true,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Let claims through again after `pause_claims`
+    pub fn resume_claims(env: Env, admin: Address) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().remove(&DataKey::ClaimsPaused);
+        ClaimsPausedEvent {
+            admin,
+            paused: false,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Whether claims are currently paused contract-wide
+    pub fn is_claims_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::ClaimsPaused)
+            .unwrap_or(false)
+    }
+
+    /// Point reputation boosts at a contributor registry, or switch them off
+    /// everywhere with `None`.
+    pub fn set_reputation_registry(
+        env: Env,
+        admin: Address,
+        registry: Option<Address>,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        match registry {
+            Some(registry) => env
+                .storage()
+                .instance()
+                .set(&DataKey::ReputationRegistry, &registry),
+            None => env
+                .storage()
+                .instance()
+                .remove(&DataKey::ReputationRegistry),
+        }
+        Ok(())
+    }
+
+    /// Get the contributor registry used for reputation boosts, if any
+    pub fn get_reputation_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::ReputationRegistry)
+    }
+
+    /// Set how much of a boosted schedule's duration each registry tier cuts,
+    /// in basis points: entry `i` applies to tier `i`, and higher tiers use
+    /// the last entry. `[0, 1_000, 2_500]` vests a tier 2 contributor's
+    /// four-year grant in three.
+    pub fn set_tier_boosts(env: Env, admin: Address, boosts: Vec<u32>) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        if boosts.len() > MAX_TIER_BOOSTS {
+            return Err(VestingError::InvalidBoost);
+        }
+        for cut_bps in boosts.iter() {
+            if cut_bps >= BPS_DENOMINATOR {
+                return Err(VestingError::InvalidBoost);
+            }
+        }
+        env.storage().instance().set(&DataKey::TierBoosts, &boosts);
+        Ok(())
+    }
+
+    /// Get the tier boost table; empty until the admin sets one
+    pub fn get_tier_boosts(env: Env) -> Vec<u32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::TierBoosts)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Flag a schedule so its timeline shortens with the beneficiary's
+    /// registry tier, or clear the flag. Milestone schedules do not vest by
+    /// time and cannot be boosted.
+    pub fn set_reputation_boosted(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+        boosted: bool,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        let mut vesting = Self::load_vesting(&env, &beneficiary, schedule_id)?;
+        if let VestingCurve::Milestones(_) = vesting.curve {
+            return Err(VestingError::InvalidMilestone);
+        }
+        if vesting.revoked_at.is_some() {
+            return Err(VestingError::AlreadyRevoked);
+        }
+        vesting.reputation_boosted = boosted;
+        Self::save_vesting(&env, &vesting);
+
+        events::ReputationBoostSetEvent {
+            beneficiary,
+            schedule_id,
+            boosted,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Block claims and beneficiary changes on one schedule while its grant
+    /// is under dispute. Vesting keeps accruing underneath.
+    pub fn freeze_schedule(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<(), VestingError> {
+        Self::set_frozen(&env, &admin, beneficiary, schedule_id, true)
+    }
+
+    /// Lift a freeze placed by `freeze_schedule`
+    pub fn unfreeze_schedule(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<(), VestingError> {
+        Self::set_frozen(&env, &admin, beneficiary, schedule_id, false)
+    }
+
+    /// Add `token` to the unallocated pool. Later schedules in that token
+    /// draw on this surplus before pulling funds from the admin.
+    pub fn deposit(
+        env: Env,
+        funder: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), VestingError> {
+        Self::get_admin(env.clone())?;
+        funder.require_auth();
+        if amount <= 0 {
+            return Err(VestingError::InvalidAmount);
+        }
+        transfer(
+            &env,
+            &token,
+            &funder,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        events::DepositedEvent {
+            funder,
+            token,
+            amount,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Withdraw tokens that no schedule is owed, e.g. an unrelated asset sent
+    /// here by mistake. For a token backing schedules, only the surplus over
+    /// what they still owe can be taken.
+    pub fn rescue_tokens(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+        to: Address,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        if amount <= 0 {
+            return Err(VestingError::InvalidAmount);
+        }
+        if amount > Self::solvency(env.clone(), token.clone()).surplus {
+            return Err(VestingError::InsufficientBalance);
+        }
+        transfer(&env, &token, &env.current_contract_address(), &to, &amount);
+
+        events::TokensRescuedEvent { token, to, amount }.publish(&env);
+        Ok(())
+    }
+
+    /// Compare the contract's balance of `token` with what every schedule
+    /// paid in it still owes
+    pub fn solvency(env: Env, token: Address) -> Solvency {
+        let balance = balance(&env, &token, &env.current_contract_address());
+        let committed = Self::total_committed(&env, &token);
+        Solvency {
+            balance,
+            committed,
+            surplus: balance - committed,
+        }
+    }
+
+    /// Set the address that receives unvested tokens clawed back by `revoke`.
+    pub fn set_treasury(env: Env, admin: Address, treasury: Address) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+        Ok(())
+    }
+
+    /// Undo a schedule created by mistake: only before its `start_time` and
+    /// before anything was claimed, revocable or not. The schedule is
+    /// deleted and its whole amount is refunded to the admin, who funded
+    /// it. Returns the refund.
+    pub fn cancel_vesting(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<i128, VestingError> {
+        Self::require_admin(&env, &admin)?;
+        let vesting = Self::load_vesting(&env, &beneficiary, schedule_id)?;
+        if env.ledger().timestamp() >= vesting.start_time || vesting.claimed_amount > 0 {
+            return Err(VestingError::ScheduleStarted);
+        }
+        if vesting.revoked_at.is_some() {
+            return Err(VestingError::AlreadyRevoked);
+        }
+
+        let storage = env.storage().persistent();
+        let position_key = DataKey::SchedulePosition(beneficiary.clone(), schedule_id);
+        if let Some(position) = storage.get::<_, u32>(&position_key) {
+            Self::tag_program(&env, position, vesting.clone(), None);
+            storage.remove(&DataKey::ScheduleAt(position));
+            storage.remove(&DataKey::ClaimCount(position));
+        }
+        storage.remove(&position_key);
+        storage.remove(&DataKey::Vesting(beneficiary.clone(), schedule_id));
+        storage.remove(&DataKey::PendingClawback(beneficiary.clone(), schedule_id));
+        storage.remove(&DataKey::PendingExtension(beneficiary.clone(), schedule_id));
+        Self::adjust_open(&env, &beneficiary, false);
+
+        let refunded = vesting.total_amount;
+        Self::release_funds(&env, &vesting.token, refunded);
+        transfer(
+            &env,
+            &vesting.token,
+            &env.current_contract_address(),
+            &admin,
+            &refunded,
+        );
+
+        events::VestingCancelledEvent {
+            beneficiary,
+            schedule_id,
+            refunded,
+        }
+        .publish(&env);
+        Ok(refunded)
+    }
+
+    /// Stop a revocable schedule. Whatever has vested so far stays claimable
+    /// by the beneficiary for at least the revocation grace period; the
+    /// unvested remainder goes to the treasury.
+    pub fn revoke(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<i128, VestingError> {
+        Self::require_admin(&env, &admin)?;
+        Self::revoke_schedule(&env, beneficiary, schedule_id)
+    }
+
+    /// Set how many seconds of notice `schedule_clawback` gives
+    pub fn set_clawback_delay(env: Env, admin: Address, delay: u64) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::ClawbackDelay, &delay);
+        Ok(())
+    }
+
+    /// Seconds of notice given before a scheduled clawback can run
+    pub fn get_clawback_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ClawbackDelay)
+            .unwrap_or(DEFAULT_CLAWBACK_DELAY)
+    }
+
+    /// Set how many seconds a beneficiary keeps to claim the vested part of
+    /// a revoked schedule. Schedules already revoked keep their deadline.
+    pub fn set_revocation_grace(env: Env, admin: Address, grace: u64) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::RevocationGrace, &grace);
+        Ok(())
+    }
+
+    /// Seconds a beneficiary keeps to claim after a revocation
+    pub fn get_revocation_grace(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RevocationGrace)
+            .unwrap_or(DEFAULT_REVOCATION_GRACE)
+    }
+
+    /// Send whatever vested on a revoked schedule but was never claimed to
+    /// the treasury, once its grace period is over. Returns the amount swept.
+    pub fn sweep_revoked(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<i128, VestingError> {
+        Self::require_admin(&env, &admin)?;
+        let treasury: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Treasury)
+            .ok_or(VestingError::TreasuryNotSet)?;
+        let mut vesting = Self::load_vesting(&env, &beneficiary, schedule_id)?;
+        let sweepable_at = vesting.sweepable_at.ok_or(VestingError::NotRevoked)?;
+        if env.ledger().timestamp() < sweepable_at {
+            return Err(VestingError::GracePeriodActive);
+        }
+        let amount = Self::outstanding_amount(&vesting);
+        if amount <= 0 {
+            return Err(VestingError::NothingToClaim);
+        }
+
+        vesting.swept = true;
+        Self::save_vesting(&env, &vesting);
+        Self::adjust_open(&env, &beneficiary, false);
+        Self::return_to_treasury(&env, &vesting, &treasury, amount);
+
+        events::VestedSweptEvent {
+            beneficiary,
+            schedule_id,
+            amount,
+        }
+        .publish(&env);
+        Ok(amount)
+    }
+
+    /// Announce that a revocable schedule will be revoked once the clawback
+    /// delay has passed, giving the beneficiary on-chain notice. Vesting
+    /// keeps accruing until `execute_clawback` runs. Returns the timestamp
+    /// from which it can.
+    pub fn schedule_clawback(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<u64, VestingError> {
+        Self::require_admin(&env, &admin)?;
+        let vesting = Self::load_vesting(&env, &beneficiary, schedule_id)?;
+        Self::require_revocable(&vesting)?;
+        let key = DataKey::PendingClawback(beneficiary.clone(), schedule_id);
+        if env.storage().persistent().has(&key) {
+            return Err(VestingError::ClawbackPending);
+        }
+
+        let executable_at = env
+            .ledger()
+            .timestamp()
+            .saturating_add(Self::get_clawback_delay(env.clone()));
+        env.storage().persistent().set(&key, &executable_at);
+
+        events::ClawbackScheduledEvent {
+            beneficiary,
+            schedule_id,
+            executable_at,
+        }
+        .publish(&env);
+
+        Ok(executable_at)
+    }
+
+    /// Call off a pending clawback
+    pub fn cancel_clawback(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        let key = DataKey::PendingClawback(beneficiary.clone(), schedule_id);
+        if !env.storage().persistent().has(&key) {
+            return Err(VestingError::ClawbackNotScheduled);
+        }
+        env.storage().persistent().remove(&key);
+
+        events::ClawbackCancelledEvent {
+            beneficiary,
+            schedule_id,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Run a scheduled clawback once its notice period is over, revoking the
+    /// schedule exactly as `revoke` would. Returns the amount returned to the
+    /// treasury.
+    pub fn execute_clawback(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<i128, VestingError> {
+        Self::require_admin(&env, &admin)?;
+        let executable_at =
+            Self::get_pending_clawback(env.clone(), beneficiary.clone(), schedule_id)
+                .ok_or(VestingError::ClawbackNotScheduled)?;
+        if env.ledger().timestamp() < executable_at {
+            return Err(VestingError::ClawbackTimelocked);
+        }
+        Self::revoke_schedule(&env, beneficiary, schedule_id)
+    }
+
+    /// When a pending clawback on the schedule becomes executable, if any
+    pub fn get_pending_clawback(env: Env, beneficiary: Address, schedule_id: u32) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingClawback(beneficiary, schedule_id))
+    }
+
+    /// Release `amount` committed to `vesting` and send it to `treasury`
+    fn return_to_treasury(env: &Env, vesting: &VestingData, treasury: &Address, amount: i128) {
+        let token = &vesting.token;
+        Self::release_funds(env, token, amount);
+        Self::update_program_stats(env, vesting, |stats| stats.total_revoked += amount);
+        let key = DataKey::Revoked(token.clone());
+        let revoked: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(revoked + amount));
+        transfer(
+            env,
+            token,
+            &env.current_contract_address(),
+            treasury,
+            &amount,
+        );
+    }
+
+    fn require_revocable(vesting: &VestingData) -> Result<(), VestingError> {
+        if !vesting.revocable {
+            return Err(VestingError::NotRevocable);
+        }
+        if vesting.revoked_at.is_some() {
+            return Err(VestingError::AlreadyRevoked);
+        }
+        Ok(())
+    }
+
+    /// Stop accrual now and send the unvested remainder to the treasury
+    fn revoke_schedule(
+        env: &Env,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<i128, VestingError> {
+        let mut vesting = Self::load_vesting(env, &beneficiary, schedule_id)?;
+        Self::require_revocable(&vesting)?;
+        let treasury: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Treasury)
+            .ok_or(VestingError::TreasuryNotSet)?;
+
+        let now = env.ledger().timestamp();
+        let was_open = Self::outstanding_amount(&vesting) > 0;
+        // Settle a boosted timeline at today's tier so what stays owed can
+        // no longer move with the registry
+        let effective = Self::effective_vesting(env, &vesting);
+        vesting.duration = effective.duration;
+        vesting.cliff_duration = effective.cliff_duration;
+        vesting.reputation_boosted = false;
+        let vested = Self::revoked_vested(now, &vesting);
+        let unvested = vesting.total_amount - vested;
+        vesting.revoked_at = Some(now);
+        vesting.sweepable_at = Some(now.saturating_add(Self::get_revocation_grace(env.clone())));
+        Self::save_vesting(env, &vesting);
+        if was_open && Self::outstanding_amount(&vesting) == 0 {
+            Self::adjust_open(env, &beneficiary, false);
+        }
+        // A direct revoke also settles any clawback still in its notice period
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingClawback(beneficiary.clone(), schedule_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingExtension(beneficiary.clone(), schedule_id));
+
+        if unvested > 0 {
+            Self::return_to_treasury(env, &vesting, &treasury, unvested);
+        }
+
+        events::VestingRevokedEvent {
+            beneficiary,
+            schedule_id,
+            vested_amount: vested,
+            returned_amount: unvested,
+            revoked_at: now,
+        }
+        .publish(env);
+
+        Ok(unvested)
+    }
+
+    /// Claim available tokens from one of the beneficiary's schedules. The
+    /// beneficiary signs; proceeds go to `recipient` if given, e.g. a cold
+    /// wallet, and to the beneficiary otherwise. `amount` withdraws only
+    /// part of the claimable balance; `None` takes all of it.
+    pub fn claim(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+        recipient: Option<Address>,
+        amount: Option<i128>,
+    ) -> Result<i128, VestingError> {
+        // Check if contract is initialized
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(VestingError::NotInitialized);
+        }
+
+        // Require beneficiary authorization
+        beneficiary.require_auth();
+        Self::claim_schedule(&env, &beneficiary, schedule_id, recipient, amount)
+    }
+
+    /// Let `operator` trigger claims on the beneficiary's behalf, e.g. an
+    /// automated claim bot. Proceeds always go to the beneficiary.
+    pub fn approve_operator(
+        env: Env,
+        beneficiary: Address,
+        operator: Address,
+    ) -> Result<(), VestingError> {
+        Self::set_operator(&env, beneficiary, operator, true)
     }
 
-    /// Create a vesting schedule for a beneficiary
-    pub fn create_vesting(
+    /// Withdraw an operator approval
+    pub fn revoke_operator(
         env: Env,
-        admin: Address,
         beneficiary: Address,
-        amount: i128,
-        start_time: u64,
-        duration: u64,
+        operator: Address,
     ) -> Result<(), VestingError> {
-        // Check if contract is initialized
-        let stored_admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(VestingError::NotInitialized)?;
+        Self::set_operator(&env, beneficiary, operator, false)
+    }
+
+    /// Whether `operator` may claim for `beneficiary`
+    pub fn is_operator(env: Env, beneficiary: Address, operator: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Operator(beneficiary, operator))
+    }
 
-        // Verify admin identity
-        if admin != stored_admin {
+    /// Claim everything available on one schedule as an approved operator.
+    /// The operator signs; the tokens go to the beneficiary.
+    pub fn operator_claim(
+        env: Env,
+        operator: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<i128, VestingError> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(VestingError::NotInitialized);
+        }
+        operator.require_auth();
+        if !Self::is_operator(env.clone(), beneficiary.clone(), operator) {
             return Err(VestingError::Unauthorized);
         }
+        Self::claim_schedule(&env, &beneficiary, schedule_id, None, None)
+    }
 
-        // Require admin authorization
-        admin.require_auth();
-
-        // Validate amount
-        if amount <= 0 {
-            return Err(VestingError::InvalidAmount);
+    fn set_operator(
+        env: &Env,
+        beneficiary: Address,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), VestingError> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(VestingError::NotInitialized);
+        }
+        beneficiary.require_auth();
+        let key = DataKey::Operator(beneficiary.clone(), operator.clone());
+        if approved {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
         }
 
-        // Validate duration
-        if duration == 0 {
-            return Err(VestingError::InvalidDuration);
+        events::OperatorUpdatedEvent {
+            beneficiary,
+            operator,
+            approved,
         }
+        .publish(env);
+        Ok(())
+    }
 
-        // Validate start time (should be in the future or current time)
-        let current_time = env.ledger().timestamp();
-        if start_time < current_time {
-            return Err(VestingError::InvalidStartTime);
+    /// Pay out of one schedule once the caller has been authorized
+    fn claim_schedule(
+        env: &Env,
+        beneficiary: &Address,
+        schedule_id: u32,
+        recipient: Option<Address>,
+        amount: Option<i128>,
+    ) -> Result<i128, VestingError> {
+        Self::require_claims_open(env)?;
+
+        let mut vesting = Self::load_vesting(env, beneficiary, schedule_id)?;
+        if vesting.frozen {
+            return Err(VestingError::ScheduleFrozen);
         }
 
-        // Get token address
-        let token: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .ok_or(VestingError::NotInitialized)?;
+        // Calculate available amount using the helper function
+        let current_time = env.ledger().timestamp();
+        let available_amount = Self::calculate_claimable_amount(env, current_time, &vesting);
 
-        let contract_address = env.current_contract_address();
+        // Check if there's anything to claim
+        if available_amount <= 0 {
+            return Err(VestingError::NothingToClaim);
+        }
 
-        // If vesting already exists, return remaining tokens to admin
-        // (total_amount - claimed_amount)
-        if let Some(existing_vesting) = env
-            .storage()
-            .persistent()
-            .get::<_, VestingData>(&DataKey::Vesting(beneficiary.clone()))
-        {
-            let remaining = existing_vesting.total_amount - existing_vesting.claimed_amount;
-            if remaining > 0 {
-                transfer(&env, &token, &contract_address, &admin, &remaining);
+        let claim_amount = match amount {
+            Some(amount) if amount <= 0 => return Err(VestingError::InvalidAmount),
+            Some(amount) if amount > available_amount => {
+                return Err(VestingError::ExceedsClaimable)
             }
+            Some(amount) => amount,
+            None => available_amount,
+        };
+
+        // Refuse to pay out more than the contract actually holds
+        if balance(env, &vesting.token, &env.current_contract_address()) < claim_amount {
+            return Err(VestingError::InsufficientBalance);
         }
 
-        // Transfer tokens from admin to contract
-        transfer(&env, &token, &admin, &contract_address, &amount);
+        let recipient = recipient.unwrap_or_else(|| beneficiary.clone());
+        Self::pay_out(env, &mut vesting, claim_amount, &recipient);
+        Ok(claim_amount)
+    }
 
-        // Create vesting data
-        let vesting = VestingData {
-            beneficiary: beneficiary.clone(),
-            total_amount: amount,
-            start_time,
-            duration,
-            claimed_amount: 0,
-        };
+    /// Claim everything currently available across all of the beneficiary's
+    /// schedules, returning the total paid out (summed across tokens).
+    /// Frozen schedules are skipped.
+    pub fn claim_all(env: Env, beneficiary: Address) -> Result<i128, VestingError> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(VestingError::NotInitialized);
+        }
+        beneficiary.require_auth();
+        Self::require_claims_open(&env)?;
 
-        // Store vesting data
-        env.storage()
-            .persistent()
-            .set(&DataKey::Vesting(beneficiary), &vesting);
+        let mut claimable = Vec::new(&env);
+        let mut totals: Map<Address, i128> = Map::new(&env);
+        let total = Self::collect_claimable(&env, &beneficiary, &mut claimable, &mut totals);
+        if total == 0 {
+            return Err(VestingError::NothingToClaim);
+        }
+        Self::pay_out_all(&env, &claimable, &totals)?;
+        Ok(total)
+    }
 
-        // Emit VestingCreated event
-        events::VestingCreatedEvent {
-            beneficiary: vesting.beneficiary.clone(),
-            amount: vesting.total_amount,
-            start_time: vesting.start_time,
-            duration: vesting.duration,
+    /// Claim each listed `(beneficiary, schedule_id)` whose beneficiary has
+    /// opted in with `set_auto_claim`, paying the beneficiaries themselves.
+    /// Anyone may run it, for up to `MAX_CLAIM_BATCH` schedules at a time.
+    /// Schedules that are missing, frozen, not opted in or have nothing to
+    /// claim are skipped. Returns the total paid out.
+    pub fn claim_for_many(
+        env: Env,
+        caller: Address,
+        schedules: Vec<(Address, u32)>,
+    ) -> Result<i128, VestingError> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(VestingError::NotInitialized);
+        }
+        caller.require_auth();
+        Self::require_claims_open(&env)?;
+        if schedules.len() > MAX_CLAIM_BATCH {
+            return Err(VestingError::InvalidBatch);
         }
-        .publish(&env);
 
-        Ok(())
+        let current_time = env.ledger().timestamp();
+        let mut claimable = Vec::new(&env);
+        let mut totals: Map<Address, i128> = Map::new(&env);
+        let mut seen: Map<(Address, u32), bool> = Map::new(&env);
+        let mut total: i128 = 0;
+        for (beneficiary, schedule_id) in schedules.iter() {
+            if seen.contains_key((beneficiary.clone(), schedule_id))
+                || !Self::is_auto_claim(env.clone(), beneficiary.clone())
+            {
+                continue;
+            }
+            seen.set((beneficiary.clone(), schedule_id), true);
+            let Ok(vesting) = Self::load_vesting(&env, &beneficiary, schedule_id) else {
+                continue;
+            };
+            if vesting.frozen {
+                continue;
+            }
+            let amount = Self::calculate_claimable_amount(&env, current_time, &vesting);
+            if amount > 0 {
+                total += amount;
+                let token_total = totals.get(vesting.token.clone()).unwrap_or(0);
+                totals.set(vesting.token.clone(), token_total + amount);
+                claimable.push_back((vesting, amount));
+            }
+        }
+        Self::pay_out_all(&env, &claimable, &totals)?;
+        Ok(total)
     }
 
-    /// Claim available tokens based on linear vesting schedule
-    pub fn claim(env: Env, beneficiary: Address) -> Result<i128, VestingError> {
-        // Check if contract is initialized
+    /// Let keepers claim on the beneficiary's behalf through
+    /// `claim_for_many`, or stop them
+    pub fn set_auto_claim(
+        env: Env,
+        beneficiary: Address,
+        enabled: bool,
+    ) -> Result<(), VestingError> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(VestingError::NotInitialized);
         }
-
-        // Require beneficiary authorization
         beneficiary.require_auth();
+        let key = DataKey::AutoClaim(beneficiary.clone());
+        if enabled {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
 
-        // Get vesting data
-        let mut vesting: VestingData = env
-            .storage()
+        events::AutoClaimUpdatedEvent {
+            beneficiary,
+            enabled,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Whether the beneficiary has opted into keeper claims
+    pub fn is_auto_claim(env: Env, beneficiary: Address) -> bool {
+        env.storage()
             .persistent()
-            .get(&DataKey::Vesting(beneficiary.clone()))
-            .ok_or(VestingError::VestingNotFound)?;
+            .get(&DataKey::AutoClaim(beneficiary))
+            .unwrap_or(false)
+    }
 
-        // Get current time
+    /// Queue every unfrozen schedule of `beneficiary` with something to
+    /// claim, adding to the per-token `totals`. Returns the amount queued.
+    fn collect_claimable(
+        env: &Env,
+        beneficiary: &Address,
+        claimable: &mut Vec<(VestingData, i128)>,
+        totals: &mut Map<Address, i128>,
+    ) -> i128 {
         let current_time = env.ledger().timestamp();
-
-        // Calculate available amount using the helper function
-        let available_amount = Self::calculate_claimable_amount(current_time, &vesting);
-
-        // Check if there's anything to claim
-        if available_amount <= 0 {
-            return Err(VestingError::NothingToClaim);
+        let mut total: i128 = 0;
+        for vesting in Self::schedules(env, beneficiary).iter() {
+            if vesting.frozen {
+                continue;
+            }
+            let amount = Self::calculate_claimable_amount(env, current_time, &vesting);
+            if amount > 0 {
+                total += amount;
+                let token_total = totals.get(vesting.token.clone()).unwrap_or(0);
+                totals.set(vesting.token.clone(), token_total + amount);
+                claimable.push_back((vesting, amount));
+            }
         }
+        total
+    }
 
-        // Get token address
-        let token: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .ok_or(VestingError::NotInitialized)?;
+    /// Pay out queued claims to their beneficiaries, after checking the
+    /// contract holds enough of every token involved
+    fn pay_out_all(
+        env: &Env,
+        claimable: &Vec<(VestingData, i128)>,
+        totals: &Map<Address, i128>,
+    ) -> Result<(), VestingError> {
+        for (token, token_total) in totals.iter() {
+            if balance(env, &token, &env.current_contract_address()) < token_total {
+                return Err(VestingError::InsufficientBalance);
+            }
+        }
+        for (mut vesting, amount) in claimable.iter() {
+            let beneficiary = vesting.beneficiary.clone();
+            Self::pay_out(env, &mut vesting, amount, &beneficiary);
+        }
+        Ok(())
+    }
 
-        // Transfer tokens from contract to beneficiary
-        let contract_address = env.current_contract_address();
-        transfer(
+    /// Get the claimable amount for a schedule without modifying state
+    /// This is a pure view method that returns how much a beneficiary could claim at the current time
+    pub fn get_claimable(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<i128, VestingError> {
+        let vesting = Self::load_vesting(&env, &beneficiary, schedule_id)?;
+        let current_time = env.ledger().timestamp();
+        Ok(Self::calculate_claimable_amount(
             &env,
-            &token,
-            &contract_address,
-            &beneficiary,
-            &available_amount,
-        );
+            current_time,
+            &vesting,
+        ))
+    }
+
+    /// Get vesting data for one of a beneficiary's schedules
+    pub fn get_vesting(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<VestingData, VestingError> {
+        Self::load_vesting(&env, &beneficiary, schedule_id)
+    }
 
-        // Update claimed amount
-        vesting.claimed_amount += available_amount;
+    /// Number of schedules ever created for a beneficiary; IDs run from 0
+    pub fn get_vesting_count(env: Env, beneficiary: Address) -> u32 {
         env.storage()
             .persistent()
-            .set(&DataKey::Vesting(beneficiary), &vesting);
+            .get(&DataKey::VestingCount(beneficiary))
+            .unwrap_or(0)
+    }
 
-        // Emit TokensClaimed event
-        let remaining = vesting.total_amount - vesting.claimed_amount;
-        events::TokensClaimedEvent {
-            beneficiary: vesting.beneficiary.clone(),
-            amount_claimed: available_amount,
-            remaining,
+    /// Extend the TTL of a schedule and every entry it is read through to
+    /// `ledgers`, so a multi-year grant outlives quiet stretches without
+    /// claims: its index entries, the beneficiary's schedule and open
+    /// counts, the claim count and the newest `MAX_TTL_CLAIMS` claim records.
+    /// Older records are extended with `extend_claim_history_ttl`. Anyone may
+    /// pay the rent.
+    pub fn extend_vesting_ttl(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+        ledgers: u32,
+    ) -> Result<(), VestingError> {
+        if ledgers == 0 || ledgers > env.storage().max_ttl() {
+            return Err(VestingError::InvalidTtl);
         }
-        .publish(&env);
+        let storage = env.storage().persistent();
+        let key = DataKey::Vesting(beneficiary.clone(), schedule_id);
+        if !storage.has(&key) {
+            return Err(VestingError::VestingNotFound);
+        }
+        storage.extend_ttl(&key, ledgers, ledgers);
+        Self::extend_if_present(&env, &DataKey::VestingCount(beneficiary.clone()), ledgers);
+        Self::extend_if_present(&env, &DataKey::OpenSchedules(beneficiary.clone()), ledgers);
+        let position_key = DataKey::SchedulePosition(beneficiary, schedule_id);
+        let Some(position) = storage.get::<_, u32>(&position_key) else {
+            return Ok(());
+        };
+        storage.extend_ttl(&position_key, ledgers, ledgers);
+        Self::extend_if_present(&env, &DataKey::ScheduleAt(position), ledgers);
+        Self::extend_if_present(&env, &DataKey::ClaimCount(position), ledgers);
+        let count: u32 = storage.get(&DataKey::ClaimCount(position)).unwrap_or(0);
+        for i in count.saturating_sub(MAX_TTL_CLAIMS)..count {
+            Self::extend_if_present(&env, &DataKey::Claim(position, i), ledgers);
+        }
+        Ok(())
+    }
 
-        Ok(available_amount)
+    /// Extend the TTL of up to `MAX_TTL_CLAIMS` claim records of a schedule,
+    /// starting at record `start`, to `ledgers`. Returns how many records it
+    /// covered. Anyone may pay the rent.
+    pub fn extend_claim_history_ttl(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+        start: u32,
+        ledgers: u32,
+    ) -> Result<u32, VestingError> {
+        if ledgers == 0 || ledgers > env.storage().max_ttl() {
+            return Err(VestingError::InvalidTtl);
+        }
+        let storage = env.storage().persistent();
+        let position: u32 = storage
+            .get(&DataKey::SchedulePosition(beneficiary, schedule_id))
+            .ok_or(VestingError::VestingNotFound)?;
+        let count: u32 = storage.get(&DataKey::ClaimCount(position)).unwrap_or(0);
+        let end = start.saturating_add(MAX_TTL_CLAIMS).min(count);
+        for i in start..end {
+            Self::extend_if_present(&env, &DataKey::Claim(position, i), ledgers);
+        }
+        Ok(end.saturating_sub(start))
     }
 
-    /// Get the claimable amount for a beneficiary without modifying state
-    /// This is a pure view method that returns how much a beneficiary could claim at the current time
-    pub fn get_claimable(env: Env, beneficiary: Address) -> Result<i128, VestingError> {
-        // Get vesting data
-        let vesting: VestingData = env
+    fn extend_if_present(env: &Env, key: &DataKey, ledgers: u32) {
+        if env.storage().persistent().has(key) {
+            env.storage().persistent().extend_ttl(key, ledgers, ledgers);
+        }
+    }
+
+    /// Get the available amount that can be claimed from a schedule
+    pub fn get_available_amount(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+    ) -> Result<i128, VestingError> {
+        Self::get_claimable(env, beneficiary, schedule_id)
+    }
+
+    /// Total vested across all of the beneficiary's schedules at timestamp
+    /// `at`, past or future. Revoked schedules stop at their revocation time.
+    pub fn vested_amount(env: Env, beneficiary: Address, at: u64) -> i128 {
+        let mut total: i128 = 0;
+        for vesting in Self::schedules(&env, &beneficiary).iter() {
+            total += Self::vested_at(at, &Self::effective_vesting(&env, &vesting));
+        }
+        total
+    }
+
+    /// Sample a schedule's cumulative vested amount at `points` timestamps
+    /// spread evenly from its start to its end, both included, for charts.
+    /// Uses today's tier for boosted schedules. At most `MAX_UNLOCK_POINTS`
+    /// samples are returned; a single point is the end of the schedule.
+    pub fn unlock_schedule(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+        points: u32,
+    ) -> Result<Vec<(u64, i128)>, VestingError> {
+        let vesting = Self::load_vesting(&env, &beneficiary, schedule_id)?;
+        let vesting = Self::effective_vesting(&env, &vesting);
+        let points = points.min(MAX_UNLOCK_POINTS) as u64;
+        let mut timetable = Vec::new(&env);
+        for i in 0..points {
+            let offset = if points == 1 {
+                vesting.duration
+            } else {
+                (vesting.duration as u128 * i as u128 / (points - 1) as u128) as u64
+            };
+            let at = vesting.start_time.saturating_add(offset);
+            timetable.push_back((at, Self::vested_at(at, &vesting)));
+        }
+        Ok(timetable)
+    }
+
+    /// Total claimable right now across all of the beneficiary's schedules;
+    /// this is exactly what `claim_all` would pay out.
+    pub fn claimable_amount(env: Env, beneficiary: Address) -> i128 {
+        let current_time = env.ledger().timestamp();
+        let mut total: i128 = 0;
+        for vesting in Self::schedules(&env, &beneficiary).iter() {
+            total += Self::calculate_claimable_amount(&env, current_time, &vesting);
+        }
+        total
+    }
+
+    /// Page through a schedule's claims, oldest first
+    pub fn get_claim_history(
+        env: Env,
+        beneficiary: Address,
+        schedule_id: u32,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<ClaimRecord>, VestingError> {
+        let storage = env.storage().persistent();
+        let position: u32 = storage
+            .get(&DataKey::SchedulePosition(beneficiary, schedule_id))
+            .ok_or(VestingError::VestingNotFound)?;
+        let count: u32 = storage.get(&DataKey::ClaimCount(position)).unwrap_or(0);
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+        let mut page = Vec::new(&env);
+        for i in start..end {
+            if let Some(record) = storage.get(&DataKey::Claim(position, i)) {
+                page.push_back(record);
+            }
+        }
+        Ok(page)
+    }
+
+    /// Tag a schedule with a funding program, move it to another, or untag
+    /// it with `None`. The program's stats follow the schedule.
+    pub fn set_program(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        schedule_id: u32,
+        program: Option<Symbol>,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        let vesting = Self::load_vesting(&env, &beneficiary, schedule_id)?;
+        let position: u32 = env
             .storage()
             .persistent()
-            .get(&DataKey::Vesting(beneficiary))
+            .get(&DataKey::SchedulePosition(beneficiary, schedule_id))
             .ok_or(VestingError::VestingNotFound)?;
+        Self::tag_program(&env, position, vesting, program);
+        Ok(())
+    }
 
-        // Get current time
-        let current_time = env.ledger().timestamp();
+    /// Page through the schedules tagged with `program`. Order follows
+    /// tagging, except that untagging one moves the last into its place.
+    pub fn list_by_program(env: Env, program: Symbol, start: u32, limit: u32) -> Vec<VestingData> {
+        let storage = env.storage().persistent();
+        let count: u32 = storage
+            .get(&DataKey::ProgramCount(program.clone()))
+            .unwrap_or(0);
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+        let mut page = Vec::new(&env);
+        for i in start..end {
+            let Some(position) = storage.get::<_, u32>(&DataKey::ProgramAt(program.clone(), i))
+            else {
+                continue;
+            };
+            if let Ok((beneficiary, schedule_id)) = Self::position_owner(env.clone(), position) {
+                if let Ok(vesting) = Self::load_vesting(&env, &beneficiary, schedule_id) {
+                    page.push_back(vesting);
+                }
+            }
+        }
+        page
+    }
 
-        // Calculate claimable amount using the helper function
-        let claimable_amount = Self::calculate_claimable_amount(current_time, &vesting);
+    /// Aggregate totals for one program
+    pub fn program_stats(env: Env, program: Symbol) -> ProgramStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProgramStats(program))
+            .unwrap_or_default()
+    }
 
-        Ok(claimable_amount)
+    /// One-call summary of every schedule, with amounts in the default token
+    pub fn stats(env: Env) -> Result<VestingStats, VestingError> {
+        let token = Self::get_token(env.clone())?;
+        Ok(VestingStats {
+            total_schedules: Self::get_schedule_count(env.clone()),
+            total_committed: Self::total_committed(&env, &token),
+            total_claimed: Self::total_claimed(env.clone(), token.clone()),
+            total_revoked: env
+                .storage()
+                .instance()
+                .get(&DataKey::Revoked(token))
+                .unwrap_or(0),
+            active_beneficiaries: env
+                .storage()
+                .instance()
+                .get(&DataKey::ActiveBeneficiaries)
+                .unwrap_or(0),
+        })
     }
 
-    /// Get vesting data for a beneficiary
-    pub fn get_vesting(env: Env, beneficiary: Address) -> Result<VestingData, VestingError> {
+    /// Number of schedules ever created, across all beneficiaries
+    pub fn get_schedule_count(env: Env) -> u32 {
         env.storage()
-            .persistent()
-            .get(&DataKey::Vesting(beneficiary))
-            .ok_or(VestingError::VestingNotFound)
+            .instance()
+            .get(&DataKey::ScheduleCount)
+            .unwrap_or(0)
     }
 
-    /// Get the available amount that can be claimed by a beneficiary
-    pub fn get_available_amount(env: Env, beneficiary: Address) -> Result<i128, VestingError> {
-        // Get vesting data
-        let vesting: VestingData = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Vesting(beneficiary))
-            .ok_or(VestingError::VestingNotFound)?;
+    /// Page through every schedule in creation order. A schedule moved by
+    /// `change_beneficiary` keeps its place under its new address.
+    pub fn list_vestings(env: Env, start: u32, limit: u32) -> Vec<VestingData> {
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(Self::get_schedule_count(env.clone()));
+        let mut page = Vec::new(&env);
+        for position in start..end {
+            let entry: Option<(Address, u32)> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ScheduleAt(position));
+            if let Some((beneficiary, schedule_id)) = entry {
+                if let Ok(vesting) = Self::load_vesting(&env, &beneficiary, schedule_id) {
+                    page.push_back(vesting);
+                }
+            }
+        }
+        page
+    }
 
-        // Get current time
-        let current_time = env.ledger().timestamp();
+    /// Amount of `token` still owed to beneficiaries across every schedule,
+    /// vested or not, excluding anything claimed or returned on revocation
+    pub fn total_locked(env: Env, token: Address) -> i128 {
+        Self::total_committed(&env, &token)
+    }
 
-        // Calculate available amount using the helper function
-        let available_amount = Self::calculate_claimable_amount(current_time, &vesting);
+    /// Amount of `token` paid out to beneficiaries over the contract's lifetime
+    pub fn total_claimed(env: Env, token: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Claimed(token))
+            .unwrap_or(0)
+    }
+
+    /// Take `fee_bps` basis points of every claim and send them to
+    /// `treasury`, up to `MAX_CLAIM_FEE_BPS`. A fee of 0 switches it off.
+    pub fn set_claim_fee(
+        env: Env,
+        admin: Address,
+        fee_bps: u32,
+        treasury: Address,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &admin)?;
+        if fee_bps > MAX_CLAIM_FEE_BPS {
+            return Err(VestingError::InvalidFee);
+        }
+        if fee_bps == 0 {
+            env.storage().instance().remove(&DataKey::ClaimFee);
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKey::ClaimFee, &ClaimFee { fee_bps, treasury });
+        }
+        Ok(())
+    }
+
+    /// Get the claim fee, if one is set
+    pub fn get_claim_fee(env: Env) -> Option<ClaimFee> {
+        env.storage().instance().get(&DataKey::ClaimFee)
+    }
 
-        Ok(available_amount)
+    /// Get treasury address
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Treasury)
     }
 
     /// Get admin address
@@ -260,7 +2374,7 @@ impl VestingWalletContract {
             .ok_or(VestingError::NotInitialized)
     }
 
-    /// Get token address
+    /// Get the default token, used by schedules that don't name their own
     pub fn get_token(env: Env) -> Result<Address, VestingError> {
         env.storage()
             .instance()
@@ -313,6 +2427,7 @@ impl VestingWalletContract {
         }
         current_admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
         AdminChangedEvent {
             old_admin: current_admin,
             new_admin,
@@ -320,6 +2435,49 @@ impl VestingWalletContract {
         .publish(&env);
         Ok(())
     }
+
+    /// Offer the admin role to `new_admin`, who must call
+    /// [`Self::accept_admin`] to take it. A later offer replaces this one.
+    pub fn transfer_admin(
+        env: Env,
+        current_admin: Address,
+        new_admin: Address,
+    ) -> Result<(), VestingError> {
+        Self::require_admin(&env, &current_admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &new_admin);
+        AdminTransferProposedEvent {
+            current_admin,
+            pending_admin: new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Claim the admin role previously offered via [`Self::transfer_admin`].
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), VestingError> {
+        let pending = Self::get_pending_admin(env.clone()).ok_or(VestingError::NoPendingAdmin)?;
+        if pending != new_admin {
+            return Err(VestingError::Unauthorized);
+        }
+        new_admin.require_auth();
+
+        let old_admin = Self::get_admin(env.clone())?;
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        AdminChangedEvent {
+            old_admin,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Address currently offered the admin role, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
 }
 
 #[cfg(test)]
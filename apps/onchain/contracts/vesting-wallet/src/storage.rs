@@ -1,19 +1,241 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, String, Symbol, Vec};
+
+/// Upper bound on the number of schedules created by one `create_vestings` call.
+/// A schedule for a new beneficiary in a new program writes nine entries, so
+/// five of them plus the shared accounting stay within the 50 entry write limit.
+pub const MAX_BATCH_SIZE: u32 = 5;
+
+/// Upper bound on the number of schedules paid by one `claim_for_many` call.
+/// Paying a schedule out writes up to six entries besides the shared ones.
+pub const MAX_CLAIM_BATCH: u32 = 7;
+
+/// Most claim records one `extend_vesting_ttl` or `extend_claim_history_ttl`
+/// call extends.
+pub const MAX_TTL_CLAIMS: u32 = 16;
+
+/// Upper bound on the number of schedules returned by one `list_vestings` page.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Time a beneficiary has to claim the vested part of a revoked schedule
+/// before the admin may sweep it, unless configured: 30 days.
+pub const DEFAULT_REVOCATION_GRACE: u64 = 30 * 86_400;
+
+/// Upper bound on samples returned by one `unlock_schedule` call.
+pub const MAX_UNLOCK_POINTS: u32 = 100;
+
+/// Notice given before a scheduled clawback can run, unless configured: 7 days.
+pub const DEFAULT_CLAWBACK_DELAY: u64 = 7 * 86_400;
+
+/// Longest template name accepted, in bytes.
+pub const MAX_TEMPLATE_NAME_LEN: u32 = 64;
+
+/// Boosts are expressed in basis points of a schedule's duration.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Highest claim fee the admin may configure: 10%, in basis points.
+pub const MAX_CLAIM_FEE_BPS: u32 = 1_000;
+
+/// Upper bound on entries in the tier boost table.
+pub const MAX_TIER_BOOSTS: u32 = 16;
+
+/// Upper bound on tranches in a milestone schedule; confirmations are a bitmask.
+pub const MAX_MILESTONES: u32 = 32;
+
+/// Roughly one day of ledgers at a 5 second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Schedules touched by any write are kept alive for ~30 days.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Only pay for an extension once less than ~29 days remain.
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    Admin,            // -> Address
-    Token,            // -> Address
-    Vesting(Address), // beneficiary -> VestingData
+    Admin,                          // -> Address
+    Token,                          // -> Address, default for new schedules
+    Vesting(Address, u32),          // (beneficiary, schedule id) -> VestingData
+    VestingCount(Address),          // beneficiary -> u32, next schedule id
+    Treasury,                       // -> Address receiving revoked, unvested tokens
+    ChangeNeedsApproval,            // -> bool, admin must co-sign beneficiary changes
+    MilestoneOracle,                // -> Address allowed to confirm milestones
+    Committed(Address),             // token -> i128 still owed across its schedules
+    ClaimsPaused,                   // -> bool, halts every payout while set
+    Claimed(Address),               // token -> i128 paid out to beneficiaries, ever
+    ScheduleCount,                  // -> u32, number of schedules ever created
+    ScheduleAt(u32),                // position -> (beneficiary, schedule id)
+    SchedulePosition(Address, u32), // (beneficiary, schedule id) -> position
+    ClawbackDelay,                  // -> u64 seconds of notice before a clawback
+    PendingClawback(Address, u32),  // (beneficiary, schedule id) -> u64 executable at
+    Operator(Address, Address),     // (beneficiary, operator) -> bool, may claim for them
+    Template(u32),                  // template id -> VestingTemplate
+    TemplateCount,                  // -> u32, next template id
+    MaxBackdate,                    // -> u64 seconds a start_time may lie in the past
+    MaxFutureStart,                 // -> u64 seconds a start_time may lie in the future
+    PendingAdmin,                   // -> Address offered the admin role
+    ClaimCount(u32),                // schedule position -> u32 claims recorded
+    Claim(u32, u32),                // (schedule position, index) -> ClaimRecord
+    Revoked(Address),               // token -> i128 returned to the treasury, ever
+    OpenSchedules(Address),         // beneficiary -> u32 schedules still owing tokens
+    ActiveBeneficiaries,            // -> u32 beneficiaries with an open schedule
+    ReputationRegistry,             // -> Address of the contributor registry
+    TierBoosts,                     // -> Vec<u32> bps cut from duration, by tier
+    PendingExtension(Address, u32), // (beneficiary, schedule id) -> u64 proposed duration
+    ClaimFee,                       // -> ClaimFee taken from every payout
+    RevocationGrace,                // -> u64 seconds to claim after a revoke
+    PositionsTransferable,          // -> bool, beneficiaries may sell their positions
+    AutoClaim(Address),             // beneficiary -> bool, keepers may claim for them
+    ProgramCount(Symbol),           // program -> u32 schedules tagged with it
+    ProgramAt(Symbol, u32),         // (program, index) -> schedule position
+    ProgramSlot(u32),               // schedule position -> index within its program
+    ProgramStats(Symbol),           // program -> ProgramStats
+    ScheduleCreator(Address),       // creator -> bool, may fund new schedules
+}
+
+/// Keys written before beneficiaries could hold more than one schedule.
+/// Encodes the same way the old `DataKey::Vesting(Address)` variant did.
+#[contracttype]
+#[derive(Clone)]
+pub enum LegacyDataKey {
+    Vesting(Address), // beneficiary -> LegacyVestingData
+}
+
+/// The original single-schedule layout, linear with no cliff. Still held on
+/// upgraded deployments until `migrate_legacy_vesting` moves it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyVestingData {
+    pub beneficiary: Address,
+    pub total_amount: i128,
+    pub start_time: u64,
+    pub duration: u64,
+    pub claimed_amount: i128,
+}
+
+/// How vested tokens unlock between `start_time` and the end of a schedule.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VestingCurve {
+    /// Continuous unlock, pro rata to elapsed time.
+    Linear,
+    /// Linear, but only in whole steps of the given number of seconds.
+    Periodic(u64),
+    /// Nothing unlocks until the schedule ends, then everything does.
+    CliffOnly,
+    /// Tranche amounts, each unlocked by `confirm_milestone` rather than by
+    /// time. They must add up to the schedule's total.
+    Milestones(Vec<i128>),
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VestingData {
     pub beneficiary: Address,
+    pub schedule_id: u32,
+    /// Asset the grant is denominated and paid in.
+    pub token: Address,
     pub total_amount: i128,
     pub start_time: u64,
     pub duration: u64,
+    pub cliff_duration: u64,
+    pub curve: VestingCurve,
     pub claimed_amount: i128,
+    /// Bit `i` is set once milestone `i` has been confirmed.
+    pub confirmed_milestones: u32,
+    pub revocable: bool,
+    /// Set by the admin while the grant is under dispute; blocks claims.
+    pub frozen: bool,
+    /// Set once revoked; vesting stops accruing at this timestamp.
+    pub revoked_at: Option<u64>,
+    /// Shorten the timeline by the beneficiary's registry tier at claim time.
+    pub reputation_boosted: bool,
+    /// Once revoked, when the grace period ends and unclaimed vested tokens
+    /// may be swept to the treasury.
+    pub sweepable_at: Option<u64>,
+    /// Set once the admin has swept the unclaimed remainder.
+    pub swept: bool,
+    /// Funding round or program the grant belongs to, if tagged.
+    pub program: Option<Symbol>,
+}
+
+/// Parameters for one schedule in a `create_vestings` batch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewVesting {
+    pub beneficiary: Address,
+    /// Asset to pay the grant in; `None` uses the contract's default token.
+    pub token: Option<Address>,
+    pub amount: i128,
+    pub start_time: u64,
+    pub duration: u64,
+    pub cliff_duration: u64,
+    pub curve: VestingCurve,
+    pub revocable: bool,
+    /// Funding round or program to tag the schedule with.
+    pub program: Option<Symbol>,
+}
+
+/// The contract's balance of one token measured against what it still owes
+/// in that token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Solvency {
+    pub balance: i128,
+    pub committed: i128,
+    /// `balance - committed`; negative means some schedules cannot be paid.
+    pub surplus: i128,
+}
+
+/// Reusable schedule terms, e.g. "4 years with a 1 year cliff". Amount,
+/// beneficiary and start time are supplied per grant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingTemplate {
+    pub name: String,
+    pub duration: u64,
+    pub cliff_duration: u64,
+    pub curve: VestingCurve,
+    pub revocable: bool,
+}
+
+/// One payout from a schedule, kept for beneficiaries' statements.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimRecord {
+    pub timestamp: u64,
+    pub amount: i128,
+    pub recipient: Address,
+}
+
+/// Contract-wide summary for the treasury dashboard. Amounts are in the
+/// default token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingStats {
+    pub total_schedules: u32,
+    pub total_committed: i128,
+    pub total_claimed: i128,
+    pub total_revoked: i128,
+    /// Beneficiaries still owed tokens on at least one schedule.
+    pub active_beneficiaries: u32,
+}
+
+/// Cut of every claim routed to a protocol treasury.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimFee {
+    pub fee_bps: u32,
+    pub treasury: Address,
+}
+
+/// Running totals for one program, summed over its schedules' own tokens.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProgramStats {
+    pub schedules: u32,
+    pub total_allocated: i128,
+    pub total_claimed: i128,
+    /// Returned to the treasury by revocations and sweeps.
+    pub total_revoked: i128,
 }
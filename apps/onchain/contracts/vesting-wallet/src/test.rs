@@ -1,11 +1,31 @@
 use crate::errors::VestingError;
+use crate::storage::{
+    ClaimRecord, DataKey, LegacyDataKey, LegacyVestingData, NewVesting, ProgramStats, Solvency,
+    VestingCurve, VestingStats, MAX_BATCH_SIZE, MAX_CLAIM_BATCH, PERSISTENT_BUMP_AMOUNT,
+};
 use crate::{VestingWalletContract, VestingWalletContractClient};
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
+    contract, contractimpl,
+    testutils::{storage::Persistent as _, Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
     token::{StellarAssetClient, TokenClient},
-    Address, Env,
+    Address, Env, FromVal, IntoVal, Map, String, Symbol, Val, Vec,
 };
 
+/// Stands in for the contributor registry's `get_tier`.
+#[contract]
+struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_tier(env: Env, address: Address, tier: u32) {
+        env.storage().instance().set(&address, &tier);
+    }
+
+    pub fn get_tier(env: Env, address: Address) -> u32 {
+        env.storage().instance().get(&address).unwrap_or(0)
+    }
+}
+
 fn create_token_contract<'a>(
     env: &Env,
     admin: &Address,
@@ -89,10 +109,19 @@ fn test_create_vesting() {
     let amount: i128 = 1_000_000;
 
     // Create vesting
-    client.create_vesting(&admin, &beneficiary, &amount, &start_time, &duration);
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount,
+        &start_time,
+        &duration,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
 
     // Verify vesting data
-    let vesting = client.get_vesting(&beneficiary);
+    let vesting = client.get_vesting(&beneficiary, &0);
     assert_eq!(vesting.beneficiary, beneficiary);
     assert_eq!(vesting.total_amount, amount);
     assert_eq!(vesting.start_time, start_time);
@@ -118,6 +147,9 @@ fn test_create_vesting_not_initialized() {
         &1_000_000,
         &(current_time + 1000),
         &10_000,
+        &0,
+        &VestingCurve::Linear,
+        &false,
     );
     assert_eq!(result, Err(Ok(VestingError::NotInitialized)));
 }
@@ -133,9 +165,17 @@ fn test_create_vesting_invalid_amount() {
     client.initialize(&admin, &token_client.address);
 
     let current_time = env.ledger().timestamp();
-    let result =
-        client.try_create_vesting(&admin, &beneficiary, &0, &(current_time + 1000), &10_000);
-    assert_eq!(result, Err(Ok(VestingError::InvalidAmount)));
+    let result = client.try_create_vesting(
+        &admin,
+        &beneficiary,
+        &0,
+        &(current_time + 1000),
+        &10_000,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(VestingError::ZeroAmount)));
 }
 
 #[test]
@@ -149,9 +189,17 @@ fn test_create_vesting_invalid_duration() {
     client.initialize(&admin, &token_client.address);
 
     let current_time = env.ledger().timestamp();
-    let result =
-        client.try_create_vesting(&admin, &beneficiary, &1_000_000, &(current_time + 1000), &0);
-    assert_eq!(result, Err(Ok(VestingError::InvalidDuration)));
+    let result = client.try_create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000_000,
+        &(current_time + 1000),
+        &0,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(VestingError::ZeroDuration)));
 }
 
 #[test]
@@ -171,7 +219,16 @@ fn test_create_vesting_invalid_start_time() {
     if current_time == 0 {
         return;
     }
-    let result = client.try_create_vesting(&admin, &beneficiary, &1_000_000, &past_time, &10_000);
+    let result = client.try_create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000_000,
+        &past_time,
+        &10_000,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
     assert_eq!(result, Err(Ok(VestingError::InvalidStartTime)));
 }
 
@@ -194,6 +251,9 @@ fn test_create_vesting_unauthorized() {
         &1_000_000,
         &(current_time + 1000),
         &10_000,
+        &0,
+        &VestingCurve::Linear,
+        &false,
     );
     assert_eq!(result, Err(Ok(VestingError::Unauthorized)));
 }
@@ -214,14 +274,23 @@ fn test_claim_before_start_time() {
     let amount: i128 = 1_000_000;
 
     // Create vesting
-    client.create_vesting(&admin, &beneficiary, &amount, &start_time, &duration);
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount,
+        &start_time,
+        &duration,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
 
     // Try to claim before start time - should fail
-    let result = client.try_claim(&beneficiary);
+    let result = client.try_claim(&beneficiary, &0, &None, &None);
     assert_eq!(result, Err(Ok(VestingError::NothingToClaim)));
 
     // Verify available amount is 0
-    assert_eq!(client.get_available_amount(&beneficiary), 0);
+    assert_eq!(client.get_available_amount(&beneficiary, &0), 0);
 }
 
 #[test]
@@ -240,13 +309,22 @@ fn test_claim_partial_vesting() {
     let amount: i128 = 1_000_000;
 
     // Create vesting
-    client.create_vesting(&admin, &beneficiary, &amount, &start_time, &duration);
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount,
+        &start_time,
+        &duration,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
 
     // Fast forward to 25% through vesting period
     env.ledger().set_timestamp(start_time + duration / 4);
 
     // Claim available tokens
-    let claimed = client.claim(&beneficiary);
+    let claimed = client.claim(&beneficiary, &0, &None, &None);
     let expected_claimed = amount / 4; // 25% of total
     assert_eq!(claimed, expected_claimed);
 
@@ -254,11 +332,11 @@ fn test_claim_partial_vesting() {
     assert_eq!(token_client.balance(&beneficiary), expected_claimed);
 
     // Verify vesting data updated
-    let vesting = client.get_vesting(&beneficiary);
+    let vesting = client.get_vesting(&beneficiary, &0);
     assert_eq!(vesting.claimed_amount, expected_claimed);
 
     // Verify available amount is now 0 (all available was claimed)
-    assert_eq!(client.get_available_amount(&beneficiary), 0);
+    assert_eq!(client.get_available_amount(&beneficiary, &0), 0);
 }
 
 #[test]
@@ -277,24 +355,33 @@ fn test_claim_full_vesting() {
     let amount: i128 = 1_000_000;
 
     // Create vesting
-    client.create_vesting(&admin, &beneficiary, &amount, &start_time, &duration);
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount,
+        &start_time,
+        &duration,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
 
     // Fast forward past vesting period
     env.ledger().set_timestamp(start_time + duration + 1000);
 
     // Claim all tokens
-    let claimed = client.claim(&beneficiary);
+    let claimed = client.claim(&beneficiary, &0, &None, &None);
     assert_eq!(claimed, amount);
 
     // Verify beneficiary received all tokens
     assert_eq!(token_client.balance(&beneficiary), amount);
 
     // Verify vesting data updated
-    let vesting = client.get_vesting(&beneficiary);
+    let vesting = client.get_vesting(&beneficiary, &0);
     assert_eq!(vesting.claimed_amount, amount);
 
     // Verify nothing left to claim
-    assert_eq!(client.get_available_amount(&beneficiary), 0);
+    assert_eq!(client.get_available_amount(&beneficiary, &0), 0);
 }
 
 #[test]
@@ -313,20 +400,29 @@ fn test_claim_multiple_times() {
     let amount: i128 = 1_000_000;
 
     // Create vesting
-    client.create_vesting(&admin, &beneficiary, &amount, &start_time, &duration);
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount,
+        &start_time,
+        &duration,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
 
     // First claim at 25%
     env.ledger().set_timestamp(start_time + duration / 4);
-    let claimed1 = client.claim(&beneficiary);
+    let claimed1 = client.claim(&beneficiary, &0, &None, &None);
     assert_eq!(claimed1, amount / 4);
 
     // Second claim at 50%
     env.ledger().set_timestamp(start_time + duration / 2);
-    let claimed2 = client.claim(&beneficiary);
+    let claimed2 = client.claim(&beneficiary, &0, &None, &None);
     assert_eq!(claimed2, amount / 4); // Another 25%
 
     // Verify total claimed
-    let vesting = client.get_vesting(&beneficiary);
+    let vesting = client.get_vesting(&beneficiary, &0);
     assert_eq!(vesting.claimed_amount, amount / 2);
 
     // Verify beneficiary balance
@@ -345,7 +441,7 @@ fn test_claim_vesting_not_found() {
 
     // Try to claim for non-existent vesting
     let beneficiary = Address::generate(&env);
-    let result = client.try_claim(&beneficiary);
+    let result = client.try_claim(&beneficiary, &0, &None, &None);
     assert_eq!(result, Err(Ok(VestingError::VestingNotFound)));
 }
 
@@ -365,7 +461,16 @@ fn test_claim_unauthorized() {
     let amount: i128 = 1_000_000;
 
     // Create vesting
-    client.create_vesting(&admin, &beneficiary, &amount, &start_time, &duration);
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount,
+        &start_time,
+        &duration,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
 
     // Fast forward to allow claiming
     env.ledger().set_timestamp(start_time + duration / 2);
@@ -374,7 +479,7 @@ fn test_claim_unauthorized() {
     let non_beneficiary = Address::generate(&env);
     // Note: This will fail auth check, but we need to test the contract logic
     // In real scenario, this would fail at auth level
-    let result = client.try_claim(&non_beneficiary);
+    let result = client.try_claim(&non_beneficiary, &0, &None, &None);
     assert_eq!(result, Err(Ok(VestingError::VestingNotFound)));
 }
 
@@ -394,27 +499,36 @@ fn test_get_available_amount_linear_calculation() {
     let amount: i128 = 1_000_000;
 
     // Create vesting
-    client.create_vesting(&admin, &beneficiary, &amount, &start_time, &duration);
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount,
+        &start_time,
+        &duration,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
 
     // Test at 30% through vesting
     env.ledger().set_timestamp(start_time + (duration * 3 / 10));
-    let available = client.get_available_amount(&beneficiary);
+    let available = client.get_available_amount(&beneficiary, &0);
     let expected = (amount * 3) / 10; // 30% of total
     assert_eq!(available, expected);
 
     // Test at 75% through vesting
     env.ledger().set_timestamp(start_time + (duration * 3 / 4));
-    let available = client.get_available_amount(&beneficiary);
+    let available = client.get_available_amount(&beneficiary, &0);
     let expected = (amount * 3) / 4; // 75% of total
     assert_eq!(available, expected);
 }
 
 #[test]
-fn test_update_vesting() {
+fn test_multiple_schedules_per_beneficiary() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
 
     // Initialize contract
     client.initialize(&admin, &token_client.address);
@@ -423,18 +537,92 @@ fn test_update_vesting() {
     let start_time = current_time + 1000;
     let duration = 10_000;
     let amount1: i128 = 1_000_000;
+    let amount2: i128 = 2_000_000;
+
+    // A second schedule sits alongside the first instead of replacing it
+    let id1 = client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount1,
+        &start_time,
+        &duration,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    let id2 = client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount2,
+        &start_time,
+        &(duration * 2),
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    assert_eq!((id1, id2), (0, 1));
+    assert_eq!(client.get_vesting_count(&beneficiary), 2);
 
-    // Create first vesting
-    client.create_vesting(&admin, &beneficiary, &amount1, &start_time, &duration);
+    assert_eq!(client.get_vesting(&beneficiary, &id1).total_amount, amount1);
+    assert_eq!(client.get_vesting(&beneficiary, &id2).total_amount, amount2);
+    assert_eq!(token_client.balance(&contract_id), amount1 + amount2);
 
-    // Update vesting with new amount (overwrites existing)
-    let amount2: i128 = 2_000_000;
-    client.create_vesting(&admin, &beneficiary, &amount2, &start_time, &duration);
+    // Claiming one schedule leaves the other untouched
+    env.ledger().set_timestamp(start_time + duration);
+    assert_eq!(client.claim(&beneficiary, &id1, &None, &None), amount1);
+    assert_eq!(client.get_vesting(&beneficiary, &id2).claimed_amount, 0);
+    assert_eq!(client.get_claimable(&beneficiary, &id2), amount2 / 2);
+
+    assert_eq!(
+        client.try_get_vesting(&beneficiary, &2),
+        Err(Ok(VestingError::VestingNotFound))
+    );
+}
+
+#[test]
+fn test_claim_all() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp() + 100;
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &4_000,
+        &start_time,
+        &400,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+
+    // Nothing has vested yet on either schedule
+    assert_eq!(
+        client.try_claim_all(&beneficiary),
+        Err(Ok(VestingError::NothingToClaim))
+    );
 
-    // Verify vesting was updated
-    let vesting = client.get_vesting(&beneficiary);
-    assert_eq!(vesting.total_amount, amount2);
-    assert_eq!(vesting.claimed_amount, 0); // Reset when overwriting
+    env.ledger().set_timestamp(start_time + 50);
+    assert_eq!(client.claim_all(&beneficiary), 500 + 500);
+    assert_eq!(token_client.balance(&beneficiary), 1_000);
+
+    env.ledger().set_timestamp(start_time + 100);
+    assert_eq!(client.claim_all(&beneficiary), 500 + 500);
+    assert_eq!(client.get_vesting(&beneficiary, &0).claimed_amount, 1_000);
+    assert_eq!(client.get_vesting(&beneficiary, &1).claimed_amount, 1_000);
 }
 
 #[test]
@@ -455,12 +643,30 @@ fn test_multiple_beneficiaries() {
     let amount2: i128 = 2_000_000;
 
     // Create vestings for two beneficiaries
-    client.create_vesting(&admin, &beneficiary1, &amount1, &start_time, &duration);
-    client.create_vesting(&admin, &beneficiary2, &amount2, &start_time, &duration);
+    client.create_vesting(
+        &admin,
+        &beneficiary1,
+        &amount1,
+        &start_time,
+        &duration,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    client.create_vesting(
+        &admin,
+        &beneficiary2,
+        &amount2,
+        &start_time,
+        &duration,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
 
     // Verify both vestings exist
-    let vesting1 = client.get_vesting(&beneficiary1);
-    let vesting2 = client.get_vesting(&beneficiary2);
+    let vesting1 = client.get_vesting(&beneficiary1, &0);
+    let vesting2 = client.get_vesting(&beneficiary2, &0);
 
     assert_eq!(vesting1.total_amount, amount1);
     assert_eq!(vesting2.total_amount, amount2);
@@ -468,8 +674,8 @@ fn test_multiple_beneficiaries() {
     // Fast forward and claim for both
     env.ledger().set_timestamp(start_time + duration / 2);
 
-    let claimed1 = client.claim(&beneficiary1);
-    let claimed2 = client.claim(&beneficiary2);
+    let claimed1 = client.claim(&beneficiary1, &0, &None, &None);
+    let claimed2 = client.claim(&beneficiary2, &0, &None, &None);
 
     assert_eq!(claimed1, amount1 / 2);
     assert_eq!(claimed2, amount2 / 2);
@@ -491,50 +697,59 @@ fn test_get_claimable_view_method() {
     let amount: i128 = 1_000_000;
 
     // Create vesting
-    client.create_vesting(&admin, &beneficiary, &amount, &start_time, &duration);
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount,
+        &start_time,
+        &duration,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
 
     // Test before vesting starts
-    let claimable = client.get_claimable(&beneficiary);
+    let claimable = client.get_claimable(&beneficiary, &0);
     assert_eq!(claimable, 0);
 
     // Test at 25% through vesting
     env.ledger().set_timestamp(start_time + (duration / 4));
-    let claimable = client.get_claimable(&beneficiary);
+    let claimable = client.get_claimable(&beneficiary, &0);
     let expected = amount / 4;
     assert_eq!(claimable, expected);
 
     // Test at 50% through vesting
     env.ledger().set_timestamp(start_time + (duration / 2));
-    let claimable = client.get_claimable(&beneficiary);
+    let claimable = client.get_claimable(&beneficiary, &0);
     let expected = amount / 2;
     assert_eq!(claimable, expected);
 
     // Verify get_claimable matches get_available_amount
-    let available = client.get_available_amount(&beneficiary);
+    let available = client.get_available_amount(&beneficiary, &0);
     assert_eq!(claimable, available);
 
     // Claim some tokens
-    let claimed = client.claim(&beneficiary);
+    let claimed = client.claim(&beneficiary, &0, &None, &None);
     assert_eq!(claimed, expected);
 
     // Test that get_claimable returns 0 immediately after claim
-    let claimable_after = client.get_claimable(&beneficiary);
+    let claimable_after = client.get_claimable(&beneficiary, &0);
     assert_eq!(claimable_after, 0);
 
     // Test at 75% through vesting (after claiming at 50%)
     env.ledger().set_timestamp(start_time + (duration * 3 / 4));
-    let claimable = client.get_claimable(&beneficiary);
+    let claimable = client.get_claimable(&beneficiary, &0);
     let expected = (amount * 3 / 4) - (amount / 2); // 75% - 50% already claimed
     assert_eq!(claimable, expected);
 
     // Test after vesting period ends
     env.ledger().set_timestamp(start_time + duration + 1000);
-    let claimable = client.get_claimable(&beneficiary);
+    let claimable = client.get_claimable(&beneficiary, &0);
     let expected = amount - (amount / 2); // All remaining tokens
     assert_eq!(claimable, expected);
 
     // Verify get_claimable still matches get_available_amount
-    let available = client.get_available_amount(&beneficiary);
+    let available = client.get_available_amount(&beneficiary, &0);
     assert_eq!(claimable, available);
 }
 
@@ -554,22 +769,31 @@ fn test_get_claimable_consistency_with_claim() {
     let amount: i128 = 1_000_000;
 
     // Create vesting
-    client.create_vesting(&admin, &beneficiary, &amount, &start_time, &duration);
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount,
+        &start_time,
+        &duration,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
 
     // Fast forward to middle of vesting
     env.ledger().set_timestamp(start_time + duration / 2);
 
     // Get claimable amount (view method - doesn't modify state)
-    let claimable_before = client.get_claimable(&beneficiary);
+    let claimable_before = client.get_claimable(&beneficiary, &0);
 
     // Claim tokens (modifies state)
-    let claimed = client.claim(&beneficiary);
+    let claimed = client.claim(&beneficiary, &0, &None, &None);
 
     // Verify that claim returned the same amount as get_claimable predicted
     assert_eq!(claimed, claimable_before);
 
     // Verify get_claimable now returns 0 (no time has passed)
-    let claimable_after = client.get_claimable(&beneficiary);
+    let claimable_after = client.get_claimable(&beneficiary, &0);
     assert_eq!(claimable_after, 0);
 }
 
@@ -625,3 +849,2315 @@ fn test_old_admin_cannot_upgrade_after_rotation() {
     let result = client.try_upgrade(&admin, &dummy);
     assert_eq!(result, Err(Ok(crate::errors::VestingError::Unauthorized)));
 }
+
+#[test]
+fn test_claim_linear_unlock_math() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp() + 100;
+    let duration = 3_000;
+    let amount: i128 = 1_000_000;
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount,
+        &start_time,
+        &duration,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+
+    // vested = total * elapsed / duration, rounded down
+    env.ledger().set_timestamp(start_time + 1_000);
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 333_333);
+
+    // Each claim pays the difference from what was already claimed
+    env.ledger().set_timestamp(start_time + 2_000);
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 333_333);
+
+    env.ledger().set_timestamp(start_time + duration);
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 333_334);
+    assert_eq!(token_client.balance(&beneficiary), amount);
+    assert_eq!(client.get_vesting(&beneficiary, &0).claimed_amount, amount);
+}
+
+#[test]
+fn test_claim_fails_when_contract_underfunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000_000,
+        &start_time,
+        &1_000,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+
+    // Drain the contract out from under the schedule
+    token_client.burn(&contract_id, &999_999);
+    env.ledger().set_timestamp(start_time + 1_000);
+
+    let result = client.try_claim(&beneficiary, &0, &None, &None);
+    assert_eq!(result, Err(Ok(VestingError::InsufficientBalance)));
+    assert_eq!(client.get_vesting(&beneficiary, &0).claimed_amount, 0);
+}
+
+#[test]
+fn test_cliff_blocks_then_unlocks_accrued_portion() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp() + 100;
+    let duration = 12_000;
+    let cliff = 3_000;
+    let amount: i128 = 1_200_000;
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount,
+        &start_time,
+        &duration,
+        &cliff,
+        &VestingCurve::Linear,
+        &false,
+    );
+    assert_eq!(client.get_vesting(&beneficiary, &0).cliff_duration, cliff);
+
+    // Just before the cliff nothing is claimable
+    env.ledger().set_timestamp(start_time + cliff - 1);
+    assert_eq!(client.get_claimable(&beneficiary, &0), 0);
+    assert_eq!(
+        client.try_claim(&beneficiary, &0, &None, &None),
+        Err(Ok(VestingError::NothingToClaim))
+    );
+
+    // At the cliff the whole cliff portion unlocks at once
+    env.ledger().set_timestamp(start_time + cliff);
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 300_000);
+
+    // Afterwards vesting continues linearly
+    env.ledger().set_timestamp(start_time + 6_000);
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 300_000);
+}
+
+#[test]
+fn test_create_vesting_rejects_cliff_longer_than_duration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp() + 100;
+    let result = client.try_create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &101,
+        &VestingCurve::Linear,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(VestingError::InvalidDuration)));
+}
+
+#[test]
+fn test_revoke_returns_unvested_to_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let treasury = Address::generate(&env);
+    client.set_treasury(&admin, &treasury);
+
+    let start_time = env.ledger().timestamp();
+    let amount: i128 = 1_000_000;
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &amount,
+        &start_time,
+        &10_000,
+        &0,
+        &VestingCurve::Linear,
+        &true,
+    );
+
+    // Revoke at 40%: the unvested 60% goes to the treasury
+    env.ledger().set_timestamp(start_time + 4_000);
+    assert_eq!(client.revoke(&admin, &beneficiary, &0), 600_000);
+    assert_eq!(token_client.balance(&treasury), 600_000);
+    assert_eq!(
+        client.get_vesting(&beneficiary, &0).revoked_at,
+        Some(start_time + 4_000)
+    );
+
+    // The vested part stays claimable, and vesting no longer accrues
+    env.ledger().set_timestamp(start_time + 10_000);
+    assert_eq!(client.get_claimable(&beneficiary, &0), 400_000);
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 400_000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+
+    assert_eq!(
+        client.try_revoke(&admin, &beneficiary, &0),
+        Err(Ok(VestingError::AlreadyRevoked))
+    );
+}
+
+#[test]
+fn test_revoke_requires_revocable_schedule_and_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let other = Address::generate(&env);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    client.create_vesting(
+        &admin,
+        &other,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &true,
+    );
+
+    assert_eq!(
+        client.try_revoke(&admin, &beneficiary, &0),
+        Err(Ok(VestingError::NotRevocable))
+    );
+    assert_eq!(
+        client.try_revoke(&admin, &other, &0),
+        Err(Ok(VestingError::TreasuryNotSet))
+    );
+    assert_eq!(
+        client.try_revoke(&other, &other, &0),
+        Err(Ok(VestingError::Unauthorized))
+    );
+}
+
+fn new_vesting(beneficiary: &Address, amount: i128, start_time: u64) -> NewVesting {
+    NewVesting {
+        beneficiary: beneficiary.clone(),
+        token: None,
+        amount,
+        start_time,
+        duration: 1_000,
+        cliff_duration: 0,
+        curve: VestingCurve::Linear,
+        revocable: false,
+        program: None,
+    }
+}
+
+#[test]
+fn test_create_vestings_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let other = Address::generate(&env);
+
+    let start_time = env.ledger().timestamp() + 10;
+    let entries = Vec::from_array(
+        &env,
+        [
+            new_vesting(&beneficiary, 1_000, start_time),
+            new_vesting(&other, 2_000, start_time),
+            new_vesting(&beneficiary, 3_000, start_time),
+        ],
+    );
+    let ids = client.create_vestings(&admin, &entries);
+
+    assert_eq!(ids, Vec::from_array(&env, [0, 0, 1]));
+    assert_eq!(token_client.balance(&contract_id), 6_000);
+    assert_eq!(client.get_vesting(&beneficiary, &1).total_amount, 3_000);
+    assert_eq!(client.get_vesting(&other, &0).total_amount, 2_000);
+}
+
+#[test]
+fn test_create_vestings_batch_is_atomic() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    // One bad entry rejects the whole batch before any funds move
+    let start_time = env.ledger().timestamp() + 10;
+    let entries = Vec::from_array(
+        &env,
+        [
+            new_vesting(&beneficiary, 1_000, start_time),
+            new_vesting(&beneficiary, 0, start_time),
+        ],
+    );
+    assert_eq!(
+        client.try_create_vestings(&admin, &entries),
+        Err(Ok(VestingError::ZeroAmount))
+    );
+    assert_eq!(client.get_vesting_count(&beneficiary), 0);
+    assert_eq!(token_client.balance(&contract_id), 0);
+
+    assert_eq!(
+        client.try_create_vestings(&admin, &Vec::new(&env)),
+        Err(Ok(VestingError::InvalidBatch))
+    );
+}
+
+#[test]
+fn test_vested_and_claimable_amount_views() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp() + 100;
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &4_000,
+        &start_time,
+        &400,
+        &50,
+        &VestingCurve::Linear,
+        &false,
+    );
+
+    // Projections can be taken at any timestamp without moving the ledger
+    assert_eq!(client.vested_amount(&beneficiary, &(start_time - 1)), 0);
+    assert_eq!(client.vested_amount(&beneficiary, &(start_time + 40)), 400);
+    assert_eq!(
+        client.vested_amount(&beneficiary, &(start_time + 50)),
+        500 + 500
+    );
+    assert_eq!(
+        client.vested_amount(&beneficiary, &(start_time + 400)),
+        5_000
+    );
+    assert_eq!(client.claimable_amount(&beneficiary), 0);
+
+    env.ledger().set_timestamp(start_time + 100);
+    assert_eq!(client.claimable_amount(&beneficiary), 1_000 + 1_000);
+    assert_eq!(client.claim_all(&beneficiary), 2_000);
+    assert_eq!(client.claimable_amount(&beneficiary), 0);
+    // Vested totals are unaffected by claims
+    assert_eq!(
+        client.vested_amount(&beneficiary, &(start_time + 100)),
+        2_000
+    );
+
+    assert_eq!(
+        client.vested_amount(&Address::generate(&env), &start_time),
+        0
+    );
+}
+
+/// Topics and data payload of the most recent event.
+fn last_event(env: &Env) -> (Vec<Val>, Map<Symbol, Val>) {
+    let (_, topics, data) = env.events().all().last().unwrap();
+    (topics, Map::from_val(env, &data))
+}
+
+#[test]
+fn test_lifecycle_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    client.set_treasury(&admin, &admin);
+
+    env.ledger().set_timestamp(1_000);
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &1_100,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &true,
+    );
+    let (topics, data) = last_event(&env);
+    assert_eq!(
+        topics,
+        (
+            Symbol::new(&env, "vesting_created_event"),
+            beneficiary.clone()
+        )
+            .into_val(&env)
+    );
+    let created_at: u64 = u64::from_val(&env, &data.get(Symbol::new(&env, "created_at")).unwrap());
+    let revocable = bool::from_val(&env, &data.get(Symbol::new(&env, "revocable")).unwrap());
+    assert_eq!((created_at, revocable), (1_000, true));
+
+    env.ledger().set_timestamp(1_150);
+    client.claim(&beneficiary, &0, &None, &None);
+    let (topics, data) = last_event(&env);
+    assert_eq!(
+        topics,
+        (
+            Symbol::new(&env, "tokens_claimed_event"),
+            beneficiary.clone()
+        )
+            .into_val(&env)
+    );
+    let claimed_at = u64::from_val(&env, &data.get(Symbol::new(&env, "claimed_at")).unwrap());
+    let amount = i128::from_val(
+        &env,
+        &data.get(Symbol::new(&env, "amount_claimed")).unwrap(),
+    );
+    assert_eq!((claimed_at, amount), (1_150, 500));
+
+    client.revoke(&admin, &beneficiary, &0);
+    let (topics, data) = last_event(&env);
+    assert_eq!(
+        topics,
+        (
+            Symbol::new(&env, "vesting_revoked_event"),
+            beneficiary.clone()
+        )
+            .into_val(&env)
+    );
+    let returned = i128::from_val(
+        &env,
+        &data.get(Symbol::new(&env, "returned_amount")).unwrap(),
+    );
+    assert_eq!(returned, 500);
+}
+
+#[test]
+fn test_change_beneficiary() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let new_wallet = Address::generate(&env);
+
+    let start_time = env.ledger().timestamp() + 100;
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    client.create_vesting(
+        &admin,
+        &new_wallet,
+        &500,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+
+    env.ledger().set_timestamp(start_time + 50);
+    client.claim(&beneficiary, &0, &None, &None);
+
+    // The schedule keeps its progress and is filed under the next free ID
+    let new_id = client.change_beneficiary(&beneficiary, &new_wallet, &0);
+    assert_eq!(new_id, 1);
+    let moved = client.get_vesting(&new_wallet, &new_id);
+    assert_eq!(moved.beneficiary, new_wallet);
+    assert_eq!(moved.claimed_amount, 500);
+    assert_eq!(
+        client.try_get_vesting(&beneficiary, &0),
+        Err(Ok(VestingError::VestingNotFound))
+    );
+    assert_eq!(client.claimable_amount(&beneficiary), 0);
+
+    env.ledger().set_timestamp(start_time + 100);
+    assert_eq!(client.claim(&new_wallet, &new_id, &None, &None), 500);
+
+    assert_eq!(
+        client.try_change_beneficiary(&new_wallet, &new_wallet, &new_id),
+        Err(Ok(VestingError::InvalidBeneficiary))
+    );
+}
+
+#[test]
+fn test_change_beneficiary_requires_admin_when_configured() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let new_wallet = Address::generate(&env);
+
+    let start_time = env.ledger().timestamp() + 100;
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    client.set_change_approval(&admin, &true);
+    assert!(client.change_needs_approval());
+
+    // Only the beneficiary signs: the admin co-signature is missing
+    env.mock_auths(&[MockAuth {
+        address: &beneficiary,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "change_beneficiary",
+            args: (&beneficiary, &new_wallet, 0u32).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client
+        .try_change_beneficiary(&beneficiary, &new_wallet, &0)
+        .is_err());
+
+    env.mock_all_auths();
+    client.change_beneficiary(&beneficiary, &new_wallet, &0);
+    let auths = env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == admin));
+    assert!(auths.iter().any(|(address, _)| *address == beneficiary));
+}
+
+#[test]
+fn test_periodic_curve_unlocks_in_steps() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    // Twelve monthly steps of 100 seconds each
+    let start_time = env.ledger().timestamp() + 10;
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &12_000,
+        &start_time,
+        &1_200,
+        &0,
+        &VestingCurve::Periodic(100),
+        &false,
+    );
+
+    assert_eq!(client.vested_amount(&beneficiary, &(start_time + 99)), 0);
+    assert_eq!(
+        client.vested_amount(&beneficiary, &(start_time + 100)),
+        1_000
+    );
+    assert_eq!(
+        client.vested_amount(&beneficiary, &(start_time + 250)),
+        2_000
+    );
+    assert_eq!(
+        client.vested_amount(&beneficiary, &(start_time + 1_199)),
+        11_000
+    );
+    assert_eq!(
+        client.vested_amount(&beneficiary, &(start_time + 1_200)),
+        12_000
+    );
+
+    env.ledger().set_timestamp(start_time + 350);
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 3_000);
+    assert_eq!(
+        client.get_vesting(&beneficiary, &0).curve,
+        VestingCurve::Periodic(100)
+    );
+}
+
+#[test]
+fn test_cliff_only_curve() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp() + 10;
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::CliffOnly,
+        &false,
+    );
+
+    assert_eq!(client.vested_amount(&beneficiary, &(start_time + 99)), 0);
+    assert_eq!(
+        client.vested_amount(&beneficiary, &(start_time + 100)),
+        1_000
+    );
+}
+
+#[test]
+fn test_periodic_curve_rejects_bad_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp() + 10;
+    for period in [0, 101] {
+        let result = client.try_create_vesting(
+            &admin,
+            &beneficiary,
+            &1_000,
+            &start_time,
+            &100,
+            &0,
+            &VestingCurve::Periodic(period),
+            &false,
+        );
+        assert_eq!(result, Err(Ok(VestingError::InvalidDuration)));
+    }
+}
+
+#[test]
+fn test_milestone_tranches() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let oracle = Address::generate(&env);
+    client.set_milestone_oracle(&admin, &Some(oracle.clone()));
+
+    let start_time = env.ledger().timestamp();
+    let tranches = Vec::from_array(&env, [200i128, 300, 500]);
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Milestones(tranches),
+        &false,
+    );
+
+    // Time alone unlocks nothing
+    env.ledger().set_timestamp(start_time + 1_000);
+    assert_eq!(client.get_claimable(&beneficiary, &0), 0);
+
+    assert_eq!(client.confirm_milestone(&admin, &beneficiary, &0, &1), 300);
+    assert_eq!(client.confirm_milestone(&oracle, &beneficiary, &0, &2), 500);
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 800);
+
+    assert_eq!(
+        client.try_confirm_milestone(&admin, &beneficiary, &0, &1),
+        Err(Ok(VestingError::MilestoneAlreadyConfirmed))
+    );
+    assert_eq!(
+        client.try_confirm_milestone(&admin, &beneficiary, &0, &3),
+        Err(Ok(VestingError::InvalidMilestone))
+    );
+    assert_eq!(
+        client.try_confirm_milestone(&beneficiary, &beneficiary, &0, &0),
+        Err(Ok(VestingError::Unauthorized))
+    );
+
+    client.confirm_milestone(&admin, &beneficiary, &0, &0);
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 200);
+}
+
+#[test]
+fn test_milestone_tranches_must_sum_to_total() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp();
+    let result = client.try_create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Milestones(Vec::from_array(&env, [200i128, 300])),
+        &false,
+    );
+    assert_eq!(result, Err(Ok(VestingError::InvalidAmount)));
+
+    // Time-based schedules have no milestones to confirm
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    assert_eq!(
+        client.try_confirm_milestone(&admin, &beneficiary, &0, &0),
+        Err(Ok(VestingError::InvalidMilestone))
+    );
+}
+
+#[test]
+fn test_claim_to_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let cold_wallet = Address::generate(&env);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+
+    env.ledger().set_timestamp(start_time + 40);
+    assert_eq!(
+        client.claim(&beneficiary, &0, &Some(cold_wallet.clone()), &None),
+        400
+    );
+
+    // Only the beneficiary's signature was needed
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, beneficiary);
+
+    assert_eq!(token_client.balance(&cold_wallet), 400);
+    assert_eq!(token_client.balance(&beneficiary), 0);
+}
+
+#[test]
+fn test_partial_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+
+    env.ledger().set_timestamp(start_time + 50);
+    assert_eq!(client.claim(&beneficiary, &0, &None, &Some(200)), 200);
+    assert_eq!(client.get_claimable(&beneficiary, &0), 300);
+
+    assert_eq!(
+        client.try_claim(&beneficiary, &0, &None, &Some(301)),
+        Err(Ok(VestingError::ExceedsClaimable))
+    );
+    assert_eq!(
+        client.try_claim(&beneficiary, &0, &None, &Some(0)),
+        Err(Ok(VestingError::InvalidAmount))
+    );
+
+    // The rest stays claimable in full
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 300);
+    assert_eq!(token_client.balance(&beneficiary), 500);
+}
+
+#[test]
+fn test_deposit_and_solvency() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    client.deposit(&admin, &token_client.address, &1_500);
+    assert_eq!(
+        client.solvency(&token_client.address),
+        Solvency {
+            balance: 1_500,
+            committed: 0,
+            surplus: 1_500
+        }
+    );
+
+    // The first grant is covered by the pool; the second pulls the shortfall
+    let start_time = env.ledger().timestamp();
+    let admin_before = token_client.balance(&admin);
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &true,
+    );
+    assert_eq!(token_client.balance(&admin), admin_before);
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Periodic(50),
+        &false,
+    );
+    assert_eq!(token_client.balance(&admin), admin_before - 500);
+    assert_eq!(client.solvency(&token_client.address).committed, 2_000);
+    assert_eq!(client.solvency(&token_client.address).surplus, 0);
+
+    // Claims and revocations both release commitments
+    env.ledger().set_timestamp(start_time + 50);
+    client.claim_all(&beneficiary);
+    assert_eq!(client.solvency(&token_client.address).committed, 1_000);
+    client.set_treasury(&admin, &admin);
+    client.revoke(&admin, &beneficiary, &0);
+    assert_eq!(
+        client.solvency(&token_client.address),
+        Solvency {
+            balance: 500,
+            committed: 500,
+            surplus: 0
+        }
+    );
+
+    assert_eq!(
+        client.try_deposit(&admin, &token_client.address, &0),
+        Err(Ok(VestingError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_increase_vesting_keeps_timeline() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &20,
+        &VestingCurve::Linear,
+        &true,
+    );
+
+    env.ledger().set_timestamp(start_time + 50);
+    client.claim(&beneficiary, &0, &None, &None);
+
+    assert_eq!(
+        client.increase_vesting(&admin, &beneficiary, &0, &1_000),
+        2_000
+    );
+    let vesting = client.get_vesting(&beneficiary, &0);
+    assert_eq!(
+        (vesting.start_time, vesting.duration, vesting.cliff_duration),
+        (start_time, 100, 20)
+    );
+    assert_eq!(token_client.balance(&contract_id), 1_500);
+
+    // Half the new total has vested; 500 was already claimed
+    assert_eq!(client.get_claimable(&beneficiary, &0), 500);
+
+    assert_eq!(
+        client.try_increase_vesting(&admin, &beneficiary, &0, &0),
+        Err(Ok(VestingError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_increase_vesting(&beneficiary, &beneficiary, &0, &1),
+        Err(Ok(VestingError::Unauthorized))
+    );
+
+    client.set_treasury(&admin, &admin);
+    client.revoke(&admin, &beneficiary, &0);
+    assert_eq!(
+        client.try_increase_vesting(&admin, &beneficiary, &0, &1),
+        Err(Ok(VestingError::AlreadyRevoked))
+    );
+}
+
+#[test]
+fn test_pause_claims() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    env.ledger().set_timestamp(start_time + 50);
+
+    client.pause_claims(&admin);
+    assert!(client.is_claims_paused());
+    assert_eq!(
+        client.try_claim(&beneficiary, &0, &None, &None),
+        Err(Ok(VestingError::ClaimsPaused))
+    );
+    assert_eq!(
+        client.try_claim_all(&beneficiary),
+        Err(Ok(VestingError::ClaimsPaused))
+    );
+    assert_eq!(
+        client.try_pause_claims(&beneficiary),
+        Err(Ok(VestingError::Unauthorized))
+    );
+
+    client.resume_claims(&admin);
+    assert!(!client.is_claims_paused());
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 500);
+}
+
+#[test]
+fn test_freeze_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp();
+    for curve in [VestingCurve::Linear, VestingCurve::Periodic(50)] {
+        client.create_vesting(
+            &admin,
+            &beneficiary,
+            &1_000,
+            &start_time,
+            &100,
+            &0,
+            &curve,
+            &false,
+        );
+    }
+    env.ledger().set_timestamp(start_time + 50);
+
+    client.freeze_schedule(&admin, &beneficiary, &0);
+    assert!(client.get_vesting(&beneficiary, &0).frozen);
+    assert_eq!(
+        client.try_claim(&beneficiary, &0, &None, &None),
+        Err(Ok(VestingError::ScheduleFrozen))
+    );
+    assert_eq!(
+        client.try_change_beneficiary(&beneficiary, &Address::generate(&env), &0),
+        Err(Ok(VestingError::ScheduleFrozen))
+    );
+
+    // Other schedules keep paying out
+    assert_eq!(client.claim_all(&beneficiary), 500);
+
+    client.unfreeze_schedule(&admin, &beneficiary, &0);
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 500);
+}
+
+#[test]
+fn test_list_vestings_and_totals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let other = Address::generate(&env);
+    let rotated = Address::generate(&env);
+
+    let start_time = env.ledger().timestamp();
+    let entries = Vec::from_array(
+        &env,
+        [
+            new_vesting(&beneficiary, 1_000, start_time),
+            new_vesting(&other, 2_000, start_time),
+            new_vesting(&beneficiary, 3_000, start_time),
+        ],
+    );
+    client.create_vestings(&admin, &entries);
+    assert_eq!(client.get_schedule_count(), 3);
+
+    let page = client.list_vestings(&0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(1).unwrap().beneficiary, other);
+    let page = client.list_vestings(&2, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().total_amount, 3_000);
+    assert_eq!(client.list_vestings(&3, &10).len(), 0);
+
+    // A moved schedule keeps its slot
+    client.change_beneficiary(&other, &rotated, &0);
+    assert_eq!(
+        client.list_vestings(&1, &1).get(0).unwrap().beneficiary,
+        rotated
+    );
+
+    assert_eq!(client.total_locked(&token_client.address), 6_000);
+    env.ledger().set_timestamp(start_time + 500);
+    client.claim_all(&beneficiary);
+    assert_eq!(client.total_claimed(&token_client.address), 2_000);
+    assert_eq!(client.total_locked(&token_client.address), 4_000);
+}
+
+#[test]
+fn test_multi_token_schedules() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let (usdc, usdc_admin) = create_token_contract(&env, &admin);
+    usdc_admin.mint(&admin, &10_000);
+
+    let start_time = env.ledger().timestamp();
+    let mut usdc_grant = new_vesting(&beneficiary, 2_000, start_time);
+    usdc_grant.token = Some(usdc.address.clone());
+    let entries = Vec::from_array(
+        &env,
+        [new_vesting(&beneficiary, 1_000, start_time), usdc_grant],
+    );
+    client.create_vestings(&admin, &entries);
+
+    assert_eq!(
+        client.get_vesting(&beneficiary, &0).token,
+        token_client.address
+    );
+    assert_eq!(client.get_vesting(&beneficiary, &1).token, usdc.address);
+    assert_eq!(token_client.balance(&contract_id), 1_000);
+    assert_eq!(usdc.balance(&contract_id), 2_000);
+    assert_eq!(client.total_locked(&usdc.address), 2_000);
+
+    // Each schedule pays out in its own asset
+    env.ledger().set_timestamp(start_time + 1_000);
+    assert_eq!(client.claim_all(&beneficiary), 3_000);
+    assert_eq!(token_client.balance(&beneficiary), 1_000);
+    assert_eq!(usdc.balance(&beneficiary), 2_000);
+    assert_eq!(client.total_claimed(&usdc.address), 2_000);
+    assert_eq!(client.solvency(&usdc.address).committed, 0);
+}
+
+#[test]
+fn test_timelocked_clawback() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let treasury = Address::generate(&env);
+    client.set_treasury(&admin, &treasury);
+    client.set_clawback_delay(&admin, &100);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &1_000,
+        &0,
+        &VestingCurve::Linear,
+        &true,
+    );
+
+    let executable_at = client.schedule_clawback(&admin, &beneficiary, &0);
+    assert_eq!(executable_at, start_time + 100);
+    assert_eq!(
+        client.get_pending_clawback(&beneficiary, &0),
+        Some(executable_at)
+    );
+    assert_eq!(
+        client.try_schedule_clawback(&admin, &beneficiary, &0),
+        Err(Ok(VestingError::ClawbackPending))
+    );
+    assert_eq!(
+        client.try_change_beneficiary(&beneficiary, &Address::generate(&env), &0),
+        Err(Ok(VestingError::ClawbackPending))
+    );
+
+    env.ledger().set_timestamp(start_time + 99);
+    assert_eq!(
+        client.try_execute_clawback(&admin, &beneficiary, &0),
+        Err(Ok(VestingError::ClawbackTimelocked))
+    );
+
+    // Vesting kept accruing through the notice period
+    env.ledger().set_timestamp(start_time + 200);
+    assert_eq!(client.execute_clawback(&admin, &beneficiary, &0), 800);
+    assert_eq!(token_client.balance(&treasury), 800);
+    assert_eq!(client.get_pending_clawback(&beneficiary, &0), None);
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 200);
+}
+
+#[test]
+fn test_cancel_clawback() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    assert_eq!(client.get_clawback_delay(), 7 * 86_400);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &1_000,
+        &0,
+        &VestingCurve::Linear,
+        &true,
+    );
+    assert_eq!(
+        client.try_cancel_clawback(&admin, &beneficiary, &0),
+        Err(Ok(VestingError::ClawbackNotScheduled))
+    );
+
+    client.schedule_clawback(&admin, &beneficiary, &0);
+    client.cancel_clawback(&admin, &beneficiary, &0);
+    env.ledger().set_timestamp(start_time + 8 * 86_400);
+    assert_eq!(
+        client.try_execute_clawback(&admin, &beneficiary, &0),
+        Err(Ok(VestingError::ClawbackNotScheduled))
+    );
+    assert_eq!(client.get_vesting(&beneficiary, &0).revoked_at, None);
+}
+
+#[test]
+fn test_operator_claims_for_beneficiary() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let bot = Address::generate(&env);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    env.ledger().set_timestamp(start_time + 50);
+
+    assert_eq!(
+        client.try_operator_claim(&bot, &beneficiary, &0),
+        Err(Ok(VestingError::Unauthorized))
+    );
+
+    client.approve_operator(&beneficiary, &bot);
+    assert!(client.is_operator(&beneficiary, &bot));
+    assert_eq!(client.operator_claim(&bot, &beneficiary, &0), 500);
+    assert_eq!(env.auths()[0].0, bot);
+    assert_eq!(token_client.balance(&beneficiary), 500);
+    assert_eq!(token_client.balance(&bot), 0);
+
+    client.revoke_operator(&beneficiary, &bot);
+    assert!(!client.is_operator(&beneficiary, &bot));
+    env.ledger().set_timestamp(start_time + 100);
+    assert_eq!(
+        client.try_operator_claim(&bot, &beneficiary, &0),
+        Err(Ok(VestingError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_vesting_templates() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let name = String::from_str(&env, "4y/1y cliff");
+    let four_years = 4 * 365 * 86_400;
+    let one_year = 365 * 86_400;
+    let template_id = client.create_template(
+        &admin,
+        &name,
+        &four_years,
+        &one_year,
+        &VestingCurve::Linear,
+        &true,
+    );
+    assert_eq!(template_id, 0);
+    assert_eq!(client.get_template(&template_id).name, name);
+
+    env.ledger().set_timestamp(5_000);
+    let id = client.create_vesting_from_template(&admin, &beneficiary, &4_000, &template_id);
+    let vesting = client.get_vesting(&beneficiary, &id);
+    assert_eq!(vesting.start_time, 5_000);
+    assert_eq!(vesting.duration, four_years);
+    assert_eq!(vesting.cliff_duration, one_year);
+    assert!(vesting.revocable);
+    assert_eq!(vesting.total_amount, 4_000);
+
+    assert_eq!(
+        client.try_create_vesting_from_template(&admin, &beneficiary, &4_000, &1),
+        Err(Ok(VestingError::TemplateNotFound))
+    );
+}
+
+#[test]
+fn test_create_template_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, _, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let name = String::from_str(&env, "standard");
+
+    assert_eq!(
+        client.try_create_template(
+            &admin,
+            &String::from_str(&env, ""),
+            &100,
+            &0,
+            &VestingCurve::Linear,
+            &false
+        ),
+        Err(Ok(VestingError::InvalidTemplate))
+    );
+    assert_eq!(
+        client.try_create_template(&admin, &name, &100, &101, &VestingCurve::Linear, &false),
+        Err(Ok(VestingError::InvalidDuration))
+    );
+    assert_eq!(
+        client.try_create_template(
+            &admin,
+            &name,
+            &100,
+            &0,
+            &VestingCurve::Milestones(Vec::from_array(&env, [100i128])),
+            &false
+        ),
+        Err(Ok(VestingError::InvalidTemplate))
+    );
+}
+
+#[test]
+fn test_backdated_start() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    env.ledger().set_timestamp(10_000);
+
+    // Backdating is off until the admin opens a window
+    let create = |start: u64| {
+        client.try_create_vesting(
+            &admin,
+            &beneficiary,
+            &1_000,
+            &start,
+            &1_000,
+            &100,
+            &VestingCurve::Linear,
+            &false,
+        )
+    };
+    assert_eq!(create(9_999), Err(Ok(VestingError::InvalidStartTime)));
+
+    client.set_start_bounds(&admin, &500, &2_000);
+    assert_eq!(client.get_start_bounds(), (500, 2_000));
+    assert_eq!(create(9_499), Err(Ok(VestingError::InvalidStartTime)));
+    assert_eq!(create(12_001), Err(Ok(VestingError::InvalidStartTime)));
+    assert!(create(12_000).is_ok());
+
+    // The portion accrued since the backdated start is claimable right away,
+    // cliff included
+    let id = create(9_600).unwrap().unwrap();
+    assert_eq!(client.get_claimable(&beneficiary, &id), 400);
+    assert_eq!(client.claim(&beneficiary, &id, &None, &None), 400);
+}
+
+#[test]
+fn test_backdated_start_before_cliff() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    env.ledger().set_timestamp(10_000);
+    client.set_start_bounds(&admin, &500, &0);
+
+    let id = client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &9_950,
+        &1_000,
+        &100,
+        &VestingCurve::Linear,
+        &false,
+    );
+    assert_eq!(client.get_claimable(&beneficiary, &id), 0);
+    env.ledger().set_timestamp(10_050);
+    assert_eq!(client.get_claimable(&beneficiary, &id), 100);
+}
+
+#[test]
+fn test_rescue_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let (stray, stray_admin) = create_token_contract(&env, &admin);
+    let rescuer = Address::generate(&env);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    // Someone sends extra vesting tokens and an unrelated asset by mistake
+    token_client.transfer(&admin, &contract_id, &300);
+    stray_admin.mint(&contract_id, &700);
+
+    assert_eq!(
+        client.try_rescue_tokens(&admin, &token_client.address, &301, &rescuer),
+        Err(Ok(VestingError::InsufficientBalance))
+    );
+    client.rescue_tokens(&admin, &token_client.address, &300, &rescuer);
+    client.rescue_tokens(&admin, &stray.address, &700, &rescuer);
+    assert_eq!(token_client.balance(&rescuer), 300);
+    assert_eq!(stray.balance(&rescuer), 700);
+
+    // The schedule is still fully backed
+    assert_eq!(client.solvency(&token_client.address).surplus, 0);
+    assert_eq!(
+        client.try_rescue_tokens(&beneficiary, &stray.address, &1, &beneficiary),
+        Err(Ok(VestingError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, _, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let new_admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_accept_admin(&new_admin),
+        Err(Ok(VestingError::NoPendingAdmin))
+    );
+
+    client.transfer_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    // Nothing changes until the nominee accepts
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(
+        client.try_accept_admin(&stranger),
+        Err(Ok(VestingError::Unauthorized))
+    );
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(
+        client.try_transfer_admin(&admin, &stranger),
+        Err(Ok(VestingError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_claim_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let cold_wallet = Address::generate(&env);
+    let rotated = Address::generate(&env);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &100,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    assert_eq!(client.get_claim_history(&beneficiary, &0, &0, &10).len(), 0);
+
+    env.ledger().set_timestamp(start_time + 10);
+    client.claim(&beneficiary, &0, &None, &None);
+    env.ledger().set_timestamp(start_time + 30);
+    client.claim(&beneficiary, &0, &Some(cold_wallet.clone()), &None);
+
+    let history = client.get_claim_history(&beneficiary, &0, &0, &10);
+    assert_eq!(
+        history,
+        Vec::from_array(
+            &env,
+            [
+                ClaimRecord {
+                    timestamp: start_time + 10,
+                    amount: 100,
+                    recipient: beneficiary.clone(),
+                },
+                ClaimRecord {
+                    timestamp: start_time + 30,
+                    amount: 200,
+                    recipient: cold_wallet.clone(),
+                },
+            ]
+        )
+    );
+    assert_eq!(client.get_claim_history(&beneficiary, &0, &1, &10).len(), 1);
+
+    // History follows the schedule to its new address
+    let new_id = client.change_beneficiary(&beneficiary, &rotated, &0);
+    env.ledger().set_timestamp(start_time + 40);
+    client.claim(&rotated, &new_id, &None, &None);
+    let history = client.get_claim_history(&rotated, &new_id, &0, &10);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(2).unwrap().recipient, rotated);
+    assert_eq!(
+        client.try_get_claim_history(&beneficiary, &0, &0, &10),
+        Err(Ok(VestingError::VestingNotFound))
+    );
+}
+
+#[test]
+fn test_stats() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    client.set_treasury(&admin, &admin);
+    let other = Address::generate(&env);
+
+    let start_time = env.ledger().timestamp();
+    let mut revocable = new_vesting(&other, 2_000, start_time);
+    revocable.revocable = true;
+    let mut periodic = new_vesting(&beneficiary, 1_000, start_time);
+    periodic.curve = VestingCurve::Periodic(500);
+    let entries = Vec::from_array(
+        &env,
+        [
+            new_vesting(&beneficiary, 1_000, start_time),
+            periodic,
+            revocable,
+        ],
+    );
+    client.create_vestings(&admin, &entries);
+    assert_eq!(client.stats().active_beneficiaries, 2);
+
+    env.ledger().set_timestamp(start_time + 500);
+    client.claim(&other, &0, &None, &None);
+    client.revoke(&admin, &other, &0);
+    assert_eq!(client.stats().active_beneficiaries, 1);
+
+    env.ledger().set_timestamp(start_time + 1_000);
+    client.claim(&beneficiary, &0, &None, &None);
+    assert_eq!(
+        client.stats(),
+        VestingStats {
+            total_schedules: 3,
+            total_committed: 1_000,
+            total_claimed: 2_000,
+            total_revoked: 1_000,
+            active_beneficiaries: 1,
+        }
+    );
+
+    client.claim(&beneficiary, &1, &None, &None);
+    assert_eq!(client.stats().active_beneficiaries, 0);
+}
+
+#[test]
+fn test_reputation_boost_shortens_duration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let registry = env.register(MockRegistry, ());
+    let registry_client = MockRegistryClient::new(&env, &registry);
+    client.set_reputation_registry(&admin, &Some(registry.clone()));
+    client.set_tier_boosts(&admin, &Vec::from_array(&env, [0, 2_000, 5_000]));
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &1_000,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    client.set_reputation_boosted(&admin, &beneficiary, &0, &true);
+
+    // Tier 0: no boost
+    env.ledger().set_timestamp(start_time + 400);
+    assert_eq!(client.get_claimable(&beneficiary, &0), 400);
+
+    // Tier 1 cuts 20%: 400 of 800 seconds
+    registry_client.set_tier(&beneficiary, &1);
+    assert_eq!(client.get_claimable(&beneficiary, &0), 500);
+
+    // Tiers past the table use its last entry: 400 of 500 seconds
+    registry_client.set_tier(&beneficiary, &7);
+    client.claim(&beneficiary, &0, &None, &None);
+    assert_eq!(token_client.balance(&beneficiary), 800);
+
+    // Dropping a tier never claws back what was paid
+    registry_client.set_tier(&beneficiary, &0);
+    assert_eq!(client.get_claimable(&beneficiary, &0), 0);
+
+    client.set_reputation_boosted(&admin, &beneficiary, &0, &false);
+    registry_client.set_tier(&beneficiary, &2);
+    assert_eq!(client.get_claimable(&beneficiary, &0), 0);
+}
+
+#[test]
+fn test_revoke_after_tier_drop_keeps_paid_out_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let treasury = Address::generate(&env);
+    client.set_treasury(&admin, &treasury);
+    let registry = env.register(MockRegistry, ());
+    let registry_client = MockRegistryClient::new(&env, &registry);
+    client.set_reputation_registry(&admin, &Some(registry.clone()));
+    client.set_tier_boosts(&admin, &Vec::from_array(&env, [0, 5_000]));
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &1_000,
+        &0,
+        &VestingCurve::Linear,
+        &true,
+    );
+    client.set_reputation_boosted(&admin, &beneficiary, &0, &true);
+
+    // 400 of 500 boosted seconds are claimed, then the tier drops back to 0
+    registry_client.set_tier(&beneficiary, &1);
+    env.ledger().set_timestamp(start_time + 400);
+    client.claim(&beneficiary, &0, &None, &None);
+    registry_client.set_tier(&beneficiary, &0);
+
+    // The unboosted timeline only vests 400, but 800 is already paid out;
+    // only the 200 never released goes back
+    assert_eq!(client.revoke(&admin, &beneficiary, &0), 200);
+    assert_eq!(token_client.balance(&treasury), 200);
+    assert_eq!(token_client.balance(&beneficiary), 800);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(client.get_claimable(&beneficiary, &0), 0);
+
+    let stats = client.stats();
+    assert_eq!(stats.total_committed, 0);
+    assert_eq!(stats.total_revoked, 200);
+    assert_eq!(stats.active_beneficiaries, 0);
+}
+
+#[test]
+fn test_set_tier_boosts_rejects_full_cut() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, _, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    assert_eq!(
+        client.try_set_tier_boosts(&admin, &Vec::from_array(&env, [0, 10_000])),
+        Err(Ok(VestingError::InvalidBoost))
+    );
+}
+
+#[test]
+fn test_extension_needs_both_parties() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &1_000,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+
+    assert_eq!(
+        client.try_accept_extension(&beneficiary, &0, &2_000),
+        Err(Ok(VestingError::NoPendingExtension))
+    );
+    assert_eq!(
+        client.try_propose_extension(&admin, &beneficiary, &0, &1_000),
+        Err(Ok(VestingError::InvalidDuration))
+    );
+
+    client.propose_extension(&admin, &beneficiary, &0, &2_000);
+    assert_eq!(client.get_pending_extension(&beneficiary, &0), Some(2_000));
+    // Proposing alone leaves the schedule untouched
+    assert_eq!(client.get_vesting(&beneficiary, &0).duration, 1_000);
+    assert_eq!(
+        client.try_accept_extension(&beneficiary, &0, &3_000),
+        Err(Ok(VestingError::InvalidDuration))
+    );
+
+    client.accept_extension(&beneficiary, &0, &2_000);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, beneficiary);
+    assert_eq!(client.get_vesting(&beneficiary, &0).duration, 2_000);
+    assert_eq!(client.get_pending_extension(&beneficiary, &0), None);
+
+    env.ledger().set_timestamp(start_time + 1_000);
+    assert_eq!(client.get_claimable(&beneficiary, &0), 500);
+}
+
+#[test]
+fn test_claim_fee_goes_to_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let fee_treasury = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_claim_fee(&admin, &1_001, &fee_treasury),
+        Err(Ok(VestingError::InvalidFee))
+    );
+    client.set_claim_fee(&admin, &250, &fee_treasury);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &1_000,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+
+    env.ledger().set_timestamp(start_time + 400);
+    client.claim(&beneficiary, &0, &None, &None);
+    let (_, data) = last_event(&env);
+    assert_eq!(
+        i128::from_val(&env, &data.get(Symbol::new(&env, "fee")).unwrap()),
+        10
+    );
+    assert_eq!(token_client.balance(&beneficiary), 390);
+    assert_eq!(token_client.balance(&fee_treasury), 10);
+    // The schedule is charged the gross amount
+    assert_eq!(client.get_vesting(&beneficiary, &0).claimed_amount, 400);
+
+    client.set_claim_fee(&admin, &0, &fee_treasury);
+    assert_eq!(client.get_claim_fee(), None);
+    env.ledger().set_timestamp(start_time + 1_000);
+    client.claim(&beneficiary, &0, &None, &None);
+    assert_eq!(token_client.balance(&beneficiary), 990);
+    assert_eq!(token_client.balance(&fee_treasury), 10);
+}
+
+#[test]
+fn test_cancel_vesting_before_start() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp() + 100;
+    let entries = Vec::from_array(
+        &env,
+        [
+            new_vesting(&beneficiary, 1_000, start_time),
+            new_vesting(&beneficiary, 2_000, start_time),
+        ],
+    );
+    client.create_vestings(&admin, &entries);
+    let admin_balance = token_client.balance(&admin);
+
+    assert_eq!(client.cancel_vesting(&admin, &beneficiary, &0), 1_000);
+    assert_eq!(token_client.balance(&admin), admin_balance + 1_000);
+    assert_eq!(token_client.balance(&contract_id), 2_000);
+    assert_eq!(client.total_locked(&token_client.address), 2_000);
+    assert_eq!(
+        client.try_get_vesting(&beneficiary, &0),
+        Err(Ok(VestingError::VestingNotFound))
+    );
+    assert_eq!(client.list_vestings(&0, &10).len(), 1);
+    assert_eq!(client.stats().active_beneficiaries, 1);
+
+    env.ledger().set_timestamp(start_time);
+    assert_eq!(
+        client.try_cancel_vesting(&admin, &beneficiary, &1),
+        Err(Ok(VestingError::ScheduleStarted))
+    );
+}
+
+#[test]
+fn test_vesting_ttl_is_extended() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &1_000,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    let ttl = || {
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .get_ttl(&DataKey::Vesting(beneficiary.clone(), 0))
+        })
+    };
+    assert_eq!(ttl(), PERSISTENT_BUMP_AMOUNT);
+
+    env.ledger().set_timestamp(start_time + 100);
+    client.claim(&beneficiary, &0, &None, &None);
+
+    let max_ttl = env.as_contract(&contract_id, || env.storage().max_ttl());
+    client.extend_vesting_ttl(&beneficiary, &0, &max_ttl);
+    assert_eq!(ttl(), max_ttl);
+    // Everything the schedule is read through outlives quiet stretches too
+    env.as_contract(&contract_id, || {
+        for key in [
+            DataKey::VestingCount(beneficiary.clone()),
+            DataKey::OpenSchedules(beneficiary.clone()),
+            DataKey::SchedulePosition(beneficiary.clone(), 0),
+            DataKey::ScheduleAt(0),
+            DataKey::ClaimCount(0),
+            DataKey::Claim(0, 0),
+        ] {
+            assert_eq!(env.storage().persistent().get_ttl(&key), max_ttl);
+        }
+    });
+    assert_eq!(
+        client.extend_claim_history_ttl(&beneficiary, &0, &0, &max_ttl),
+        1
+    );
+
+    assert_eq!(
+        client.try_extend_vesting_ttl(&beneficiary, &0, &(max_ttl + 1)),
+        Err(Ok(VestingError::InvalidTtl))
+    );
+    assert_eq!(
+        client.try_extend_vesting_ttl(&beneficiary, &1, &max_ttl),
+        Err(Ok(VestingError::VestingNotFound))
+    );
+}
+
+#[test]
+fn test_unlock_schedule_samples_curve() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &1_000,
+        &250,
+        &VestingCurve::Linear,
+        &false,
+    );
+
+    let timetable = client.unlock_schedule(&beneficiary, &0, &5);
+    assert_eq!(
+        timetable,
+        Vec::from_array(
+            &env,
+            [
+                (start_time, 0),
+                (start_time + 250, 250),
+                (start_time + 500, 500),
+                (start_time + 750, 750),
+                (start_time + 1_000, 1_000),
+            ]
+        )
+    );
+    // Each sample matches what the contract would pay at that time
+    let (at, vested) = timetable.get(2).unwrap();
+    env.ledger().set_timestamp(at);
+    assert_eq!(client.get_claimable(&beneficiary, &0), vested);
+
+    assert_eq!(
+        client.unlock_schedule(&beneficiary, &0, &1),
+        Vec::from_array(&env, [(start_time + 1_000, 1_000)])
+    );
+    assert_eq!(client.unlock_schedule(&beneficiary, &0, &500).len(), 100);
+}
+
+#[test]
+fn test_create_vesting_strict_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    let start_time = env.ledger().timestamp();
+    assert_eq!(
+        client.try_create_vesting(
+            &admin,
+            &beneficiary,
+            &1_000,
+            &u64::MAX,
+            &1_000,
+            &0,
+            &VestingCurve::Linear,
+            &false,
+        ),
+        Err(Ok(VestingError::TimelineOverflow))
+    );
+    assert_eq!(
+        client.try_create_vesting(
+            &admin,
+            &beneficiary,
+            &-1,
+            &start_time,
+            &1_000,
+            &0,
+            &VestingCurve::Linear,
+            &false,
+        ),
+        Err(Ok(VestingError::InvalidAmount))
+    );
+    let overflowing = Vec::from_array(
+        &env,
+        [
+            new_vesting(&beneficiary, i128::MAX, start_time),
+            new_vesting(&beneficiary, 1, start_time),
+        ],
+    );
+    assert_eq!(
+        client.try_create_vestings(&admin, &overflowing),
+        Err(Ok(VestingError::AmountOverflow))
+    );
+
+    // The same grant twice, in one batch or across calls
+    let twice = Vec::from_array(
+        &env,
+        [
+            new_vesting(&beneficiary, 1_000, start_time),
+            new_vesting(&beneficiary, 1_000, start_time),
+        ],
+    );
+    assert_eq!(
+        client.try_create_vestings(&admin, &twice),
+        Err(Ok(VestingError::DuplicateSchedule))
+    );
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &1_000,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    assert_eq!(
+        client.try_create_vesting(
+            &admin,
+            &beneficiary,
+            &1_000,
+            &start_time,
+            &1_000,
+            &0,
+            &VestingCurve::Linear,
+            &true,
+        ),
+        Err(Ok(VestingError::DuplicateSchedule))
+    );
+}
+
+#[test]
+fn test_sweep_waits_for_revocation_grace() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let treasury = Address::generate(&env);
+    client.set_treasury(&admin, &treasury);
+    client.set_revocation_grace(&admin, &1_000);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &1_000,
+        &0,
+        &VestingCurve::Linear,
+        &true,
+    );
+    assert_eq!(
+        client.try_sweep_revoked(&admin, &beneficiary, &0),
+        Err(Ok(VestingError::NotRevoked))
+    );
+
+    env.ledger().set_timestamp(start_time + 600);
+    client.revoke(&admin, &beneficiary, &0);
+    assert_eq!(token_client.balance(&treasury), 400);
+    // A later change does not shorten a deadline already granted
+    client.set_revocation_grace(&admin, &0);
+
+    env.ledger().set_timestamp(start_time + 1_599);
+    assert_eq!(
+        client.try_sweep_revoked(&admin, &beneficiary, &0),
+        Err(Ok(VestingError::GracePeriodActive))
+    );
+    client.claim(&beneficiary, &0, &None, &Some(100));
+
+    env.ledger().set_timestamp(start_time + 1_600);
+    assert_eq!(client.sweep_revoked(&admin, &beneficiary, &0), 500);
+    assert_eq!(token_client.balance(&treasury), 900);
+    assert_eq!(client.get_claimable(&beneficiary, &0), 0);
+    assert_eq!(client.total_locked(&token_client.address), 0);
+    assert_eq!(
+        client.try_sweep_revoked(&admin, &beneficiary, &0),
+        Err(Ok(VestingError::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_transfer_position() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let buyer = Address::generate(&env);
+
+    let start_time = env.ledger().timestamp();
+    client.create_vesting(
+        &admin,
+        &beneficiary,
+        &1_000,
+        &start_time,
+        &1_000,
+        &0,
+        &VestingCurve::Linear,
+        &false,
+    );
+    env.ledger().set_timestamp(start_time + 300);
+    client.claim(&beneficiary, &0, &None, &None);
+
+    assert_eq!(
+        client.try_transfer_position(&beneficiary, &buyer, &0),
+        Err(Ok(VestingError::PositionsNotTransferable))
+    );
+    client.set_positions_transferable(&admin, &true);
+    assert_eq!(
+        client.try_transfer_position(&buyer, &beneficiary, &0),
+        Err(Ok(VestingError::Unauthorized))
+    );
+
+    assert_eq!(client.transfer_position(&beneficiary, &buyer, &0), 0);
+    assert_eq!(client.position_owner(&0), (buyer.clone(), 0));
+    assert_eq!(client.get_vesting_count(&beneficiary), 1);
+    assert_eq!(
+        client.try_get_vesting(&beneficiary, &0),
+        Err(Ok(VestingError::VestingNotFound))
+    );
+
+    // The buyer collects what vests from here on
+    env.ledger().set_timestamp(start_time + 1_000);
+    assert_eq!(client.claim(&buyer, &0, &None, &None), 700);
+    assert_eq!(token_client.balance(&beneficiary), 300);
+    assert_eq!(token_client.balance(&buyer), 700);
+}
+
+#[test]
+fn test_claim_for_many_only_pays_opted_in() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let other = Address::generate(&env);
+    let keeper = Address::generate(&env);
+
+    let start_time = env.ledger().timestamp();
+    let mut periodic = new_vesting(&beneficiary, 1_000, start_time);
+    periodic.curve = VestingCurve::Periodic(250);
+    let entries = Vec::from_array(
+        &env,
+        [
+            new_vesting(&beneficiary, 1_000, start_time),
+            periodic,
+            new_vesting(&other, 1_000, start_time),
+        ],
+    );
+    client.create_vestings(&admin, &entries);
+    client.set_auto_claim(&beneficiary, &true);
+    assert!(client.is_auto_claim(&beneficiary));
+
+    env.ledger().set_timestamp(start_time + 600);
+    // Listing a schedule twice does not pay it twice
+    let batch = Vec::from_array(
+        &env,
+        [
+            (beneficiary.clone(), 0),
+            (beneficiary.clone(), 1),
+            (other.clone(), 0),
+            (beneficiary.clone(), 0),
+            (beneficiary.clone(), 7),
+        ],
+    );
+    assert_eq!(client.claim_for_many(&keeper, &batch), 1_100);
+    assert_eq!(token_client.balance(&beneficiary), 1_100);
+    assert_eq!(token_client.balance(&other), 0);
+    assert_eq!(token_client.balance(&keeper), 0);
+
+    // Nothing new to claim is not an error for keepers
+    assert_eq!(client.claim_for_many(&keeper, &batch), 0);
+
+    client.set_auto_claim(&beneficiary, &false);
+    env.ledger().set_timestamp(start_time + 1_000);
+    assert_eq!(client.claim_for_many(&keeper, &batch), 0);
+}
+
+#[test]
+fn test_program_tagging_and_stats() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, beneficiary, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    client.set_treasury(&admin, &admin);
+    let other = Address::generate(&env);
+    let seed = Symbol::new(&env, "seed");
+    let series_a = Symbol::new(&env, "series_a");
+
+    let start_time = env.ledger().timestamp();
+    let mut first = new_vesting(&beneficiary, 1_000, start_time);
+    first.program = Some(seed.clone());
+    let mut second = new_vesting(&other, 2_000, start_time);
+    second.program = Some(seed.clone());
+    second.revocable = true;
+    let entries = Vec::from_array(
+        &env,
+        [first, second, new_vesting(&other, 3_000, start_time)],
+    );
+    client.create_vestings(&admin, &entries);
+
+    let tagged = client.list_by_program(&seed, &0, &10);
+    assert_eq!(tagged.len(), 2);
+    assert_eq!(tagged.get(0).unwrap().program, Some(seed.clone()));
+
+    env.ledger().set_timestamp(start_time + 500);
+    client.claim(&beneficiary, &0, &None, &None);
+    client.revoke(&admin, &other, &0);
+    assert_eq!(
+        client.program_stats(&seed),
+        ProgramStats {
+            schedules: 2,
+            total_allocated: 3_000,
+            total_claimed: 500,
+            total_revoked: 1_000,
+        }
+    );
+
+    // Retagging carries the schedule's totals across programs
+    client.set_program(&admin, &beneficiary, &0, &Some(series_a.clone()));
+    assert_eq!(
+        client.program_stats(&seed),
+        ProgramStats {
+            schedules: 1,
+            total_allocated: 2_000,
+            total_claimed: 0,
+            total_revoked: 1_000,
+        }
+    );
+    assert_eq!(client.program_stats(&series_a).total_claimed, 500);
+    let remaining = client.list_by_program(&seed, &0, &10);
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining.get(0).unwrap().beneficiary, other);
+    assert_eq!(
+        client.program_stats(&Symbol::new(&env, "unused")),
+        ProgramStats::default()
+    );
+}
+
+#[test]
+fn test_migrate_legacy_vesting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    // A schedule written by the single-schedule layout, 300 already claimed
+    env.ledger().set_timestamp(1_000);
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(
+            &LegacyDataKey::Vesting(beneficiary.clone()),
+            &LegacyVestingData {
+                beneficiary: beneficiary.clone(),
+                total_amount: 1_000,
+                start_time: 0,
+                duration: 2_000,
+                claimed_amount: 300,
+            },
+        );
+    });
+    token_client.transfer(&admin, &contract_id, &700);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_migrate_legacy_vesting(&stranger, &beneficiary),
+        Err(Ok(VestingError::Unauthorized))
+    );
+    assert_eq!(client.migrate_legacy_vesting(&admin, &beneficiary), 0);
+    assert_eq!(
+        client.try_migrate_legacy_vesting(&admin, &beneficiary),
+        Err(Ok(VestingError::VestingNotFound))
+    );
+
+    let vesting = client.get_vesting(&beneficiary, &0);
+    assert_eq!(vesting.claimed_amount, 300);
+    assert_eq!(vesting.curve, VestingCurve::Linear);
+    assert_eq!(client.total_locked(&token_client.address), 700);
+    assert_eq!(client.total_claimed(&token_client.address), 300);
+    assert_eq!(client.get_schedule_count(), 1);
+
+    // Half the duration has passed: 500 vested, 200 of it still unclaimed
+    assert_eq!(client.claim(&beneficiary, &0, &None, &None), 200);
+    assert_eq!(client.total_locked(&token_client.address), 500);
+}
+
+#[test]
+fn test_schedule_creator_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, beneficiary, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    let creator = Address::generate(&env);
+    token_client.transfer(&admin, &creator, &1_000);
+    let start = env.ledger().timestamp();
+
+    let create = |by: &Address| {
+        client.try_create_vesting(
+            by,
+            &beneficiary,
+            &500,
+            &start,
+            &1_000,
+            &0,
+            &VestingCurve::Linear,
+            &false,
+        )
+    };
+    assert_eq!(create(&creator), Err(Ok(VestingError::Unauthorized)));
+
+    client.set_schedule_creator(&admin, &creator, &true);
+    assert!(client.is_schedule_creator(&creator));
+    assert_eq!(create(&creator), Ok(Ok(0)));
+    assert_eq!(token_client.balance(&creator), 500);
+    assert_eq!(token_client.balance(&contract_id), 500);
+    // The role grants nothing beyond creating schedules
+    assert_eq!(
+        client.try_set_treasury(&creator, &creator),
+        Err(Ok(VestingError::Unauthorized))
+    );
+
+    client.set_schedule_creator(&admin, &creator, &false);
+    assert_eq!(create(&creator), Err(Ok(VestingError::Unauthorized)));
+    assert_eq!(
+        client.try_set_schedule_creator(&creator, &creator, &true),
+        Err(Ok(VestingError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_create_vestings_at_cap_fits_one_transaction() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, _, token_client, contract_id) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+
+    // Worst case: a new beneficiary and a new program per entry
+    let start_time = env.ledger().timestamp() + 10;
+    let mut entries = Vec::new(&env);
+    for i in 0..MAX_BATCH_SIZE {
+        let mut entry = new_vesting(&Address::generate(&env), 1_000, start_time);
+        entry.program = Some(Symbol::new(&env, ["a", "b", "c", "d", "e"][i as usize]));
+        entries.push_back(entry);
+    }
+    client.create_vestings(&admin, &entries);
+    let resources = env.cost_estimate().resources();
+    assert!(resources.write_entries <= 50);
+    assert!(resources.disk_read_entries + resources.memory_read_entries <= 100);
+    assert_eq!(
+        token_client.balance(&contract_id),
+        1_000 * MAX_BATCH_SIZE as i128
+    );
+
+    entries.push_back(new_vesting(&admin, 1_000, start_time));
+    assert_eq!(
+        client.try_create_vestings(&admin, &entries),
+        Err(Ok(VestingError::InvalidBatch))
+    );
+}
+
+#[test]
+fn test_claim_for_many_at_cap_fits_one_transaction() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, _, token_client, _) = setup_test(&env);
+    client.initialize(&admin, &token_client.address);
+    client.set_claim_fee(&admin, &100, &admin);
+    let keeper = Address::generate(&env);
+
+    // Worst case: every schedule is tagged and claimed out in full
+    let start_time = env.ledger().timestamp();
+    let mut batch = Vec::new(&env);
+    for _ in 0..MAX_CLAIM_BATCH {
+        let beneficiary = Address::generate(&env);
+        let mut entry = new_vesting(&beneficiary, 1_000, start_time);
+        entry.program = Some(Symbol::new(&env, "grants"));
+        client.create_vestings(&admin, &Vec::from_array(&env, [entry]));
+        client.set_auto_claim(&beneficiary, &true);
+        batch.push_back((beneficiary, 0));
+    }
+
+    env.ledger().set_timestamp(start_time + 1_000);
+    assert_eq!(
+        client.claim_for_many(&keeper, &batch),
+        1_000 * MAX_CLAIM_BATCH as i128
+    );
+    let resources = env.cost_estimate().resources();
+    assert!(resources.write_entries <= 50);
+    assert!(resources.disk_read_entries + resources.memory_read_entries <= 100);
+
+    batch.push_back((keeper.clone(), 0));
+    assert_eq!(
+        client.try_claim_for_many(&keeper, &batch),
+        Err(Ok(VestingError::InvalidBatch))
+    );
+}
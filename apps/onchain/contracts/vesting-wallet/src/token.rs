@@ -7,7 +7,6 @@ pub fn transfer(env: &Env, token: &Address, from: &Address, to: &Address, amount
 }
 
 /// Get the balance of an address for a given token
-#[allow(dead_code)]
 pub fn balance(env: &Env, token: &Address, address: &Address) -> i128 {
     let token_client = soroban_sdk::token::Client::new(env, token);
     token_client.balance(address)